@@ -0,0 +1,107 @@
+//! Benchmarks for parsing frames from a read buffer, and serializing them to be written.
+
+use bytes::{Bytes, BytesMut};
+use criterion::{Criterion, criterion_group, criterion_main};
+use redis_asyncx::{Connection, Frame};
+use std::hint::black_box;
+use std::io::Cursor;
+
+/// Builds the reply of e.g. LRANGE on a list of `len` elements.
+fn array_reply(len: usize) -> BytesMut {
+    let mut buf = BytesMut::new();
+    buf.extend_from_slice(format!("*{}\r\n", len).as_bytes());
+
+    for i in 0..len {
+        let value = format!("value:{}", i);
+        buf.extend_from_slice(format!("${}\r\n{}\r\n", value.len(), value).as_bytes());
+    }
+
+    buf
+}
+
+/// Builds an array of `len` integers, e.g. the reply of a pipeline of INCR commands.
+fn integer_reply(len: usize) -> BytesMut {
+    let mut buf = BytesMut::new();
+    buf.extend_from_slice(format!("*{}\r\n", len).as_bytes());
+
+    for i in 0..len {
+        buf.extend_from_slice(format!(":{}\r\n", i).as_bytes());
+    }
+
+    buf
+}
+
+/// Builds the reply of a GET on a value of `len` bytes.
+fn bulk_reply(len: usize) -> BytesMut {
+    let mut buf = BytesMut::new();
+    buf.extend_from_slice(format!("${}\r\n", len).as_bytes());
+    buf.extend_from_slice(&vec![b'x'; len]);
+    buf.extend_from_slice(b"\r\n");
+
+    buf
+}
+
+fn parse_array(c: &mut Criterion) {
+    let bulk_strings = array_reply(10_000);
+    let integers = integer_reply(10_000);
+
+    c.bench_function("parse 10k bulk string array", |b| {
+        b.iter(|| Frame::try_parse(&mut Cursor::new(black_box(&bulk_strings[..]))))
+    });
+
+    c.bench_function("parse 10k integer array", |b| {
+        b.iter(|| Frame::try_parse(&mut Cursor::new(black_box(&integers[..]))))
+    });
+}
+
+fn parse_large_bulk_string(c: &mut Criterion) {
+    let buf = bulk_reply(16 * 1024 * 1024).freeze();
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap_or_else(|err| panic!("Failed to build runtime: {:?}", err));
+
+    // copies the payload out of the buffer
+    c.bench_function("parse 16MB bulk string", |b| {
+        b.iter(|| Frame::try_parse(&mut Cursor::new(black_box(&buf[..]))))
+    });
+
+    // slices the payload out of the buffer, the way replies are read from a connection
+    c.bench_function("deserialize 16MB bulk string", |b| {
+        b.iter(|| runtime.block_on(Frame::deserialize(black_box(buf.clone()))))
+    });
+}
+
+fn serialize_large_bulk_string(c: &mut Criterion) {
+    let frame = Frame::Array(vec![
+        Frame::bulk("SET"),
+        Frame::bulk("mykey"),
+        Frame::BulkString(Bytes::from(vec![b'x'; 8 * 1024 * 1024])),
+    ]);
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap_or_else(|err| panic!("Failed to build runtime: {:?}", err));
+
+    // copies the value into the encoding
+    c.bench_function("serialize 8MB SET", |b| {
+        b.iter(|| runtime.block_on(black_box(&frame).serialize()))
+    });
+
+    // shares the value between the chunks, the way commands are written to a connection
+    c.bench_function("serialize_chunks 8MB SET", |b| {
+        b.iter(|| runtime.block_on(black_box(&frame).serialize_chunks()))
+    });
+
+    // the whole write, into a stream that discards the bytes
+    let mut conn = Connection::new(tokio::io::join(tokio::io::empty(), tokio::io::sink()));
+    c.bench_function("write 8MB SET", |b| {
+        b.iter(|| runtime.block_on(conn.write_frame(black_box(&frame))))
+    });
+}
+
+criterion_group!(
+    benches,
+    parse_array,
+    parse_large_bulk_string,
+    serialize_large_bulk_string
+);
+criterion_main!(benches);
@@ -0,0 +1,18 @@
+//! Feeds arbitrary bytes into the frame parser, which must return an error rather than panic.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use redis_asyncx::Frame;
+use std::io::Cursor;
+
+fuzz_target!(|data: &[u8]| {
+    let mut cursor = Cursor::new(data);
+
+    // keep parsing as a connection would, until the buffer runs out or the parser gives up
+    while let Ok(_frame) = Frame::try_parse(&mut cursor) {
+        if cursor.position() as usize >= data.len() {
+            break;
+        }
+    }
+});
@@ -12,6 +12,7 @@
 //! - `GET`: Retrieve the value of a key.
 //! - `SET`: Set the value of a key.
 //! - `DEL`: Delete a key.
+//! - `UNLINK`: Delete a key in the background.
 //! - `EXISTS`: Check if a key exists.
 //! - `INFO`: Get information about the server.
 //! - `FLUSHDB`: Flush the current database.
@@ -38,14 +39,26 @@
 //! - `ZCARD`: Get the number of members in a sorted set.
 //! - `ZCOUNT`: Get the number of members in a sorted set with scores within a given range.
 //! - `ZINCRBY`: Increment the score of a member in a sorted set.
+//!
+//! Any other command, e.g. `CONFIG GET maxmemory`, is sent to the server as is and its reply
+//! printed whatever its shape.
+//!
+//! `--latency` pings the server in a loop and prints the min, max and average latency, and in
+//! interactive mode `timing on` prints how long each command took after its reply.
 
+use anyhow::anyhow;
 use bytes::Bytes;
 use clap::{Parser, Subcommand};
 use colored::Colorize;
-use redis_asyncx::{Client, Result};
+use redis_asyncx::{Client, Frame, Pong, Result};
 use shlex::split;
 use std::io::{self, Write};
 use std::str;
+use std::time::Duration;
+use tokio::time::Instant;
+
+// how long `--latency` waits between two pings, the same as redis-cli
+const LATENCY_INTERVAL: Duration = Duration::from_millis(10);
 
 #[derive(Parser, Debug)]
 #[command(name = "redis-async-cli")]
@@ -56,6 +69,38 @@ struct Cli {
     host: String,
     #[arg(short, long, default_value = "6379", help = "Redis server port.")]
     port: u16,
+    #[arg(long, help = "List all keys using the SCAN command.")]
+    scan: bool,
+    #[arg(long, help = "Keys pattern when using the --scan option.")]
+    pattern: Option<String>,
+    #[arg(
+        long,
+        help = "Count hint when using the --scan option, number of samples with --latency."
+    )]
+    count: Option<u64>,
+    #[arg(
+        long,
+        help = "Enter a special mode continuously sampling latency, until Ctrl+C or --count samples."
+    )]
+    latency: bool,
+    #[arg(long, help = "Use raw formatting for replies.")]
+    raw: bool,
+    #[arg(
+        short,
+        long,
+        default_value = "1",
+        allow_negative_numbers = true,
+        help = "Execute the command N times, -1 to repeat until interrupted."
+    )]
+    repeat: i64,
+    #[arg(
+        short,
+        long,
+        default_value = "0",
+        value_parser = parse_interval,
+        help = "When -r is used, wait <INTERVAL> seconds between commands. Fractions like 0.1 are allowed."
+    )]
+    interval: Duration,
     #[command(flatten)]
     verbose: clap_verbosity_flag::Verbosity,
     // Redis command
@@ -101,6 +146,11 @@ enum RedisCommand {
         /// Keys to delete.
         keys: Vec<String>,
     },
+    /// Delete a key in the background, without blocking the server.
+    Unlink {
+        /// Keys to delete.
+        keys: Vec<String>,
+    },
     /// Check if a key exists.
     Exists {
         /// Keys to check.
@@ -169,182 +219,132 @@ enum RedisCommand {
     },
     /// Clear the screen.
     Clear,
+    /// Any other command, sent as is. Arguments may contain `\xHH`, `\n`, `\r` and `\t` escapes.
+    #[command(external_subcommand)]
+    Raw(Vec<String>),
 }
 
 impl RedisCommand {
     async fn execute(&self, client: &mut Client) -> Result<()> {
-        match self {
+        let reply = match self {
             RedisCommand::Hello { proto } => {
                 let response = client.hello(*proto).await?;
 
-                for (key, value) in response {
-                    if let Ok(string) = str::from_utf8(&value) {
-                        println!("\"{}\" => \"{}\"", key, string);
-                    } else {
-                        println!("\"{}\" => {:?}", key, value);
-                    }
-                }
+                Frame::Map(vec![
+                    (Frame::bulk("server"), Frame::bulk(response.server)),
+                    (Frame::bulk("version"), Frame::bulk(response.version)),
+                    (Frame::bulk("proto"), Frame::int(response.proto.into())),
+                    (Frame::bulk("id"), Frame::int(response.id)),
+                    (Frame::bulk("mode"), Frame::bulk(response.mode)),
+                    (Frame::bulk("role"), Frame::bulk(response.role)),
+                    (
+                        Frame::bulk("modules"),
+                        Frame::Array(response.modules.into_iter().map(Frame::bulk).collect()),
+                    ),
+                ])
             }
             RedisCommand::Ping { message } => {
                 let message = message.as_deref();
 
-                let response = client.ping(message).await?;
-                if let Ok(string) = str::from_utf8(&response) {
-                    // we need to format simple string and bulk string differently
-                    // simple string: no quotes
-                    // bulk string: with quotes
-                    if message.is_some() {
-                        println!("\"{}\"", string);
-                    } else {
-                        println!("PONG");
-                    }
-                } else {
-                    println!("{response:?}");
-                }
-            }
-            RedisCommand::Get { key } => {
-                let response = client.get(key).await?;
-                if let Some(value) = response {
-                    if let Ok(string) = str::from_utf8(&value) {
-                        println!("\"{}\"", string);
-                    } else {
-                        println!("{:?}", value);
-                    }
-                } else {
-                    println!("(nil)");
-                }
-            }
-            RedisCommand::Set { key, value } => {
-                let response = client.set(key, value).await?;
-                if let Some(value) = response {
-                    if let Ok(string) = str::from_utf8(&value) {
-                        println!("{}", string);
-                    } else {
-                        println!("{:?}", value);
-                    }
-                } else {
-                    println!("(nil)");
+                match client.ping(message).await? {
+                    Pong::Pong => Frame::simple("PONG"),
+                    Pong::Echo(data) => Frame::bulk(data),
                 }
             }
+            RedisCommand::Get { key } => match client.get(key).await? {
+                Some(value) => Frame::bulk(value),
+                None => Frame::Null,
+            },
+            RedisCommand::Set { key, value } => match client.set(key, value).await? {
+                Some(value) => Frame::simple(String::from_utf8_lossy(&value)),
+                None => Frame::Null,
+            },
             RedisCommand::Del { keys } => {
                 let response = client
                     .del(keys.iter().map(String::as_str).collect::<Vec<&str>>())
                     .await?;
-                println!("{response:?}");
+                Frame::int(response.try_into()?)
+            }
+            RedisCommand::Unlink { keys } => {
+                let response = client
+                    .unlink(keys.iter().map(String::as_str).collect::<Vec<&str>>())
+                    .await?;
+                Frame::int(response.try_into()?)
             }
             RedisCommand::Exists { keys } => {
                 let response = client
                     .exists(keys.iter().map(String::as_str).collect::<Vec<&str>>())
                     .await?;
-                println!("(integer) {response}");
+                Frame::int(response.try_into()?)
             }
             RedisCommand::Expire { key, seconds } => {
-                let response = client.expire(key, *seconds).await?;
-                println!("(integer) {response}");
-            }
-            RedisCommand::Ttl { key } => {
-                let response = client.ttl(key).await?;
-                println!("(integer) {response}");
-            }
-            RedisCommand::Incr { key } => {
-                let response = client.incr(key).await?;
-                println!("(integer) {response}");
-            }
-            RedisCommand::Decr { key } => {
-                let response = client.decr(key).await?;
-                println!("(integer) {response}");
+                Frame::int(client.expire(key, *seconds).await?.try_into()?)
             }
+            RedisCommand::Ttl { key } => Frame::int(client.ttl(key).await?),
+            RedisCommand::Incr { key } => Frame::int(client.incr(key).await?),
+            RedisCommand::Decr { key } => Frame::int(client.decr(key).await?),
             RedisCommand::Lpush { key, values } => {
                 let response = client
                     .lpush(key, values.iter().map(|s| s.as_bytes()).collect())
                     .await?;
-                println!("(integer) {response}");
+                Frame::int(response.try_into()?)
             }
             RedisCommand::Rpush { key, values } => {
                 let response = client
                     .rpush(key, values.iter().map(|s| s.as_bytes()).collect())
                     .await?;
-                println!("(integer) {response}");
-            }
-            RedisCommand::Lpop { key, count } => {
-                match count {
-                    Some(count) => {
-                        // multiple pop
-                        if let Some(response) = client.lpop_n(key, *count).await? {
-                            for line in response {
-                                if let Ok(string) = str::from_utf8(&line) {
-                                    println!("\"{}\"", string);
-                                } else {
-                                    println!("{line:?}");
-                                }
-                            }
-                        } else {
-                            println!("(nil)");
-                        }
-                    }
-                    None => {
-                        // single pop
-                        if let Some(response) = client.lpop(key).await? {
-                            if let Ok(string) = str::from_utf8(&response) {
-                                println!("\"{}\"", string);
-                            } else {
-                                println!("{response:?}");
-                            }
-                        } else {
-                            println!("(nil)");
-                        }
-                    }
-                }
-            }
-            RedisCommand::Rpop { key, count } => {
-                match count {
-                    Some(count) => {
-                        // multiple pop
-                        if let Some(response) = client.rpop_n(key, *count).await? {
-                            for line in response {
-                                if let Ok(string) = str::from_utf8(&line) {
-                                    println!("\"{}\"", string);
-                                } else {
-                                    println!("{line:?}");
-                                }
-                            }
-                        } else {
-                            println!("(nil)");
-                        }
-                    }
-                    None => {
-                        // single pop
-                        if let Some(response) = client.rpop(key).await? {
-                            if let Ok(string) = str::from_utf8(&response) {
-                                println!("\"{}\"", string);
-                            } else {
-                                println!("{response:?}");
-                            }
-                        } else {
-                            println!("(nil)");
-                        }
-                    }
-                }
+                Frame::int(response.try_into()?)
             }
+            RedisCommand::Lpop { key, count } => match count {
+                // multiple pop
+                Some(count) => bulk_array(client.lpop_n(key, *count).await?),
+                // single pop
+                None => match client.lpop(key).await? {
+                    Some(value) => Frame::bulk(value),
+                    None => Frame::Null,
+                },
+            },
+            RedisCommand::Rpop { key, count } => match count {
+                // multiple pop
+                Some(count) => bulk_array(client.rpop_n(key, *count).await?),
+                // single pop
+                None => match client.rpop(key).await? {
+                    Some(value) => Frame::bulk(value),
+                    None => Frame::Null,
+                },
+            },
             RedisCommand::Lrange { key, start, end } => {
-                let response = client.lrange(key, *start, *end).await?;
-                for line in response {
-                    if let Ok(string) = str::from_utf8(&line) {
-                        println!("\"{}\"", string);
-                    } else {
-                        println!("{line:?}");
-                    }
-                }
+                bulk_array(Some(client.lrange(key, *start, *end).await?))
             }
             RedisCommand::Clear => {
                 clear_screen();
+                return Ok(());
             }
-        }
+            RedisCommand::Raw(args) => {
+                let args: Vec<Vec<u8>> = args.iter().map(|arg| unescape_arg(arg)).collect();
+
+                match client.command_raw(&args).await? {
+                    Some(reply) => reply,
+                    None => return Err(anyhow!("connection closed by the server").into()),
+                }
+            }
+        };
+
+        // nested replies are printed one element per line, like redis-cli does
+        println!("{reply}");
 
         Ok(())
     }
 }
 
+/// Turns a list of values into an array reply, or a nil reply if there is no list.
+fn bulk_array(values: Option<Vec<Bytes>>) -> Frame {
+    match values {
+        Some(values) => Frame::Array(values.into_iter().map(Frame::bulk).collect()),
+        None => Frame::Null,
+    }
+}
+
 #[tokio::main(flavor = "current_thread")]
 async fn main() -> Result<()> {
     // Collect raw arguments and normalize subcommands to lowercase
@@ -364,12 +364,23 @@ async fn main() -> Result<()> {
     // Connect to the Redis server
     let mut client = Client::connect(&addr).await?;
 
-    if let Some(command) = cli.command {
+    if cli.scan {
+        // --scan takes precedence over any subcommand
+        scan_keys(&mut client, cli.pattern.as_deref(), cli.count, cli.raw).await?;
+    } else if cli.latency {
+        sample_latency(&mut client, cli.count).await?;
+    } else if let Some(command) = cli.command {
         // If a command is provided, execute it
-        command.execute(&mut client).await?;
+        if cli.repeat == 1 {
+            command.execute(&mut client).await?;
+        } else {
+            repeat_command(&command, &mut client, cli.repeat, cli.interval).await;
+        }
     } else {
         // Interactive mode if no command is provided
         println!("{}", "Interactive mode. Type 'exit' to quit.".green());
+        // toggled with `timing on` and `timing off`
+        let mut timing = false;
 
         loop {
             print!("{addr}> "); // Print the prompt
@@ -383,17 +394,28 @@ async fn main() -> Result<()> {
                 break;
             }
 
-            if let Some(args) = split(input) {
-                if args.is_empty() {
+            match input {
+                "timing on" => {
+                    timing = true;
+                    continue;
+                }
+                "timing off" => {
+                    timing = false;
                     continue;
                 }
-            } else {
-                eprintln!("Error parsing input: {input}");
-                continue;
+                _ => {}
             }
 
+            let mut args = match split(input) {
+                Some(args) if args.is_empty() => continue,
+                Some(args) => args,
+                None => {
+                    eprintln!("Error parsing input: {input}");
+                    continue;
+                }
+            };
+
             // Convert the first argument to lowercase
-            let mut args = args.to_vec();
             let lowercased = args[0].to_lowercase();
             args[0] = lowercased;
 
@@ -405,7 +427,9 @@ async fn main() -> Result<()> {
                 Ok(cli) => {
                     // If a command is provided, execute it
                     if let Some(command) = cli.command {
+                        let start = Instant::now();
                         match command.execute(&mut client).await {
+                            Ok(_) if timing => println!("({})", format_millis(start.elapsed())),
                             Ok(_) => {}
                             Err(e) => {
                                 eprintln!("Error executing command: {e}");
@@ -429,6 +453,245 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+/// Executes the command `repeat` times (forever if negative), sleeping `interval` between runs.
+///
+/// Errors do not stop the loop. Ctrl+C stops it early, and a summary of the
+/// successful and failed runs is printed to stderr in both cases.
+async fn repeat_command(
+    command: &RedisCommand,
+    client: &mut Client,
+    repeat: i64,
+    interval: Duration,
+) {
+    let mut succeeded: u64 = 0;
+    let mut failed: u64 = 0;
+
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+
+    let mut run: i64 = 0;
+    while repeat < 0 || run < repeat {
+        if run > 0 && !interval.is_zero() {
+            tokio::select! {
+                _ = &mut ctrl_c => break,
+                _ = tokio::time::sleep(interval) => {}
+            }
+        }
+
+        tokio::select! {
+            _ = &mut ctrl_c => break,
+            result = command.execute(client) => match result {
+                Ok(_) => succeeded += 1,
+                Err(e) => {
+                    eprintln!("Error executing command: {e}");
+                    failed += 1;
+                }
+            },
+        }
+
+        run += 1;
+    }
+
+    eprintln!("{succeeded} succeeded, {failed} failed");
+}
+
+/// Pings the server every `LATENCY_INTERVAL`, refreshing a line with the latency so far, until
+/// Ctrl+C or `count` samples. A summary with the percentiles is printed last.
+async fn sample_latency(client: &mut Client, count: Option<u64>) -> Result<()> {
+    let mut latencies = Latencies::default();
+    let mut stdout = io::stdout();
+
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+
+    while count.is_none_or(|count| latencies.len() < count) {
+        if !latencies.is_empty() {
+            tokio::select! {
+                _ = &mut ctrl_c => break,
+                _ = tokio::time::sleep(LATENCY_INTERVAL) => {}
+            }
+        }
+
+        let start = Instant::now();
+        tokio::select! {
+            _ = &mut ctrl_c => break,
+            result = client.ping(None) => {
+                result?;
+            }
+        }
+        latencies.push(start.elapsed());
+
+        // \r and erase the line, to refresh it in place
+        write!(stdout, "\r\x1B[K{}", latencies.status())?;
+        stdout.flush()?;
+    }
+
+    if !latencies.is_empty() {
+        writeln!(stdout)?;
+    }
+    writeln!(stdout, "{}", latencies.summary())?;
+
+    Ok(())
+}
+
+/// The latencies sampled by `--latency`.
+#[derive(Default)]
+struct Latencies {
+    samples: Vec<Duration>,
+    min: Duration,
+    max: Duration,
+    total: Duration,
+}
+
+impl Latencies {
+    fn push(&mut self, latency: Duration) {
+        if self.samples.is_empty() || latency < self.min {
+            self.min = latency;
+        }
+        self.max = self.max.max(latency);
+        self.total += latency;
+        self.samples.push(latency);
+    }
+
+    fn len(&self) -> u64 {
+        self.samples.len() as u64
+    }
+
+    fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    fn avg(&self) -> Duration {
+        match self.samples.len() {
+            0 => Duration::ZERO,
+            len => self.total.div_f64(len as f64),
+        }
+    }
+
+    /// Returns the latency `percentile` percent of the samples are below or equal to, by the
+    /// nearest rank.
+    fn percentile(&self, percentile: usize) -> Duration {
+        let mut sorted = self.samples.clone();
+        sorted.sort_unstable();
+
+        let rank = (sorted.len() * percentile).div_ceil(100);
+        sorted
+            .get(rank.saturating_sub(1))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// e.g. `min: 0.12ms, max: 1.30ms, avg: 0.25ms (42 samples)`
+    fn status(&self) -> String {
+        format!(
+            "min: {}, max: {}, avg: {} ({} samples)",
+            format_millis(self.min),
+            format_millis(self.max),
+            format_millis(self.avg()),
+            self.len()
+        )
+    }
+
+    /// The status along with the median and the 99th percentile, e.g.
+    /// `min: 0.12ms, max: 1.30ms, avg: 0.25ms, p50: 0.21ms, p99: 1.02ms (42 samples)`
+    fn summary(&self) -> String {
+        format!(
+            "min: {}, max: {}, avg: {}, p50: {}, p99: {} ({} samples)",
+            format_millis(self.min),
+            format_millis(self.max),
+            format_millis(self.avg()),
+            format_millis(self.percentile(50)),
+            format_millis(self.percentile(99)),
+            self.len()
+        )
+    }
+}
+
+/// Formats a duration in milliseconds, e.g. `0.42ms`.
+fn format_millis(duration: Duration) -> String {
+    format!("{:.2}ms", duration.as_secs_f64() * 1000.0)
+}
+
+/// Parses the `--interval` flag, a non-negative number of seconds.
+fn parse_interval(s: &str) -> std::result::Result<Duration, String> {
+    let seconds: f64 = s.parse().map_err(|e| format!("{e}"))?;
+
+    Duration::try_from_secs_f64(seconds).map_err(|e| format!("{e}"))
+}
+
+/// Lists the keys matching the pattern, one key per line.
+///
+/// In raw mode the keys are written as is, otherwise they are escaped so that
+/// keys containing newlines or binary data still take exactly one line.
+async fn scan_keys(
+    client: &mut Client,
+    pattern: Option<&str>,
+    count: Option<u64>,
+    raw: bool,
+) -> Result<()> {
+    let mut stdout = io::stdout();
+    let mut iter = client.scan_iter(pattern, count);
+
+    while let Some(key) = iter.next_key().await? {
+        if raw {
+            stdout.write_all(&key)?;
+            stdout.write_all(b"\n")?;
+        } else {
+            writeln!(stdout, "{}", escape_bytes(&key))?;
+        }
+    }
+
+    stdout.flush()?;
+
+    Ok(())
+}
+
+/// Escapes control characters, quotes and non-ASCII bytes the same way redis-cli does.
+fn escape_bytes(bytes: &[u8]) -> String {
+    let mut escaped = String::with_capacity(bytes.len());
+
+    for &byte in bytes {
+        match byte {
+            b'\\' => escaped.push_str("\\\\"),
+            b'"' => escaped.push_str("\\\""),
+            b'\n' => escaped.push_str("\\n"),
+            b'\r' => escaped.push_str("\\r"),
+            b'\t' => escaped.push_str("\\t"),
+            0x20..=0x7e => escaped.push(byte as char),
+            _ => escaped.push_str(&format!("\\x{byte:02x}")),
+        }
+    }
+
+    escaped
+}
+
+/// Turns the `\xHH`, `\n`, `\r` and `\t` escapes of an argument into the bytes they stand for,
+/// so that binary arguments can be typed. Any other backslash is kept as is.
+fn unescape_arg(arg: &str) -> Vec<u8> {
+    let bytes = arg.as_bytes();
+    let mut unescaped = Vec::with_capacity(bytes.len());
+
+    let mut i = 0;
+    while i < bytes.len() {
+        let escape = match bytes.get(i..) {
+            Some([b'\\', b'n', ..]) => Some((b'\n', 2)),
+            Some([b'\\', b'r', ..]) => Some((b'\r', 2)),
+            Some([b'\\', b't', ..]) => Some((b'\t', 2)),
+            Some([b'\\', b'x', hi, lo, ..]) => str::from_utf8(&[*hi, *lo])
+                .ok()
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                .map(|byte| (byte, 4)),
+            _ => None,
+        };
+
+        let (byte, len) = escape.unwrap_or((bytes[i], 1));
+        unescaped.push(byte);
+        i += len;
+    }
+
+    unescaped
+}
+
 // TODO: catch signals like Ctrl+C and Ctrl+D
 fn clear_screen() {
     print!("\x1B[2J\x1B[1;1H"); // Clears the screen and moves the cursor to the top-left
@@ -0,0 +1,132 @@
+//! A toy Redis server.
+//!
+//! This application is a small in-memory server speaking RESP2, built on the `Connection` and
+//! `Frame` of the `redis-async` lib crate in this repository, so that the parser is exercised
+//! from the server side too. It gives tests and experiments a server to talk to without running
+//! Redis. The application supports a handful of commands:
+//! - `PING`: Check if the server is alive.
+//! - `GET`: Retrieve the value of a key.
+//! - `SET`: Set the value of a key.
+//! - `DEL`: Delete keys.
+//! - `EXISTS`: Count the keys that exist.
+//!
+//! Besides arrays of bulk strings, commands can be typed as inline commands, e.g. `SET key value`
+//! followed by a newline, which suits debugging with telnet.
+
+use bytes::Bytes;
+use clap::Parser;
+use redis_asyncx::{Connection, Frame, Result};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use tokio::net::{TcpListener, TcpStream};
+
+#[derive(Parser, Debug)]
+#[command(name = "server")]
+#[command(version = "0.1.0")]
+#[command(about = "A toy Redis server", long_about = None)]
+struct Cli {
+    #[arg(long, default_value = "127.0.0.1", help = "Address to listen on.")]
+    host: String,
+    #[arg(short, long, default_value = "6379", help = "Port to listen on.")]
+    port: u16,
+}
+
+/// The keys of the server, shared by every connection.
+type Db = Arc<Mutex<HashMap<Bytes, Bytes>>>;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    let listener = TcpListener::bind((cli.host.as_str(), cli.port)).await?;
+    println!("Listening on {}", listener.local_addr()?);
+
+    let db = Db::default();
+    loop {
+        let (socket, _) = listener.accept().await?;
+        let db = db.clone();
+
+        // a task per connection, so that a slow client does not hold the others back
+        tokio::spawn(async move {
+            if let Err(e) = serve(socket, db).await {
+                eprintln!("Error serving connection: {e}");
+            }
+        });
+    }
+}
+
+/// Replies to the commands of a connection until it is closed.
+async fn serve(socket: TcpStream, db: Db) -> Result<()> {
+    let mut conn = Connection::new(socket).with_inline_commands(true);
+
+    while let Some(frame) = conn.read_frame().await? {
+        let reply = match command_args(frame) {
+            Some(args) => execute(&args, &db),
+            None => Frame::SimpleError("ERR commands are arrays of bulk strings".to_string()),
+        };
+
+        conn.write_frame(&reply).await?;
+    }
+
+    Ok(())
+}
+
+/// Returns the name and arguments of a command, None if the frame is not a command.
+fn command_args(frame: Frame) -> Option<Vec<Bytes>> {
+    let Frame::Array(items) = frame else {
+        return None;
+    };
+
+    items
+        .into_iter()
+        .map(|item| match item {
+            Frame::BulkString(data) => Some(data),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Runs a command against the keys and returns its reply.
+fn execute(args: &[Bytes], db: &Db) -> Frame {
+    let Some((name, args)) = args.split_first() else {
+        return Frame::SimpleError("ERR empty command".to_string());
+    };
+    let typed = String::from_utf8_lossy(name);
+    let name = typed.to_uppercase();
+
+    match (name.as_str(), args) {
+        ("PING", []) => Frame::simple("PONG"),
+        ("PING", [message]) => Frame::bulk(message.clone()),
+        ("GET", [key]) => match lock(db).get(key) {
+            Some(value) => Frame::bulk(value.clone()),
+            None => Frame::Null,
+        },
+        ("SET", [key, value]) => {
+            lock(db).insert(key.clone(), value.clone());
+            Frame::simple("OK")
+        }
+        ("DEL", keys) if !keys.is_empty() => {
+            let mut db = lock(db);
+            count(keys.iter().filter(|key| db.remove(*key).is_some()))
+        }
+        ("EXISTS", keys) if !keys.is_empty() => {
+            let db = lock(db);
+            count(keys.iter().filter(|key| db.contains_key(*key)))
+        }
+        ("PING" | "GET" | "SET" | "DEL" | "EXISTS", _) => Frame::SimpleError(format!(
+            "ERR wrong number of arguments for '{}' command",
+            name.to_lowercase()
+        )),
+        _ => Frame::SimpleError(format!("ERR unknown command '{typed}'")),
+    }
+}
+
+/// Returns an Integer Frame of the number of items.
+fn count<T>(items: impl Iterator<Item = T>) -> Frame {
+    Frame::int(i64::try_from(items.count()).unwrap_or(i64::MAX))
+}
+
+/// Locks the keys, a panic in another connection leaves them usable.
+fn lock(db: &Db) -> MutexGuard<'_, HashMap<Bytes, Bytes>> {
+    db.lock().unwrap_or_else(PoisonError::into_inner)
+}
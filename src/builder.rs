@@ -0,0 +1,238 @@
+//! A builder to connect a fully configured client in one call.
+
+use crate::Client;
+use crate::ConnectionOptions;
+use crate::RedisError;
+use crate::Result;
+use crate::RetryPolicy;
+use crate::ToRedisArg;
+use crate::metrics::{MetricsObserver, NoopObserver};
+use bytes::Bytes;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Builds a `Client`, created with `Client::builder()`.
+///
+/// `build` connects to the server, then authenticates, switches to RESP3 and selects the database
+/// as configured, so the client is ready to use once it returns.
+///
+/// # Examples
+///
+/// ```ignore
+/// use redis_asyncx::Client;
+/// use std::time::Duration;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut client = Client::builder()
+///         .host("redis.internal")
+///         .port(6380)
+///         .username("worker")
+///         .password("secret")
+///         .db(2)
+///         .resp3(true)
+///         .connect_timeout(Duration::from_secs(1))
+///         .build()
+///         .await
+///         .unwrap();
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ClientBuilder {
+    host: String,
+    port: u16,
+    db: Option<u64>,
+    username: Option<String>,
+    password: Option<String>,
+    resp3: bool,
+    options: ConnectionOptions,
+    command_timeout: Option<Duration>,
+    retry_policy: Option<RetryPolicy>,
+    key_prefix: Bytes,
+    observer: Arc<dyn MetricsObserver>,
+}
+
+impl Default for ClientBuilder {
+    fn default() -> Self {
+        Self {
+            host: "127.0.0.1".to_string(),
+            port: 6379,
+            db: None,
+            username: None,
+            password: None,
+            resp3: false,
+            options: ConnectionOptions::default(),
+            command_timeout: None,
+            retry_policy: None,
+            key_prefix: Bytes::new(),
+            observer: Arc::new(NoopObserver),
+        }
+    }
+}
+
+impl ClientBuilder {
+    /// Creates a new ClientBuilder for a server on 127.0.0.1:6379.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the host name or IP address of the server.
+    pub fn host(mut self, host: &str) -> Self {
+        self.host = host.to_string();
+        self
+    }
+
+    /// Sets the port of the server, 6379 by default.
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+
+    /// Selects a logical database once connected, the server uses 0 by default.
+    pub fn db(mut self, db: u64) -> Self {
+        self.db = Some(db);
+        self
+    }
+
+    /// Authenticates as the given ACL user, requires a password.
+    pub fn username(mut self, username: &str) -> Self {
+        self.username = Some(username.to_string());
+        self
+    }
+
+    /// Authenticates with the given password, as the default user unless a username is set.
+    pub fn password(mut self, password: &str) -> Self {
+        self.password = Some(password.to_string());
+        self
+    }
+
+    /// Switches the connection to RESP3 with HELLO 3, which also authenticates it.
+    pub fn resp3(mut self, resp3: bool) -> Self {
+        self.resp3 = resp3;
+        self
+    }
+
+    /// Sets the socket options of the connection, see `ConnectionOptions`.
+    pub fn connection_options(mut self, options: ConnectionOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Fails the connection attempt if it takes longer than the timeout, 10 seconds by default.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.options.connect_timeout = timeout;
+        self
+    }
+
+    /// Sets how long every command waits for its reply, see `Client::set_command_timeout`.
+    pub fn command_timeout(mut self, timeout: Duration) -> Self {
+        self.command_timeout = Some(timeout);
+        self
+    }
+
+    /// Retries the commands failing with a transient error, see `Client::set_retry_policy`.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Adds a prefix to the keys of every command, see `Client::set_key_prefix`.
+    pub fn key_prefix(mut self, prefix: impl ToRedisArg) -> Self {
+        self.key_prefix = prefix.to_redis_arg();
+        self
+    }
+
+    /// Reports the connection and every command to the given metrics observer.
+    pub fn observer(mut self, observer: Arc<dyn MetricsObserver>) -> Self {
+        self.observer = observer;
+        self
+    }
+
+    /// Connects to the server and sets the connection up.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Client)` once the connection is ready
+    /// * `Err(RedisError::InvalidArgument)` if a username is set without a password
+    /// * `Err(RedisError::Timeout)` if the server did not accept the connection in time
+    /// * `Err(RedisError)` if connecting or any of the setup commands fails
+    pub async fn build(self) -> Result<Client> {
+        if self.username.is_some() && self.password.is_none() {
+            return Err(RedisError::InvalidArgument(
+                "a username requires a password".to_string(),
+            ));
+        }
+
+        let mut client = Client::connect_configured(
+            (self.host.as_str(), self.port),
+            self.options,
+            self.observer,
+        )
+        .await?;
+        client.set_command_timeout(self.command_timeout);
+        client.set_retry_policy(self.retry_policy);
+        client.set_key_prefix(self.key_prefix);
+
+        if self.resp3 {
+            // HELLO authenticates in the same round trip
+            let auth = self
+                .password
+                .as_deref()
+                .map(|password| (self.username.as_deref().unwrap_or("default"), password));
+            client.hello_with(3, auth, None).await?;
+        } else if let Some(password) = &self.password {
+            client.auth(self.username.as_deref(), password).await?;
+        }
+
+        if let Some(db) = self.db {
+            client.select(db).await?;
+        }
+
+        Ok(client)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockRedisServer;
+    use crate::{Frame, ProtocolVersion};
+
+    #[tokio::test]
+    async fn test_build() {
+        let server = MockRedisServer::start().await;
+        server
+            .expect_command(["AUTH", "worker", "secret"])
+            .respond(Frame::simple("OK"));
+        server
+            .expect_command(["SELECT", "2"])
+            .respond(Frame::simple("OK"));
+
+        let (host, port) = server.addr().rsplit_once(':').unwrap_or_default();
+        let port = port
+            .parse()
+            .unwrap_or_else(|err| panic!("Failed to parse port: {:?}", err));
+        let client = ClientBuilder::new()
+            .host(host)
+            .port(port)
+            .username("worker")
+            .password("secret")
+            .db(2)
+            .build()
+            .await
+            .unwrap_or_else(|err| panic!("Failed to build client: {:?}", err));
+        assert_eq!(client.protocol_version(), ProtocolVersion::Resp2);
+    }
+
+    #[tokio::test]
+    async fn test_build_username_without_password() {
+        // rejected before connecting, nothing listens on the port
+        let result = ClientBuilder::new()
+            .port(1)
+            .username("worker")
+            .build()
+            .await;
+
+        assert!(matches!(result, Err(RedisError::InvalidArgument(_))));
+    }
+}
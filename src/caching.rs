@@ -0,0 +1,501 @@
+//! A client that caches GET replies and relies on the server to invalidate them.
+//!
+//! With CLIENT TRACKING on, Redis remembers the keys read by a connection and sends it an
+//! `invalidate` push frame once one of them is modified, by any client. The cached entries of
+//! those keys are dropped as soon as the push frame arrives, until then reads are served locally.
+
+use crate::Frame;
+use crate::MultiplexedClient;
+use crate::ProtocolVersion;
+use crate::RedisError;
+use crate::Result;
+use crate::client::Response;
+use crate::cmd::ClientTracking;
+use anyhow::anyhow;
+use bytes::Bytes;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio::task::JoinHandle;
+
+/// The limits of the cache of a `CachingClient`.
+///
+/// Once a limit is reached, the least recently used entries are evicted first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheConfig {
+    /// The maximum number of cached keys.
+    pub max_entries: usize,
+    /// The maximum size of the cached keys and values, in bytes.
+    pub max_bytes: usize,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            max_entries: 10_000,
+            max_bytes: 64 * 1024 * 1024,
+        }
+    }
+}
+
+/// A cached GET reply, None for a missing key.
+struct Entry {
+    value: Option<Bytes>,
+    // when the entry was last read or written
+    tick: u64,
+}
+
+/// The GET replies by key, with the least recently used evicted first.
+struct Cache {
+    config: CacheConfig,
+    entries: HashMap<String, Entry>,
+    // the keys by the tick of their entry, the first one is the least recently used
+    order: BTreeMap<u64, String>,
+    tick: u64,
+    bytes: usize,
+    // bumped by every invalidation, a reply read before the bump may already be stale
+    epoch: u64,
+}
+
+impl Cache {
+    fn new(config: CacheConfig) -> Self {
+        Self {
+            config,
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+            tick: 0,
+            bytes: 0,
+            epoch: 0,
+        }
+    }
+
+    fn get(&mut self, key: &str) -> Option<Option<Bytes>> {
+        let entry = self.entries.get_mut(key)?;
+
+        self.order.remove(&entry.tick);
+        self.tick += 1;
+        entry.tick = self.tick;
+        self.order.insert(self.tick, key.to_string());
+
+        Some(entry.value.clone())
+    }
+
+    fn insert(&mut self, key: &str, value: Option<Bytes>) {
+        self.remove(key);
+
+        let size = entry_size(key, &value);
+        if self.config.max_entries == 0 || size > self.config.max_bytes {
+            return;
+        }
+
+        while self.entries.len() >= self.config.max_entries
+            || self.bytes + size > self.config.max_bytes
+        {
+            let Some((_, oldest)) = self.order.pop_first() else {
+                break;
+            };
+            if let Some(entry) = self.entries.remove(&oldest) {
+                self.bytes -= entry_size(&oldest, &entry.value);
+            }
+        }
+
+        self.tick += 1;
+        self.order.insert(self.tick, key.to_string());
+        self.entries.insert(
+            key.to_string(),
+            Entry {
+                value,
+                tick: self.tick,
+            },
+        );
+        self.bytes += size;
+    }
+
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.order.remove(&entry.tick);
+            self.bytes -= entry_size(key, &entry.value);
+        }
+    }
+
+    fn invalidate<'a>(&mut self, keys: impl IntoIterator<Item = &'a str>) {
+        for key in keys {
+            self.remove(key);
+        }
+        self.epoch += 1;
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+        self.bytes = 0;
+        self.epoch += 1;
+    }
+}
+
+fn entry_size(key: &str, value: &Option<Bytes>) -> usize {
+    key.len() + value.as_ref().map_or(0, Bytes::len)
+}
+
+// the cache is left consistent by every method, so a panic elsewhere does not corrupt it
+fn lock(cache: &Mutex<Cache>) -> MutexGuard<'_, Cache> {
+    cache.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// A Redis client keeping an in-process cache of GET replies, requires RESP3.
+///
+/// Reads are served from the cache when possible, and the server invalidates the cached keys once
+/// they are modified by any client. Writes through the caching client evict the keys right away,
+/// without waiting for the invalidation.
+///
+/// Other commands can be sent with `CachingClient::client`, the server still invalidates the keys
+/// they modify.
+///
+/// # Examples
+///
+/// ```ignore
+/// use redis_asyncx::{CacheConfig, CachingClient, Client};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut client = Client::builder().resp3(true).build().await.unwrap();
+///     let client = CachingClient::new(client.into_multiplexed(), CacheConfig::default())
+///         .await
+///         .unwrap();
+///
+///     client.get("mykey").await.unwrap();
+///     // served from the cache until another client modifies mykey
+///     client.get("mykey").await.unwrap();
+/// }
+/// ```
+pub struct CachingClient {
+    client: MultiplexedClient,
+    cache: Arc<Mutex<Cache>>,
+    // drops the invalidated keys, aborted when the client is dropped
+    invalidations: JoinHandle<()>,
+}
+
+impl CachingClient {
+    /// Turns tracking on for the connection of the given client and starts caching.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - A client whose connection uses RESP3, the invalidations are push frames
+    /// * `config` - The limits of the cache
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CachingClient)` once tracking is on
+    /// * `Err(RedisError)` if the connection does not use RESP3 or tracking cannot be turned on
+    pub async fn new(client: MultiplexedClient, config: CacheConfig) -> Result<Self> {
+        if client.protocol_version() != ProtocolVersion::Resp3 {
+            return Err(RedisError::Other(anyhow!(
+                "client-side caching requires RESP3"
+            )));
+        }
+
+        // subscribe before any key is tracked, so that no invalidation is missed
+        let pushes = client.pushes();
+
+        match client.execute(ClientTracking::new(true)).await? {
+            Response::Simple(_) => {}
+            Response::Error(err) => return Err(err),
+            _ => return Err(RedisError::UnexpectedResponseType),
+        }
+
+        let cache = Arc::new(Mutex::new(Cache::new(config)));
+        let key_prefix = Bytes::copy_from_slice(client.key_prefix());
+        let invalidations = tokio::spawn(invalidate(pushes, cache.clone(), key_prefix));
+
+        Ok(Self {
+            client,
+            cache,
+            invalidations,
+        })
+    }
+
+    /// Returns the underlying client, to send the commands that are not cached.
+    pub fn client(&self) -> &MultiplexedClient {
+        &self.client
+    }
+
+    /// Returns the number of cached keys.
+    pub fn len(&self) -> usize {
+        lock(&self.cache).entries.len()
+    }
+
+    /// Returns true if no key is cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the value of a key, from the cache if it was read before and not modified since.
+    ///
+    /// Missing keys are cached as well, see `Client::get`.
+    pub async fn get(&self, key: &str) -> Result<Option<Bytes>> {
+        let epoch = {
+            let mut cache = lock(&self.cache);
+            // the server no longer invalidates anything once the connection is gone
+            if self.client.is_closed() {
+                cache.clear();
+            } else if let Some(value) = cache.get(key) {
+                return Ok(value);
+            }
+            cache.epoch
+        };
+
+        let value = self.client.get(key).await?;
+
+        let mut cache = lock(&self.cache);
+        // an invalidation handled in the meantime may be about this very reply
+        if cache.epoch == epoch {
+            cache.insert(key, value.clone());
+        }
+
+        Ok(value)
+    }
+
+    /// Sets the value of a key and evicts it from the cache, see `Client::set`.
+    pub async fn set(&self, key: &str, val: &[u8]) -> Result<Option<Bytes>> {
+        let result = self.client.set(key, val).await;
+        // the key may have been modified even if no valid reply was read
+        lock(&self.cache).invalidate([key]);
+
+        result
+    }
+
+    /// Deletes the given keys and evicts them from the cache, see `Client::del`.
+    pub async fn del(&self, keys: Vec<&str>) -> Result<u64> {
+        let result = self.client.del(keys.clone()).await;
+        lock(&self.cache).invalidate(keys);
+
+        result
+    }
+
+    /// Sets a timeout on a key and evicts it from the cache, see `Client::expire`.
+    pub async fn expire(&self, key: &str, seconds: i64) -> Result<u64> {
+        let result = self.client.expire(key, seconds).await;
+        lock(&self.cache).invalidate([key]);
+
+        result
+    }
+}
+
+impl Drop for CachingClient {
+    fn drop(&mut self) {
+        self.invalidations.abort();
+    }
+}
+
+/// Drops the cached keys the server sends invalidations for, which name the prefixed keys.
+async fn invalidate(
+    mut pushes: broadcast::Receiver<Frame>,
+    cache: Arc<Mutex<Cache>>,
+    key_prefix: Bytes,
+) {
+    loop {
+        match pushes.recv().await {
+            Ok(Frame::Push(data)) => match data.as_slice() {
+                [Frame::BulkString(kind), Frame::Array(keys)] if kind[..] == b"invalidate"[..] => {
+                    let keys: Vec<String> = keys
+                        .iter()
+                        .filter_map(|key| match key {
+                            Frame::BulkString(key) => key
+                                .strip_prefix(&key_prefix[..])
+                                .map(|key| String::from_utf8_lossy(key).into()),
+                            _ => None,
+                        })
+                        .collect();
+                    lock(&cache).invalidate(keys.iter().map(String::as_str));
+                }
+                // sent without keys when the server flushes its databases
+                [Frame::BulkString(kind), _] if kind[..] == b"invalidate"[..] => {
+                    lock(&cache).clear();
+                }
+                _ => {}
+            },
+            Ok(_) => {}
+            // invalidations were dropped, any entry may be stale
+            Err(RecvError::Lagged(_)) => lock(&cache).clear(),
+            Err(RecvError::Closed) => break,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Connection;
+    use crate::metrics::CountingObserver;
+    use std::io::Cursor;
+    use std::time::Duration;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+    use tokio::sync::mpsc;
+
+    #[test]
+    fn test_cache_evicts_least_recently_used() {
+        let mut cache = Cache::new(CacheConfig {
+            max_entries: 2,
+            max_bytes: 1024,
+        });
+
+        cache.insert("first", Some(Bytes::from_static(b"1")));
+        cache.insert("second", None);
+        // reading first makes second the least recently used
+        assert_eq!(cache.get("first"), Some(Some(Bytes::from_static(b"1"))));
+        cache.insert("third", Some(Bytes::from_static(b"3")));
+
+        assert_eq!(cache.get("second"), None);
+        assert_eq!(cache.get("first"), Some(Some(Bytes::from_static(b"1"))));
+        assert_eq!(cache.get("third"), Some(Some(Bytes::from_static(b"3"))));
+    }
+
+    #[test]
+    fn test_cache_max_bytes() {
+        let mut cache = Cache::new(CacheConfig {
+            max_entries: 10,
+            max_bytes: 8,
+        });
+
+        cache.insert("a", Some(Bytes::from_static(b"111")));
+        cache.insert("b", Some(Bytes::from_static(b"222")));
+        assert_eq!(cache.bytes, 8);
+
+        // evicts a to make room
+        cache.insert("c", Some(Bytes::from_static(b"3")));
+        assert_eq!(cache.get("a"), None);
+        assert_eq!(cache.bytes, 6);
+
+        // larger than the whole cache, never cached
+        cache.insert("d", Some(Bytes::from_static(b"too large")));
+        assert_eq!(cache.get("d"), None);
+        assert_eq!(cache.bytes, 6);
+
+        cache.invalidate(["b", "c"]);
+        assert!(cache.entries.is_empty());
+        assert!(cache.order.is_empty());
+        assert_eq!(cache.bytes, 0);
+    }
+
+    #[tokio::test]
+    async fn test_caching_client() {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to bind listener: {:?}", err));
+        let addr = listener
+            .local_addr()
+            .unwrap_or_else(|err| panic!("Failed to get listener address: {:?}", err));
+        let (invalidate, mut invalidations) = mpsc::unbounded_channel::<()>();
+
+        // replies to GET with the number of GETs so far, and sends an invalidation on demand
+        tokio::spawn(async move {
+            let Ok((mut socket, _)) = listener.accept().await else {
+                return;
+            };
+            let mut received = Vec::new();
+            let mut gets = 0;
+            let mut buf = vec![0; 1024];
+            loop {
+                tokio::select! {
+                    Some(()) = invalidations.recv() => {
+                        let push = b">2\r\n$10\r\ninvalidate\r\n*1\r\n$3\r\nkey\r\n";
+                        let _ = socket.write_all(push).await;
+                    }
+                    n = socket.read(&mut buf) => {
+                        match n {
+                            Ok(0) | Err(_) => return,
+                            Ok(n) => received.extend_from_slice(&buf[..n]),
+                        }
+
+                        let mut cursor = Cursor::new(&received[..]);
+                        let mut reply = Vec::new();
+                        while let Ok(Frame::Array(args)) = Frame::try_parse(&mut cursor) {
+                            if args.first() == Some(&Frame::bulk("GET")) {
+                                gets += 1;
+                                reply.extend_from_slice(format!("$2\r\nv{gets}\r\n").as_bytes());
+                            } else {
+                                reply.extend_from_slice(b"+OK\r\n");
+                            }
+                        }
+                        let consumed = cursor.position() as usize;
+                        received.drain(..consumed);
+                        let _ = socket.write_all(&reply).await;
+                    }
+                }
+            }
+        });
+
+        let stream = TcpStream::connect(addr)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
+        let observer = Arc::new(CountingObserver::new());
+        let client = MultiplexedClient::new(
+            Connection::new(stream).with_protocol_version(ProtocolVersion::Resp3),
+            observer.clone(),
+        );
+        let client = CachingClient::new(client, CacheConfig::default())
+            .await
+            .unwrap_or_else(|err| panic!("Failed to create caching client: {:?}", err));
+        assert_eq!(observer.commands(), 1);
+
+        let get = async |key| {
+            client
+                .get(key)
+                .await
+                .unwrap_or_else(|err| panic!("Failed to GET: {:?}", err))
+        };
+
+        assert_eq!(get("key").await, Some(Bytes::from_static(b"v1")));
+        assert_eq!(observer.commands(), 2);
+
+        // served from the cache
+        assert_eq!(get("key").await, Some(Bytes::from_static(b"v1")));
+        assert_eq!(observer.commands(), 2);
+
+        let _ = invalidate.send(());
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while !client.is_empty() {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap_or_else(|err| panic!("Key not invalidated: {:?}", err));
+
+        assert_eq!(get("key").await, Some(Bytes::from_static(b"v2")));
+        assert_eq!(observer.commands(), 3);
+
+        // writes evict without waiting for the server
+        client
+            .set("key", b"value")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to SET: {:?}", err));
+        assert!(client.is_empty());
+
+        assert_eq!(get("key").await, Some(Bytes::from_static(b"v3")));
+        assert_eq!(observer.commands(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_caching_client_requires_resp3() {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to bind listener: {:?}", err));
+        let addr = listener
+            .local_addr()
+            .unwrap_or_else(|err| panic!("Failed to get listener address: {:?}", err));
+
+        let stream = TcpStream::connect(addr)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
+        let observer = Arc::new(CountingObserver::new());
+        let client = MultiplexedClient::new(Connection::new(stream), observer.clone());
+
+        assert!(
+            CachingClient::new(client, CacheConfig::default())
+                .await
+                .is_err()
+        );
+        assert_eq!(observer.commands(), 0);
+    }
+}
@@ -5,30 +5,200 @@
 //! It provides simple APIs to send commands to the Redis server and get the response.
 //! The client is designed to be used in an async context, using the tokio runtime.
 
+use crate::ClientBuilder;
 use crate::Connection;
+use crate::ConnectionOptions;
 use crate::Frame;
+use crate::MultiplexedClient;
+use crate::ProtocolVersion;
+use crate::ReconnectPolicy;
 use crate::RedisError;
 use crate::Result;
+use crate::RetryPolicy;
+use crate::Script;
 use crate::cmd::*;
+use crate::connection::connect_tcp;
+use crate::lock::{EXTEND_LOCK, RELEASE_LOCK};
+use crate::metrics::{MetricsObserver, NoopObserver, Outcome};
+use crate::rate_limit::SLIDING_WINDOW_LOG;
+use crate::{RateLimitAlgorithm, RateLimitDecision};
 use anyhow::{Context, anyhow};
-use std::collections::HashMap;
+use bytes::Bytes;
+use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::pin::Pin;
 use std::str::from_utf8;
-use tokio::net::{TcpStream, ToSocketAddrs};
-
+use std::sync::Arc;
+use std::task::{Context as TaskContext, Poll};
+use std::time::{Duration, Instant};
+use tokio::net::ToSocketAddrs;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio_stream::Stream;
+
+// how long `Client::is_alive` waits for the PONG
+const IS_ALIVE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// A reply of the Redis server, returned as is by `Client::command`.
 #[derive(Debug)]
 pub enum Response {
-    Simple(Vec<u8>),
-    Array(Vec<Vec<u8>>),
-    Map(HashMap<String, Vec<u8>>),
+    Simple(Bytes),
+    // an array of scalars, nils left empty, a reply with nested arrays or maps is rejected
+    Array(Vec<Bytes>),
+    // the pairs are kept in the order the server sent them, the values as they are nested, so that
+    // arrays, maps and nulls are not flattened into strings
+    Map(Vec<(String, StructuredResponse)>),
+    // RESP3 doubles are kept as is, instead of going through a decimal string
+    Double(f64),
+    // RESP3 booleans, RESP2 replies with the integers 1 and 0 instead
+    Bool(bool),
+    Null,
+    Error(RedisError),
+}
+
+impl TryFrom<Frame> for Response {
+    type Error = RedisError;
+
+    /// Decodes a reply frame into the human readable message returned to the client.
+    fn try_from(frame: Frame) -> Result<Self> {
+        match frame {
+            Frame::SimpleString(data) => Ok(Response::Simple(Bytes::from(data))),
+            Frame::SimpleError(data) => Ok(Response::Error(RedisError::from_server(&data))),
+            Frame::Integer(data) => Ok(Response::Simple(Bytes::from(data.to_string()))),
+            Frame::BulkString(data) => Ok(Response::Simple(data)),
+            // RESP3 replies with a set where RESP2 replies with an array, e.g. for SMEMBERS
+            Frame::Array(data) | Frame::Set(data) => {
+                let result: Vec<Bytes> = data
+                    .into_iter()
+                    .map(|frame| match frame {
+                        // a nil element, e.g. a key missing from MGET, is left empty
+                        Frame::Null => Ok(Bytes::new()),
+                        // nested arrays and maps have no flat form, see `StructuredResponse`
+                        frame => scalar_into_bytes(frame).ok_or(RedisError::UnexpectedResponseType),
+                    })
+                    .collect::<Result<_>>()?;
+
+                Ok(Response::Array(result))
+            }
+            Frame::Null => Ok(Response::Null), // nil reply usually means no error
+            Frame::Boolean(data) => Ok(Response::Bool(data)),
+            Frame::Double(data) => Ok(Response::Double(data)),
+            Frame::BulkError(data) => Ok(Response::Error(RedisError::from_server(
+                &String::from_utf8_lossy(&data),
+            ))),
+            Frame::Map(data) => {
+                let result: Vec<(String, StructuredResponse)> =
+                    data.into_iter()
+                        .map(|(key, value)| {
+                            let key = match key {
+                                Frame::BulkString(data) => String::from_utf8(data.to_vec())
+                                    .map_err(|err| err.utf8_error())?,
+                                Frame::SimpleString(data) => data,
+                                Frame::Integer(data) => data.to_string(),
+                                _ => return Err(RedisError::UnexpectedResponseType),
+                            };
+
+                            Ok((key, StructuredResponse::from(value)))
+                        })
+                        .collect::<Result<_>>()?;
+
+                Ok(Response::Map(result))
+            }
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+}
+
+/// A reply of the Redis server decoded as a tree, keeping the type of every nested reply.
+///
+/// Unlike `Response`, the elements of an array are not flattened into strings, e.g. the reply of
+/// EXEC holds the reply of each queued command at its position, whatever its type.
+#[derive(Debug)]
+pub enum StructuredResponse {
+    /// A status reply, e.g. `OK` or `QUEUED`.
+    Status(String),
+    /// A bulk string, possibly binary. RESP3 verbatim strings lose their encoding.
+    Bulk(Bytes),
+    Integer(i64),
+    // RESP3 only
+    Double(f64),
+    Bool(bool),
+    /// A RESP3 big number, as its decimal digits.
+    BigNumber(String),
     Null,
+    /// An array, or a RESP3 set or push.
+    Array(Vec<StructuredResponse>),
+    // the pairs are kept in the order the server sent them
+    Map(Vec<(StructuredResponse, StructuredResponse)>),
+    /// An error reply, typed like the errors of the other commands.
     Error(RedisError),
 }
 
+impl StructuredResponse {
+    /// Returns the bytes of a scalar reply, the way `Response` decodes it, e.g. `"5"` for an
+    /// integer.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Bytes)` for a status, a bulk string, an integer, a double or a boolean
+    /// * `Err(RedisError::UnexpectedResponseType)` for a null, an error or a nested reply
+    pub fn into_scalar(self) -> Result<Bytes> {
+        match self {
+            StructuredResponse::Status(data) => Ok(Bytes::from(data)),
+            StructuredResponse::Bulk(data) => Ok(data),
+            StructuredResponse::Integer(data) => Ok(Bytes::from(data.to_string())),
+            StructuredResponse::Double(data) => Ok(Bytes::from(data.to_string())),
+            StructuredResponse::Bool(data) => {
+                Ok(Bytes::from_static(if data { b"1" } else { b"0" }))
+            }
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+}
+
+impl From<Frame> for StructuredResponse {
+    fn from(frame: Frame) -> Self {
+        match frame {
+            Frame::SimpleString(data) => StructuredResponse::Status(data),
+            Frame::SimpleError(data) => StructuredResponse::Error(RedisError::from_server(&data)),
+            Frame::Integer(data) => StructuredResponse::Integer(data),
+            Frame::BulkString(data) | Frame::VerbatimString(_, data) => {
+                StructuredResponse::Bulk(data)
+            }
+            Frame::Array(items) | Frame::Set(items) | Frame::Push(items) => {
+                StructuredResponse::Array(items.into_iter().map(StructuredResponse::from).collect())
+            }
+            // attributes carry no reply of their own
+            Frame::Null | Frame::Attribute => StructuredResponse::Null,
+            Frame::Boolean(data) => StructuredResponse::Bool(data),
+            Frame::Double(data) => StructuredResponse::Double(data),
+            Frame::BigNumber(data) => StructuredResponse::BigNumber(data.to_string()),
+            Frame::BulkError(data) => {
+                StructuredResponse::Error(RedisError::from_server(&String::from_utf8_lossy(&data)))
+            }
+            Frame::Map(pairs) => StructuredResponse::Map(
+                pairs
+                    .into_iter()
+                    .map(|(key, value)| (key.into(), value.into()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
 /// Redis client implementation.
 pub struct Client {
     // todo: modify it to use a connection pool shared across multiple clients
     // spawn a new connection for each client is inefficient when the number of clients is large
     conn: Connection,
+    observer: Arc<dyn MetricsObserver>,
+    // how long a command waits for its reply, forever if None
+    command_timeout: Option<Duration>,
+    // how commands failing with a transient error are retried, never if None
+    retry_policy: Option<RetryPolicy>,
+    // added to the keys of every command, empty for none
+    key_prefix: Bytes,
 }
 
 impl Client {
@@ -45,469 +215,449 @@ impl Client {
     /// }
     /// ```
     pub async fn connect<A: ToSocketAddrs>(addr: A) -> Result<Self> {
-        let stream = TcpStream::connect(addr)
-            .await
-            .with_context(|| "failed to connect to Redis server")?;
-
-        let conn = Connection::new(stream);
-
-        Ok(Client { conn })
+        Self::connect_with(addr, ConnectionOptions::default()).await
     }
 
-    /// Sends a HELLO command to the Redis server.
+    /// Establish a connection to the Redis server, with the given socket options.
     ///
-    /// # Arguments
+    /// # Returns
     ///
-    /// * `proto` - An optional protocol version to use
+    /// * `Ok(Client)` once connected
+    /// * `Err(RedisError::Timeout)` if the server did not accept the connection within
+    ///   `connect_timeout`
+    /// * `Err(RedisError)` if the connection cannot be opened
     ///
-    /// # Returns
+    /// # Examples
     ///
-    /// * `Ok(HashMap<String, Vec<u8>>)` if the HELLO command is successful
-    /// * `Err(RedisError)` if an error occurs
-    pub async fn hello(&mut self, proto: Option<u8>) -> Result<HashMap<String, Vec<u8>>> {
-        let frame: Frame = Hello::new(proto).try_into()?;
+    /// ```ignore
+    /// use redis_asyncx::{Client, ConnectionOptions};
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let options = ConnectionOptions {
+    ///         connect_timeout: Duration::from_millis(500),
+    ///         ..Default::default()
+    ///     };
+    ///     let mut c = Client::connect_with("127.0.0.1:6379", options).await.unwrap();
+    /// }
+    /// ```
+    pub async fn connect_with<A: ToSocketAddrs>(
+        addr: A,
+        options: ConnectionOptions,
+    ) -> Result<Self> {
+        Self::connect_configured(addr, options, Arc::new(NoopObserver)).await
+    }
 
-        self.conn
-            .write_frame(&frame)
-            .await
-            .with_context(|| "failed to write frame for HELLO command")?;
+    /// Establish a connection to the Redis server, reporting the connection and
+    /// the round trip of every command to the given metrics observer.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use redis_asyncx::{Client, CountingObserver};
+    /// use std::sync::Arc;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let observer = Arc::new(CountingObserver::new());
+    ///     let mut c = Client::connect_with_observer("127.0.0.1:6379", observer.clone()).await.unwrap();
+    ///     c.ping(None).await.unwrap();
+    ///     assert_eq!(observer.commands(), 1);
+    /// }
+    /// ```
+    pub async fn connect_with_observer<A: ToSocketAddrs>(
+        addr: A,
+        observer: Arc<dyn MetricsObserver>,
+    ) -> Result<Self> {
+        Self::connect_configured(addr, ConnectionOptions::default(), observer).await
+    }
 
-        match self
-            .read_response()
-            .await
-            .with_context(|| "failed to read response for HELLO command")?
-        {
-            Response::Array(data) => {
-                let map = data
-                    .chunks(2)
-                    .filter_map(|chunk| {
-                        if chunk.len() == 2 {
-                            let key = from_utf8(&chunk[0]).ok()?.to_string();
-                            let value = chunk[1].to_vec();
-                            Some((key, value))
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
+    /// Establish a connection to the Redis server with the given socket options, reporting it to
+    /// the given metrics observer.
+    pub(crate) async fn connect_configured<A: ToSocketAddrs>(
+        addr: A,
+        options: ConnectionOptions,
+        observer: Arc<dyn MetricsObserver>,
+    ) -> Result<Self> {
+        let start = Instant::now();
+        let stream = match connect_tcp(addr, &options).await {
+            Err(RedisError::Timeout) => return Err(RedisError::Timeout),
+            result => result.with_context(|| "failed to connect to Redis server")?,
+        };
+        observer.on_connect(start.elapsed());
 
-                Ok(map)
-            }
-            Response::Map(data) => Ok(data),
-            Response::Error(err) => Err(err),
-            _ => Err(RedisError::UnexpectedResponseType),
-        }
+        let conn = Connection::new(stream);
+
+        Ok(Client {
+            conn,
+            observer,
+            command_timeout: None,
+            retry_policy: None,
+            key_prefix: Bytes::new(),
+        })
     }
 
-    /// Sends a PING command to the Redis server, optionally with a message.
-    ///
-    /// # Arguments
-    ///
-    /// * `msg` - An optional message to send to the server
+    /// Establish a connection to the Redis server and switch it to RESP3 with `HELLO 3`.
     ///
     /// # Returns
     ///
-    /// * `Ok(String)` if the PING command is successful
-    /// * `Err(RedisError)` if an error occurs
-    ///     
+    /// * `Ok(Client)` once the connection speaks RESP3
+    /// * `Err(RedisError::UnsupportedProtocol)` if the server does not speak RESP3, e.g. before Redis 6.0
+    /// * `Err(RedisError)` if connecting or the HELLO command fails
+    ///
     /// # Examples
     ///
     /// ```ignore
-    /// use async_redis::Client;
-    ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
-    ///     let resp = client.ping(Some("Hello Redis".to_string())).await.unwrap();
+    ///     let mut c = Client::connect_resp3("127.0.0.1:6379").await.unwrap();
     /// }
     /// ```
-    pub async fn ping(&mut self, msg: Option<&[u8]>) -> Result<Vec<u8>> {
-        let frame: Frame = Ping::new(msg).try_into()?;
-
-        self.conn
-            .write_frame(&frame)
-            .await
-            .with_context(|| "failed to write frame for PING command")?;
+    pub async fn connect_resp3<A: ToSocketAddrs>(addr: A) -> Result<Self> {
+        let mut client = Self::connect(addr).await?;
+        client.hello(Some(3)).await?;
 
-        match self
-            .read_response()
-            .await
-            .with_context(|| "failed to read response for PING command")?
-        {
-            Response::Simple(data) => Ok(data),
-            Response::Error(err) => Err(err),
-            _ => Err(RedisError::UnexpectedResponseType),
-        }
+        Ok(client)
     }
 
-    /// Sends a GET command to the Redis server.
+    /// Reports the round trip of every command from now on to the given metrics observer,
+    /// instead of the one the client was connected with.
     ///
-    /// # Description
+    /// # Examples
     ///
-    /// The GET command retrieves the value of a key stored on the Redis server.
+    /// ```ignore
+    /// use redis_asyncx::{Client, CountingObserver};
+    /// use std::sync::Arc;
     ///
-    /// # Arguments
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let observer = Arc::new(CountingObserver::new());
+    ///     let mut c = Client::connect("127.0.0.1:6379")
+    ///         .await
+    ///         .unwrap()
+    ///         .with_observer(observer.clone());
+    ///     c.ping(None).await.unwrap();
+    ///     assert_eq!(observer.commands(), 1);
+    /// }
+    /// ```
+    pub fn with_observer(mut self, observer: Arc<dyn MetricsObserver>) -> Self {
+        self.observer = observer;
+        self
+    }
+
+    /// Returns a builder to connect a client that is authenticated, uses RESP3 or another database.
     ///
-    /// * `key` - A required key to send to the server
+    /// # Examples
     ///
-    /// # Returns
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::builder().port(6380).password("secret").build().await.unwrap();
+    /// }
+    /// ```
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
+    }
+
+    /// Hands the connection over to a `MultiplexedClient`, which can be cloned and shared between tasks.
+    ///
+    /// The negotiated protocol version, the metrics observer and the key prefix are kept.
     ///
-    /// * `Ok(Some(String))` if the key to GET exists
-    /// * `Ok(None)` if the key to GET does not exist
-    /// * `Err(RedisError)` if an error occurs
-    ///     
     /// # Examples
     ///
     /// ```ignore
-    /// use async_redis::Client;
-    ///
     /// #[tokio::main]
     /// async fn main() {
     ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
-    ///     let resp = client.get("mykey").await?;
+    ///     client.hello(Some(3)).await.unwrap();
+    ///
+    ///     let client = client.into_multiplexed();
     /// }
     /// ```
-    pub async fn get(&mut self, key: &str) -> Result<Option<Vec<u8>>> {
-        let frame: Frame = Get::new(key).try_into()?;
-
-        self.conn
-            .write_frame(&frame)
-            .await
-            .with_context(|| "failed to write frame for GET command")?;
-
-        match self
-            .read_response()
-            .await
-            .with_context(|| "failed to read response for GET command")?
-        {
-            Response::Simple(data) => Ok(Some(data)),
-            Response::Null => Ok(None),
-            Response::Error(err) => Err(err),
-            _ => Err(RedisError::UnexpectedResponseType),
-        }
+    pub fn into_multiplexed(self) -> MultiplexedClient {
+        MultiplexedClient::new(self.conn, self.observer).with_key_prefix(self.key_prefix)
     }
 
-    /// Sends a GETEX command to the Redis server.
+    /// Sends a HELLO command to the Redis server.
     ///
     /// # Description
-    /// The GETEX command retrieves the value of a key stored on the Redis server and sets an expiry time.
+    ///
+    /// The HELLO command switches the connection to the given protocol version and
+    /// returns the server properties. The negotiated version is tracked by the client.
     ///
     /// # Arguments
     ///
-    /// * `key` - A required key to send to the server
-    /// * `expiry` - An optional expiry time to set
+    /// * `proto` - An optional protocol version to use
     ///
     /// # Returns
     ///
-    /// * `Ok(Some(String))` if the key to GETEX exists
-    /// * `Ok(None)` if the key to GETEX does not exist
+    /// * `Ok(HelloResponse)` if the HELLO command is successful
     /// * `Err(RedisError)` if an error occurs
     ///
     /// # Examples
     ///
     /// ```ignore
-    /// use async_redisx::{Client, Expiry};
-    ///
     /// #[tokio::main]
     /// async fn main() {
     ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
-    ///     let resp = client.get_ex("mykey", Some(Expirt::EX(1_u64))).await?;
+    ///     let resp = client.hello(Some(3)).await?;
+    ///     assert_eq!(resp.proto, 3);
     /// }
-    /// ```
-    pub async fn get_ex(&mut self, key: &str, expiry: Option<Expiry>) -> Result<Option<Vec<u8>>> {
-        let frame: Frame = GetEx::new(key, expiry).try_into()?;
-
-        self.conn.write_frame(&frame).await?;
-
-        match self.read_response().await? {
-            Response::Simple(data) => Ok(Some(data)),
-            Response::Null => Ok(None),
-            Response::Error(err) => Err(err),
-            _ => Err(RedisError::UnexpectedResponseType),
-        }
-    }
-
-    /// Sends a MGET command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn mget(&mut self, keys: Vec<&str>) -> Result<Option<Vec<Vec<u8>>>> {
-        todo!("MGET command is not implemented yet");
-        // let frame: Frame = MGet::new(keys).into_stream();
-
-        // self.conn.write_frame(&frame).await?;
-
-        // match self.read_response().await? {
-        //     Response::Array(data) => Ok(Some(data)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+    pub async fn hello(&mut self, proto: Option<u8>) -> Result<HelloResponse> {
+        self.handshake(Hello::new(proto)).await
     }
 
-    // todo: the real SET command has some other options like EX, PX, NX, XX
-    // we need to add these options to the SET command. Possibly with option pattern
-    /// Sends a SET command to the Redis server.
+    /// Sends a HELLO command with the AUTH and SETNAME clauses to the Redis server.
     ///
     /// # Description
     ///
-    /// The SET command sets the value of a key in the Redis server.
+    /// This switches the protocol, authenticates and names the connection in a single round trip.
     ///
     /// # Arguments
     ///
-    /// * `key` - A required key to set
-    /// * `val` - A required value to set
+    /// * `proto` - A required protocol version to use
+    /// * `auth` - An optional pair of username and password to authenticate with
+    /// * `client_name` - An optional name for the connection
     ///
     /// # Returns
     ///
-    /// * `Ok(Some(String))` if the key is set successfully
-    /// * `Ok(None)` if the key is not set
+    /// * `Ok(HelloResponse)` if the HELLO command is successful
+    /// * `Err(RedisError)` if an error occurs
     ///
     /// # Examples
     ///
     /// ```ignore
-    /// use async_redis::Client;
-    ///
     /// #[tokio::main]
     /// async fn main() {
     ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
-    ///     let resp = client.set("mykey", "myvalue").await?;
+    ///     let resp = client.hello_with(3, Some(("default", "secret")), Some("worker-1")).await?;
     /// }
-    pub async fn set(&mut self, key: &str, val: &[u8]) -> Result<Option<Vec<u8>>> {
-        let frame: Frame = Set::new(key, val).try_into()?;
-
-        self.conn
-            .write_frame(&frame)
-            .await
-            .with_context(|| "failed to write frame for SET command")?;
-
-        match self
-            .read_response()
-            .await
-            .with_context(|| "failed to read response for SET command")?
-        {
-            Response::Simple(data) => Ok(Some(data)),
-            Response::Null => Ok(None),
-            Response::Error(err) => Err(err),
-            _ => Err(RedisError::UnexpectedResponseType),
+    pub async fn hello_with(
+        &mut self,
+        proto: u8,
+        auth: Option<(&str, &str)>,
+        client_name: Option<&str>,
+    ) -> Result<HelloResponse> {
+        let mut hello = Hello::new(Some(proto));
+
+        if let Some((username, password)) = auth {
+            hello = hello.auth(username, password);
         }
-    }
-
-    /// Sends a SETEX command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn set_ex(&mut self, key: &str, val: &[u8], seconds: i64) -> Result<Option<Vec<u8>>> {
-        todo!("SETEX command is not implemented yet");
-        // let frame: Frame = SetEx::new(key, val, seconds).into_stream();
 
-        // self.conn.write_frame(&frame).await?;
+        if let Some(name) = client_name {
+            hello = hello.setname(name);
+        }
 
-        // match self.read_response().await? {
-        //     Response::Simple(data) => Ok(Some(data)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+        self.handshake(hello).await
     }
 
-    /// Sends a SETNX command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn set_nx(&mut self, key: &str, val: &[u8]) -> Result<Option<Vec<u8>>> {
-        todo!("SETNX command is not implemented yet");
-        // let frame: Frame = SetNx::new(key, val).into_stream();
-
-        // self.conn.write_frame(&frame).await?;
-
-        // match self.read_response().await? {
-        //     Response::Simple(data) => Ok(Some(data)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+    async fn handshake(&mut self, hello: Hello) -> Result<HelloResponse> {
+        // parse the raw frame, a `Response` cannot hold the nested module list
+        match self.execute_raw(hello).await? {
+            Some(Frame::SimpleError(data)) => Err(unsupported_protocol(data)),
+            Some(Frame::BulkError(data)) => Err(unsupported_protocol(
+                String::from_utf8_lossy(&data).to_string(),
+            )),
+            Some(frame) => {
+                let response = HelloResponse::try_from(frame)?;
+                self.conn
+                    .set_protocol_version(ProtocolVersion::try_from(response.proto)?);
+
+                Ok(response)
+            }
+            None => Err(RedisError::Unknown),
+        }
     }
 
-    /// Sends a DEL command to the Redis server.
-    ///
-    /// # Description
+    /// Returns the RESP version of the connection.
     ///
-    /// The DEL command deletes a key from the Redis server.
+    /// It is RESP2 until a HELLO command negotiates another version, and is only updated when the
+    /// HELLO command succeeds.
     ///
-    /// # Arguments
+    /// # Examples
     ///
-    /// * `keys` - A required vector of keys to delete
+    /// ```ignore
+    /// use redis_asyncx::ProtocolVersion;
     ///
-    /// # Returns
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     client.hello(Some(3)).await.unwrap();
+    ///     assert_eq!(client.protocol_version(), ProtocolVersion::Resp3);
+    /// }
+    /// ```
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.conn.protocol_version()
+    }
+
+    /// Sets how long every command waits for its reply, None to wait forever, the default.
     ///
-    /// * `Ok(u64)` the number of keys deleted
+    /// A command that times out fails with `RedisError::Timeout`. Its reply may still arrive
+    /// later, so the connection is left unusable and the client should be dropped.
     ///
     /// # Examples
     ///
     /// ```ignore
-    ///
-    /// use async_redis::Client;
+    /// use std::time::Duration;
     ///
     /// #[tokio::main]
     /// async fn main() {
     ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
-    ///     let resp = client.del(vec!["foo", "bar", "baz"]).await?;
+    ///     client.set_command_timeout(Some(Duration::from_millis(500)));
     /// }
-    pub async fn del(&mut self, keys: Vec<&str>) -> Result<u64> {
-        let frame: Frame = Del::new(keys).try_into()?;
-
-        self.conn
-            .write_frame(&frame)
-            .await
-            .with_context(|| "failed to write frame for DEL command")?;
-
-        match self
-            .read_response()
-            .await
-            .with_context(|| "failed to read response for DEL command")?
-        {
-            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
-            Response::Error(err) => Err(err),
-            _ => Err(RedisError::UnexpectedResponseType),
-        }
+    /// ```
+    pub fn set_command_timeout(&mut self, timeout: Option<Duration>) {
+        self.command_timeout = timeout;
     }
 
-    /// Sends an EXISTS command to the Redis server.
+    /// Sets how commands failing with a transient error are retried, none are by default.
     ///
     /// # Description
     ///
-    /// The EXISTS command checks if a key exists in the Redis server.
-    ///
-    /// # Arguments
-    ///
-    /// * `keys` - A required vector of keys to check
-    ///
-    /// # Returns
-    ///
-    /// * `Ok(u64)` the number of keys that exist
+    /// A command the server rejects with `LOADING`, `BUSY` or `TRYAGAIN` is sent again after a
+    /// backoff, up to `max_attempts` times, and fails with the last error after that. A command
+    /// that timed out is not retried, since the connection is no longer usable. Neither are the
+    /// non-idempotent commands unless listed in `retry_non_idempotent`, nor the commands sent with
+    /// `Client::command` or `Client::command_raw`, whose effects are not known.
     ///
     /// # Examples
     ///
     /// ```ignore
+    /// use redis_asyncx::{Client, RetryPolicy};
+    ///
     /// #[tokio::main]
     /// async fn main() {
     ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
-    ///     let resp = client.exists(vec!["foo", "bar", "baz"]).await?;
+    ///     client.set_retry_policy(Some(RetryPolicy::default()));
     /// }
-    pub async fn exists(&mut self, keys: Vec<&str>) -> Result<u64> {
-        let frame: Frame = Exists::new(keys).try_into()?;
-
-        self.conn
-            .write_frame(&frame)
-            .await
-            .with_context(|| "failed to write frame for EXISTS command")?;
-
-        match self
-            .read_response()
-            .await
-            .with_context(|| "failed to read response for EXISTS command")?
-        {
-            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
-            Response::Error(err) => Err(err),
-            _ => Err(RedisError::UnexpectedResponseType),
-        }
+    /// ```
+    pub fn set_retry_policy(&mut self, policy: Option<RetryPolicy>) {
+        self.retry_policy = policy;
     }
 
-    // todo: add EXAT, PXAT, NX, XX options
-    /// Sends an EXPIRE command to the Redis server.
+    /// Sets a prefix added to the keys of every command, e.g. `tenant:42:`, empty for none, the
+    /// default.
     ///
     /// # Description
     ///
-    /// The EXPIRE command sets a timeout on a key. After the timeout has expired, the key will be deleted.
+    /// The prefix namespaces the keys of the client, so that applications sharing a database do
+    /// not see each other's keys. It is added to every key argument, of multi-key commands such as
+    /// MGET and DEL too, never to values, members or fields. It is stripped from the keys in
+    /// replies: those of `Client::scan` and `Client::scan_iter`, which only return the keys of the
+    /// namespace, of `Client::random_key`, of the blocking pops, and of the keyspace notifications
+    /// received by a `Subscriber`.
+    ///
+    /// Scripts see the prefixed keys in KEYS. The commands sent as raw arguments, with
+    /// `Client::command`, `Client::command_raw` or in a `Pipeline`, and the channels subscribed to
+    /// are sent as they are. The prefix is kept by `Client::into_multiplexed`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     client.set_key_prefix("tenant:42:");
+    ///     // sets tenant:42:mykey
+    ///     client.set("mykey", "myvalue".as_bytes()).await?;
+    /// }
+    /// ```
+    pub fn set_key_prefix(&mut self, prefix: impl ToRedisArg) {
+        self.key_prefix = prefix.to_redis_arg();
+    }
+
+    /// Returns the prefix added to the keys of every command, empty for none.
+    pub fn key_prefix(&self) -> &[u8] {
+        &self.key_prefix
+    }
+
+    /// Sends a PING command to the Redis server, optionally with a message.
     ///
     /// # Arguments
     ///
-    /// * `key` - A required key to set the timeout
-    /// * `seconds` - A required number of seconds to set the timeout
+    /// * `msg` - An optional message to send to the server
     ///
     /// # Returns
     ///
-    /// * `Ok(1)` if the key is set successfully
-    /// * `Ok(0)` if the key is not set
+    /// * `Ok(Pong::Pong)` the bare PONG reply, without a message
+    /// * `Ok(Pong::Echo(Bytes))` the message echoed back
+    /// * `Err(RedisError)` if an error occurs
     ///
     /// # Examples
     ///
     /// ```ignore
+    /// use redis_asyncx::{Client, Pong};
+    ///
     /// #[tokio::main]
     /// async fn main() {
     ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
-    ///     let resp = client.expire("mykey", 1).await?;
+    ///     let pong = client.ping(Some(b"Hello Redis")).await.unwrap();
+    ///     assert_eq!(pong, Pong::Echo("Hello Redis".into()));
     /// }
-    pub async fn expire(&mut self, key: &str, seconds: i64) -> Result<u64> {
-        let frame: Frame = Expire::new(key, seconds).try_into()?;
-
-        self.conn
-            .write_frame(&frame)
-            .await
-            .with_context(|| "failed to write frame for EXPIRE command")?;
-
-        match self
-            .read_response()
-            .await
-            .with_context(|| "failed to read response for EXPIRE command")?
-        {
-            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
-            Response::Error(err) => Err(err),
-            _ => Err(RedisError::UnexpectedResponseType),
+    /// ```
+    pub async fn ping(&mut self, msg: Option<&[u8]>) -> Result<Pong> {
+        match self.execute_raw(Ping::new(msg)).await? {
+            Some(Frame::SimpleError(data)) => Err(RedisError::Other(anyhow!(data))),
+            Some(Frame::BulkError(data)) => Err(RedisError::Other(anyhow!(
+                String::from_utf8_lossy(&data).to_string()
+            ))),
+            Some(frame) => Pong::try_from(frame),
+            None => Err(RedisError::Unknown),
         }
     }
 
-    /// Sends a TTL command to the Redis server.
+    /// Checks that the server still answers, e.g. before reusing an idle connection.
     ///
     /// # Description
     ///
-    /// The TTL command returns the remaining time to live of a key that has an expire set.
-    ///
-    /// # Arguments
-    ///
-    /// * `key` - A required key to check ttl
+    /// Sends a PING command and waits up to one second for the PONG. Unlike `Client::ping`, errors
+    /// are not propagated, any failure means the connection is not alive. A PING that times out
+    /// leaves the connection unusable, since its reply may still arrive later.
     ///
     /// # Returns
     ///
-    /// * `Ok(-2)` if the key does not exist
-    /// * `Ok(-1)` if the key exists but has no expire set
-    /// * `Ok(other)` if the key exists and has an expire set
+    /// * `true` if the server replied with PONG in time
+    /// * `false` otherwise
     ///
     /// # Examples
     ///
     /// ```ignore
+    /// use redis_asyncx::Client;
+    ///
     /// #[tokio::main]
     /// async fn main() {
     ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
-    ///     let resp = client.ttl("mykey").await?;
+    ///     assert!(client.is_alive().await);
     /// }
-    pub async fn ttl(&mut self, key: &str) -> Result<i64> {
-        let frame: Frame = Ttl::new(key).try_into()?;
-
-        self.conn
-            .write_frame(&frame)
-            .await
-            .with_context(|| "failed to write frame for TTL command")?;
-
-        match self
-            .read_response()
-            .await
-            .with_context(|| "failed to read response for TTL command")?
-        {
-            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<i64>()?),
-            Response::Error(err) => Err(err),
-            _ => Err(RedisError::UnexpectedResponseType),
+    /// ```
+    pub async fn is_alive(&mut self) -> bool {
+        match tokio::time::timeout(IS_ALIVE_TIMEOUT, self.ping(None)).await {
+            Ok(Ok(pong)) => pong == Pong::Pong,
+            Ok(Err(_)) => false,
+            Err(_) => {
+                self.conn.poison();
+                false
+            }
         }
     }
 
-    /// Sends an INCR command to the Redis server.
+    /// Returns whether the connection can still be used, see `Connection::is_usable`.
+    pub(crate) fn is_usable(&self) -> bool {
+        self.conn.is_usable()
+    }
+
+    /// Sends a QUIT command to the Redis server and closes the connection.
     ///
     /// # Description
     ///
-    /// The INCR command increments the integer value of a key by one.
-    ///
-    /// # Arguments
-    ///
-    /// * `key` - A required key to increment
+    /// The QUIT command asks the server to close the connection once it has replied. The client
+    /// waits for the reply, then shuts down its side of the stream, so the server logs a regular
+    /// disconnection instead of a dropped one.
     ///
     /// # Returns
     ///
-    /// * `Ok(i64)` the new value of the key after increment
+    /// * `Ok(())` if the connection is closed
     /// * `Err(RedisError)` if an error occurs
     ///
     /// # Examples
@@ -515,72 +665,36 @@ impl Client {
     /// ```ignore
     /// #[tokio::main]
     /// async fn main() {
-    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
-    ///     let resp = client.incr("mykey").await?;
+    ///     let client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     client.quit().await?;
     /// }
-    pub async fn incr(&mut self, key: &str) -> Result<i64> {
-        let frame: Frame = Incr::new(key).try_into()?;
-
-        self.conn
-            .write_frame(&frame)
-            .await
-            .with_context(|| "failed to write frame for INCR command")?;
-
-        match self
-            .read_response()
-            .await
-            .with_context(|| "failed to read response for INCR command")?
-        {
-            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<i64>()?),
+    pub async fn quit(mut self) -> Result<()> {
+        match self.execute(Quit::new()).await? {
+            Response::Simple(_) => Ok(self
+                .conn
+                .shutdown()
+                .await
+                .with_context(|| "failed to shut down the connection")?),
             Response::Error(err) => Err(err),
             _ => Err(RedisError::UnexpectedResponseType),
         }
     }
 
-    /// Sends an INCRBY command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn incr_by(&mut self, key: &str, increment: i64) -> Result<i64> {
-        todo!("INCRBY command is not implemented yet");
-        // let frame: Frame = IncrBy::new(key, increment).into_stream();
-
-        // self.conn.write_frame(&frame).await?;
-
-        // match self.read_response().await? {
-        //     Response::Simple(data) => Ok(from_utf8(&data)?.parse::<i64>()?),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
-    }
-
-    /// Sends an INCRBYFLOAT command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn incr_by_float(&mut self, key: &str, increment: f64) -> Result<f64> {
-        todo!("INCRBYFLOAT command is not implemented yet");
-        // let frame: Frame = IncrByFloat::new(key, increment).into_stream();
-
-        // self.conn.write_frame(&frame).await?;
-
-        // match self.read_response().await? {
-        //     Response::Simple(data) => Ok(from_utf8(&data)?.parse::<f64>()?),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
-    }
-
-    /// Sends a DECR command to the Redis server.
+    /// Sends an AUTH command to the Redis server.
     ///
     /// # Description
     ///
-    /// The DECR command decrements the integer value of a key by one.
+    /// The AUTH command authenticates the connection, as the given ACL user or as the default user.
     ///
     /// # Arguments
     ///
-    /// * `key` - A required key to decrement
+    /// * `username` - An optional ACL user, the default user if None
+    /// * `password` - A required password of the user
     ///
     /// # Returns
     ///
-    /// * `Ok(i64)` the new value of the key after decrement
-    /// * `Err(RedisError)` if an error occurs
+    /// * `Ok(())` if the connection is authenticated
+    /// * `Err(RedisError)` if the credentials are rejected or an error occurs
     ///
     /// # Examples
     ///
@@ -588,72 +702,30 @@ impl Client {
     /// #[tokio::main]
     /// async fn main() {
     ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
-    ///     let resp = client.decr("mykey").await?;
+    ///     client.auth(Some("worker"), "secret").await?;
     /// }
-    pub async fn decr(&mut self, key: &str) -> Result<i64> {
-        let frame: Frame = Decr::new(key).try_into()?;
-
-        self.conn
-            .write_frame(&frame)
-            .await
-            .with_context(|| "failed to write frame for DECR command")?;
-
-        match self
-            .read_response()
-            .await
-            .with_context(|| "failed to read response for DECR command")?
-        {
-            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<i64>()?),
+    pub async fn auth(&mut self, username: Option<&str>, password: &str) -> Result<()> {
+        match self.execute(Auth::new(username, password)).await? {
+            Response::Simple(_) => Ok(()),
             Response::Error(err) => Err(err),
             _ => Err(RedisError::UnexpectedResponseType),
         }
     }
 
-    /// Sends a DECRBY command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn decr_by(&mut self, key: &str, decrement: i64) -> Result<i64> {
-        todo!("DECRBY command is not implemented yet");
-        // let frame: Frame = DecrBy::new(key, decrement).into_stream();
-
-        // self.conn.write_frame(&frame).await?;
-
-        // match self.read_response().await? {
-        //     Response::Simple(data) => Ok(from_utf8(&data)?.parse::<i64>()?),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
-    }
-
-    /// Sends a DECRBYFLOAT command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn decr_by_float(&mut self, key: &str, decrement: f64) -> Result<f64> {
-        todo!("DECRBYFLOAT command is not implemented yet");
-        // let frame: Frame = DecrByFloat::new(key, decrement).into_stream();
-
-        // self.conn.write_frame(&frame).await?;
-
-        // match self.read_response().await? {
-        //     Response::Simple(data) => Ok(from_utf8(&data)?.parse::<f64>()?),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
-    }
-
-    /// Sends an LPUSH command to the Redis server.
+    /// Sends a SELECT command to the Redis server.
     ///
     /// # Description
     ///
-    /// The LPUSH command inserts all the specified values at the head of the list stored at key.
+    /// The SELECT command switches the connection to another logical database, 0 by default.
     ///
     /// # Arguments
     ///
-    /// * `key` - A required key to insert values
-    /// * `values` - A required vector of values to insert
+    /// * `db` - A required index of the database
     ///
     /// # Returns
     ///
-    /// * `Ok(u64)` the length of the list after the push operation
-    /// * `Err(RedisError)` if an error occurs
+    /// * `Ok(())` if the database is selected
+    /// * `Err(RedisError)` if the index is out of range or an error occurs
     ///
     /// # Examples
     ///
@@ -661,84 +733,86 @@ impl Client {
     /// #[tokio::main]
     /// async fn main() {
     ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
-    ///     let resp = client.lpush("mykey", vec!["foo", "bar", "baz"]).await?;
+    ///     client.select(1).await?;
     /// }
-    pub async fn lpush(&mut self, key: &str, values: Vec<&[u8]>) -> Result<u64> {
-        let frame: Frame = LPush::new(key, values).try_into()?;
-
-        self.conn
-            .write_frame(&frame)
-            .await
-            .with_context(|| "failed to write frame for LPUSH command")?;
-
-        match self
-            .read_response()
-            .await
-            .with_context(|| "failed to read response for LPUSH command")?
-        {
-            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+    pub async fn select(&mut self, db: u64) -> Result<()> {
+        match self.execute(Select::new(db)).await? {
+            Response::Simple(_) => Ok(()),
             Response::Error(err) => Err(err),
             _ => Err(RedisError::UnexpectedResponseType),
         }
     }
 
-    /// Sends an RPUSH command to the Redis server.
+    /// Sends a CLUSTER SLOTS command to the Redis server.
     ///
     /// # Description
     ///
-    /// The RPUSH command inserts all the specified values at the tail of the list stored at key.
-    ///
-    /// # Arguments
-    ///
-    /// * `key` - A required key to insert values
-    /// * `values` - A required vector of values to insert
+    /// The CLUSTER SLOTS command returns which node of a Redis Cluster serves each range of hash
+    /// slots. Only the master of every range is returned, replicas are left out.
     ///
     /// # Returns
     ///
-    /// * `Ok(u64)` the length of the list after the push operation
+    /// * `Ok(Vec<(u16, u16, String)>)` the first and last slot of every range, and the address of
+    ///   its master as `host:port`. The host is empty if the node does not know its own address
+    /// * `Err(RedisError)` if an error occurs, e.g. if cluster mode is disabled
     ///
     /// # Examples
     ///
     /// ```ignore
     /// #[tokio::main]
     /// async fn main() {
-    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
-    ///     let resp = client.rpush("mykey", vec!["foo", "bar", "baz"]).await?;
+    ///     let mut client = Client::connect("127.0.0.1:7000").await.unwrap();
+    ///     for (start, end, addr) in client.cluster_slots().await.unwrap() {
+    ///         println!("{start}-{end}: {addr}");
+    ///     }
     /// }
-    pub async fn rpush(&mut self, key: &str, values: Vec<&[u8]>) -> Result<u64> {
-        let frame: Frame = RPush::new(key, values).try_into()?;
-
-        self.conn
-            .write_frame(&frame)
-            .await
-            .with_context(|| "failed to write frame for RPUSH command")?;
-
-        match self
-            .read_response()
-            .await
-            .with_context(|| "failed to read response for RPUSH command")?
-        {
-            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
-            Response::Error(err) => Err(err),
+    /// ```
+    pub async fn cluster_slots(&mut self) -> Result<Vec<(u16, u16, String)>> {
+        // every range nests the address of its nodes, which a `Response` cannot hold
+        match self.execute_raw(ClusterSlots::new()).await? {
+            Some(Frame::Array(ranges)) => ranges
+                .into_iter()
+                .map(|range| match range {
+                    Frame::Array(range) => match range.as_slice() {
+                        [
+                            Frame::Integer(start),
+                            Frame::Integer(end),
+                            Frame::Array(master),
+                            ..,
+                        ] => match master.as_slice() {
+                            [Frame::BulkString(host), Frame::Integer(port), ..] => Ok((
+                                u16::try_from(*start)?,
+                                u16::try_from(*end)?,
+                                format!("{}:{}", from_utf8(host)?, port),
+                            )),
+                            _ => Err(RedisError::UnexpectedResponseType),
+                        },
+                        _ => Err(RedisError::UnexpectedResponseType),
+                    },
+                    _ => Err(RedisError::UnexpectedResponseType),
+                })
+                .collect(),
+            Some(Frame::SimpleError(data)) => Err(RedisError::Other(anyhow!(data))),
             _ => Err(RedisError::UnexpectedResponseType),
         }
     }
 
-    /// Sends an LPOP command to the Redis server.
+    /// Sends a DEBUG SLEEP command to the Redis server.
     ///
     /// # Description
     ///
-    /// The LPOP command removes and returns the removed elements from the head of the list stored at key.
+    /// The DEBUG SLEEP command blocks the server for the given time before it replies, which is
+    /// meant to test how slow replies are handled, e.g. with `Client::set_command_timeout`. The
+    /// whole server is blocked, not only the connection. Since Redis 7.0, the DEBUG commands have
+    /// to be enabled with the `enable-debug-command` option.
     ///
     /// # Arguments
     ///
-    /// * `key` - A required key to remove values
-    /// * `count` - An optional number of elements to remove
+    /// * `seconds` - How long the server sleeps, fractions of a second are allowed
     ///
     /// # Returns
     ///
-    /// * `Ok(Some(String))` if the key exists and the elements are removed
-    /// * `Ok(None)` if the key does not exist
+    /// * `Ok(())` once the server woke up
     /// * `Err(RedisError)` if an error occurs
     ///
     /// # Examples
@@ -747,63 +821,27 @@ impl Client {
     /// #[tokio::main]
     /// async fn main() {
     ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
-    ///     let resp = client.lpop("mykey", 1).await?;
+    ///     client.debug_sleep(0.1).await.unwrap();
     /// }
-    pub async fn lpop(&mut self, key: &str) -> Result<Option<Vec<u8>>> {
-        let frame: Frame = LPop::new(key, None).try_into()?;
-
-        self.conn
-            .write_frame(&frame)
-            .await
-            .with_context(|| "failed to write frame for LPOP command")?;
-
-        match self
-            .read_response()
-            .await
-            .with_context(|| "failed to read response for LPOP command")?
-        {
-            Response::Simple(data) => Ok(Some(data)),
-            Response::Null => Ok(None),
-            Response::Error(err) => Err(err),
-            _ => Err(RedisError::UnexpectedResponseType),
-        }
-    }
-
-    pub async fn lpop_n(&mut self, key: &str, count: u64) -> Result<Option<Vec<Vec<u8>>>> {
-        let frame: Frame = LPop::new(key, Some(count)).try_into()?;
-
-        self.conn
-            .write_frame(&frame)
-            .await
-            .with_context(|| "failed to write frame for LPOP command")?;
-
-        match self
-            .read_response()
-            .await
-            .with_context(|| "failed to read response for LPOP command")?
-        {
-            Response::Array(data) => Ok(Some(data)),
-            Response::Null => Ok(None),
+    /// ```
+    pub async fn debug_sleep(&mut self, seconds: f64) -> Result<()> {
+        match self.execute(DebugSleep::new(seconds)).await? {
+            Response::Simple(_) => Ok(()),
             Response::Error(err) => Err(err),
             _ => Err(RedisError::UnexpectedResponseType),
         }
     }
 
-    /// Sends an RPOP command to the Redis server.
+    /// Sends a ROLE command to the Redis server.
     ///
     /// # Description
     ///
-    /// The RPOP command removes and returns the removed elements from the tail of the list stored at key.
-    ///
-    /// # Arguments
-    ///
-    /// * `key` - A required key to remove values
-    /// * `count` - An optional number of elements to remove
+    /// The ROLE command returns the role of the server in replication, along with details about
+    /// its replicas or its master, which are left out here.
     ///
     /// # Returns
     ///
-    /// * `Ok(Some(String))` if the key exists and the elements are removed
-    /// * `Ok(None)` if the key does not exist
+    /// * `Ok(String)` the role, either `master`, `slave` or `sentinel`
     /// * `Err(RedisError)` if an error occurs
     ///
     /// # Examples
@@ -812,64 +850,65 @@ impl Client {
     /// #[tokio::main]
     /// async fn main() {
     ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
-    ///     let resp = client.rpop("mykey", 1).await?;
+    ///     assert_eq!(client.role().await.unwrap(), "master");
     /// }
-    pub async fn rpop(&mut self, key: &str) -> Result<Option<Vec<u8>>> {
-        let frame: Frame = RPop::new(key, None).try_into()?;
-
-        self.conn
-            .write_frame(&frame)
-            .await
-            .with_context(|| "failed to write frame for RPOP command")?;
-
-        match self
-            .read_response()
-            .await
-            .with_context(|| "failed to read response for RPOP command")?
-        {
-            Response::Simple(data) => Ok(Some(data)),
-            Response::Null => Ok(None),
-            Response::Error(err) => Err(err),
+    /// ```
+    pub async fn role(&mut self) -> Result<String> {
+        // the details nest arrays, which a `Response` cannot hold
+        match self.execute_raw(Role::new()).await? {
+            Some(Frame::Array(data)) => match data.into_iter().next() {
+                Some(Frame::BulkString(role)) => Ok(from_utf8(&role)?.to_string()),
+                _ => Err(RedisError::UnexpectedResponseType),
+            },
+            Some(Frame::SimpleError(data)) => Err(RedisError::Other(anyhow!(data))),
             _ => Err(RedisError::UnexpectedResponseType),
         }
     }
 
-    pub async fn rpop_n(&mut self, key: &str, count: u64) -> Result<Option<Vec<Vec<u8>>>> {
-        let frame: Frame = RPop::new(key, Some(count)).try_into()?;
-
-        self.conn
-            .write_frame(&frame)
-            .await
-            .with_context(|| "failed to write frame for RPOP command")?;
-
-        match self
-            .read_response()
-            .await
-            .with_context(|| "failed to read response for RPOP command")?
-        {
-            Response::Array(data) => Ok(Some(data)),
-            Response::Null => Ok(None),
+    /// Sends a COMMAND COUNT command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The COMMAND COUNT command returns the number of commands the server supports, including
+    /// the ones of the loaded modules.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` the number of commands
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let count = client.command_count().await.unwrap();
+    /// }
+    /// ```
+    pub async fn command_count(&mut self) -> Result<u64> {
+        match self.execute(CommandCount::new()).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
             Response::Error(err) => Err(err),
             _ => Err(RedisError::UnexpectedResponseType),
         }
     }
 
-    /// Sends an LRANGE command to the Redis server.
+    /// Tells whether the server supports a command, with a COMMAND INFO command.
     ///
     /// # Description
     ///
-    /// The LRANGE command returns the specified elements of the list stored at key.
+    /// The COMMAND INFO command describes each of the given commands, or replies with a nil entry
+    /// for the ones the server does not know. The description itself is left out here.
     ///
     /// # Arguments
     ///
-    /// * `key` - A required key to get values
-    /// * `start` - A required start index
-    /// * `end` - A required end index
+    /// * `name` - The name of the command, in any case, e.g. `get` or `CONFIG`
     ///
     /// # Returns
     ///
-    /// * `Ok(Some(String))` if the key exists and the elements are returned
-    /// * `Ok(None)` if the key does not exist
+    /// * `Ok(true)` if the server supports the command
+    /// * `Ok(false)` if it does not
     /// * `Err(RedisError)` if an error occurs
     ///
     /// # Examples
@@ -878,539 +917,7089 @@ impl Client {
     /// #[tokio::main]
     /// async fn main() {
     ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
-    ///     let resp = client.lrange("mykey", 0, -1).await?;
+    ///     assert!(client.command_exists("get").await.unwrap());
     /// }
-    pub async fn lrange(&mut self, key: &str, start: i64, end: i64) -> Result<Vec<Vec<u8>>> {
-        let frame: Frame = LRange::new(key, start, end).try_into()?;
+    /// ```
+    pub async fn command_exists(&mut self, name: &str) -> Result<bool> {
+        // the description nests arrays, which a `Response` cannot hold
+        match self.execute_raw(CommandInfo::new(vec![name])).await? {
+            Some(Frame::Array(entries)) => match entries.first() {
+                Some(Frame::Null) => Ok(false),
+                Some(Frame::Array(_)) => Ok(true),
+                _ => Err(RedisError::UnexpectedResponseType),
+            },
+            Some(Frame::SimpleError(data)) => Err(RedisError::Other(anyhow!(data))),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
 
-        self.conn
-            .write_frame(&frame)
-            .await
-            .with_context(|| "failed to write frame for LRANGE command")?;
+    /// Sends a SENTINEL GET-MASTER-ADDR-BY-NAME command to a Redis Sentinel.
+    ///
+    /// # Description
+    ///
+    /// The SENTINEL GET-MASTER-ADDR-BY-NAME command returns the address of the current master of
+    /// the given name, as known by the sentinel. It changes once a failover is complete.
+    ///
+    /// # Arguments
+    ///
+    /// * `master_name` - The name of the master monitored by the sentinel
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(String))` the address of the master as `host:port`
+    /// * `Ok(None)` if the sentinel does not monitor a master of that name
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut sentinel = Client::connect("127.0.0.1:26379").await.unwrap();
+    ///     let addr = sentinel.sentinel_master_addr("mymaster").await.unwrap();
+    /// }
+    /// ```
+    pub async fn sentinel_master_addr(&mut self, master_name: &str) -> Result<Option<String>> {
+        match self
+            .execute(SentinelGetMasterAddrByName::new(master_name))
+            .await?
+        {
+            Response::Array(data) => match <[Bytes; 2]>::try_from(data) {
+                Ok([host, port]) => {
+                    Ok(Some(format!("{}:{}", from_utf8(&host)?, from_utf8(&port)?)))
+                }
+                Err(_) => Err(RedisError::UnexpectedResponseType),
+            },
+            Response::Null => Ok(None),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a CLIENT SETNAME command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The CLIENT SETNAME command assigns a name to the current connection, which then
+    /// shows up in the output of CLIENT LIST. An empty name removes the current name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - A required name, without spaces, newlines or special characters
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the name is set successfully
+    /// * `Err(RedisError::InvalidArgument)` if the name is rejected before sending
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     client.set_client_name("worker-1").await?;
+    /// }
+    pub async fn set_client_name(&mut self, name: &str) -> Result<()> {
+        match self.execute(ClientSetName::new(name)).await? {
+            Response::Simple(_) => Ok(()),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a CLIENT GETNAME command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The CLIENT GETNAME command returns the name of the current connection.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(String))` if the connection has a name
+    /// * `Ok(None)` if no name is set
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let name = client.get_client_name().await?;
+    /// }
+    pub async fn get_client_name(&mut self) -> Result<Option<String>> {
+        match self.execute(ClientGetName::new()).await? {
+            Response::Simple(data) => Ok(Some(String::from_utf8_lossy(&data).to_string())),
+            Response::Null => Ok(None),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a CLIENT ID command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The CLIENT ID command returns the unique ID of the connection, to find it in CLIENT LIST
+    /// or to kill it with CLIENT KILL from another connection.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(i64)` the ID of the connection
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let id = client.client_id().await?;
+    /// }
+    pub async fn client_id(&mut self) -> Result<i64> {
+        match self.execute(ClientId::new()).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<i64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a CLIENT NO-EVICT command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The CLIENT NO-EVICT command excludes the connection from client eviction, so that a
+    /// long-lived maintenance connection is not closed once the memory used by the clients exceeds
+    /// `maxmemory-clients`.
+    ///
+    /// # Arguments
+    ///
+    /// * `on` - Whether the connection is excluded, false to make it evictable again
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` once the mode is set
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     client.client_no_evict(true).await?;
+    /// }
+    pub async fn client_no_evict(&mut self, on: bool) -> Result<()> {
+        match self.execute(ClientNoEvict::new(on)).await? {
+            Response::Simple(_) => Ok(()),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a CLIENT NO-TOUCH command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The CLIENT NO-TOUCH command stops the commands of the connection from updating the last
+    /// access time and the access frequency of the keys they read, so that e.g. a backup or an
+    /// inspection does not skew the LRU and LFU eviction. TOUCH still updates them.
+    ///
+    /// # Arguments
+    ///
+    /// * `on` - Whether the keys are left untouched, false to update them again
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` once the mode is set
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     client.client_no_touch(true).await?;
+    /// }
+    pub async fn client_no_touch(&mut self, on: bool) -> Result<()> {
+        match self.execute(ClientNoTouch::new(on)).await? {
+            Response::Simple(_) => Ok(()),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a CLIENT LIST command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The CLIENT LIST command describes the connections to the server, one per line, which are
+    /// parsed into a `ClientInfo` each.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - The connections to list, `ClientListFilter::new()` for all of them
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<ClientInfo>)` the connections
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let clients = client.client_list(ClientListFilter::new()).await?;
+    /// }
+    pub async fn client_list(&mut self, filter: ClientListFilter) -> Result<Vec<ClientInfo>> {
+        match self.execute_raw(ClientList::new(filter)).await? {
+            Some(Frame::SimpleError(data)) => Err(RedisError::Other(anyhow!(data))),
+            Some(Frame::BulkError(data)) => Err(RedisError::Other(anyhow!(
+                String::from_utf8_lossy(&data).to_string()
+            ))),
+            Some(frame) => parse_client_list(frame),
+            None => Err(RedisError::Unknown),
+        }
+    }
+
+    /// Sends a CLIENT KILL command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The CLIENT KILL command closes the connections matching every condition of the filter.
+    /// Unless the filter is by ID, the connection sending the command is spared.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - The connections to close, at least one condition is required
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` the number of connections closed
+    /// * `Err(RedisError::InvalidArgument)` if the filter has no condition
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let killed = client.client_kill(ClientKillFilter::new().id(42)).await?;
+    /// }
+    pub async fn client_kill(&mut self, filter: ClientKillFilter) -> Result<u64> {
+        match self.execute(ClientKill::new(filter)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a CONFIG GET command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The CONFIG GET command reads the configuration parameters of a running Redis server.
+    ///
+    /// # Arguments
+    ///
+    /// * `parameter` - The parameter to read, glob-style patterns like `maxmemory*` are allowed
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<String, String>)` the matched parameters and their values, empty if none
+    ///   matched
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let config = client.config_get("maxmemory").await?;
+    /// }
+    pub async fn config_get(&mut self, parameter: &str) -> Result<HashMap<String, String>> {
+        match self.execute(ConfigGet::new(parameter)).await? {
+            // RESP2 replies with a flat array of alternating names and values
+            Response::Array(data) => {
+                if data.len() % 2 != 0 {
+                    return Err(RedisError::UnexpectedResponseType);
+                }
+
+                data.chunks_exact(2)
+                    .map(|pair| {
+                        Ok((
+                            from_utf8(&pair[0])?.to_string(),
+                            from_utf8(&pair[1])?.to_string(),
+                        ))
+                    })
+                    .collect()
+            }
+            Response::Map(data) => data
+                .into_iter()
+                .map(|(key, value)| Ok((key, from_utf8(&value.into_scalar()?)?.to_string())))
+                .collect(),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a CONFIG SET command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The CONFIG SET command changes a configuration parameter at runtime, without restarting the server.
+    ///
+    /// # Arguments
+    ///
+    /// * `parameter` - The parameter to change
+    /// * `value` - The new value of the parameter
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the parameter is changed
+    /// * `Err(RedisError::UnknownConfigParameter)` if the server does not know the parameter
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     client.config_set("maxmemory-policy", "allkeys-lru").await?;
+    /// }
+    pub async fn config_set(&mut self, parameter: &str, value: &str) -> Result<()> {
+        match self.execute(ConfigSet::new(parameter, value)).await? {
+            Response::Simple(_) => Ok(()),
+            Response::Error(err) => Err(unknown_config_parameter(err)),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a CONFIG SET command changing several parameters to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// Since Redis 7.0, CONFIG SET changes several parameters at once. Either all of them are
+    /// changed or none is.
+    ///
+    /// # Arguments
+    ///
+    /// * `pairs` - The parameters to change and their new values
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if every parameter is changed
+    /// * `Err(RedisError::UnknownConfigParameter)` if the server does not know one of the
+    ///   parameters
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     client
+    ///         .config_set_many(vec![("maxmemory", "100mb"), ("maxmemory-policy", "allkeys-lru")])
+    ///         .await?;
+    /// }
+    pub async fn config_set_many(&mut self, pairs: Vec<(&str, &str)>) -> Result<()> {
+        match self.execute(ConfigSet::many(pairs)).await? {
+            Response::Simple(_) => Ok(()),
+            Response::Error(err) => Err(unknown_config_parameter(err)),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a CONFIG RESETSTAT command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The CONFIG RESETSTAT command resets the statistics reported by INFO, e.g. the number of
+    /// commands processed and the keyspace hits and misses.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` once the statistics are reset
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     client.config_resetstat().await?;
+    /// }
+    pub async fn config_resetstat(&mut self) -> Result<()> {
+        match self.execute(ConfigResetStat::new()).await? {
+            Response::Simple(_) => Ok(()),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a CONFIG REWRITE command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The CONFIG REWRITE command writes the configuration changed with CONFIG SET back to the
+    /// configuration file the server was started with.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` once the file is rewritten
+    /// * `Err(RedisError)` if an error occurs, e.g. the server was started without a
+    ///   configuration file
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     client.config_rewrite().await?;
+    /// }
+    pub async fn config_rewrite(&mut self) -> Result<()> {
+        match self.execute(ConfigRewrite::new()).await? {
+            Response::Simple(_) => Ok(()),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a SLOWLOG GET command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The SLOWLOG GET command returns the most recent entries of the slow log, the commands
+    /// whose execution took longer than `slowlog-log-slower-than` microseconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The number of entries to return, the 10 most recent if None, all of them with
+    ///   -1 since Redis 7.0
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<SlowlogEntry>)` the entries, the most recent first
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let entries = client.slowlog_get(Some(5)).await?;
+    /// }
+    pub async fn slowlog_get(&mut self, count: Option<i64>) -> Result<Vec<SlowlogEntry>> {
+        match self.execute_raw(SlowlogGet::new(count)).await? {
+            Some(Frame::Array(entries)) => {
+                entries.into_iter().map(SlowlogEntry::try_from).collect()
+            }
+            Some(Frame::SimpleError(data)) => Err(RedisError::Other(anyhow!(data))),
+            Some(Frame::BulkError(data)) => Err(RedisError::Other(anyhow!(
+                String::from_utf8_lossy(&data).to_string()
+            ))),
+            Some(_) => Err(RedisError::UnexpectedResponseType),
+            None => Err(RedisError::Unknown),
+        }
+    }
+
+    /// Sends a SLOWLOG LEN command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The SLOWLOG LEN command returns the number of entries in the slow log, at most
+    /// `slowlog-max-len`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` the number of entries
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let len = client.slowlog_len().await?;
+    /// }
+    pub async fn slowlog_len(&mut self) -> Result<u64> {
+        match self.execute(SlowlogLen::new()).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a SLOWLOG RESET command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The SLOWLOG RESET command deletes every entry of the slow log, for good.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` once the slow log is empty
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     client.slowlog_reset().await?;
+    /// }
+    pub async fn slowlog_reset(&mut self) -> Result<()> {
+        match self.execute(SlowlogReset::new()).await? {
+            Response::Simple(_) => Ok(()),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a GET command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The GET command retrieves the value of a key stored on the Redis server.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key to send to the server
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(String))` if the key to GET exists
+    /// * `Ok(None)` if the key to GET does not exist
+    /// * `Err(RedisError)` if an error occurs
+    ///     
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use async_redis::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.get("mykey").await?;
+    /// }
+    /// ```
+    pub async fn get(&mut self, key: impl ToRedisArg) -> Result<Option<Bytes>> {
+        match self.execute(Get::new(key)).await? {
+            Response::Simple(data) => Ok(Some(data)),
+            Response::Null => Ok(None),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a GETEX command to the Redis server.
+    ///
+    /// # Description
+    /// The GETEX command retrieves the value of a key stored on the Redis server and sets an expiry time.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key to send to the server
+    /// * `expiry` - An optional expiry time to set
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(String))` if the key to GETEX exists
+    /// * `Ok(None)` if the key to GETEX does not exist
+    /// * `Err(RedisError::InvalidArgument)` if the expiry is not positive or too large for the server
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use redis_asyncx::{Client, Expiry};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.get_ex("mykey", Some(Expiry::EX(1_u64))).await?;
+    /// }
+    /// ```
+    pub async fn get_ex(
+        &mut self,
+        key: impl ToRedisArg,
+        expiry: Option<Expiry>,
+    ) -> Result<Option<Bytes>> {
+        match self.execute(GetEx::new(key, expiry)).await? {
+            Response::Simple(data) => Ok(Some(data)),
+            Response::Null => Ok(None),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a GETEX command with the PERSIST option to the Redis server.
+    ///
+    /// # Description
+    /// Retrieves the value of a key and removes its expiry, a shorthand for `get_ex(key, Some(Expiry::PERSIST))`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key to send to the server
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(String))` if the key exists
+    /// * `Ok(None)` if the key does not exist
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.get_ex_persist("mykey").await?;
+    /// }
+    /// ```
+    pub async fn get_ex_persist(&mut self, key: impl ToRedisArg) -> Result<Option<Bytes>> {
+        self.get_ex(key, Some(Expiry::PERSIST)).await
+    }
+
+    /// Serializes a value into JSON and sets it as the value of a key, with a SET command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to set
+    /// * `value` - The value to serialize with `serde_json`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` once the key is set
+    /// * `Err(RedisError::Serde)` if the value does not serialize into JSON, e.g. a map whose
+    ///   keys are not strings
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[derive(serde::Serialize, serde::Deserialize)]
+    /// struct User {
+    ///     name: String,
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     client.set_json("user:1", &User { name: "alice".to_string() }).await?;
+    ///     let user: Option<User> = client.get_json("user:1").await?;
+    /// }
+    /// ```
+    #[cfg(feature = "serde")]
+    pub async fn set_json<T: serde::Serialize + ?Sized>(
+        &mut self,
+        key: impl ToRedisArg,
+        value: &T,
+    ) -> Result<()> {
+        let key = key.to_redis_arg();
+        let value = to_json(&key, value)?;
+        self.set(key, &value).await?;
+
+        Ok(())
+    }
+
+    /// Gets the value of a key, with a GET command, and deserializes it from JSON.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to get
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(T))` the deserialized value of the key
+    /// * `Ok(None)` if the key does not exist
+    /// * `Err(RedisError::Serde)` if the value is not the JSON of a `T`, along with the key
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let user: Option<User> = client.get_json("user:1").await?;
+    /// }
+    /// ```
+    #[cfg(feature = "serde")]
+    pub async fn get_json<T: serde::de::DeserializeOwned>(
+        &mut self,
+        key: impl ToRedisArg,
+    ) -> Result<Option<T>> {
+        let key = key.to_redis_arg();
+        match self.get(&key).await? {
+            Some(data) => from_json(&key, &data).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Sends an MGET command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The MGET command returns the values of several keys, in a single round trip.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The keys to get the values of
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Option<Bytes>>)` the value of each key in the order of `keys`, None for the keys
+    ///   that do not exist or do not hold a string
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let values = client.mget(vec!["key1", "key2"]).await?;
+    /// }
+    /// ```
+    pub async fn mget(&mut self, keys: Vec<impl ToRedisArg>) -> Result<Vec<Option<Bytes>>> {
+        // a `Response` cannot tell an empty string from a missing key
+        match self.execute_raw(MGet::new(keys)).await? {
+            Some(Frame::Array(values)) => values
+                .into_iter()
+                .map(|value| match value {
+                    Frame::BulkString(data) => Ok(Some(data)),
+                    Frame::Null => Ok(None),
+                    _ => Err(RedisError::UnexpectedResponseType),
+                })
+                .collect(),
+            Some(Frame::SimpleError(data)) => Err(RedisError::from_server(&data)),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a SET command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The SET command sets the value of a key in the Redis server.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key to set
+    /// * `val` - A required value to set
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(String))` if the key is set successfully
+    /// * `Ok(None)` if the key is not set
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use async_redis::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.set("mykey", "myvalue").await?;
+    /// }
+    pub async fn set(&mut self, key: impl ToRedisArg, val: &[u8]) -> Result<Option<Bytes>> {
+        match self.execute(Set::new(key, val)).await? {
+            Response::Simple(data) => Ok(Some(data)),
+            Response::Null => Ok(None),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a SET command with options to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The SET command sets the value of a key, optionally with an expiry, only if the key exists
+    /// or does not exist, and returning the value it replaces.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key to set
+    /// * `val` - A required value to set
+    /// * `options` - The SET options
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SetOutcome::Set)` if the key is set
+    /// * `Ok(SetOutcome::NotSet)` if the condition does not hold
+    /// * `Ok(SetOutcome::PreviousValue(value))` with `SetOptions::get`, the value stored before,
+    ///   None if the key did not exist
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use redis_asyncx::{Client, Expiry, SetCondition, SetOptions};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let options = SetOptions {
+    ///         expiry: Some(Expiry::EX(60)),
+    ///         condition: Some(SetCondition::NX),
+    ///         ..Default::default()
+    ///     };
+    ///     let outcome = client.set_opts("mykey", b"myvalue", options).await?;
+    /// }
+    pub async fn set_opts(
+        &mut self,
+        key: impl ToRedisArg,
+        val: &[u8],
+        options: SetOptions,
+    ) -> Result<SetOutcome> {
+        // with GET, a null reply means there was no previous value rather than a failed condition
+        let get = options.get;
+        match self
+            .execute_raw(Set::with_options(key, val, options))
+            .await?
+        {
+            Some(Frame::SimpleString(_)) if !get => Ok(SetOutcome::Set),
+            Some(Frame::Null) if !get => Ok(SetOutcome::NotSet),
+            Some(Frame::BulkString(data)) if get => {
+                Ok(SetOutcome::PreviousValue(Some(data.to_vec())))
+            }
+            Some(Frame::Null) => Ok(SetOutcome::PreviousValue(None)),
+            Some(Frame::SimpleError(data)) => Err(RedisError::Other(anyhow!(data))),
+            Some(Frame::BulkError(data)) => Err(RedisError::Other(anyhow!(
+                String::from_utf8_lossy(&data).to_string()
+            ))),
+            Some(_) => Err(RedisError::UnexpectedResponseType),
+            None => Err(RedisError::Unknown),
+        }
+    }
+
+    /// Sends a SETEX command to the Redis server.
+    #[allow(unused_variables)]
+    pub async fn set_ex(
+        &mut self,
+        key: impl ToRedisArg,
+        val: &[u8],
+        seconds: i64,
+    ) -> Result<Option<Bytes>> {
+        todo!("SETEX command is not implemented yet");
+        // match self.execute(SetEx::new(key, val, seconds)).await? {
+        //     Response::Simple(data) => Ok(Some(data)),
+        //     Response::Null => Ok(None),
+        //     Response::Error(err) => Err(err),
+        //     _ => Err(RedisError::UnexpectedResponseType),
+        // }
+    }
+
+    /// Sends a SETNX command to the Redis server.
+    #[allow(unused_variables)]
+    pub async fn set_nx(&mut self, key: impl ToRedisArg, val: &[u8]) -> Result<Option<Bytes>> {
+        todo!("SETNX command is not implemented yet");
+        // match self.execute(SetNx::new(key, val)).await? {
+        //     Response::Simple(data) => Ok(Some(data)),
+        //     Response::Null => Ok(None),
+        //     Response::Error(err) => Err(err),
+        //     _ => Err(RedisError::UnexpectedResponseType),
+        // }
+    }
+
+    /// Sends an MSET command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The MSET command sets the values of several keys at once, replacing the values already
+    /// there. The keys are all set together, no client sees some of them set and the others not.
+    ///
+    /// # Arguments
+    ///
+    /// * `pairs` - The keys and their values, at least one
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` once the keys are set
+    /// * `Err(RedisError::InvalidArgument)` if `pairs` is empty
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     client.mset(vec![("key1", b"value1".as_slice()), ("key2", b"value2".as_slice())]).await?;
+    /// }
+    pub async fn mset(&mut self, pairs: Vec<(impl ToRedisArg, &[u8])>) -> Result<()> {
+        match self.execute(MSet::new(pairs)).await? {
+            Response::Simple(_) => Ok(()),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an MSETNX command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The MSETNX command sets the values of several keys at once, unless any of them already
+    /// exists, in which case none is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `pairs` - The keys and their values, at least one
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` if every key is set
+    /// * `Ok(false)` if a key already exists, so none is set
+    /// * `Err(RedisError::InvalidArgument)` if `pairs` is empty
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let set = client.msetnx(vec![("key1", b"value1".as_slice()), ("key2", b"value2".as_slice())]).await?;
+    /// }
+    pub async fn msetnx(&mut self, pairs: Vec<(impl ToRedisArg, &[u8])>) -> Result<bool> {
+        match self.execute(MSetNx::new(pairs)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u8>()? == 1),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a DEL command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The DEL command deletes a key from the Redis server.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - A required vector of keys to delete
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` the number of keys deleted
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    ///
+    /// use async_redis::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.del(vec!["foo", "bar", "baz"]).await?;
+    /// }
+    pub async fn del(&mut self, keys: Vec<impl ToRedisArg>) -> Result<u64> {
+        match self.execute(Del::new(keys)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an UNLINK command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The UNLINK command removes keys like DEL, but reclaims their memory in a background thread.
+    /// Unlike DEL, it does not block the server while deleting a large list, set or hash.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - A required vector of keys to delete
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` the number of keys removed
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.unlink(vec!["foo", "bar", "baz"]).await?;
+    /// }
+    pub async fn unlink(&mut self, keys: Vec<impl ToRedisArg>) -> Result<u64> {
+        match self.execute(Unlink::new(keys)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an EXISTS command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The EXISTS command checks if a key exists in the Redis server.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - A required vector of keys to check
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` the number of keys that exist
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.exists(vec!["foo", "bar", "baz"]).await?;
+    /// }
+    pub async fn exists(&mut self, keys: Vec<impl ToRedisArg>) -> Result<u64> {
+        match self.execute(Exists::new(keys)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    // todo: add EXAT, PXAT, NX, XX options
+    /// Sends a TOUCH command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The TOUCH command updates the last access time of keys, as if they were read, e.g. to keep
+    /// them from being evicted by an LRU policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - A required vector of keys to touch
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` the number of keys that exist among the given ones
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.touch(vec!["foo", "bar"]).await?;
+    /// }
+    pub async fn touch(&mut self, keys: Vec<impl ToRedisArg>) -> Result<u64> {
+        match self.execute(Touch::new(keys)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a RANDOMKEY command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The RANDOMKEY command returns the name of a random key of the current database.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(String))` the name of the key
+    /// * `Ok(None)` if the database is empty, or if the key is not in the namespace of the
+    ///   client, see `Client::set_key_prefix`
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let key = client.random_key().await?;
+    /// }
+    pub async fn random_key(&mut self) -> Result<Option<String>> {
+        match self.execute(RandomKey::new()).await? {
+            Response::Simple(data) => Ok(self
+                .unprefixed(data)
+                .map(|key| String::from_utf8_lossy(&key).to_string())),
+            Response::Null => Ok(None),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an EXPIRE command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The EXPIRE command sets a timeout on a key. After the timeout has expired, the key will be deleted.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key to set the timeout
+    /// * `seconds` - A required number of seconds to set the timeout
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(1)` if the key is set successfully
+    /// * `Ok(0)` if the key is not set
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.expire("mykey", 1).await?;
+    /// }
+    pub async fn expire(&mut self, key: impl ToRedisArg, seconds: i64) -> Result<u64> {
+        match self.execute(Expire::new(key, seconds)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a PEXPIRE command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The PEXPIRE command sets a timeout on a key like EXPIRE, in milliseconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key to set the timeout
+    /// * `milliseconds` - A required number of milliseconds to set the timeout
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(1)` if the timeout is set
+    /// * `Ok(0)` if the key does not exist
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.pexpire("mykey", 1500).await?;
+    /// }
+    pub async fn pexpire(&mut self, key: impl ToRedisArg, milliseconds: u64) -> Result<u64> {
+        match self.execute(PExpire::new(key, milliseconds)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a TTL command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The TTL command returns the remaining time to live of a key that has an expire set.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key to check ttl
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(-2)` if the key does not exist
+    /// * `Ok(-1)` if the key exists but has no expire set
+    /// * `Ok(other)` if the key exists and has an expire set
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.ttl("mykey").await?;
+    /// }
+    pub async fn ttl(&mut self, key: impl ToRedisArg) -> Result<i64> {
+        match self.execute(Ttl::new(key)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<i64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a PTTL command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The PTTL command returns the remaining time to live of a key like TTL, in milliseconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key to check ttl
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(-2)` if the key does not exist
+    /// * `Ok(-1)` if the key exists but has no expire set
+    /// * `Ok(other)` if the key exists and has an expire set
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.pttl("mykey").await?;
+    /// }
+    pub async fn pttl(&mut self, key: impl ToRedisArg) -> Result<i64> {
+        match self.execute(PTtl::new(key)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<i64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an EXPIRETIME command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The EXPIRETIME command returns the absolute Unix timestamp, in seconds, at which a key
+    /// expires, as opposed to the time left returned by TTL. Available since Redis 7.0.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key to check the expiration time of
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(-2)` if the key does not exist
+    /// * `Ok(-1)` if the key exists but has no expire set
+    /// * `Ok(other)` the Unix timestamp in seconds the key expires at
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.expiretime("mykey").await?;
+    /// }
+    pub async fn expiretime(&mut self, key: impl ToRedisArg) -> Result<i64> {
+        match self.execute(ExpireTime::new(key)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<i64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a PEXPIRETIME command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The PEXPIRETIME command returns the absolute Unix timestamp a key expires at like
+    /// EXPIRETIME, in milliseconds. Available since Redis 7.0.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key to check the expiration time of
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(-2)` if the key does not exist
+    /// * `Ok(-1)` if the key exists but has no expire set
+    /// * `Ok(other)` the Unix timestamp in milliseconds the key expires at
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.pexpiretime("mykey").await?;
+    /// }
+    pub async fn pexpiretime(&mut self, key: impl ToRedisArg) -> Result<i64> {
+        match self.execute(PExpireTime::new(key)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<i64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an LCS command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The LCS command finds the longest common subsequence of the strings stored at two keys,
+    /// e.g. "mytext" for "ohmytext" and "mynewtext". A missing key is read as an empty string.
+    ///
+    /// # Arguments
+    ///
+    /// * `key1` - The key of the first string
+    /// * `key2` - The key of the second string
+    /// * `options` - What to return, see `LcsOptions`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(LcsResult::Match)` the subsequence, without options
+    /// * `Ok(LcsResult::Len)` its length, with `LcsOptions::len`
+    /// * `Ok(LcsResult::Idx)` the positions of the matches, with `LcsOptions::idx`
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let result = client
+    ///         .lcs("key1", "key2", LcsOptions::new().idx().with_match_len())
+    ///         .await?;
+    /// }
+    pub async fn lcs(
+        &mut self,
+        key1: impl ToRedisArg,
+        key2: impl ToRedisArg,
+        options: LcsOptions,
+    ) -> Result<LcsResult> {
+        match self.execute_raw(Lcs::new(key1, key2, options)).await? {
+            Some(Frame::SimpleError(data)) => Err(RedisError::Other(anyhow!(data))),
+            Some(Frame::BulkError(data)) => Err(RedisError::Other(anyhow!(
+                String::from_utf8_lossy(&data).to_string()
+            ))),
+            Some(frame) => LcsResult::try_from(frame),
+            None => Err(RedisError::Unknown),
+        }
+    }
+
+    /// Sends a DUMP command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The DUMP command serializes the value stored at a key in a Redis specific format, to be
+    /// restored with RESTORE. The payload is binary and returned untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key to serialize
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Bytes))` the serialized value
+    /// * `Ok(None)` if the key does not exist
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let payload = client.dump("mykey").await?;
+    /// }
+    pub async fn dump(&mut self, key: impl ToRedisArg) -> Result<Option<Bytes>> {
+        match self.execute(Dump::new(key)).await? {
+            Response::Simple(data) => Ok(Some(data)),
+            Response::Null => Ok(None),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a RESTORE command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The RESTORE command creates a key from a value serialized by DUMP.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key to create
+    /// * `ttl` - The time to live of the key in milliseconds, 0 for no expiry
+    /// * `payload` - The serialized value, as returned by `Client::dump`
+    /// * `options` - The RESTORE options, see `RestoreOptions`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the key was created
+    /// * `Err(RedisError::BusyKey)` if the key already exists and `options.replace` is not set
+    /// * `Err(RedisError)` if an error occurs, e.g. the payload is corrupted
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let payload = client.dump("mykey").await?.unwrap();
+    ///     client.restore("mycopy", 0, &payload, RestoreOptions::default()).await?;
+    /// }
+    pub async fn restore(
+        &mut self,
+        key: impl ToRedisArg,
+        ttl: u64,
+        payload: &[u8],
+        options: RestoreOptions,
+    ) -> Result<()> {
+        let payload = Bytes::copy_from_slice(payload);
+        match self
+            .execute(Restore::new(key, ttl, payload, options))
+            .await?
+        {
+            Response::Simple(_) => Ok(()),
+            Response::Error(err) => Err(busy_key(err)),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a MIGRATE command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The MIGRATE command transfers keys to another Redis instance, and deletes them from this
+    /// one unless `options.copy` is set. The transfer is atomic, the client blocks until the
+    /// target instance has created the keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `host` - The host of the target instance
+    /// * `port` - The port of the target instance
+    /// * `keys` - The keys to transfer, at least one
+    /// * `db` - The database of the target instance the keys are transferred to
+    /// * `timeout` - The maximum idle time of the transfer in milliseconds
+    /// * `options` - The MIGRATE options, see `MigrateOptions`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` if the keys were transferred
+    /// * `Ok(false)` if none of the keys exists
+    /// * `Err(RedisError::BusyKey)` if a key already exists on the target and `options.replace`
+    ///   is not set
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     client
+    ///         .migrate("10.0.0.2", 6379, vec!["mykey"], 0, 5000, MigrateOptions::default())
+    ///         .await?;
+    /// }
+    pub async fn migrate(
+        &mut self,
+        host: &str,
+        port: u16,
+        keys: Vec<impl ToRedisArg>,
+        db: u64,
+        timeout: u64,
+        options: MigrateOptions,
+    ) -> Result<bool> {
+        match self
+            .execute(Migrate::new(host, port, keys, db, timeout, options))
+            .await?
+        {
+            Response::Simple(data) => Ok(data != "NOKEY"),
+            Response::Error(err) => Err(busy_key(err)),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an OBJECT IDLETIME command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The OBJECT IDLETIME command returns the number of seconds since the key was last read or
+    /// written. The server does not track it when an LFU maxmemory policy is selected.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key to get the idle time of
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(u64))` the idle time in seconds
+    /// * `Ok(None)` if the key does not exist
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let idle = client.object_idletime("mykey").await?;
+    /// }
+    pub async fn object_idletime(&mut self, key: impl ToRedisArg) -> Result<Option<u64>> {
+        match self.execute(ObjectIdleTime::new(key)).await? {
+            Response::Simple(data) => Ok(Some(from_utf8(&data)?.parse::<u64>()?)),
+            Response::Null => Ok(None),
+            Response::Error(err) if no_such_key(&err) => Ok(None),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an OBJECT REFCOUNT command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The OBJECT REFCOUNT command returns the number of references to the value of a key, mostly
+    /// useful for debugging. Shared values, e.g. small integers, have a very large count.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key to get the reference count of
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(u64))` the reference count
+    /// * `Ok(None)` if the key does not exist
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let refcount = client.object_refcount("mykey").await?;
+    /// }
+    pub async fn object_refcount(&mut self, key: impl ToRedisArg) -> Result<Option<u64>> {
+        match self.execute(ObjectRefCount::new(key)).await? {
+            Response::Simple(data) => Ok(Some(from_utf8(&data)?.parse::<u64>()?)),
+            Response::Null => Ok(None),
+            Response::Error(err) if no_such_key(&err) => Ok(None),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an OBJECT FREQ command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The OBJECT FREQ command returns the logarithmic access frequency counter of the key. The
+    /// server only tracks it when an LFU maxmemory policy is selected, e.g. `allkeys-lfu`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key to get the access frequency of
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(u64))` the access frequency counter
+    /// * `Ok(None)` if the key does not exist
+    /// * `Err(RedisError::LfuNotSelected)` if the maxmemory policy is not an LFU one
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let freq = client.object_freq("mykey").await?;
+    /// }
+    pub async fn object_freq(&mut self, key: impl ToRedisArg) -> Result<Option<u64>> {
+        match self.execute(ObjectFreq::new(key)).await? {
+            Response::Simple(data) => Ok(Some(from_utf8(&data)?.parse::<u64>()?)),
+            Response::Null => Ok(None),
+            Response::Error(err) if no_such_key(&err) => Ok(None),
+            Response::Error(err) => Err(lfu_not_selected(err)),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a SCAN command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The SCAN command incrementally iterates over the keys in the current database.
+    /// Each call returns the cursor to continue from and a batch of keys. The iteration
+    /// is complete when the returned cursor is 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - A required cursor, 0 to start a new iteration
+    /// * `pattern` - An optional glob-style pattern to filter the keys
+    /// * `count` - An optional hint of how many keys to return per call
+    ///
+    /// With a key prefix, only the keys of the namespace are matched, and returned without the
+    /// prefix, see `Client::set_key_prefix`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((u64, Vec<Bytes>))` the next cursor and the batch of keys
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let (cursor, keys) = client.scan(0, Some("user:*"), None).await?;
+    /// }
+    pub async fn scan(
+        &mut self,
+        cursor: u64,
+        pattern: Option<&str>,
+        count: Option<u64>,
+    ) -> Result<(u64, Vec<Bytes>)> {
+        let pattern = self.namespaced_pattern(pattern);
+
+        // the keys are nested in a second array, which a `Response` cannot hold
+        match self
+            .execute_raw(Scan::new(cursor, pattern.as_deref(), count))
+            .await?
+        {
+            Some(Frame::Array(data)) => match <[Frame; 2]>::try_from(data) {
+                Ok([Frame::BulkString(cursor), Frame::Array(keys)]) => {
+                    let cursor = from_utf8(&cursor)?.parse::<u64>()?;
+                    let keys = keys
+                        .into_iter()
+                        .map(|key| match key {
+                            Frame::BulkString(data) => self
+                                .unprefixed(data)
+                                .ok_or(RedisError::UnexpectedResponseType),
+                            _ => Err(RedisError::UnexpectedResponseType),
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+
+                    Ok((cursor, keys))
+                }
+                _ => Err(RedisError::UnexpectedResponseType),
+            },
+            Some(Frame::SimpleError(data)) => Err(RedisError::Other(anyhow!(data))),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Iterates over all the keys matching a pattern by issuing SCAN commands.
+    ///
+    /// # Description
+    ///
+    /// Unlike KEYS, the iteration does not block the server. The returned iterator
+    /// calls SCAN lazily, one batch at a time, until the server returns cursor 0.
+    /// A key may be returned more than once if the keyspace changes during the iteration.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - An optional glob-style pattern to filter the keys
+    /// * `count` - An optional hint of how many keys to fetch per batch
+    ///
+    /// # Returns
+    ///
+    /// A `ScanIter` yielding the keys one by one
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let mut iter = client.scan_iter(Some("user:*"), None);
+    ///     while let Some(key) = iter.next_key().await? {
+    ///         println!("{key:?}");
+    ///     }
+    /// }
+    pub fn scan_iter(&mut self, pattern: Option<&str>, count: Option<u64>) -> ScanIter<'_> {
+        ScanIter {
+            client: self,
+            pattern: pattern.map(|p| p.to_string()),
+            count,
+            cursor: 0,
+            keys: VecDeque::new(),
+            finished: false,
+        }
+    }
+
+    /// Samples up to `n` keys and returns them from the hottest to the coldest.
+    ///
+    /// # Description
+    ///
+    /// The keys are fetched with SCAN, and the heat of each batch is read with OBJECT FREQ, or
+    /// OBJECT IDLETIME when the server does not track access frequencies. The OBJECT commands of a
+    /// batch are pipelined, so sampling costs two round trips per SCAN batch.
+    ///
+    /// The sample is as random as SCAN is, it is not the `n` hottest keys of the database. Keys
+    /// deleted during the sampling and keys that are not valid UTF-8 are left out.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The maximum number of keys to sample
+    /// * `pattern` - An optional glob-style pattern to filter the keys
+    /// * `count` - An optional hint of how many keys to fetch per batch
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(Bytes, KeyHeat)>)` the keys, the most frequently or recently used first
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     for (key, heat) in client.sample_hot_keys(100, Some("user:*"), None).await? {
+    ///         println!("{key:?}: {heat:?}");
+    ///     }
+    /// }
+    pub async fn sample_hot_keys(
+        &mut self,
+        n: usize,
+        pattern: Option<&str>,
+        count: Option<u64>,
+    ) -> Result<Vec<(Bytes, KeyHeat)>> {
+        let mut sampled = Vec::new();
+        // OBJECT FREQ until the server turns out not to track it
+        let mut lfu = true;
+        let mut cursor = 0;
+
+        while sampled.len() < n {
+            let (next, keys) = self.scan(cursor, pattern, count).await?;
+            let keys: Vec<String> = keys
+                .iter()
+                .filter_map(|key| from_utf8(key).ok().map(str::to_string))
+                .take(n - sampled.len())
+                .collect();
+
+            sampled.extend(self.key_heats(keys, &mut lfu).await?);
+
+            if next == 0 {
+                break;
+            }
+            cursor = next;
+        }
+
+        sampled.sort_by(|(_, a), (_, b)| match (a, b) {
+            (KeyHeat::Freq(a), KeyHeat::Freq(b)) => b.cmp(a),
+            (KeyHeat::IdleTime(a), KeyHeat::IdleTime(b)) => a.cmp(b),
+            // only if the maxmemory policy changed during the sampling
+            (KeyHeat::Freq(_), KeyHeat::IdleTime(_)) => Ordering::Less,
+            (KeyHeat::IdleTime(_), KeyHeat::Freq(_)) => Ordering::Greater,
+        });
+
+        Ok(sampled)
+    }
+
+    /// Reads the heat of a batch of keys in one round trip, falling back to OBJECT IDLETIME for
+    /// good once the server rejects OBJECT FREQ.
+    async fn key_heats(
+        &mut self,
+        keys: Vec<String>,
+        lfu: &mut bool,
+    ) -> Result<Vec<(Bytes, KeyHeat)>> {
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        if *lfu {
+            let cmds = keys.iter().map(ObjectFreq::new).collect();
+            let responses = self.execute_batch(cmds).await?;
+            match key_heats(&keys, responses, KeyHeat::Freq) {
+                Err(RedisError::LfuNotSelected) => *lfu = false,
+                result => return result,
+            }
+        }
+
+        let cmds = keys.iter().map(ObjectIdleTime::new).collect();
+        let responses = self.execute_batch(cmds).await?;
+        key_heats(&keys, responses, KeyHeat::IdleTime)
+    }
+
+    /// Sends an INCR command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The INCR command increments the integer value of a key by one.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key to increment
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(i64)` the new value of the key after increment
+    /// * `Err(RedisError::NotANumber)` if the value of the key is not a number
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.incr("mykey").await?;
+    /// }
+    pub async fn incr(&mut self, key: impl ToRedisArg) -> Result<i64> {
+        match self.execute(Incr::new(key)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<i64>()?),
+            Response::Error(err) => Err(not_a_number(err)),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an INCRBY command to the Redis server.
+    #[allow(unused_variables)]
+    pub async fn incr_by(&mut self, key: impl ToRedisArg, increment: i64) -> Result<i64> {
+        todo!("INCRBY command is not implemented yet");
+        // match self.execute(IncrBy::new(key, increment)).await? {
+        //     Response::Simple(data) => Ok(from_utf8(&data)?.parse::<i64>()?),
+        //     Response::Error(err) => Err(err),
+        //     _ => Err(RedisError::UnexpectedResponseType),
+        // }
+    }
+
+    /// Sends an INCRBYFLOAT command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The INCRBYFLOAT command increments the floating point value of a key by the given amount.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key to increment
+    /// * `increment` - The amount to add, negative to decrement
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` the new value of the key after increment
+    /// * `Err(RedisError::InvalidArgument)` if the increment is NaN
+    /// * `Err(RedisError::NotANumber)` if the value of the key is not a number
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.incr_by_float("mykey", 0.1).await?;
+    /// }
+    pub async fn incr_by_float(&mut self, key: impl ToRedisArg, increment: f64) -> Result<f64> {
+        response_into_f64(self.execute(IncrByFloat::new(key, increment)).await?)
+    }
+
+    /// Sends a DECR command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The DECR command decrements the integer value of a key by one.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key to decrement
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(i64)` the new value of the key after decrement
+    /// * `Err(RedisError::NotANumber)` if the value of the key is not a number
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.decr("mykey").await?;
+    /// }
+    pub async fn decr(&mut self, key: impl ToRedisArg) -> Result<i64> {
+        match self.execute(Decr::new(key)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<i64>()?),
+            Response::Error(err) => Err(not_a_number(err)),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a DECRBY command to the Redis server.
+    #[allow(unused_variables)]
+    pub async fn decr_by(&mut self, key: impl ToRedisArg, decrement: i64) -> Result<i64> {
+        todo!("DECRBY command is not implemented yet");
+        // match self.execute(DecrBy::new(key, decrement)).await? {
+        //     Response::Simple(data) => Ok(from_utf8(&data)?.parse::<i64>()?),
+        //     Response::Error(err) => Err(err),
+        //     _ => Err(RedisError::UnexpectedResponseType),
+        // }
+    }
+
+    /// Sends a DECRBYFLOAT command to the Redis server.
+    #[allow(unused_variables)]
+    pub async fn decr_by_float(&mut self, key: impl ToRedisArg, decrement: f64) -> Result<f64> {
+        todo!("DECRBYFLOAT command is not implemented yet");
+        // match self.execute(DecrByFloat::new(key, decrement)).await? {
+        //     Response::Simple(data) => Ok(from_utf8(&data)?.parse::<f64>()?),
+        //     Response::Error(err) => Err(err),
+        //     _ => Err(RedisError::UnexpectedResponseType),
+        // }
+    }
+
+    /// Sends an LPUSH command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The LPUSH command inserts all the specified values at the head of the list stored at key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key to insert values
+    /// * `values` - A required vector of values to insert
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` the length of the list after the push operation
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.lpush("mykey", vec!["foo", "bar", "baz"]).await?;
+    /// }
+    pub async fn lpush(&mut self, key: impl ToRedisArg, values: Vec<&[u8]>) -> Result<u64> {
+        match self.execute(LPush::new(key, values)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an RPUSH command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The RPUSH command inserts all the specified values at the tail of the list stored at key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key to insert values
+    /// * `values` - A required vector of values to insert
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` the length of the list after the push operation
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.rpush("mykey", vec!["foo", "bar", "baz"]).await?;
+    /// }
+    pub async fn rpush(&mut self, key: impl ToRedisArg, values: Vec<&[u8]>) -> Result<u64> {
+        match self.execute(RPush::new(key, values)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an LPOP command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The LPOP command removes and returns the first element from the head of the list stored at key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key to remove values
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Bytes))` if the key exists and the element is removed
+    /// * `Ok(None)` if the key does not exist
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.lpop("mykey").await?;
+    /// }
+    pub async fn lpop(&mut self, key: impl ToRedisArg) -> Result<Option<Bytes>> {
+        match self.execute(LPop::new(key, None)).await? {
+            Response::Simple(data) => Ok(Some(data)),
+            Response::Null => Ok(None),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an LPOP command with a count to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The LPOP command with a count removes and returns up to `count` elements from the head of
+    /// the list stored at key. Unlike `Client::lpop`, the elements come in an array even if
+    /// there is only one.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key to remove values
+    /// * `count` - The maximum number of elements to remove
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Vec<Bytes>))` if the key exists, with at most `count` elements, and no element
+    ///   at all if `count` is 0
+    /// * `Ok(None)` if the key does not exist, whatever the count
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.lpop_n("mykey", 2).await?;
+    /// }
+    pub async fn lpop_n(&mut self, key: impl ToRedisArg, count: u64) -> Result<Option<Vec<Bytes>>> {
+        match self.execute(LPop::new(key, Some(count))).await? {
+            Response::Array(data) => Ok(Some(data)),
+            Response::Null => Ok(None),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a BLPOP command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The BLPOP command is the blocking version of LPOP. It pops an element from the head of the first
+    /// non-empty list among the given keys, waiting up to `timeout` seconds if all of them are empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The lists to pop from, checked in the given order
+    /// * `timeout` - The maximum number of seconds to block, 0 blocks indefinitely
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some((key, value)))` the name of the list and the popped element
+    /// * `Ok(None)` if the timeout expired before an element was available
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.blpop(vec!["mylist"], 0.5).await?;
+    /// }
+    pub async fn blpop(
+        &mut self,
+        keys: Vec<impl ToRedisArg>,
+        timeout: f64,
+    ) -> Result<Option<(Bytes, Bytes)>> {
+        match self.execute(BLPop::new(keys, timeout)).await? {
+            Response::Array(data) => match <[Bytes; 2]>::try_from(data) {
+                Ok([key, value]) => match self.unprefixed(key) {
+                    Some(key) => Ok(Some((key, value))),
+                    None => Err(RedisError::UnexpectedResponseType),
+                },
+                Err(_) => Err(RedisError::UnexpectedResponseType),
+            },
+            Response::Null => Ok(None),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an RPOP command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The RPOP command removes and returns the first element from the tail of the list stored at key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key to remove values
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Bytes))` if the key exists and the element is removed
+    /// * `Ok(None)` if the key does not exist
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.rpop("mykey").await?;
+    /// }
+    pub async fn rpop(&mut self, key: impl ToRedisArg) -> Result<Option<Bytes>> {
+        match self.execute(RPop::new(key, None)).await? {
+            Response::Simple(data) => Ok(Some(data)),
+            Response::Null => Ok(None),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an RPOP command with a count to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The RPOP command with a count removes and returns up to `count` elements from the tail of
+    /// the list stored at key. Unlike `Client::rpop`, the elements come in an array even if
+    /// there is only one.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key to remove values
+    /// * `count` - The maximum number of elements to remove
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Vec<Bytes>))` if the key exists, with at most `count` elements, and no element
+    ///   at all if `count` is 0
+    /// * `Ok(None)` if the key does not exist, whatever the count
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.rpop_n("mykey", 2).await?;
+    /// }
+    pub async fn rpop_n(&mut self, key: impl ToRedisArg, count: u64) -> Result<Option<Vec<Bytes>>> {
+        match self.execute(RPop::new(key, Some(count))).await? {
+            Response::Array(data) => Ok(Some(data)),
+            Response::Null => Ok(None),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an LRANGE command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The LRANGE command returns the specified elements of the list stored at key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key to get values
+    /// * `start` - A required start index
+    /// * `end` - A required end index
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(String))` if the key exists and the elements are returned
+    /// * `Ok(None)` if the key does not exist
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.lrange("mykey", 0, -1).await?;
+    /// }
+    pub async fn lrange(
+        &mut self,
+        key: impl ToRedisArg,
+        start: i64,
+        end: i64,
+    ) -> Result<Vec<Bytes>> {
+        match self.execute(LRange::new(key, start, end)).await? {
+            Response::Array(data) => Ok(data),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an HGET command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The HGET command returns the value of a field of a hash.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The hash holding the field
+    /// * `field` - The field to get the value of
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Bytes))` the value of the field
+    /// * `Ok(None)` if the field or the key does not exist
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let value = client.hget("myhash", "field").await?;
+    /// }
+    pub async fn hget(&mut self, key: impl ToRedisArg, field: &str) -> Result<Option<Bytes>> {
+        match self.execute(HGet::new(key, field)).await? {
+            Response::Simple(data) => Ok(Some(data)),
+            Response::Null => Ok(None),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Gets the value of a field of a hash and deserializes it from JSON, see `Client::get_json`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(T))` the deserialized value of the field
+    /// * `Ok(None)` if the field or the key does not exist
+    /// * `Err(RedisError::Serde)` if the value is not the JSON of a `T`
+    /// * `Err(RedisError)` if an error occurs
+    #[cfg(feature = "serde")]
+    pub async fn hget_json<T: serde::de::DeserializeOwned>(
+        &mut self,
+        key: impl ToRedisArg,
+        field: &str,
+    ) -> Result<Option<T>> {
+        let key = key.to_redis_arg();
+        match self.hget(&key, field).await? {
+            Some(data) => from_json(&key, &data).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Sends an HMGET command to the Redis server.
+    #[allow(unused_variables)]
+    pub async fn hmget(
+        &mut self,
+        key: impl ToRedisArg,
+        fields: Vec<&str>,
+    ) -> Result<Option<Vec<Bytes>>> {
+        todo!("HMGET command is not implemented yet");
+        // match self.execute(HMGet::new(key, fields)).await? {
+        //     Response::Array(data) => Ok(Some(data)),
+        //     Response::Null => Ok(None),
+        //     Response::Error(err) => Err(err),
+        //     _ => Err(RedisError::UnexpectedResponseType),
+        // }
+    }
+
+    /// Sends an HGETALL command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The HGETALL command returns all the fields and values of the hash stored at key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key of the hash
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(String, Bytes)>)` the fields and their values in the order the server sent them,
+    ///   empty if the key does not exist
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let fields = client.hget_all("myhash").await?;
+    /// }
+    pub async fn hget_all(&mut self, key: impl ToRedisArg) -> Result<Vec<(String, Bytes)>> {
+        match self.execute(HGetAll::new(key)).await? {
+            // RESP2 replies with a flat array of alternating fields and values
+            Response::Array(data) => {
+                if data.len() % 2 != 0 {
+                    return Err(RedisError::UnexpectedResponseType);
+                }
+
+                let mut pairs = Vec::with_capacity(data.len() / 2);
+                let mut data = data.into_iter();
+                while let (Some(field), Some(value)) = (data.next(), data.next()) {
+                    pairs.push((from_utf8(&field)?.to_string(), value));
+                }
+
+                Ok(pairs)
+            }
+            Response::Map(data) => data
+                .into_iter()
+                .map(|(field, value)| Ok((field, value.into_scalar()?)))
+                .collect(),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an HRANDFIELD command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The HRANDFIELD command returns a random field of the hash stored at key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key of the hash
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(String))` a random field
+    /// * `Ok(None)` if the key does not exist
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let field = client.hrandfield("myhash").await?;
+    /// }
+    pub async fn hrandfield(&mut self, key: impl ToRedisArg) -> Result<Option<String>> {
+        match self.execute(HRandField::new(key, None, false)).await? {
+            Response::Simple(data) => Ok(Some(from_utf8(&data)?.to_string())),
+            Response::Null => Ok(None),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an HRANDFIELD command with a count to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The HRANDFIELD command with a positive count returns up to count distinct fields of the
+    /// hash. With a negative count, it returns exactly -count fields, possibly more than once.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key of the hash
+    /// * `count` - The number of fields to pick, negative to allow repeats
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` the picked fields, empty if the key does not exist
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let fields = client.hrandfield_n("myhash", -5).await?;
+    /// }
+    pub async fn hrandfield_n(&mut self, key: impl ToRedisArg, count: i64) -> Result<Vec<String>> {
+        match self
+            .execute(HRandField::new(key, Some(count), false))
+            .await?
+        {
+            Response::Array(data) => data
+                .iter()
+                .map(|field| Ok(from_utf8(field)?.to_string()))
+                .collect(),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an HRANDFIELD command with a count and WITHVALUES to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// Like `Client::hrandfield_n`, but the fields come with their values.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key of the hash
+    /// * `count` - The number of fields to pick, negative to allow repeats
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(String, Bytes)>)` the picked fields and their values, empty if the key does
+    ///   not exist
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let fields = client.hrandfield_n_withvalues("myhash", 3).await?;
+    /// }
+    pub async fn hrandfield_n_withvalues(
+        &mut self,
+        key: impl ToRedisArg,
+        count: i64,
+    ) -> Result<Vec<(String, Bytes)>> {
+        match self
+            .execute_raw(HRandField::new(key, Some(count), true))
+            .await?
+        {
+            Some(Frame::Array(data)) => frames_into_pairs(data, frame_into_bytes)?
+                .into_iter()
+                .map(|(field, value)| Ok((from_utf8(&field)?.to_string(), value)))
+                .collect(),
+            Some(Frame::SimpleError(data)) => Err(RedisError::Other(anyhow!(data))),
+            Some(Frame::BulkError(data)) => Err(RedisError::Other(anyhow!(
+                String::from_utf8_lossy(&data).to_string()
+            ))),
+            Some(_) => Err(RedisError::UnexpectedResponseType),
+            None => Err(RedisError::Unknown),
+        }
+    }
+
+    /// Sends an HINCRBY command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The HINCRBY command increments the integer value of a field of a hash by the given amount.
+    /// A missing field is set to 0 first.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key of the hash
+    /// * `field` - The field to increment
+    /// * `increment` - The amount to add, negative to decrement
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(i64)` the new value of the field after increment
+    /// * `Err(RedisError::NotANumber)` if the value of the field is not an integer
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let visits = client.hincrby("myhash", "visits", 1).await?;
+    /// }
+    pub async fn hincrby(
+        &mut self,
+        key: impl ToRedisArg,
+        field: &str,
+        increment: i64,
+    ) -> Result<i64> {
+        match self.execute(HIncrBy::new(key, field, increment)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<i64>()?),
+            Response::Error(err) => Err(not_a_number(err)),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an HINCRBYFLOAT command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The HINCRBYFLOAT command increments the floating point value of a field of a hash by the
+    /// given amount. A missing field is set to 0 first.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key of the hash
+    /// * `field` - The field to increment
+    /// * `increment` - The amount to add, negative to decrement
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` the new value of the field after increment
+    /// * `Err(RedisError::InvalidArgument)` if the increment is NaN
+    /// * `Err(RedisError::NotANumber)` if the value of the field is not a float
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let balance = client.hincrbyfloat("myhash", "balance", 0.1).await?;
+    /// }
+    pub async fn hincrbyfloat(
+        &mut self,
+        key: impl ToRedisArg,
+        field: &str,
+        increment: f64,
+    ) -> Result<f64> {
+        response_into_f64(
+            self.execute(HIncrByFloat::new(key, field, increment))
+                .await?,
+        )
+    }
+
+    /// Sends an HEXISTS command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The HEXISTS command tells whether a field exists in the hash stored at key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key of the hash
+    /// * `field` - The field to check
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` whether the field exists, false if the key does not exist
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let exists = client.hexists("myhash", "field").await?;
+    /// }
+    pub async fn hexists(&mut self, key: impl ToRedisArg, field: &str) -> Result<bool> {
+        match self.execute(HExists::new(key, field)).await? {
+            // RESP2 replies with 1 or 0, RESP3 may reply with a boolean
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u8>()? == 1),
+            Response::Bool(data) => Ok(data),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an HSTRLEN command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The HSTRLEN command returns the length in bytes of the value of a field of a hash.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key of the hash
+    /// * `field` - The field to get the length of the value of
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` the length of the value, 0 if the field or the key does not exist
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let len = client.hstrlen("myhash", "field").await?;
+    /// }
+    pub async fn hstrlen(&mut self, key: impl ToRedisArg, field: &str) -> Result<u64> {
+        match self.execute(HStrLen::new(key, field)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an HKEYS command to the Redis server.
+    #[allow(unused_variables)]
+    pub async fn hkeys(&mut self, key: impl ToRedisArg) -> Result<Option<Vec<Bytes>>> {
+        todo!("HKEYS command is not implemented yet");
+        // match self.execute(HKeys::new(key)).await? {
+        //     Response::Array(data) => Ok(Some(data)),
+        //     Response::Null => Ok(None),
+        //     Response::Error(err) => Err(err),
+        //     _ => Err(RedisError::UnexpectedResponseType),
+        // }
+    }
+
+    /// Sends an HVALS command to the Redis server.
+    #[allow(unused_variables)]
+    pub async fn hvals(&mut self, key: impl ToRedisArg) -> Result<Option<Vec<Bytes>>> {
+        todo!("HVALS command is not implemented yet");
+        // match self.execute(HVals::new(key)).await? {
+        //     Response::Array(data) => Ok(Some(data)),
+        //     Response::Null => Ok(None),
+        //     Response::Error(err) => Err(err),
+        //     _ => Err(RedisError::UnexpectedResponseType),
+        // }
+    }
+
+    /// Sends an HLEN command to the Redis server.
+    #[allow(unused_variables)]
+    pub async fn hlen(&mut self, key: impl ToRedisArg) -> Result<Option<u64>> {
+        todo!("HLEN command is not implemented yet");
+        // match self.execute(HLen::new(key)).await? {
+        //     Response::Simple(data) => Ok(Some(from_utf8(&data)?.parse::<u64>()?)),
+        //     Response::Null => Ok(None),
+        //     Response::Error(err) => Err(err),
+        //     _ => Err(RedisError::UnexpectedResponseType),
+        // }
+    }
+
+    /// Sends an HSET command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The HSET command sets a field of the hash stored at key, creating the hash if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key of the hash
+    /// * `field` - A required field to set
+    /// * `value` - A required value of the field
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(1)` if the field is new
+    /// * `Ok(0)` if the field already existed and its value was overwritten
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.hset("myhash", "field", b"value").await?;
+    /// }
+    pub async fn hset(&mut self, key: impl ToRedisArg, field: &str, value: &[u8]) -> Result<u64> {
+        match self.execute(HSet::new(key, field, value)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Serializes a value into JSON and sets it as the value of a field of a hash, see
+    /// `Client::set_json`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` the number of fields that were added, 0 if the field was updated
+    /// * `Err(RedisError::Serde)` if the value does not serialize into JSON
+    /// * `Err(RedisError)` if an error occurs
+    #[cfg(feature = "serde")]
+    pub async fn hset_json<T: serde::Serialize + ?Sized>(
+        &mut self,
+        key: impl ToRedisArg,
+        field: &str,
+        value: &T,
+    ) -> Result<u64> {
+        let key = key.to_redis_arg();
+        let value = to_json(&key, value)?;
+        self.hset(key, field, &value).await
+    }
+
+    /// Sends an HSETNX command to the Redis server.
+    #[allow(unused_variables)]
+    pub async fn hset_nx(
+        &mut self,
+        key: impl ToRedisArg,
+        field: &str,
+        value: &[u8],
+    ) -> Result<Option<Bytes>> {
+        todo!("HSETNX command is not implemented yet");
+        // match self.execute(HSetNx::new(key, field, value)).await? {
+        //     Response::Simple(data) => Ok(Some(data)),
+        //     Response::Null => Ok(None),
+        //     Response::Error(err) => Err(err),
+        //     _ => Err(RedisError::UnexpectedResponseType),
+        // }
+    }
+
+    /// Sends an HMSET command to the Redis server.
+    #[allow(unused_variables)]
+    pub async fn hmset(
+        &mut self,
+        key: impl ToRedisArg,
+        fields: HashMap<String, Vec<u8>>,
+    ) -> Result<Option<Bytes>> {
+        todo!("HMSET command is not implemented yet");
+        // match self.execute(HMSet::new(key, fields)).await? {
+        //     Response::Simple(data) => Ok(Some(data)),
+        //     Response::Null => Ok(None),
+        //     Response::Error(err) => Err(err),
+        //     _ => Err(RedisError::UnexpectedResponseType),
+        // }
+    }
+
+    /// Sends an HDEL command to the Redis server.
+    #[allow(unused_variables)]
+    pub async fn hdel(&mut self, key: impl ToRedisArg, field: &str) -> Result<Option<Bytes>> {
+        todo!("HDEL command is not implemented yet");
+        // match self.execute(HDel::new(key, field)).await? {
+        //     Response::Simple(data) => Ok(Some(data)),
+        //     Response::Null => Ok(None),
+        //     Response::Error(err) => Err(err),
+        //     _ => Err(RedisError::UnexpectedResponseType),
+        // }
+    }
+
+    /// Sends an SADD command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The SADD command adds the specified members to the set stored at key, members already in the set are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key of the set
+    /// * `members` - A required vector of members to add
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` the number of members that were added, not counting the ones already in the set
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.sadd("myset", vec![b"foo", b"bar"]).await?;
+    /// }
+    pub async fn sadd(&mut self, key: impl ToRedisArg, members: Vec<&[u8]>) -> Result<u64> {
+        match self.execute(SAdd::new(key, members)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an SREM command to the Redis server.
+    #[allow(unused_variables)]
+    pub async fn srem(
+        &mut self,
+        key: impl ToRedisArg,
+        members: Vec<&[u8]>,
+    ) -> Result<Option<Bytes>> {
+        todo!("SREM command is not implemented yet");
+        // match self.execute(SRem::new(key, members)).await? {
+        //     Response::Simple(data) => Ok(Some(data)),
+        //     Response::Null => Ok(None),
+        //     Response::Error(err) => Err(err),
+        //     _ => Err(RedisError::UnexpectedResponseType),
+        // }
+    }
+
+    /// Sends an SISMEMBER command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The SISMEMBER command checks whether member is a member of the set stored at key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key of the set
+    /// * `member` - A required member to check
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` if the member is in the set
+    /// * `Ok(false)` if the member is not in the set, or the key does not exist
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.sismember("myset", b"foo").await?;
+    /// }
+    pub async fn sismember(&mut self, key: impl ToRedisArg, member: &[u8]) -> Result<bool> {
+        match self.execute(SIsMember::new(key, member)).await? {
+            // RESP2 replies with 1 or 0, RESP3 may reply with a boolean
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u8>()? == 1),
+            Response::Bool(data) => Ok(data),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an SMEMBERS command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The SMEMBERS command returns all the members of the set stored at key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key of the set
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Bytes>)` the members of the set, in no particular order, empty if the key does not exist
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.smembers("myset").await?;
+    /// }
+    pub async fn smembers(&mut self, key: impl ToRedisArg) -> Result<Vec<Bytes>> {
+        match self.execute(SMembers::new(key)).await? {
+            Response::Array(data) => Ok(data),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an SMISMEMBER command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The SMISMEMBER command checks whether each of the members belongs to the set stored at key,
+    /// in a single round trip.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key of the set
+    /// * `members` - The members to check
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<bool>)` whether each member belongs to the set, in the order of `members`
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.smismember("myset", vec![b"foo", b"bar"]).await?;
+    /// }
+    pub async fn smismember(
+        &mut self,
+        key: impl ToRedisArg,
+        members: Vec<&[u8]>,
+    ) -> Result<Vec<bool>> {
+        match self.execute(SMIsMember::new(key, members)).await? {
+            Response::Array(data) => Ok(data.iter().map(|member| member == "1").collect()),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an SRANDMEMBER command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The SRANDMEMBER command returns a random member of the set stored at key, without removing
+    /// it.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key of the set
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Bytes))` a random member
+    /// * `Ok(None)` if the key does not exist
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.srandmember("myset").await?;
+    /// }
+    pub async fn srandmember(&mut self, key: impl ToRedisArg) -> Result<Option<Bytes>> {
+        match self.execute(SRandMember::new(key, None)).await? {
+            Response::Simple(data) => Ok(Some(data)),
+            Response::Null => Ok(None),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an SRANDMEMBER command with a count to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The SRANDMEMBER command with a count returns random members of the set stored at key,
+    /// without removing them. Unlike `Client::srandmember`, the members come in an array even if
+    /// there is only one.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A required key of the set
+    /// * `count` - A positive count returns up to `count` distinct members, a negative count
+    ///   returns exactly `-count` members, possibly more than once
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Bytes>)` the members, empty if the key does not exist or `count` is 0
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.srandmember_n("myset", -5).await?;
+    /// }
+    pub async fn srandmember_n(&mut self, key: impl ToRedisArg, count: i64) -> Result<Vec<Bytes>> {
+        match self.execute(SRandMember::new(key, Some(count))).await? {
+            Response::Array(data) => Ok(data),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an SINTER command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The SINTER command returns the members of the intersection of all the given sets.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The sets to intersect
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Bytes>)` the members common to all the sets, in no particular order, empty if any of the keys does not exist
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.sinter(vec!["set1", "set2"]).await?;
+    /// }
+    pub async fn sinter(&mut self, keys: Vec<impl ToRedisArg>) -> Result<Vec<Bytes>> {
+        match self.execute(SInter::new(keys)).await? {
+            Response::Array(data) => Ok(data),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an SINTERSTORE command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The SINTERSTORE command is like SINTER, but stores the resulting set at `destination`
+    /// instead of returning it. The destination is overwritten if it already exists, and deleted
+    /// if the resulting set is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - The key to store the resulting set at
+    /// * `keys` - The sets to intersect
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` the number of members in the resulting set
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let len = client.sinterstore("result", vec!["set1", "set2"]).await?;
+    /// }
+    pub async fn sinterstore(
+        &mut self,
+        destination: impl ToRedisArg,
+        keys: Vec<impl ToRedisArg>,
+    ) -> Result<u64> {
+        match self.execute(SInterStore::new(destination, keys)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an SINTERCARD command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The SINTERCARD command returns the number of members of the intersection of the given sets,
+    /// without sending the members themselves.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The sets to intersect
+    /// * `limit` - Stops counting once the intersection reaches this many members, which saves
+    ///   work on large sets. Both None and `Some(0)` count the whole intersection
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` the number of members in the intersection, at most `limit`
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let len = client.sintercard(vec!["set1", "set2"], Some(10)).await?;
+    /// }
+    pub async fn sintercard(
+        &mut self,
+        keys: Vec<impl ToRedisArg>,
+        limit: Option<u64>,
+    ) -> Result<u64> {
+        match self.execute(SInterCard::new(keys, limit)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an SUNION command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The SUNION command returns the members of the union of all the given sets.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The sets to join
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Bytes>)` the members found in any of the sets, in no particular order, missing keys are read as empty sets
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.sunion(vec!["set1", "set2"]).await?;
+    /// }
+    pub async fn sunion(&mut self, keys: Vec<impl ToRedisArg>) -> Result<Vec<Bytes>> {
+        match self.execute(SUnion::new(keys)).await? {
+            Response::Array(data) => Ok(data),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an SUNIONSTORE command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The SUNIONSTORE command is like SUNION, but stores the resulting set at `destination`
+    /// instead of returning it. The destination is overwritten if it already exists, and deleted
+    /// if the resulting set is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - The key to store the resulting set at
+    /// * `keys` - The sets to join
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` the number of members in the resulting set
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let len = client.sunionstore("result", vec!["set1", "set2"]).await?;
+    /// }
+    pub async fn sunionstore(
+        &mut self,
+        destination: impl ToRedisArg,
+        keys: Vec<impl ToRedisArg>,
+    ) -> Result<u64> {
+        match self.execute(SUnionStore::new(destination, keys)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an SDIFF command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The SDIFF command returns the members of the first set that are in none of the other sets.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The first set, followed by the sets to subtract from it
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Bytes>)` the members of the first set that are in none of the others, in no particular order, missing keys are read as empty sets
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let resp = client.sdiff(vec!["set1", "set2"]).await?;
+    /// }
+    pub async fn sdiff(&mut self, keys: Vec<impl ToRedisArg>) -> Result<Vec<Bytes>> {
+        match self.execute(SDiff::new(keys)).await? {
+            Response::Array(data) => Ok(data),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an SDIFFSTORE command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The SDIFFSTORE command is like SDIFF, but stores the resulting set at `destination`
+    /// instead of returning it. The destination is overwritten if it already exists, and deleted
+    /// if the resulting set is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - The key to store the resulting set at
+    /// * `keys` - The first set, followed by the sets to subtract from it
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` the number of members in the resulting set
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let len = client.sdiffstore("result", vec!["set1", "set2"]).await?;
+    /// }
+    pub async fn sdiffstore(
+        &mut self,
+        destination: impl ToRedisArg,
+        keys: Vec<impl ToRedisArg>,
+    ) -> Result<u64> {
+        match self.execute(SDiffStore::new(destination, keys)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an SPOP command to the Redis server.
+    #[allow(unused_variables)]
+    pub async fn spop(&mut self, key: impl ToRedisArg) -> Result<Option<Bytes>> {
+        todo!("SPOP command is not implemented yet");
+        // match self.execute(SPop::new(key)).await? {
+        //     Response::Simple(data) => Ok(Some(data)),
+        //     Response::Null => Ok(None),
+        //     Response::Error(err) => Err(err),
+        //     _ => Err(RedisError::UnexpectedResponseType),
+        // }
+    }
+
+    /// Sends a ZADD command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The ZADD command adds members with their scores to a sorted set,
+    /// or updates the score of the members that already exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The sorted set to add the members to
+    /// * `members` - The scores and the members, `inf` and `-inf` are valid scores
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` the number of members added, not counting the updated ones
+    /// * `Err(RedisError::InvalidArgument)` if a score is NaN
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let added = client.zadd("leaderboard", vec![(1.5, b"alice")]).await?;
+    /// }
+    pub async fn zadd(&mut self, key: impl ToRedisArg, members: Vec<(f64, &[u8])>) -> Result<u64> {
+        match self.execute(ZAdd::new(key, members)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a ZREM command to the Redis server.
+    #[allow(unused_variables)]
+    pub async fn zrem(
+        &mut self,
+        key: impl ToRedisArg,
+        members: Vec<&[u8]>,
+    ) -> Result<Option<Bytes>> {
+        todo!("ZREM command is not implemented yet");
+        // match self.execute(ZRem::new(key, members)).await? {
+        //     Response::Simple(data) => Ok(Some(data)),
+        //     Response::Null => Ok(None),
+        //     Response::Error(err) => Err(err),
+        //     _ => Err(RedisError::UnexpectedResponseType),
+        // }
+    }
+
+    /// Sends a ZRANGE command to the Redis server, by rank.
+    ///
+    /// # Description
+    ///
+    /// The ZRANGE command returns the members of a sorted set from the `start` rank to the `end`
+    /// rank, both included, from the lowest score to the highest. See `Client::zrange_by` for
+    /// score and lex ranges.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The sorted set to read the members from
+    /// * `start` - The rank of the first member, negative ranks count from the end
+    /// * `end` - The rank of the last member, -1 for the last member
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Bytes>)` the members, empty if the key does not exist or the range is empty
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let members = client.zrange("leaderboard", 0, -1).await?;
+    /// }
+    pub async fn zrange(
+        &mut self,
+        key: impl ToRedisArg,
+        start: i64,
+        end: i64,
+    ) -> Result<Vec<Bytes>> {
+        self.zrange_by(key, ZRangeBy::Rank(start, end), ZRangeOptions::default())
+            .await
+    }
+
+    /// Sends a ZREVRANGE command to the Redis server.
+    #[allow(unused_variables)]
+    pub async fn zrevrange(
+        &mut self,
+        key: impl ToRedisArg,
+        start: i64,
+        end: i64,
+    ) -> Result<Option<Vec<Bytes>>> {
+        todo!("ZREVRANGE command is not implemented yet");
+        // match self.execute(ZRevRange::new(key, start, end)).await? {
+        //     Response::Array(data) => Ok(Some(data)),
+        //     Response::Null => Ok(None),
+        //     Response::Error(err) => Err(err),
+        //     _ => Err(RedisError::UnexpectedResponseType),
+        // }
+    }
+
+    /// Sends a ZRANK command to the Redis server.
+    #[allow(unused_variables)]
+    pub async fn zrank(&mut self, key: impl ToRedisArg, member: &[u8]) -> Result<Option<u64>> {
+        todo!("ZRANK command is not implemented yet");
+        // match self.execute(ZRank::new(key, member)).await? {
+        //     Response::Simple(data) => Ok(Some(from_utf8(&data)?.parse::<u64>()?)),
+        //     Response::Null => Ok(None),
+        //     Response::Error(err) => Err(err),
+        //     _ => Err(RedisError::UnexpectedResponseType),
+        // }
+    }
+
+    /// Sends a ZREVRANK command to the Redis server.
+    #[allow(unused_variables)]
+    pub async fn zrevrank(&mut self, key: impl ToRedisArg, member: &[u8]) -> Result<Option<u64>> {
+        todo!("ZREVRANK command is not implemented yet");
+        // match self.execute(ZRevRank::new(key, member)).await? {
+        //     Response::Simple(data) => Ok(Some(from_utf8(&data)?.parse::<u64>()?)),
+        //     Response::Null => Ok(None),
+        //     Response::Error(err) => Err(err),
+        //     _ => Err(RedisError::UnexpectedResponseType),
+        // }
+    }
+
+    /// Sends a ZSCORE command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The ZSCORE command returns the score of a member in a sorted set.
+    /// In RESP3 the score is received as a double, without a decimal round trip.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The sorted set to look the member up in
+    /// * `member` - The member to get the score of
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(f64))` the score of the member
+    /// * `Ok(None)` if the sorted set or the member does not exist
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let score = client.zscore("leaderboard", b"alice").await?;
+    /// }
+    pub async fn zscore(&mut self, key: impl ToRedisArg, member: &[u8]) -> Result<Option<f64>> {
+        match self.execute(ZScore::new(key, member)).await? {
+            Response::Simple(data) => Ok(Some(from_utf8(&data)?.parse::<f64>()?)),
+            Response::Double(data) => Ok(Some(data)),
+            Response::Null => Ok(None),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a ZMSCORE command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The ZMSCORE command returns the scores of several members of a sorted set, in a single
+    /// round trip.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The sorted set to look the members up in
+    /// * `members` - The members to get the scores of
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Option<f64>>)` the score of each member in the order of `members`, None for the
+    ///   members that do not exist
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let scores = client.zmscore("leaderboard", vec![b"alice", b"bob"]).await?;
+    /// }
+    pub async fn zmscore(
+        &mut self,
+        key: impl ToRedisArg,
+        members: Vec<&[u8]>,
+    ) -> Result<Vec<Option<f64>>> {
+        // a `Response` cannot tell an empty string from a missing member
+        match self.execute_raw(ZMScore::new(key, members)).await? {
+            Some(Frame::Array(scores)) => scores
+                .into_iter()
+                .map(|score| match score {
+                    Frame::Null => Ok(None),
+                    score => frame_into_score(score).map(Some),
+                })
+                .collect(),
+            Some(Frame::SimpleError(data)) => Err(RedisError::Other(anyhow!(data))),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a ZRANDMEMBER command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The ZRANDMEMBER command returns a random member of the sorted set stored at key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The sorted set to pick the member from
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Bytes))` a random member
+    /// * `Ok(None)` if the key does not exist
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let member = client.zrandmember("leaderboard").await?;
+    /// }
+    pub async fn zrandmember(&mut self, key: impl ToRedisArg) -> Result<Option<Bytes>> {
+        match self.execute(ZRandMember::new(key, None, false)).await? {
+            Response::Simple(data) => Ok(Some(data)),
+            Response::Null => Ok(None),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a ZRANDMEMBER command with a count to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The ZRANDMEMBER command with a positive count returns up to count distinct members of the
+    /// sorted set. With a negative count, it returns exactly -count members, possibly more than
+    /// once.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The sorted set to pick the members from
+    /// * `count` - The number of members to pick, negative to allow repeats
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Bytes>)` the picked members, empty if the key does not exist
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let members = client.zrandmember_n("leaderboard", -5).await?;
+    /// }
+    pub async fn zrandmember_n(&mut self, key: impl ToRedisArg, count: i64) -> Result<Vec<Bytes>> {
+        match self
+            .execute(ZRandMember::new(key, Some(count), false))
+            .await?
+        {
+            Response::Array(data) => Ok(data),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a ZRANDMEMBER command with a count and WITHSCORES to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// Like `Client::zrandmember_n`, but the members come with their scores.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The sorted set to pick the members from
+    /// * `count` - The number of members to pick, negative to allow repeats
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(Bytes, f64)>)` the picked members and their scores, empty if the key does not
+    ///   exist
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let members = client.zrandmember_n_withscores("leaderboard", 3).await?;
+    /// }
+    pub async fn zrandmember_n_withscores(
+        &mut self,
+        key: impl ToRedisArg,
+        count: i64,
+    ) -> Result<Vec<(Bytes, f64)>> {
+        match self
+            .execute_raw(ZRandMember::new(key, Some(count), true))
+            .await?
+        {
+            Some(Frame::Array(data)) => frames_into_scored_members(data),
+            Some(Frame::SimpleError(data)) => Err(RedisError::Other(anyhow!(data))),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a ZPOPMIN command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The ZPOPMIN command removes and returns the members with the lowest scores of a sorted
+    /// set, the lowest score first.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The sorted set to pop the members from
+    /// * `count` - The number of members to pop, 1 if None
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(Bytes, f64)>)` the members and their scores, empty if the key does not exist
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let popped = client.zpopmin("leaderboard", Some(2)).await?;
+    /// }
+    pub async fn zpopmin(
+        &mut self,
+        key: impl ToRedisArg,
+        count: Option<u64>,
+    ) -> Result<Vec<(Bytes, f64)>> {
+        match self.execute_raw(ZPopMin::new(key, count)).await? {
+            Some(Frame::Array(data)) => frames_into_scored_members(data),
+            Some(Frame::SimpleError(data)) => Err(RedisError::Other(anyhow!(data))),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a ZPOPMAX command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The ZPOPMAX command removes and returns the members with the highest scores of a sorted
+    /// set, the highest score first.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The sorted set to pop the members from
+    /// * `count` - The number of members to pop, 1 if None
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(Bytes, f64)>)` the members and their scores, empty if the key does not exist
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let popped = client.zpopmax("leaderboard", Some(2)).await?;
+    /// }
+    pub async fn zpopmax(
+        &mut self,
+        key: impl ToRedisArg,
+        count: Option<u64>,
+    ) -> Result<Vec<(Bytes, f64)>> {
+        match self.execute_raw(ZPopMax::new(key, count)).await? {
+            Some(Frame::Array(data)) => frames_into_scored_members(data),
+            Some(Frame::SimpleError(data)) => Err(RedisError::Other(anyhow!(data))),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a BZPOPMIN command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The BZPOPMIN command is the blocking version of ZPOPMIN. It pops the member with the lowest
+    /// score from the first non-empty sorted set among the given keys, waiting up to `timeout`
+    /// if all of them are empty.
+    ///
+    /// The command timeout of the client, if any, is extended by `timeout`.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The sorted sets to pop from, checked in the given order
+    /// * `timeout` - How long to block, zero blocks indefinitely
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some((key, member, score)))` the name of the sorted set, the popped member and its score
+    /// * `Ok(None)` if the timeout expired before a member was available
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let popped = client.bzpopmin(vec!["jobs"], Duration::from_secs(1)).await?;
+    /// }
+    pub async fn bzpopmin(
+        &mut self,
+        keys: Vec<impl ToRedisArg>,
+        timeout: Duration,
+    ) -> Result<Option<(String, Bytes, f64)>> {
+        match self.execute_raw(BZPopMin::new(keys, timeout)).await? {
+            Some(Frame::Array(data)) => self.unprefixed_scored_member(data).map(Some),
+            Some(Frame::Null) => Ok(None),
+            Some(Frame::SimpleError(data)) => Err(RedisError::Other(anyhow!(data))),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a BZPOPMAX command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The BZPOPMAX command is the blocking version of ZPOPMAX. It pops the member with the highest
+    /// score from the first non-empty sorted set among the given keys, waiting up to `timeout`
+    /// if all of them are empty.
+    ///
+    /// The command timeout of the client, if any, is extended by `timeout`.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The sorted sets to pop from, checked in the given order
+    /// * `timeout` - How long to block, zero blocks indefinitely
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some((key, member, score)))` the name of the sorted set, the popped member and its score
+    /// * `Ok(None)` if the timeout expired before a member was available
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let popped = client.bzpopmax(vec!["jobs"], Duration::from_secs(1)).await?;
+    /// }
+    pub async fn bzpopmax(
+        &mut self,
+        keys: Vec<impl ToRedisArg>,
+        timeout: Duration,
+    ) -> Result<Option<(String, Bytes, f64)>> {
+        match self.execute_raw(BZPopMax::new(keys, timeout)).await? {
+            Some(Frame::Array(data)) => self.unprefixed_scored_member(data).map(Some),
+            Some(Frame::Null) => Ok(None),
+            Some(Frame::SimpleError(data)) => Err(RedisError::Other(anyhow!(data))),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a ZRANGEBYSCORE command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The ZRANGEBYSCORE command returns the members of a sorted set whose score is within a
+    /// range, from the lowest score to the highest.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The sorted set to read the members from
+    /// * `min` - The lowest score in the range, e.g. `ScoreBound::Exclusive(1.0)` for `(1`
+    /// * `max` - The highest score in the range, e.g. `ScoreBound::PosInf` for `+inf`
+    /// * `limit` - An optional window of the range, as the offset of its first member and the
+    ///   number of members
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Bytes>)` the members, empty if the key does not exist
+    /// * `Err(RedisError::InvalidArgument)` if a bound is NaN
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let members = client
+    ///         .zrangebyscore("leaderboard", ScoreBound::Exclusive(1.0), ScoreBound::PosInf, None)
+    ///         .await?;
+    /// }
+    pub async fn zrangebyscore(
+        &mut self,
+        key: impl ToRedisArg,
+        min: ScoreBound,
+        max: ScoreBound,
+        limit: Option<(u64, u64)>,
+    ) -> Result<Vec<Bytes>> {
+        match self
+            .execute(ZRangeByScore::new(key, min, max, false, limit))
+            .await?
+        {
+            Response::Array(data) => Ok(data),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a ZRANGEBYSCORE command with WITHSCORES to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// Like `Client::zrangebyscore`, but the members come with their scores.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The sorted set to read the members from
+    /// * `min` - The lowest score in the range
+    /// * `max` - The highest score in the range
+    /// * `limit` - An optional window of the range, as the offset of its first member and the
+    ///   number of members
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(Bytes, f64)>)` the members and their scores, empty if the key does not exist
+    /// * `Err(RedisError::InvalidArgument)` if a bound is NaN
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let top = client
+    ///         .zrangebyscore_withscores("leaderboard", ScoreBound::NegInf, ScoreBound::PosInf, Some((0, 10)))
+    ///         .await?;
+    /// }
+    pub async fn zrangebyscore_withscores(
+        &mut self,
+        key: impl ToRedisArg,
+        min: ScoreBound,
+        max: ScoreBound,
+        limit: Option<(u64, u64)>,
+    ) -> Result<Vec<(Bytes, f64)>> {
+        match self
+            .execute_raw(ZRangeByScore::new(key, min, max, true, limit))
+            .await?
+        {
+            Some(Frame::Array(data)) => frames_into_scored_members(data),
+            Some(Frame::SimpleError(data)) => Err(RedisError::Other(anyhow!(data))),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a ZRANGEBYLEX command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The ZRANGEBYLEX command returns the members of a sorted set within a lexicographical
+    /// range. All the members are expected to have the same score, the order is undefined
+    /// otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The sorted set to read the members from
+    /// * `min` - The lowest member in the range, e.g. `LexBound::Inclusive(Bytes::from("a"))` for `[a`
+    /// * `max` - The highest member in the range, e.g. `LexBound::Max` for `+`
+    /// * `limit` - An optional window of the range, as the offset of its first member and the
+    ///   number of members
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Bytes>)` the members, empty if the key does not exist
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let names = client
+    ///         .zrangebylex("names", LexBound::Inclusive(Bytes::from("a")), LexBound::Max, None)
+    ///         .await?;
+    /// }
+    pub async fn zrangebylex(
+        &mut self,
+        key: impl ToRedisArg,
+        min: LexBound,
+        max: LexBound,
+        limit: Option<(u64, u64)>,
+    ) -> Result<Vec<Bytes>> {
+        match self.execute(ZRangeByLex::new(key, min, max, limit)).await? {
+            Response::Array(data) => Ok(data),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a ZRANGE command to the Redis server, by rank, score or member.
+    ///
+    /// # Description
+    ///
+    /// The unified ZRANGE command, available since Redis 6.2, returns a range of the members of
+    /// a sorted set. It replaces ZRANGEBYSCORE, ZRANGEBYLEX and their REV variants, see
+    /// `Client::zrangebyscore` and `Client::zrangebylex` for older servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The sorted set to read the members from
+    /// * `range` - The ranks, scores or members to read
+    /// * `options` - The ZRANGE options, e.g. `rev` to read from the highest score down
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Bytes>)` the members, empty if the key does not exist
+    /// * `Err(RedisError::InvalidArgument)` if a limit is given for a rank range, or a score
+    ///   bound is NaN
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     // the second page of ten members, from the highest score down
+    ///     let page = client
+    ///         .zrange_by(
+    ///             "leaderboard",
+    ///             ZRangeBy::Score(ScoreBound::NegInf, ScoreBound::PosInf),
+    ///             ZRangeOptions { rev: true, limit: Some((10, 10)) },
+    ///         )
+    ///         .await?;
+    /// }
+    pub async fn zrange_by(
+        &mut self,
+        key: impl ToRedisArg,
+        range: ZRangeBy,
+        options: ZRangeOptions,
+    ) -> Result<Vec<Bytes>> {
+        match self
+            .execute(ZRange::new(key, range, options, false))
+            .await?
+        {
+            Response::Array(data) => Ok(data),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a ZRANGE command with WITHSCORES to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// Like `Client::zrange_by`, but the members come with their scores.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The sorted set to read the members from
+    /// * `range` - The ranks or scores to read
+    /// * `options` - The ZRANGE options
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(Bytes, f64)>)` the members and their scores, empty if the key does not exist
+    /// * `Err(RedisError::InvalidArgument)` if the range is a lex range, a limit is given for a
+    ///   rank range, or a score bound is NaN
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let top = client
+    ///         .zrange_by_withscores(
+    ///             "leaderboard",
+    ///             ZRangeBy::Rank(0, 9),
+    ///             ZRangeOptions { rev: true, limit: None },
+    ///         )
+    ///         .await?;
+    /// }
+    pub async fn zrange_by_withscores(
+        &mut self,
+        key: impl ToRedisArg,
+        range: ZRangeBy,
+        options: ZRangeOptions,
+    ) -> Result<Vec<(Bytes, f64)>> {
+        match self
+            .execute_raw(ZRange::new(key, range, options, true))
+            .await?
+        {
+            Some(Frame::Array(data)) => frames_into_scored_members(data),
+            Some(Frame::SimpleError(data)) => Err(RedisError::Other(anyhow!(data))),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a ZRANGESTORE command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The ZRANGESTORE command is like `Client::zrange_by`, but stores the range at the
+    /// destination key instead of returning it.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - The key to store the resulting sorted set at, overwritten if it exists
+    /// * `source` - The sorted set to read the members from
+    /// * `range` - The ranks, scores or members to store
+    /// * `options` - The ZRANGE options
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` the number of members in the resulting sorted set
+    /// * `Err(RedisError::InvalidArgument)` if a limit is given for a rank range, or a score
+    ///   bound is NaN
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let stored = client
+    ///         .zrangestore(
+    ///             "top10",
+    ///             "leaderboard",
+    ///             ZRangeBy::Rank(0, 9),
+    ///             ZRangeOptions { rev: true, limit: None },
+    ///         )
+    ///         .await?;
+    /// }
+    pub async fn zrangestore(
+        &mut self,
+        destination: impl ToRedisArg,
+        source: impl ToRedisArg,
+        range: ZRangeBy,
+        options: ZRangeOptions,
+    ) -> Result<u64> {
+        match self
+            .execute(ZRangeStore::new(destination, source, range, options))
+            .await?
+        {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a ZUNION command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The ZUNION command returns the union of the given sorted sets, the scores of a member being
+    /// weighted and aggregated as the options say, summed up by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The sorted sets to combine
+    /// * `options` - The weights of the sorted sets and how their scores are combined
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Bytes>)` the members, from the lowest score to the highest
+    /// * `Err(RedisError::InvalidArgument)` if no key is given, or not as many weights as keys
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let members = client
+    ///         .zunion(vec!["daily", "weekly"], ZCombineOptions::default())
+    ///         .await?;
+    /// }
+    pub async fn zunion(
+        &mut self,
+        keys: Vec<impl ToRedisArg>,
+        options: ZCombineOptions,
+    ) -> Result<Vec<Bytes>> {
+        match self.execute(ZUnion::new(keys, options, false)).await? {
+            Response::Array(data) => Ok(data),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a ZUNION command with WITHSCORES to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// Like `Client::zunion`, but the members come with their combined scores.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The sorted sets to combine
+    /// * `options` - The weights of the sorted sets and how their scores are combined
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(Bytes, f64)>)` the members and their scores, from the lowest score to the highest
+    /// * `Err(RedisError::InvalidArgument)` if no key is given, or not as many weights as keys
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let scored = client
+    ///         .zunion_withscores(vec!["daily", "weekly"], ZCombineOptions::default())
+    ///         .await?;
+    /// }
+    pub async fn zunion_withscores(
+        &mut self,
+        keys: Vec<impl ToRedisArg>,
+        options: ZCombineOptions,
+    ) -> Result<Vec<(Bytes, f64)>> {
+        match self.execute_raw(ZUnion::new(keys, options, true)).await? {
+            Some(Frame::Array(data)) => frames_into_scored_members(data),
+            Some(Frame::SimpleError(data)) => Err(RedisError::Other(anyhow!(data))),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a ZUNIONSTORE command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The ZUNIONSTORE command is like `Client::zunion`, but stores the resulting sorted set at
+    /// the destination key instead of returning it.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - The key to store the resulting sorted set at, overwritten if it exists
+    /// * `keys` - The sorted sets to combine
+    /// * `options` - The weights of the sorted sets and how their scores are combined
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` the number of members in the resulting sorted set
+    /// * `Err(RedisError::InvalidArgument)` if no key is given, or not as many weights as keys
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let options = ZCombineOptions { weights: vec![2.0, 1.0], aggregate: None };
+    ///     let stored = client
+    ///         .zunionstore("overall", vec!["daily", "weekly"], options)
+    ///         .await?;
+    /// }
+    pub async fn zunionstore(
+        &mut self,
+        destination: impl ToRedisArg,
+        keys: Vec<impl ToRedisArg>,
+        options: ZCombineOptions,
+    ) -> Result<u64> {
+        match self
+            .execute(ZUnionStore::new(destination, keys, options))
+            .await?
+        {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a ZINTER command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The ZINTER command returns the intersection of the given sorted sets, the scores of a member being
+    /// weighted and aggregated as the options say, summed up by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The sorted sets to intersect
+    /// * `options` - The weights of the sorted sets and how their scores are combined
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Bytes>)` the members, from the lowest score to the highest
+    /// * `Err(RedisError::InvalidArgument)` if no key is given, or not as many weights as keys
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let members = client
+    ///         .zinter(vec!["daily", "weekly"], ZCombineOptions::default())
+    ///         .await?;
+    /// }
+    pub async fn zinter(
+        &mut self,
+        keys: Vec<impl ToRedisArg>,
+        options: ZCombineOptions,
+    ) -> Result<Vec<Bytes>> {
+        match self.execute(ZInter::new(keys, options, false)).await? {
+            Response::Array(data) => Ok(data),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a ZINTER command with WITHSCORES to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// Like `Client::zinter`, but the members come with their combined scores.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The sorted sets to intersect
+    /// * `options` - The weights of the sorted sets and how their scores are combined
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(Bytes, f64)>)` the members and their scores, from the lowest score to the highest
+    /// * `Err(RedisError::InvalidArgument)` if no key is given, or not as many weights as keys
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let scored = client
+    ///         .zinter_withscores(vec!["daily", "weekly"], ZCombineOptions::default())
+    ///         .await?;
+    /// }
+    pub async fn zinter_withscores(
+        &mut self,
+        keys: Vec<impl ToRedisArg>,
+        options: ZCombineOptions,
+    ) -> Result<Vec<(Bytes, f64)>> {
+        match self.execute_raw(ZInter::new(keys, options, true)).await? {
+            Some(Frame::Array(data)) => frames_into_scored_members(data),
+            Some(Frame::SimpleError(data)) => Err(RedisError::Other(anyhow!(data))),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a ZINTERSTORE command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The ZINTERSTORE command is like `Client::zinter`, but stores the resulting sorted set at
+    /// the destination key instead of returning it.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - The key to store the resulting sorted set at, overwritten if it exists
+    /// * `keys` - The sorted sets to intersect
+    /// * `options` - The weights of the sorted sets and how their scores are combined
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` the number of members in the resulting sorted set
+    /// * `Err(RedisError::InvalidArgument)` if no key is given, or not as many weights as keys
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let options = ZCombineOptions { weights: vec![2.0, 1.0], aggregate: None };
+    ///     let stored = client
+    ///         .zinterstore("overall", vec!["daily", "weekly"], options)
+    ///         .await?;
+    /// }
+    pub async fn zinterstore(
+        &mut self,
+        destination: impl ToRedisArg,
+        keys: Vec<impl ToRedisArg>,
+        options: ZCombineOptions,
+    ) -> Result<u64> {
+        match self
+            .execute(ZInterStore::new(destination, keys, options))
+            .await?
+        {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a ZCARD command to the Redis server.
+    #[allow(unused_variables)]
+    pub async fn zcard(&mut self, key: impl ToRedisArg) -> Result<Option<u64>> {
+        todo!("ZCARD command is not implemented yet");
+        // match self.execute(ZCard::new(key)).await? {
+        //     Response::Simple(data) => Ok(Some(from_utf8(&data)?.parse::<u64>()?)),
+        //     Response::Null => Ok(None),
+        //     Response::Error(err) => Err(err),
+        //     _ => Err(RedisError::UnexpectedResponseType),
+        // }
+    }
+
+    /// Sends a ZCOUNT command to the Redis server.
+    #[allow(unused_variables)]
+    pub async fn zcount(
+        &mut self,
+        key: impl ToRedisArg,
+        min: f64,
+        max: f64,
+    ) -> Result<Option<u64>> {
+        todo!("ZCOUNT command is not implemented yet");
+        // match self.execute(ZCount::new(key, min, max)).await? {
+        //     Response::Simple(data) => Ok(Some(from_utf8(&data)?.parse::<u64>()?)),
+        //     Response::Null => Ok(None),
+        //     Response::Error(err) => Err(err),
+        //     _ => Err(RedisError::UnexpectedResponseType),
+        // }
+    }
+
+    /// Sends a ZINCRBY command to the Redis server.
+    #[allow(unused_variables)]
+    pub async fn zincr_by(
+        &mut self,
+        key: impl ToRedisArg,
+        increment: f64,
+        member: &[u8],
+    ) -> Result<Option<f64>> {
+        todo!("ZINCRBY command is not implemented yet");
+        // match self.execute(ZIncrBy::new(key, increment, member)).await? {
+        //     Response::Simple(data) => Ok(Some(from_utf8(&data)?.parse::<f64>()?)),
+        //     Response::Null => Ok(None),
+        //     Response::Error(err) => Err(err),
+        //     _ => Err(RedisError::UnexpectedResponseType),
+        // }
+    }
+
+    /// Sends a PUBLISH command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The PUBLISH command posts a message to the given channel.
+    ///
+    /// # Arguments
+    ///
+    /// * `channel` - The channel to post the message to
+    /// * `message` - The message to post
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` the number of clients that received the message
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let receivers = client.publish("news.tech", b"hello").await?;
+    /// }
+    pub async fn publish(&mut self, channel: &str, message: &[u8]) -> Result<u64> {
+        match self.execute(Publish::new(channel, message)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a PUBSUB CHANNELS command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The PUBSUB CHANNELS command lists the channels with at least one subscriber,
+    /// not counting pattern subscriptions.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - An optional glob-style pattern to filter the channels, all of them if None
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` the active channels
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let channels = client.pubsub_channels(Some("news.*")).await?;
+    /// }
+    pub async fn pubsub_channels(&mut self, pattern: Option<&str>) -> Result<Vec<String>> {
+        match self.execute(PubSubChannels::new(pattern)).await? {
+            Response::Array(data) => data
+                .iter()
+                .map(|channel| Ok(from_utf8(channel)?.to_string()))
+                .collect(),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a PUBSUB NUMSUB command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The PUBSUB NUMSUB command counts the subscribers of the given channels,
+    /// not counting pattern subscriptions.
+    ///
+    /// # Arguments
+    ///
+    /// * `channels` - The channels to count the subscribers of
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<String, u64>)` the number of subscribers of each channel
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let counts = client.pubsub_numsub(vec!["news", "weather"]).await?;
+    /// }
+    pub async fn pubsub_numsub(&mut self, channels: Vec<&str>) -> Result<HashMap<String, u64>> {
+        match self.execute(PubSubNumSub::new(channels)).await? {
+            // a flat array of alternating channels and counts
+            Response::Array(data) => {
+                if data.len() % 2 != 0 {
+                    return Err(RedisError::UnexpectedResponseType);
+                }
+
+                data.chunks_exact(2)
+                    .map(|pair| {
+                        Ok((
+                            from_utf8(&pair[0])?.to_string(),
+                            from_utf8(&pair[1])?.parse::<u64>()?,
+                        ))
+                    })
+                    .collect()
+            }
+            Response::Map(data) => data
+                .into_iter()
+                .map(|(channel, count)| {
+                    Ok((channel, from_utf8(&count.into_scalar()?)?.parse::<u64>()?))
+                })
+                .collect(),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a SUBSCRIBE command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The SUBSCRIBE command subscribes the client to the given channels.
+    /// The connection enters pub/sub mode, so the client is consumed and turned into a `Subscriber`.
+    ///
+    /// # Arguments
+    ///
+    /// * `channels` - The channels to subscribe to
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Subscriber)` once the server confirmed every channel
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let mut subscriber = client.subscribe(vec!["news"]).await?;
+    ///
+    ///     while let Some(message) = subscriber.next_message().await? {
+    ///         println!("{}: {:?}", message.channel, message.payload);
+    ///     }
+    /// }
+    pub async fn subscribe(self, channels: Vec<&str>) -> Result<Subscriber> {
+        let mut subscriber = Subscriber::new(self);
+        subscriber.subscribe(channels).await?;
+
+        Ok(subscriber)
+    }
+
+    /// Sends a PSUBSCRIBE command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The PSUBSCRIBE command subscribes the client to the channels matching the given glob-style patterns.
+    /// The connection enters pub/sub mode, so the client is consumed and turned into a `Subscriber`.
+    ///
+    /// # Arguments
+    ///
+    /// * `patterns` - The patterns to subscribe to, e.g. `news.*`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Subscriber)` once the server confirmed every pattern
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let mut subscriber = client.psubscribe(vec!["news.*"]).await?;
+    ///
+    ///     while let Some(message) = subscriber.next_message().await? {
+    ///         println!("{:?} on {}: {:?}", message.pattern, message.channel, message.payload);
+    ///     }
+    /// }
+    pub async fn psubscribe(self, patterns: Vec<&str>) -> Result<Subscriber> {
+        let mut subscriber = Subscriber::new(self);
+        subscriber.psubscribe(patterns).await?;
+
+        Ok(subscriber)
+    }
+
+    /// Sends a WATCH command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The WATCH command marks keys to watch for changes, for optimistic locking. If any of them
+    /// is changed by another client before EXEC, the transaction is not run and
+    /// `Client::exec` fails with `RedisError::TransactionAborted`, so that the caller can read the
+    /// keys again and retry. The keys are unwatched by EXEC, DISCARD and UNWATCH.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The keys to watch
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` once the keys are watched
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     client.watch(vec!["balance"]).await?;
+    /// }
+    pub async fn watch(&mut self, keys: Vec<impl ToRedisArg>) -> Result<()> {
+        match self.execute(Watch::new(keys)).await? {
+            Response::Simple(_) => Ok(()),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an UNWATCH command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The UNWATCH command forgets every key watched with WATCH, e.g. when deciding not to run the
+    /// transaction after all.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` once no key is watched
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     client.unwatch().await?;
+    /// }
+    pub async fn unwatch(&mut self) -> Result<()> {
+        match self.execute(Unwatch::new()).await? {
+            Response::Simple(_) => Ok(()),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a MULTI command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The MULTI command starts a transaction. The following commands are queued instead of run,
+    /// until EXEC runs them all at once. Queue them with `Client::command`, the server replies
+    /// `QUEUED` to each of them, which the typed methods do not expect.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` once the transaction is started
+    /// * `Err(RedisError)` if an error occurs, e.g. a transaction is already started
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     client.multi().await?;
+    ///     client.command(["INCR", "counter"]).await?;
+    ///     let replies = client.exec().await?;
+    /// }
+    pub async fn multi(&mut self) -> Result<()> {
+        match self.execute(Multi::new()).await? {
+            Response::Simple(_) => Ok(()),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an EXEC command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The EXEC command runs the commands queued since MULTI, without any command of another
+    /// client in between.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<StructuredResponse>)` the replies of the queued commands, in order. A command
+    ///   that failed has a `StructuredResponse::Error`, the others still ran
+    /// * `Err(RedisError::TransactionAborted)` if a watched key changed, nothing ran
+    /// * `Err(RedisError)` if an error occurs, e.g. a command could not be queued
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     client.multi().await?;
+    ///     client.command(["INCR", "counter"]).await?;
+    ///     let replies = client.exec().await?;
+    /// }
+    pub async fn exec(&mut self) -> Result<Vec<StructuredResponse>> {
+        match self.execute_raw(Exec::new()).await? {
+            Some(Frame::Array(replies)) => Ok(replies.into_iter().map(Into::into).collect()),
+            // a null array in RESP2, a null in RESP3
+            Some(Frame::Null) => Err(RedisError::TransactionAborted),
+            Some(Frame::SimpleError(data)) => Err(RedisError::Other(anyhow!(data))),
+            Some(Frame::BulkError(data)) => Err(RedisError::Other(anyhow!(
+                String::from_utf8_lossy(&data).to_string()
+            ))),
+            Some(_) => Err(RedisError::UnexpectedResponseType),
+            None => Err(RedisError::Unknown),
+        }
+    }
+
+    /// Sends a DISCARD command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The DISCARD command drops the commands queued since MULTI, and unwatches every key.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` once the transaction is dropped
+    /// * `Err(RedisError)` if an error occurs, e.g. no transaction is started
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     client.multi().await?;
+    ///     client.command(["INCR", "counter"]).await?;
+    ///     client.discard().await?;
+    /// }
+    pub async fn discard(&mut self) -> Result<()> {
+        match self.execute(Discard::new()).await? {
+            Response::Simple(_) => Ok(()),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an EVAL command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The EVAL command runs a Lua script on the server, without any command of another client
+    /// in between. The server caches the script, so that `Client::evalsha` can run it again
+    /// without sending its source. Prefer `Client::invoke_script`, which does both.
+    ///
+    /// # Arguments
+    ///
+    /// * `script` - The Lua source of the script
+    /// * `keys` - The keys the script accesses, as `KEYS` in the script
+    /// * `args` - The other arguments of the script, as `ARGV` in the script
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(StructuredResponse)` the value returned by the script
+    /// * `Err(RedisError)` if an error occurs, e.g. the script does not compile or raised an error
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let value = client
+    ///         .eval("return redis.call('GET', KEYS[1])", vec!["mykey"], Vec::<&str>::new())
+    ///         .await?;
+    /// }
+    pub async fn eval(
+        &mut self,
+        script: &str,
+        keys: Vec<impl ToRedisArg>,
+        args: Vec<impl ToRedisArg>,
+    ) -> Result<StructuredResponse> {
+        script_reply(self.execute_raw(Eval::new(script, keys, args)).await?)
+    }
+
+    /// Sends an EVALSHA command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The EVALSHA command runs a Lua script the server has cached, referred to by the SHA1
+    /// digest of its source.
+    ///
+    /// # Arguments
+    ///
+    /// * `sha` - The SHA1 digest of the script, in hex
+    /// * `keys` - The keys the script accesses, as `KEYS` in the script
+    /// * `args` - The other arguments of the script, as `ARGV` in the script
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(StructuredResponse)` the value returned by the script
+    /// * `Err(RedisError::NoScript)` if the server has no script with this digest
+    /// * `Err(RedisError)` if an error occurs, e.g. the script raised an error
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let value = client
+    ///         .evalsha("e0e1f9fabfc9d4800c877a703b823ac0578ff8db", Vec::<&str>::new(), Vec::<&str>::new())
+    ///         .await?;
+    /// }
+    pub async fn evalsha(
+        &mut self,
+        sha: &str,
+        keys: Vec<impl ToRedisArg>,
+        args: Vec<impl ToRedisArg>,
+    ) -> Result<StructuredResponse> {
+        script_reply(self.execute_raw(EvalSha::new(sha, keys, args)).await?)
+    }
+
+    /// Runs a Lua script with EVALSHA, or with EVAL if the server has not cached it yet.
+    ///
+    /// # Description
+    ///
+    /// Only the digest of the script is sent, unless the server replies `NOSCRIPT`, e.g. the
+    /// first time the script runs or after SCRIPT FLUSH. The source is then sent with EVAL, which
+    /// caches it for the next time.
+    ///
+    /// # Arguments
+    ///
+    /// * `script` - The script to run
+    /// * `keys` - The keys the script accesses, as `KEYS` in the script
+    /// * `args` - The other arguments of the script, as `ARGV` in the script
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(StructuredResponse)` the value returned by the script
+    /// * `Err(RedisError)` if an error occurs, e.g. the script raised an error
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use redis_asyncx::{Client, Script};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let script = Script::new("return redis.call('GET', KEYS[1])");
+    ///     let value = client
+    ///         .invoke_script(&script, vec!["mykey"], Vec::<&str>::new())
+    ///         .await?;
+    /// }
+    pub async fn invoke_script(
+        &mut self,
+        script: &Script,
+        keys: Vec<impl ToRedisArg>,
+        args: Vec<impl ToRedisArg>,
+    ) -> Result<StructuredResponse> {
+        let keys: Vec<Bytes> = keys.iter().map(ToRedisArg::to_redis_arg).collect();
+        let args: Vec<Bytes> = args.iter().map(ToRedisArg::to_redis_arg).collect();
+
+        match self.evalsha(script.sha(), keys.clone(), args.clone()).await {
+            Err(RedisError::NoScript) => self.eval(script.code(), keys, args).await,
+            result => result,
+        }
+    }
+
+    /// Acquires a lock, stored in a key expiring after a while.
+    ///
+    /// # Description
+    ///
+    /// The lock is taken with `SET key token NX PX ttl`, so only one client holds it at a time,
+    /// and it is released on its own once the TTL elapses, e.g. if its holder crashed. The token
+    /// must be unique to the holder, so that `Client::release_lock` and `Client::extend_lock` can
+    /// tell whether the lock is still theirs.
+    ///
+    /// This is a lock on a single server: it does not survive a failover to a replica the key was
+    /// not replicated to yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key the lock is stored in
+    /// * `token` - A value unique to the holder, e.g. a random UUID
+    /// * `ttl` - How long the lock is held at most, at least a millisecond
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` if the lock is acquired
+    /// * `Ok(false)` if another holder has it
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     if client.acquire_lock("lock:report", b"worker-1", Duration::from_secs(10)).await? {
+    ///         // ...
+    ///         client.release_lock("lock:report", b"worker-1").await?;
+    ///     }
+    /// }
+    pub async fn acquire_lock(
+        &mut self,
+        key: impl ToRedisArg,
+        token: &[u8],
+        ttl: Duration,
+    ) -> Result<bool> {
+        let options = SetOptions {
+            expiry: Some(Expiry::PX(positive_millis(ttl)?)),
+            condition: Some(SetCondition::NX),
+            ..Default::default()
+        };
+
+        match self.set_opts(key, token, options).await? {
+            SetOutcome::Set => Ok(true),
+            SetOutcome::NotSet => Ok(false),
+            SetOutcome::PreviousValue(_) => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Releases a lock acquired with `Client::acquire_lock`, if still held with the given token.
+    ///
+    /// # Description
+    ///
+    /// A script deletes the key only if it still holds the token, so that a holder whose lock
+    /// expired does not release the lock another holder acquired since.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key the lock is stored in
+    /// * `token` - The token the lock was acquired with
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` if the lock is released
+    /// * `Ok(false)` if the lock expired, or is held with another token
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let released = client.release_lock("lock:report", b"worker-1").await?;
+    /// }
+    pub async fn release_lock(&mut self, key: impl ToRedisArg, token: &[u8]) -> Result<bool> {
+        let reply = self
+            .invoke_script(&RELEASE_LOCK, vec![key], vec![token])
+            .await?;
+
+        lock_reply(reply)
+    }
+
+    /// Extends a lock acquired with `Client::acquire_lock`, if still held with the given token.
+    ///
+    /// # Description
+    ///
+    /// A script resets the TTL of the key only if it still holds the token, so that the expired
+    /// lock of a holder is not extended once another holder acquired it.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key the lock is stored in
+    /// * `token` - The token the lock was acquired with
+    /// * `ttl` - How long the lock is held at most from now on, at least a millisecond
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` if the lock is extended
+    /// * `Ok(false)` if the lock expired, or is held with another token
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let extended = client
+    ///         .extend_lock("lock:report", b"worker-1", Duration::from_secs(10))
+    ///         .await?;
+    /// }
+    pub async fn extend_lock(
+        &mut self,
+        key: impl ToRedisArg,
+        token: &[u8],
+        ttl: Duration,
+    ) -> Result<bool> {
+        let ttl = positive_millis(ttl)?.to_string();
+        let reply = self
+            .invoke_script(&EXTEND_LOCK, vec![key], vec![token, ttl.as_bytes()])
+            .await?;
+
+        lock_reply(reply)
+    }
+
+    /// Counts a request against a rate limit, and tells whether it is allowed.
+    ///
+    /// # Description
+    ///
+    /// With `RateLimitAlgorithm::FixedWindow`, INCR, PEXPIRE NX and PTTL are pipelined in a single
+    /// round trip: the first request of a window starts it, and the key expires at its end. PEXPIRE
+    /// NX requires Redis 7.0 or later. With `RateLimitAlgorithm::SlidingWindowLog`, a script logs
+    /// the request in a sorted set if fewer than `max` were logged over the last `window`.
+    ///
+    /// A fixed window counts the denied requests too, a sliding window log does not.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key counting the requests, e.g. one per caller
+    /// * `max` - The number of requests allowed per window
+    /// * `window` - The length of the window, at least a millisecond
+    /// * `algorithm` - How the requests are counted
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RateLimitDecision)` whether the request is allowed, and how many more are
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use redis_asyncx::{Client, RateLimitAlgorithm};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let decision = client
+    ///         .check_rate_limit("ratelimit:alice", 100, Duration::from_secs(60), RateLimitAlgorithm::FixedWindow)
+    ///         .await?;
+    ///     if !decision.allowed {
+    ///         println!("retry in {:?}", decision.reset_after);
+    ///     }
+    /// }
+    pub async fn check_rate_limit(
+        &mut self,
+        key: impl ToRedisArg,
+        max: u64,
+        window: Duration,
+        algorithm: RateLimitAlgorithm,
+    ) -> Result<RateLimitDecision> {
+        let millis = positive_millis(window)?;
+        let key = key.to_redis_arg();
+
+        match algorithm {
+            RateLimitAlgorithm::FixedWindow => {
+                let cmds = vec![
+                    (Incr::NAME, self.frame_of(&Incr::new(&key))?),
+                    (PExpire::NAME, self.frame_of(&PExpire::nx(&key, millis))?),
+                    (PTtl::NAME, self.frame_of(&PTtl::new(&key))?),
+                ];
+                let mut frames = self.execute_raw_batch(&cmds).await?.into_iter();
+                let mut next_integer = || match frames.next().map(Response::try_from) {
+                    Some(Ok(Response::Simple(data))) => Ok(from_utf8(&data)?.parse::<i64>()?),
+                    Some(Ok(Response::Error(err)) | Err(err)) => Err(err),
+                    _ => Err(RedisError::UnexpectedResponseType),
+                };
+
+                let count = u64::try_from(next_integer()?)?;
+                next_integer()?;
+                // -1 or -2 if the key expired in between
+                let pttl = u64::try_from(next_integer()?).unwrap_or(millis);
+
+                Ok(RateLimitDecision {
+                    allowed: count <= max,
+                    remaining: max.saturating_sub(count),
+                    reset_after: Duration::from_millis(pttl),
+                })
+            }
+            RateLimitAlgorithm::SlidingWindowLog => {
+                let args = [millis.to_string(), max.to_string()];
+                let reply = self
+                    .invoke_script(&SLIDING_WINDOW_LOG, vec![key], args.to_vec())
+                    .await?;
+
+                match reply {
+                    StructuredResponse::Array(values) => match values.as_slice() {
+                        [
+                            StructuredResponse::Integer(allowed),
+                            StructuredResponse::Integer(count),
+                            StructuredResponse::Integer(reset),
+                        ] => Ok(RateLimitDecision {
+                            allowed: *allowed == 1,
+                            remaining: max.saturating_sub(u64::try_from(*count)?),
+                            reset_after: Duration::from_millis(u64::try_from(*reset)?),
+                        }),
+                        _ => Err(RedisError::UnexpectedResponseType),
+                    },
+                    _ => Err(RedisError::UnexpectedResponseType),
+                }
+            }
+        }
+    }
+
+    /// Sends a MONITOR command to the Redis server.
+    ///
+    /// # Description
+    ///
+    /// The MONITOR command streams back every command processed by the server, from any client.
+    /// The connection cannot be used for anything else afterwards, so the client is consumed and
+    /// turned into a `MonitorStream`.
+    ///
+    /// MONITOR is meant for debugging only. It is expensive for the server, which formats every
+    /// command it processes, and the stream shows every argument, secrets included.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MonitorStream)` once the server started monitoring
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let mut monitor = client.monitor().await?;
+    ///
+    ///     while let Some(line) = monitor.next_line().await? {
+    ///         println!("{line}");
+    ///     }
+    /// }
+    pub async fn monitor(mut self) -> Result<MonitorStream> {
+        match self.execute(Monitor::new()).await? {
+            Response::Simple(_) => Ok(MonitorStream { client: self }),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends any command to the Redis server, given as its name followed by its arguments.
+    ///
+    /// # Description
+    ///
+    /// An escape hatch for the commands the client does not have a method for yet. Every argument
+    /// is sent as a bulk string, and the reply is returned as is, decoded into a `Response`.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The command name and its arguments, e.g. `["SET", "mykey", "myvalue"]`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Response)` the reply of the server, `Response::Error` if it rejected the command
+    /// * `Err(RedisError::InvalidArgument)` if `args` is empty
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use redis_asyncx::{Client, Response};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     client.command(["SET", "mykey", "myvalue"]).await.unwrap();
+    ///     let resp = client.command(["GET", "mykey"]).await.unwrap();
+    /// }
+    /// ```
+    pub async fn command<I, S>(&mut self, args: I) -> Result<Response>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<[u8]>,
+    {
+        let (name, frame) = command_frame(args)?;
+
+        let timeout = self.command_timeout;
+        self.execute_frame(&name, frame, timeout).await
+    }
+
+    /// Sends any command to the Redis server, like `Client::command`, and returns the reply frame as is.
+    ///
+    /// # Description
+    ///
+    /// Unlike `Client::command`, any reply can be returned, e.g. the nested arrays of CLUSTER SLOTS
+    /// or the maps of XINFO STREAM. Errors replied by the server are returned as error frames.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The command name and its arguments, e.g. `["CONFIG", "GET", "maxmemory"]`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Frame))` the reply of the server
+    /// * `Ok(None)` if the server closed the connection
+    /// * `Err(RedisError::InvalidArgument)` if `args` is empty
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let reply = client.command_raw(["CONFIG", "GET", "maxmemory"]).await.unwrap();
+    /// }
+    /// ```
+    pub async fn command_raw<I, S>(&mut self, args: I) -> Result<Option<Frame>>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<[u8]>,
+    {
+        let (name, frame) = command_frame(args)?;
+
+        let timeout = self.command_timeout;
+        self.execute_raw_frame(&name, frame, timeout).await
+    }
+
+    /// Sends a command to the Redis server and decodes its reply into a `Response`.
+    ///
+    /// The round trip is reported to the metrics observer.
+    async fn execute<C: Command>(&mut self, cmd: C) -> Result<Response> {
+        let timeout = self.reply_timeout(cmd.blocking_timeout());
+        let frame = self.frame_of(&cmd)?;
+        if self.retry_policy.is_none() {
+            return self.execute_frame(C::NAME, frame, timeout).await;
+        }
+
+        let mut attempt = 1;
+        loop {
+            let result = self.execute_frame(C::NAME, frame.clone(), timeout).await;
+            let backoff = match &result {
+                Ok(Response::Error(err)) | Err(err) => {
+                    self.retry_backoff(C::NAME, C::IDEMPOTENT, err, attempt)
+                }
+                Ok(_) => None,
+            };
+            let Some(backoff) = backoff else {
+                #[cfg(feature = "tracing")]
+                crate::trace::record_attempts(C::NAME, attempt, result.is_ok());
+                return result;
+            };
+
+            tokio::time::sleep(backoff).await;
+            attempt += 1;
+        }
+    }
+
+    /// Sends a command already turned into a frame, e.g. one built by `Client::command`, and
+    /// waits up to `command_timeout` for its reply.
+    async fn execute_frame(
+        &mut self,
+        name: &str,
+        frame: Frame,
+        command_timeout: Option<Duration>,
+    ) -> Result<Response> {
+        let start = Instant::now();
+        #[cfg(feature = "tracing")]
+        let span = crate::trace::command_span(name, &frame);
+
+        let result = async {
+            match self.round_trip(name, &frame).await? {
+                Some(frame) => Response::try_from(frame),
+                None => Err(RedisError::Unknown),
+            }
+        };
+        let result = with_timeout(command_timeout, result);
+        #[cfg(feature = "tracing")]
+        let result = tracing::Instrument::instrument(result, span.clone());
+        let result = result.await;
+        if matches!(result, Err(RedisError::Timeout)) {
+            self.conn.poison();
+        }
+
+        let outcome = match &result {
+            Ok(Response::Error(_)) => Outcome::ServerError,
+            Ok(_) => Outcome::Success,
+            Err(_) => Outcome::Failed,
+        };
+        self.observer
+            .on_command_complete(name, start.elapsed(), outcome);
+        #[cfg(feature = "tracing")]
+        match &result {
+            Ok(Response::Error(err)) | Err(err) => {
+                crate::trace::record_outcome(&span, start.elapsed(), outcome, Some(err));
+            }
+            Ok(_) => crate::trace::record_outcome(&span, start.elapsed(), outcome, None),
+        }
+
+        result
+    }
+
+    /// Sends a command to the Redis server and returns the reply frame as is,
+    /// for replies that a `Response` cannot represent, e.g. nested arrays.
+    ///
+    /// The round trip is reported to the metrics observer.
+    async fn execute_raw<C: Command>(&mut self, cmd: C) -> Result<Option<Frame>> {
+        let timeout = self.reply_timeout(cmd.blocking_timeout());
+        let frame = self.frame_of(&cmd)?;
+        if self.retry_policy.is_none() {
+            return self.execute_raw_frame(C::NAME, frame, timeout).await;
+        }
+
+        let mut attempt = 1;
+        loop {
+            let result = self
+                .execute_raw_frame(C::NAME, frame.clone(), timeout)
+                .await;
+            let backoff = match &result {
+                Ok(Some(Frame::SimpleError(data))) => {
+                    let err = RedisError::from_server(data);
+                    self.retry_backoff(C::NAME, C::IDEMPOTENT, &err, attempt)
+                }
+                Ok(Some(Frame::BulkError(data))) => {
+                    let err = RedisError::from_server(&String::from_utf8_lossy(data));
+                    self.retry_backoff(C::NAME, C::IDEMPOTENT, &err, attempt)
+                }
+                Err(err) => self.retry_backoff(C::NAME, C::IDEMPOTENT, err, attempt),
+                Ok(_) => None,
+            };
+            let Some(backoff) = backoff else {
+                #[cfg(feature = "tracing")]
+                crate::trace::record_attempts(C::NAME, attempt, result.is_ok());
+                return result;
+            };
+
+            tokio::time::sleep(backoff).await;
+            attempt += 1;
+        }
+    }
+
+    /// Returns how long to wait before sending a command again after its `attempt`th attempt
+    /// failed, or None if it is not retried.
+    fn retry_backoff(
+        &self,
+        name: &str,
+        idempotent: bool,
+        err: &RedisError,
+        attempt: u32,
+    ) -> Option<Duration> {
+        // a poisoned connection fails whatever is sent next
+        if !self.is_usable() {
+            return None;
+        }
+
+        let backoff = self
+            .retry_policy
+            .as_ref()?
+            .backoff(name, idempotent, err, attempt)?;
+        #[cfg(feature = "tracing")]
+        crate::trace::record_retry(name, attempt, backoff, err);
+
+        Some(backoff)
+    }
+
+    /// Sends a command already turned into a frame and returns the reply frame as is, waiting up
+    /// to `command_timeout` for it.
+    async fn execute_raw_frame(
+        &mut self,
+        name: &str,
+        frame: Frame,
+        command_timeout: Option<Duration>,
+    ) -> Result<Option<Frame>> {
+        let start = Instant::now();
+        #[cfg(feature = "tracing")]
+        let span = crate::trace::command_span(name, &frame);
+        let result = self.round_trip(name, &frame);
+        let result = with_timeout(command_timeout, result);
+        #[cfg(feature = "tracing")]
+        let result = tracing::Instrument::instrument(result, span.clone());
+        let result = result.await;
+        if matches!(result, Err(RedisError::Timeout)) {
+            self.conn.poison();
+        }
+
+        let outcome = match &result {
+            Ok(Some(Frame::SimpleError(_) | Frame::BulkError(_))) => Outcome::ServerError,
+            Ok(Some(_)) => Outcome::Success,
+            Ok(None) | Err(_) => Outcome::Failed,
+        };
+        self.observer
+            .on_command_complete(name, start.elapsed(), outcome);
+        #[cfg(feature = "tracing")]
+        match &result {
+            Ok(Some(Frame::SimpleError(err))) => {
+                crate::trace::record_outcome(&span, start.elapsed(), outcome, Some(err));
+            }
+            Err(err) => crate::trace::record_outcome(&span, start.elapsed(), outcome, Some(err)),
+            _ => crate::trace::record_outcome(&span, start.elapsed(), outcome, None),
+        }
+
+        result
+    }
+
+    /// Sends a batch of commands in one write and reads their responses, in the same order.
+    ///
+    /// The whole batch costs a single round trip. Each command is reported to the metrics
+    /// observer, with the duration of the batch.
+    async fn execute_batch<C: Command>(&mut self, cmds: Vec<C>) -> Result<Vec<Response>> {
+        let frames = cmds
+            .iter()
+            .map(|cmd| Ok((C::NAME, self.frame_of(cmd)?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        self.execute_raw_batch(&frames)
+            .await?
+            .into_iter()
+            .map(Response::try_from)
+            .collect()
+    }
+
+    /// Sends a batch of commands already turned into frames, along with their names, and returns
+    /// the reply frames as is, see `Client::execute_batch`. The commands may differ from one
+    /// another.
+    pub(crate) async fn execute_raw_batch<S: AsRef<str>>(
+        &mut self,
+        cmds: &[(S, Frame)],
+    ) -> Result<Vec<Frame>> {
+        let start = Instant::now();
+
+        let command_timeout = self.command_timeout;
+        #[cfg(feature = "tracing")]
+        let span = crate::trace::batch_span(cmds);
+        let result = async {
+            self.conn.begin_round_trip();
+            for (name, frame) in cmds {
+                with_context(self.conn.write_frame_no_flush(frame).await, || {
+                    format!("failed to write frame for {} command", name.as_ref())
+                })?;
+            }
+            self.conn.flush().await?;
+
+            let mut frames = Vec::with_capacity(cmds.len());
+            for (name, _) in cmds {
+                let frame = with_context(self.conn.read_frame().await, || {
+                    format!("failed to read response for {} command", name.as_ref())
+                })?;
+                frames.push(frame.ok_or(RedisError::Unknown)?);
+            }
+            self.conn.end_round_trip();
+
+            Ok(frames)
+        };
+        let result = with_timeout(command_timeout, result);
+        #[cfg(feature = "tracing")]
+        let result = tracing::Instrument::instrument(result, span.clone());
+        let result = result.await;
+        // the replies left unread would be taken for the replies of the next commands
+        if result.is_err() {
+            self.conn.poison();
+        }
+
+        let elapsed = start.elapsed();
+        match &result {
+            Ok(frames) => {
+                for ((name, _), frame) in cmds.iter().zip(frames) {
+                    let outcome = match frame {
+                        Frame::SimpleError(_) | Frame::BulkError(_) => Outcome::ServerError,
+                        _ => Outcome::Success,
+                    };
+                    self.observer
+                        .on_command_complete(name.as_ref(), elapsed, outcome);
+                }
+            }
+            Err(_) => {
+                for (name, _) in cmds {
+                    self.observer
+                        .on_command_complete(name.as_ref(), elapsed, Outcome::Failed);
+                }
+            }
+        }
+        #[cfg(feature = "tracing")]
+        match &result {
+            Ok(_) => crate::trace::record_outcome(&span, elapsed, Outcome::Success, None),
+            Err(err) => crate::trace::record_outcome(&span, elapsed, Outcome::Failed, Some(err)),
+        }
+
+        result
+    }
+
+    /// Returns how long to wait for a reply, the command timeout extended by how long the
+    /// command may block on the server.
+    fn reply_timeout(&self, blocking: Option<Duration>) -> Option<Duration> {
+        match blocking {
+            // blocks indefinitely, so may the client
+            Some(Duration::ZERO) => None,
+            Some(blocking) => self.command_timeout.map(|timeout| timeout + blocking),
+            None => self.command_timeout,
+        }
+    }
+
+    /// Turns a command into the frame sent to the server, with the key prefix added to its keys.
+    fn frame_of<C: Command>(&self, cmd: &C) -> Result<Frame> {
+        prefixed_frame(cmd, &self.key_prefix)
+    }
+
+    /// Returns the key without the key prefix, None if it is not in the namespace of the client.
+    fn unprefixed(&self, key: Bytes) -> Option<Bytes> {
+        key.starts_with(&self.key_prefix)
+            .then(|| key.slice(self.key_prefix.len()..))
+    }
+
+    /// Returns the SCAN pattern matching the keys of the namespace only, the prefix escaped so
+    /// that its glob characters are matched literally.
+    fn namespaced_pattern(&self, pattern: Option<&str>) -> Option<String> {
+        if self.key_prefix.is_empty() {
+            return pattern.map(str::to_string);
+        }
+
+        let mut namespaced = String::new();
+        for c in String::from_utf8_lossy(&self.key_prefix).chars() {
+            if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+                namespaced.push('\\');
+            }
+            namespaced.push(c);
+        }
+        namespaced.push_str(pattern.unwrap_or("*"));
+
+        Some(namespaced)
+    }
+
+    /// Converts the reply of BZPOPMIN or BZPOPMAX, with the key prefix stripped from the key.
+    fn unprefixed_scored_member(&self, frames: Vec<Frame>) -> Result<(String, Bytes, f64)> {
+        let (key, member, score) = blocking_pop_into_scored_member(frames)?;
+        // stripped before the key is decoded, the prefix does not have to be valid UTF-8
+        match self.unprefixed(key) {
+            Some(key) => Ok((from_utf8(&key)?.to_string(), member, score)),
+            None => Err(RedisError::UnexpectedResponseType),
+        }
+    }
 
-        match self
-            .read_response()
-            .await
-            .with_context(|| "failed to read response for LRANGE command")?
+    /// Strips the key prefix from the key of a keyspace notification, named in the channel of
+    /// `__keyspace@<db>__` notifications and in the payload of `__keyevent@<db>__` ones.
+    fn unprefixed_notification(&self, mut message: Message) -> Message {
+        let Some((space, key)) = message.channel.split_once("__:") else {
+            return message;
+        };
+
+        let prefix_len = self.key_prefix.len();
+        if space.starts_with("__keyspace@") && key.as_bytes().starts_with(&self.key_prefix) {
+            let key = String::from_utf8_lossy(&key.as_bytes()[prefix_len..]);
+            message.channel = format!("{space}__:{key}");
+        } else if space.starts_with("__keyevent@") && message.payload.starts_with(&self.key_prefix)
         {
-            Response::Array(data) => Ok(data),
-            Response::Error(err) => Err(err),
-            _ => Err(RedisError::UnexpectedResponseType),
+            message.payload = message.payload.slice(prefix_len..);
         }
+
+        message
     }
 
-    /// Sends an HGET command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn hget(&mut self, key: &str, field: &str) -> Result<Option<Vec<u8>>> {
-        todo!("HGET command is not implemented yet");
-        // let frame: Frame = HGet::new(key, field).into_stream();
+    /// Sends a command to the Redis server without waiting for the reply.
+    async fn send<C: Command>(&mut self, cmd: C) -> Result<()> {
+        let frame = self.frame_of(&cmd)?;
 
-        // self.conn.write_frame(&frame).await?;
+        self.write_command(C::NAME, &frame).await
+    }
 
-        // match self.read_response().await? {
-        //     Response::Simple(data) => Ok(Some(data)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+    async fn write_command(&mut self, name: &str, frame: &Frame) -> Result<()> {
+        with_context(self.conn.write_frame(frame).await, || {
+            format!("failed to write frame for {name} command")
+        })?;
+
+        Ok(())
     }
 
-    /// Sends an HMGET command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn hmget(&mut self, key: &str, fields: Vec<&str>) -> Result<Option<Vec<Vec<u8>>>> {
-        todo!("HMGET command is not implemented yet");
-        // let frame: Frame = HMGet::new(key, fields).into_stream();
+    /// Writes a command and reads its reply frame, None if the server closed the connection.
+    ///
+    /// The connection is in flight in between, so that it is left unusable if the round trip is
+    /// given up on midway, e.g. because its future is dropped, see `Connection::is_usable`.
+    async fn round_trip(&mut self, name: &str, frame: &Frame) -> Result<Option<Frame>> {
+        self.conn.begin_round_trip();
+        self.write_command(name, frame).await?;
 
-        // self.conn.write_frame(&frame).await?;
+        let frame = with_context(self.conn.read_frame().await, || {
+            format!("failed to read response for {name} command")
+        })?;
+        self.conn.end_round_trip();
 
-        // match self.read_response().await? {
-        //     Response::Array(data) => Ok(Some(data)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+        Ok(frame)
     }
+}
 
-    /// Sends an HGETALL command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn hget_all(&mut self, key: &str) -> Result<Option<HashMap<String, Vec<u8>>>> {
-        todo!("HGETALL command is not implemented yet");
-        // let frame: Frame = HGetAll::new(key).into_stream();
+/// An iterator over the keys of the database, created by `Client::scan_iter`.
+pub struct ScanIter<'a> {
+    client: &'a mut Client,
+    pattern: Option<String>,
+    count: Option<u64>,
+    cursor: u64,
+    keys: VecDeque<Bytes>,
+    finished: bool,
+}
+
+impl ScanIter<'_> {
+    /// Returns the next key, fetching a new batch from the server when needed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Bytes))` the next key
+    /// * `Ok(None)` if the iteration is complete
+    /// * `Err(RedisError)` if an error occurs
+    pub async fn next_key(&mut self) -> Result<Option<Bytes>> {
+        loop {
+            if let Some(key) = self.keys.pop_front() {
+                return Ok(Some(key));
+            }
 
-        // self.conn.write_frame(&frame).await?;
+            if self.finished {
+                return Ok(None);
+            }
 
-        // match self.read_response().await? {
-        //     Response::Map(data) => Ok(Some(data)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+            let (cursor, keys) = self
+                .client
+                .scan(self.cursor, self.pattern.as_deref(), self.count)
+                .await?;
+
+            self.cursor = cursor;
+            self.finished = cursor == 0;
+            self.keys.extend(keys);
+        }
     }
+}
 
-    /// Sends an HKEYS command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn hkeys(&mut self, key: &str) -> Result<Option<Vec<Vec<u8>>>> {
-        todo!("HKEYS command is not implemented yet");
-        // let frame: Frame = HKeys::new(key).into_stream();
+/// How hot a key is, as sampled by `Client::sample_hot_keys`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyHeat {
+    /// The logarithmic access frequency counter, with an LFU maxmemory policy.
+    Freq(u64),
+    /// The seconds since the last access, with any other maxmemory policy.
+    IdleTime(u64),
+}
 
-        // self.conn.write_frame(&frame).await?;
+/// A message received by a `Subscriber`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    /// The channel the message was published to.
+    pub channel: String,
+    /// The pattern matching the channel, set for messages received through a pattern subscription.
+    pub pattern: Option<String>,
+    pub payload: Bytes,
+}
 
-        // match self.read_response().await? {
-        //     Response::Array(data) => Ok(Some(data)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+/// What a `Subscriber` receives, returned by `Subscriber::next_event`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SubscriberEvent {
+    /// A message published to one of the subscriptions.
+    Message(Message),
+    /// The connection dropped and was replaced, with every subscription restored. Messages
+    /// published in the meantime were missed.
+    Reconnected,
+}
+
+/// A client in pub/sub mode, created by `Client::subscribe` or `Client::psubscribe`.
+///
+/// In pub/sub mode the connection only receives messages and subscription changes,
+/// other commands are rejected by the server. Once every subscription is dropped,
+/// `into_client` turns the subscriber back into a regular `Client`.
+pub struct Subscriber {
+    client: Client,
+    channels: Vec<String>,
+    patterns: Vec<String>,
+    // messages received while waiting for the confirmation of a subscription change
+    pending: VecDeque<Message>,
+    // the server to reconnect to, None if its address could not be read
+    addr: Option<SocketAddr>,
+    // how a dropped connection is replaced, never if None
+    reconnect_policy: Option<ReconnectPolicy>,
+}
+
+/// A pub/sub push sent by the server, either a message or the confirmation of a subscription change.
+enum PubSubEvent {
+    Message(Message),
+    Pong(Pong),
+    Confirmation {
+        kind: String,
+        // None when unsubscribing while not subscribed to anything
+        name: Option<String>,
+    },
+}
+
+impl Subscriber {
+    fn new(client: Client) -> Self {
+        // read while the connection is up, a dropped one may not know its peer anymore
+        let addr = client.conn.peer_addr().ok();
+
+        Self {
+            client,
+            channels: Vec::new(),
+            patterns: Vec::new(),
+            pending: VecDeque::new(),
+            addr,
+            reconnect_policy: None,
+        }
     }
 
-    /// Sends an HVALS command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn hvals(&mut self, key: &str) -> Result<Option<Vec<Vec<u8>>>> {
-        todo!("HVALS command is not implemented yet");
-        // let frame: Frame = HVals::new(key).into_stream();
+    /// Sets how the subscriber reconnects once its connection drops, never by default.
+    ///
+    /// With a policy, `next_event` replaces a dropped connection with a new one to the same
+    /// server, subscribes it again to every channel and pattern, and returns
+    /// `SubscriberEvent::Reconnected`. The new connection speaks the same RESP version, it is not
+    /// authenticated nor switched to another database though.
+    pub fn set_reconnect_policy(&mut self, policy: Option<ReconnectPolicy>) {
+        self.reconnect_policy = policy;
+    }
 
-        // self.conn.write_frame(&frame).await?;
+    /// Returns the channels the subscriber is currently subscribed to.
+    pub fn get_subscribed_channels(&self) -> &[String] {
+        &self.channels
+    }
 
-        // match self.read_response().await? {
-        //     Response::Array(data) => Ok(Some(data)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+    /// Returns the patterns the subscriber is currently subscribed to.
+    pub fn get_subscribed_patterns(&self) -> &[String] {
+        &self.patterns
     }
 
-    /// Sends an HLEN command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn hlen(&mut self, key: &str) -> Result<Option<u64>> {
-        todo!("HLEN command is not implemented yet");
-        // let frame: Frame = HLen::new(key).into_stream();
+    /// Waits for the next message published to one of the subscriptions.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Message))` the next message
+    /// * `Ok(None)` if the server closed the connection
+    /// * `Err(RedisError)` if an error occurs
+    ///
+    /// With a reconnect policy, the connection is replaced once it drops, see `next_event` to
+    /// tell when that happens.
+    pub async fn next_message(&mut self) -> Result<Option<Message>> {
+        loop {
+            match self.next_event().await? {
+                Some(SubscriberEvent::Message(message)) => return Ok(Some(message)),
+                Some(SubscriberEvent::Reconnected) => continue,
+                None => return Ok(None),
+            }
+        }
+    }
 
-        // self.conn.write_frame(&frame).await?;
+    /// Waits for the next message published to one of the subscriptions, or for the connection
+    /// to be replaced.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(SubscriberEvent::Message(Message)))` the next message
+    /// * `Ok(Some(SubscriberEvent::Reconnected))` once the connection dropped and was replaced,
+    ///   with a reconnect policy only
+    /// * `Ok(None)` if the server closed the connection, without a reconnect policy
+    /// * `Err(RedisError)` if an error occurs, or if the reconnect policy ran out of attempts
+    pub async fn next_event(&mut self) -> Result<Option<SubscriberEvent>> {
+        if let Some(message) = self.pending.pop_front() {
+            return Ok(Some(SubscriberEvent::Message(message)));
+        }
 
-        // match self.read_response().await? {
-        //     Response::Simple(data) => Ok(Some(from_utf8(&data)?.parse::<u64>()?)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+        loop {
+            let dropped = match self.read_event().await {
+                Ok(Some(PubSubEvent::Message(message))) => {
+                    return Ok(Some(SubscriberEvent::Message(message)));
+                }
+                // confirmations and pongs are consumed by the method that sent the command
+                Ok(Some(PubSubEvent::Confirmation { .. } | PubSubEvent::Pong(_))) => continue,
+                Ok(None) => None,
+                Err(err @ RedisError::Io(_)) => Some(err),
+                Err(err) => return Err(err),
+            };
+
+            if self.reconnect_policy.is_none() {
+                return match dropped {
+                    Some(err) => Err(err),
+                    None => Ok(None),
+                };
+            }
+
+            self.reconnect().await?;
+            return Ok(Some(SubscriberEvent::Reconnected));
+        }
     }
 
-    /// Sends an HSET command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn hset(&mut self, key: &str, field: &str, value: &[u8]) -> Result<Option<Vec<u8>>> {
-        todo!("HSET command is not implemented yet");
-        // let frame: Frame = HSet::new(key, field, value).into_stream();
+    /// Sends a SUBSCRIBE command to the Redis server, adding channels to the subscriptions.
+    ///
+    /// # Arguments
+    ///
+    /// * `channels` - The channels to subscribe to
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` once the server confirmed every channel
+    /// * `Err(RedisError)` if an error occurs
+    pub async fn subscribe(&mut self, channels: Vec<&str>) -> Result<()> {
+        let count = channels.len();
+        self.client.send(Subscribe::new(channels)).await?;
 
-        // self.conn.write_frame(&frame).await?;
+        with_context(
+            self.await_confirmations("subscribe", count).await,
+            || "failed to read response for SUBSCRIBE command",
+        )?;
 
-        // match self.read_response().await? {
-        //     Response::Simple(data) => Ok(Some(data)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+        Ok(())
     }
 
-    /// Sends an HSETNX command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn hset_nx(
-        &mut self,
-        key: &str,
-        field: &str,
-        value: &[u8],
-    ) -> Result<Option<Vec<u8>>> {
-        todo!("HSETNX command is not implemented yet");
-        // let frame: Frame = HSetNx::new(key, field, value).into_stream();
+    /// Sends an UNSUBSCRIBE command to the Redis server.
+    ///
+    /// # Arguments
+    ///
+    /// * `channels` - The channels to unsubscribe from, all of them if empty
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` once the server confirmed every channel
+    /// * `Err(RedisError)` if an error occurs
+    pub async fn unsubscribe(&mut self, channels: Vec<&str>) -> Result<()> {
+        // without arguments the server confirms each subscribed channel, or once if there is none
+        let count = match channels.len() {
+            0 => self.channels.len().max(1),
+            count => count,
+        };
+        self.client.send(Unsubscribe::new(channels)).await?;
+
+        with_context(
+            self.await_confirmations("unsubscribe", count).await,
+            || "failed to read response for UNSUBSCRIBE command",
+        )?;
+
+        Ok(())
+    }
+
+    /// Sends a PSUBSCRIBE command to the Redis server, adding patterns to the subscriptions.
+    ///
+    /// # Arguments
+    ///
+    /// * `patterns` - The patterns to subscribe to, e.g. `news.*`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` once the server confirmed every pattern
+    /// * `Err(RedisError)` if an error occurs
+    pub async fn psubscribe(&mut self, patterns: Vec<&str>) -> Result<()> {
+        let count = patterns.len();
+        self.client.send(PSubscribe::new(patterns)).await?;
+
+        with_context(
+            self.await_confirmations("psubscribe", count).await,
+            || "failed to read response for PSUBSCRIBE command",
+        )?;
+
+        Ok(())
+    }
+
+    /// Sends a PUNSUBSCRIBE command to the Redis server.
+    ///
+    /// # Arguments
+    ///
+    /// * `patterns` - The patterns to unsubscribe from, all of them if empty
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` once the server confirmed every pattern
+    /// * `Err(RedisError)` if an error occurs
+    pub async fn punsubscribe(&mut self, patterns: Vec<&str>) -> Result<()> {
+        // without arguments the server confirms each subscribed pattern, or once if there is none
+        let count = match patterns.len() {
+            0 => self.patterns.len().max(1),
+            count => count,
+        };
+        self.client.send(PUnsubscribe::new(patterns)).await?;
+
+        with_context(
+            self.await_confirmations("punsubscribe", count).await,
+            || "failed to read response for PUNSUBSCRIBE command",
+        )?;
+
+        Ok(())
+    }
+
+    /// Sends a PING command to the Redis server while in pub/sub mode.
+    ///
+    /// # Description
+    ///
+    /// In pub/sub mode a RESP2 server replies with an array of `pong` and the message instead of
+    /// the usual reply, which is read here and returned in the same shape as `Client::ping`.
+    /// Messages received before the reply are kept for `next_message`. An empty message cannot be
+    /// told apart from no message on RESP2, both return `Pong::Pong`.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - An optional message to send to the server
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Pong::Pong)` the bare PONG reply, without a message
+    /// * `Ok(Pong::Echo(Bytes))` the message echoed back
+    /// * `Err(RedisError)` if an error occurs
+    pub async fn ping(&mut self, msg: Option<&[u8]>) -> Result<Pong> {
+        self.client.send(Ping::new(msg)).await?;
+
+        loop {
+            match self.read_event().await? {
+                Some(PubSubEvent::Pong(pong)) => return Ok(pong),
+                Some(PubSubEvent::Message(message)) => self.pending.push_back(message),
+                Some(PubSubEvent::Confirmation { .. }) => {}
+                None => {
+                    return Err(RedisError::Other(anyhow!(
+                        "connection closed while waiting for PONG"
+                    )));
+                }
+            }
+        }
+    }
+
+    /// Turns the subscriber back into a regular `Client`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Client)` if there is no subscription left
+    /// * `Err(RedisError)` if the subscriber is still subscribed to a channel or pattern,
+    ///   in which case the subscriber is dropped along with its connection
+    pub fn into_client(self) -> Result<Client> {
+        if !self.channels.is_empty() || !self.patterns.is_empty() {
+            return Err(RedisError::Other(anyhow!(
+                "cannot leave pub/sub mode while subscribed to {} channels and {} patterns",
+                self.channels.len(),
+                self.patterns.len()
+            )));
+        }
+
+        Ok(self.client)
+    }
+
+    /// Turns the subscriber into a stream, read by a task of its own into a buffer of `buffer`
+    /// events, at least one.
+    ///
+    /// Once the buffer is full, the task waits for the stream to be read before reading the next
+    /// message from the socket, so that messages queue up in the socket buffers and the server
+    /// stops sending them through TCP flow control. None is dropped however slow the stream is
+    /// read, although the server disconnects a subscriber whose output buffer outgrows
+    /// `client-output-buffer-limit pubsub`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use redis_asyncx::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::connect("127.0.0.1:6379").await.unwrap();
+    ///     let subscriber = client.subscribe(vec!["news"]).await.unwrap();
+    ///
+    ///     let mut stream = subscriber.into_stream(64);
+    ///     while let Some(message) = stream.next_message().await.unwrap() {
+    ///         println!("{:?}", message.payload);
+    ///     }
+    /// }
+    /// ```
+    pub fn into_stream(self, buffer: usize) -> SubscriberStream {
+        let (sender, events) = mpsc::channel(buffer.max(1));
+        let task = tokio::spawn(forward_events(self, sender));
+
+        SubscriberStream { events, task }
+    }
+
+    /// Replaces the connection with a new one to the same server, and subscribes it again to
+    /// every channel and pattern, as many times as the reconnect policy allows.
+    async fn reconnect(&mut self) -> Result<()> {
+        let (Some(addr), Some(policy)) = (self.addr, self.reconnect_policy.clone()) else {
+            return Err(RedisError::Other(anyhow!(
+                "cannot reconnect without the address of the server"
+            )));
+        };
+
+        let mut attempt = 1;
+        loop {
+            let Some(backoff) = policy.backoff(attempt) else {
+                return Err(RedisError::Other(anyhow!(
+                    "failed to reconnect to {addr} after {} attempts",
+                    policy.max_attempts
+                )));
+            };
+            tokio::time::sleep(backoff).await;
+
+            if self.resubscribe(addr).await.is_ok() {
+                self.client.observer.on_reconnect();
+                return Ok(());
+            }
+            attempt += 1;
+        }
+    }
+
+    /// Connects to the server and restores the subscriptions of the dropped connection.
+    async fn resubscribe(&mut self, addr: SocketAddr) -> Result<()> {
+        let mut client = Client::connect_with_observer(addr, self.client.observer.clone()).await?;
+        client.command_timeout = self.client.command_timeout;
+        client.retry_policy = self.client.retry_policy.clone();
+        client.key_prefix = self.client.key_prefix.clone();
+        if self.client.protocol_version() == ProtocolVersion::Resp3 {
+            client.hello(Some(3)).await?;
+        }
+        self.client = client;
+
+        let channels: Vec<String> = self.channels.clone();
+        let patterns: Vec<String> = self.patterns.clone();
+        if !channels.is_empty() {
+            self.subscribe(channels.iter().map(String::as_str).collect())
+                .await?;
+        }
+        if !patterns.is_empty() {
+            self.psubscribe(patterns.iter().map(String::as_str).collect())
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads pushes until `count` confirmations of `kind` arrive, keeping the subscriptions in sync.
+    /// Messages received in the meantime are kept for `next_message`.
+    async fn await_confirmations(&mut self, kind: &str, count: usize) -> Result<()> {
+        let mut confirmed = 0;
+
+        while confirmed < count {
+            match self.read_event().await? {
+                Some(PubSubEvent::Message(message)) => self.pending.push_back(message),
+                Some(PubSubEvent::Confirmation {
+                    kind: received,
+                    name,
+                }) if received == kind => {
+                    confirmed += 1;
+
+                    match (received.as_str(), name) {
+                        ("subscribe", Some(name)) if !self.channels.contains(&name) => {
+                            self.channels.push(name)
+                        }
+                        ("unsubscribe", Some(name)) => self.channels.retain(|c| *c != name),
+                        ("psubscribe", Some(name)) if !self.patterns.contains(&name) => {
+                            self.patterns.push(name)
+                        }
+                        ("punsubscribe", Some(name)) => self.patterns.retain(|p| *p != name),
+                        _ => {}
+                    }
+                }
+                Some(PubSubEvent::Confirmation { .. } | PubSubEvent::Pong(_)) => {}
+                None => {
+                    return Err(RedisError::Other(anyhow!(
+                        "connection closed while waiting for {kind} confirmation"
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads the next pub/sub push, an array in RESP2 or a push frame in RESP3, or the reply to a
+    /// PING.
+    async fn read_event(&mut self) -> Result<Option<PubSubEvent>> {
+        let items = match self.client.conn.read_frame().await? {
+            // a regular reply on RESP3, an array of two items on RESP2
+            Some(frame @ (Frame::SimpleString(_) | Frame::BulkString(_))) => {
+                return Ok(Some(PubSubEvent::Pong(Pong::try_from(frame)?)));
+            }
+            Some(Frame::Array(items) | Frame::Push(items)) if items.len() == 2 => {
+                return Ok(Some(PubSubEvent::Pong(Pong::try_from(Frame::Array(
+                    items,
+                ))?)));
+            }
+            Some(Frame::Array(items) | Frame::Push(items)) => items,
+            Some(Frame::SimpleError(err)) => return Err(RedisError::Other(anyhow!(err))),
+            Some(Frame::BulkError(err)) => {
+                return Err(RedisError::Other(anyhow!(
+                    String::from_utf8_lossy(&err).to_string()
+                )));
+            }
+            Some(_) => return Err(RedisError::UnexpectedResponseType),
+            None => return Ok(None),
+        };
+
+        match <[Frame; 3]>::try_from(items) {
+            // message, channel, payload
+            Ok([kind, channel, payload]) => match &frame_into_bytes(kind)?[..] {
+                b"message" => Ok(Some(PubSubEvent::Message(
+                    self.client.unprefixed_notification(Message {
+                        channel: from_utf8(&frame_into_bytes(channel)?)?.to_string(),
+                        pattern: None,
+                        payload: frame_into_bytes(payload)?,
+                    }),
+                ))),
+                // subscribe, unsubscribe, psubscribe or punsubscribe, the name and the subscription count
+                kind => Ok(Some(PubSubEvent::Confirmation {
+                    kind: from_utf8(kind)?.to_string(),
+                    name: match channel {
+                        Frame::Null => None,
+                        name => Some(from_utf8(&frame_into_bytes(name)?)?.to_string()),
+                    },
+                })),
+            },
+            // pmessage, pattern, channel, payload
+            Err(items) => match <[Frame; 4]>::try_from(items) {
+                Ok([kind, pattern, channel, payload]) => {
+                    if frame_into_bytes(kind)? != b"pmessage"[..] {
+                        return Err(RedisError::UnexpectedResponseType);
+                    }
+
+                    Ok(Some(PubSubEvent::Message(
+                        self.client.unprefixed_notification(Message {
+                            channel: from_utf8(&frame_into_bytes(channel)?)?.to_string(),
+                            pattern: Some(from_utf8(&frame_into_bytes(pattern)?)?.to_string()),
+                            payload: frame_into_bytes(payload)?,
+                        }),
+                    )))
+                }
+                Err(_) => Err(RedisError::UnexpectedResponseType),
+            },
+        }
+    }
+}
+
+/// Adds context to an untyped error, passing the typed ones through so that callers can still
+/// match on them, e.g. `RedisError::FrameTooLarge` or `RedisError::Io`.
+fn with_context<T, C>(result: Result<T>, context: impl FnOnce() -> C) -> Result<T>
+where
+    C: std::fmt::Display + Send + Sync + 'static,
+{
+    match result {
+        Err(RedisError::Other(err)) => Err(RedisError::Other(err.context(context()))),
+        result => result,
+    }
+}
+
+/// Runs a command round trip, failing with `RedisError::Timeout` if it takes longer than `timeout`.
+async fn with_timeout<T>(
+    timeout: Option<Duration>,
+    round_trip: impl Future<Output = Result<T>>,
+) -> Result<T> {
+    match timeout {
+        Some(timeout) => tokio::time::timeout(timeout, round_trip)
+            .await
+            .unwrap_or(Err(RedisError::Timeout)),
+        None => round_trip.await,
+    }
+}
+
+/// Builds the frame of a command given as its name followed by its arguments, and returns the
+/// name in upper case, the way errors and metrics label the commands with a method.
+pub(crate) fn command_frame<I, S>(args: I) -> Result<(String, Frame)>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<[u8]>,
+{
+    let args: Vec<Frame> = args
+        .into_iter()
+        .map(|arg| Frame::bulk(Bytes::copy_from_slice(arg.as_ref())))
+        .collect();
+    let name = match args.first() {
+        Some(Frame::BulkString(name)) => String::from_utf8_lossy(name).to_uppercase(),
+        _ => {
+            return Err(RedisError::InvalidArgument(
+                "a command requires at least its name".to_string(),
+            ));
+        }
+    };
+
+    Ok((name, Frame::Array(args)))
+}
+
+/// Turns a command into the frame sent to the server, with the prefix added to the arguments at
+/// its `Command::key_indices`. Shared by the clients, which all namespace the keys the same way.
+pub(crate) fn prefixed_frame<C: Command>(cmd: &C, prefix: &[u8]) -> Result<Frame> {
+    let mut frame = cmd.to_frame()?;
+    if prefix.is_empty() {
+        return Ok(frame);
+    }
+
+    if let Frame::Array(args) = &mut frame {
+        for index in cmd.key_indices() {
+            if let Some(Frame::BulkString(key)) = args.get_mut(index) {
+                *key = [prefix, &key[..]].concat().into();
+            }
+        }
+    }
+
+    Ok(frame)
+}
+
+/// A `Subscriber` read by a task of its own, created by `Subscriber::into_stream`.
+///
+/// The subscriptions cannot change anymore. Dropping the stream drops the connection.
+pub struct SubscriberStream {
+    events: mpsc::Receiver<Result<SubscriberEvent>>,
+    task: JoinHandle<()>,
+}
+
+impl SubscriberStream {
+    /// Waits for the next message published to one of the subscriptions, see
+    /// `Subscriber::next_message`.
+    pub async fn next_message(&mut self) -> Result<Option<Message>> {
+        loop {
+            match self.next_event().await? {
+                Some(SubscriberEvent::Message(message)) => return Ok(Some(message)),
+                Some(SubscriberEvent::Reconnected) => continue,
+                None => return Ok(None),
+            }
+        }
+    }
+
+    /// Waits for the next message published to one of the subscriptions, or for the connection
+    /// to be replaced, see `Subscriber::next_event`.
+    pub async fn next_event(&mut self) -> Result<Option<SubscriberEvent>> {
+        self.events.recv().await.transpose()
+    }
+
+    /// Returns how many events are buffered, read from the socket but not from the stream yet.
+    pub fn buffered(&self) -> usize {
+        self.events.len()
+    }
+}
+
+impl Stream for SubscriberStream {
+    type Item = Result<SubscriberEvent>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Option<Self::Item>> {
+        self.events.poll_recv(cx)
+    }
+}
+
+impl Drop for SubscriberStream {
+    fn drop(&mut self) {
+        // the task may be waiting for a message that never comes
+        self.task.abort();
+    }
+}
+
+/// Reads the events of the subscriber into the buffer of a `SubscriberStream`, until the
+/// connection is closed, an error occurs or the stream is dropped.
+async fn forward_events(mut subscriber: Subscriber, events: mpsc::Sender<Result<SubscriberEvent>>) {
+    while let Some(event) = subscriber.next_event().await.transpose() {
+        let failed = event.is_err();
+        // waits while the buffer is full, leaving the next messages in the socket
+        if events.send(event).await.is_err() || failed {
+            return;
+        }
+    }
+}
+
+/// A client in monitor mode, created by `Client::monitor`.
+///
+/// The server sends a line for every command it processes, e.g.
+/// `1700000000.123456 [0 127.0.0.1:52110] "SET" "mykey" "myvalue"`: the time, the database and the
+/// address of the client, then the command and its arguments.
+pub struct MonitorStream {
+    client: Client,
+}
 
-        // self.conn.write_frame(&frame).await?;
+impl MonitorStream {
+    /// Waits for the next command processed by the server.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(String))` the line describing the command
+    /// * `Ok(None)` if the server closed the connection
+    /// * `Err(RedisError)` if an error occurs
+    pub async fn next_line(&mut self) -> Result<Option<String>> {
+        match self.client.conn.read_frame().await? {
+            Some(Frame::SimpleString(line)) => Ok(Some(line)),
+            Some(Frame::SimpleError(data)) => Err(RedisError::Other(anyhow!(data))),
+            Some(_) => Err(RedisError::UnexpectedResponseType),
+            None => Ok(None),
+        }
+    }
+}
 
-        // match self.read_response().await? {
-        //     Response::Simple(data) => Ok(Some(data)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+/// Surfaces the HELLO errors of servers that do not speak the requested version as
+/// `RedisError::UnsupportedProtocol`: NOPROTO since Redis 6.0, and an unknown command before.
+fn unsupported_protocol(err: String) -> RedisError {
+    if err.starts_with("NOPROTO") || err.starts_with("ERR unknown command") {
+        RedisError::UnsupportedProtocol
+    } else {
+        RedisError::Other(anyhow!(err))
     }
+}
 
-    /// Sends an HMSET command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn hmset(
-        &mut self,
-        key: &str,
-        fields: HashMap<String, Vec<u8>>,
-    ) -> Result<Option<Vec<u8>>> {
-        todo!("HMSET command is not implemented yet");
-        // let frame: Frame = HMSet::new(key, fields).into_stream();
+/// Surfaces the CONFIG SET errors about a parameter as `RedisError::UnknownConfigParameter`:
+/// `Unknown option` since Redis 7.0, and `Unsupported CONFIG parameter` before.
+fn unknown_config_parameter(err: RedisError) -> RedisError {
+    let message = err.to_string();
+    if message.contains("Unknown option") || message.contains("Unsupported CONFIG parameter") {
+        RedisError::UnknownConfigParameter(message)
+    } else {
+        err
+    }
+}
 
-        // self.conn.write_frame(&frame).await?;
+/// Surfaces the error of OBJECT FREQ without an LFU policy as `RedisError::LfuNotSelected`.
+fn lfu_not_selected(err: RedisError) -> RedisError {
+    if err
+        .to_string()
+        .contains("An LFU maxmemory policy is not selected")
+    {
+        RedisError::LfuNotSelected
+    } else {
+        err
+    }
+}
 
-        // match self.read_response().await? {
-        //     Response::Simple(data) => Ok(Some(data)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+/// Reads the reply of INCRBYFLOAT or HINCRBYFLOAT, a bulk string in RESP2 and a double in RESP3.
+fn response_into_f64(response: Response) -> Result<f64> {
+    match response {
+        Response::Simple(data) => Ok(from_utf8(&data)?.parse::<f64>()?),
+        Response::Double(data) => Ok(data),
+        Response::Error(err) => Err(not_a_number(err)),
+        _ => Err(RedisError::UnexpectedResponseType),
     }
+}
 
-    /// Sends an HDEL command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn hdel(&mut self, key: &str, field: &str) -> Result<Option<Vec<u8>>> {
-        todo!("HDEL command is not implemented yet");
-        // let frame: Frame = HDel::new(key, field).into_stream();
+/// Surfaces the error of an increment on a value that is not a number as
+/// `RedisError::NotANumber`, e.g. `ERR hash value is not an integer`.
+fn not_a_number(err: RedisError) -> RedisError {
+    let message = err.to_string();
+    if message.contains("is not an integer")
+        || message.contains("is not a float")
+        || message.contains("is not a valid float")
+    {
+        RedisError::NotANumber
+    } else {
+        err
+    }
+}
 
-        // self.conn.write_frame(&frame).await?;
+/// Tells whether the error is the `no such key` some servers reply to OBJECT for a missing key,
+/// instead of a null.
+fn no_such_key(err: &RedisError) -> bool {
+    err.to_string().contains("no such key")
+}
 
-        // match self.read_response().await? {
-        //     Response::Simple(data) => Ok(Some(data)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+/// Pairs the keys of a batch with the OBJECT FREQ or OBJECT IDLETIME replies, skipping the keys
+/// deleted since they were scanned.
+fn key_heats(
+    keys: &[String],
+    responses: Vec<Response>,
+    heat: fn(u64) -> KeyHeat,
+) -> Result<Vec<(Bytes, KeyHeat)>> {
+    let mut heats = Vec::with_capacity(keys.len());
+    for (key, response) in keys.iter().zip(responses) {
+        match response {
+            Response::Simple(data) => {
+                let value = from_utf8(&data)?.parse::<u64>()?;
+                heats.push((Bytes::from(key.clone()), heat(value)));
+            }
+            Response::Null => {}
+            Response::Error(err) => return Err(lfu_not_selected(err)),
+            _ => return Err(RedisError::UnexpectedResponseType),
+        }
     }
 
-    /// Sends an SADD command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn sadd(&mut self, key: &str, members: Vec<&[u8]>) -> Result<Option<Vec<u8>>> {
-        todo!("SADD command is not implemented yet");
-        // let frame: Frame = SAdd::new(key, members).into_stream();
+    Ok(heats)
+}
 
-        // self.conn.write_frame(&frame).await?;
+/// Serializes the value of a key into JSON.
+#[cfg(feature = "serde")]
+fn to_json<T: serde::Serialize + ?Sized>(key: &[u8], value: &T) -> Result<Vec<u8>> {
+    serde_json::to_vec(value).map_err(|source| RedisError::Serde {
+        key: String::from_utf8_lossy(key).to_string(),
+        source,
+    })
+}
 
-        // match self.read_response().await? {
-        //     Response::Simple(data) => Ok(Some(data)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
-    }
+/// Deserializes the JSON value of a key.
+#[cfg(feature = "serde")]
+fn from_json<T: serde::de::DeserializeOwned>(key: &[u8], data: &[u8]) -> Result<T> {
+    serde_json::from_slice(data).map_err(|source| RedisError::Serde {
+        key: String::from_utf8_lossy(key).to_string(),
+        source,
+    })
+}
 
-    /// Sends an SREM command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn srem(&mut self, key: &str, members: Vec<&[u8]>) -> Result<Option<Vec<u8>>> {
-        todo!("SREM command is not implemented yet");
-        // let frame: Frame = SRem::new(key, members).into_stream();
+/// Surfaces the BUSYKEY error of RESTORE and MIGRATE as `RedisError::BusyKey`.
+fn busy_key(err: RedisError) -> RedisError {
+    // MIGRATE relays the error of the target instance after its own prefix
+    if err.to_string().contains("BUSYKEY") {
+        RedisError::BusyKey
+    } else {
+        err
+    }
+}
 
-        // self.conn.write_frame(&frame).await?;
+/// Turns a frame nested in an array into the string a RESP2 server would have sent.
+///
+/// RESP3 booleans and doubles become "1"/"0" and a decimal string, so the typed methods parse the
+/// same bytes whichever protocol the connection speaks.
+fn scalar_into_bytes(frame: Frame) -> Option<Bytes> {
+    match frame {
+        Frame::BulkString(data) => Some(data),
+        Frame::SimpleString(data) => Some(Bytes::from(data)),
+        Frame::Integer(data) => Some(Bytes::from(data.to_string())),
+        Frame::Boolean(data) => Some(Bytes::from_static(if data { b"1" } else { b"0" })),
+        Frame::Double(data) => Some(Bytes::from(data.to_string())),
+        _ => None,
+    }
+}
 
-        // match self.read_response().await? {
-        //     Response::Simple(data) => Ok(Some(data)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+/// Reads a score, a decimal string in RESP2 and a double in RESP3.
+fn frame_into_score(frame: Frame) -> Result<f64> {
+    match frame {
+        Frame::BulkString(data) => Ok(from_utf8(&data)?.parse::<f64>()?),
+        Frame::Double(data) => Ok(data),
+        _ => Err(RedisError::UnexpectedResponseType),
     }
+}
 
-    /// Sends an SISMEMBER command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn sismember(&mut self, key: &str, member: &[u8]) -> Result<Option<Vec<u8>>> {
-        todo!("SISMEMBER command is not implemented yet");
-        // let frame: Frame = SIsMember::new(key, member).into_stream();
+/// Splits the reply of BZPOPMIN or BZPOPMAX into the key, the member and its score.
+fn blocking_pop_into_scored_member(frames: Vec<Frame>) -> Result<(Bytes, Bytes, f64)> {
+    match <[Frame; 3]>::try_from(frames) {
+        Ok([key, member, score]) => Ok((
+            frame_into_bytes(key)?,
+            frame_into_bytes(member)?,
+            frame_into_score(score)?,
+        )),
+        Err(_) => Err(RedisError::UnexpectedResponseType),
+    }
+}
 
-        // self.conn.write_frame(&frame).await?;
+/// Pairs the members with their scores, given as a flat array of members and scores in RESP2.
+///
+/// RESP3 nests each pair in its own array, except when a single member is popped without a count.
+fn frames_into_scored_members(frames: Vec<Frame>) -> Result<Vec<(Bytes, f64)>> {
+    frames_into_pairs(frames, frame_into_score)
+}
 
-        // match self.read_response().await? {
-        //     Response::Simple(data) => Ok(Some(data)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+/// Pairs the elements of a flat RESP2 array two by two, or takes the pairs RESP3 nests in their
+/// own arrays, converting the second element of each pair with `second`.
+fn frames_into_pairs<T>(
+    frames: Vec<Frame>,
+    second: impl Fn(Frame) -> Result<T>,
+) -> Result<Vec<(Bytes, T)>> {
+    let mut pairs = Vec::new();
+    let mut frames = frames.into_iter();
+    while let Some(frame) = frames.next() {
+        let (first, other) = match frame {
+            Frame::Array(pair) => match <[Frame; 2]>::try_from(pair) {
+                Ok([first, other]) => (first, other),
+                Err(_) => return Err(RedisError::UnexpectedResponseType),
+            },
+            first => match frames.next() {
+                Some(other) => (first, other),
+                None => return Err(RedisError::UnexpectedResponseType),
+            },
+        };
+
+        pairs.push((frame_into_bytes(first)?, second(other)?));
     }
 
-    /// Sends an SMEMBERS command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn smembers(&mut self, key: &str) -> Result<Option<Vec<Vec<u8>>>> {
-        todo!("SMEMBERS command is not implemented yet");
-        // let frame: Frame = SMembers::new(key).into_stream();
+    Ok(pairs)
+}
 
-        // self.conn.write_frame(&frame).await?;
+/// Extracts the content of a string frame in a pub/sub push.
+fn frame_into_bytes(frame: Frame) -> Result<Bytes> {
+    match frame {
+        Frame::BulkString(data) => Ok(data),
+        Frame::SimpleString(data) => Ok(Bytes::from(data)),
+        _ => Err(RedisError::UnexpectedResponseType),
+    }
+}
 
-        // match self.read_response().await? {
-        //     Response::Array(data) => Ok(Some(data)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+/// Turns the reply of EVAL or EVALSHA into the value returned by the script, typing the error
+/// the script raised or the server replied.
+fn script_reply(frame: Option<Frame>) -> Result<StructuredResponse> {
+    match frame.map(StructuredResponse::from) {
+        Some(StructuredResponse::Error(err)) => Err(err),
+        Some(reply) => Ok(reply),
+        None => Err(RedisError::Unknown),
     }
+}
 
-    /// Sends an SPOP command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn spop(&mut self, key: &str) -> Result<Option<Vec<u8>>> {
-        todo!("SPOP command is not implemented yet");
-        // let frame: Frame = SPop::new(key).into_stream();
+/// Returns a lock TTL or a rate limit window in milliseconds, as PX and PEXPIRE take it.
+fn positive_millis(duration: Duration) -> Result<u64> {
+    match u64::try_from(duration.as_millis()) {
+        Ok(0) | Err(_) => Err(RedisError::InvalidArgument(format!(
+            "a duration of {duration:?} is out of range, expected at least 1ms"
+        ))),
+        Ok(millis) => Ok(millis),
+    }
+}
 
-        // self.conn.write_frame(&frame).await?;
+/// Reads the reply of the lock scripts, 1 if the lock was still held with the token.
+fn lock_reply(reply: StructuredResponse) -> Result<bool> {
+    match reply {
+        StructuredResponse::Integer(held) => Ok(held == 1),
+        _ => Err(RedisError::UnexpectedResponseType),
+    }
+}
 
-        // match self.read_response().await? {
-        //     Response::Simple(data) => Ok(Some(data)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockRedisServer;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_structured_response() {
+        let frame = Frame::try_parse(&mut Cursor::new(&b"*3\r\n+OK\r\n:5\r\n$3\r\nfoo\r\n"[..]))
+            .unwrap_or_else(|err| panic!("Failed to parse EXEC reply: {:?}", err));
+
+        let StructuredResponse::Array(replies) = StructuredResponse::from(frame) else {
+            panic!("EXEC reply is not an array");
+        };
+        assert!(matches!(
+            replies.as_slice(),
+            [
+                StructuredResponse::Status(status),
+                StructuredResponse::Integer(5),
+                StructuredResponse::Bulk(bulk),
+            ] if status == "OK" && bulk == "foo"
+        ));
     }
 
-    /// Sends a ZADD command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn zadd(
-        &mut self,
-        key: &str,
-        members: HashMap<String, f64>,
-    ) -> Result<Option<Vec<u8>>> {
-        todo!("ZADD command is not implemented yet");
-        // let frame: Frame = ZAdd::new(key, members).into_stream();
+    #[test]
+    fn test_response_array_nested() {
+        let frame = Frame::Array(vec![Frame::bulk("foo"), Frame::Null, Frame::Integer(5)]);
+        let Ok(Response::Array(data)) = Response::try_from(frame) else {
+            panic!("reply is not an array");
+        };
+        assert_eq!(data, vec!["foo", "", "5"]);
+
+        // a SCAN like reply, the nested array is not concatenated into a single string
+        let frame = Frame::Array(vec![
+            Frame::bulk("0"),
+            Frame::Array(vec![Frame::bulk("foo"), Frame::bulk("bar")]),
+        ]);
+        assert!(matches!(
+            Response::try_from(frame),
+            Err(RedisError::UnexpectedResponseType)
+        ));
+
+        let frame = Frame::Set(vec![Frame::map_from([(
+            Frame::bulk("foo"),
+            Frame::bulk("bar"),
+        )])]);
+        assert!(matches!(
+            Response::try_from(frame),
+            Err(RedisError::UnexpectedResponseType)
+        ));
+    }
 
-        // self.conn.write_frame(&frame).await?;
+    #[test]
+    fn test_response_map_nested() {
+        // a COMMAND DOCS like reply, with an array nested in a value
+        let frame = Frame::map_from([
+            (Frame::bulk("summary"), Frame::bulk("Returns the value")),
+            (Frame::bulk("since"), Frame::Null),
+            (
+                Frame::bulk("history"),
+                Frame::Array(vec![Frame::bulk("6.2.0"), Frame::bulk("Added EX")]),
+            ),
+        ]);
+
+        // the nested array and the null are kept as they are, not flattened into strings
+        let Ok(Response::Map(pairs)) = Response::try_from(frame.clone()) else {
+            panic!("reply is not a map");
+        };
+        assert!(matches!(
+            pairs.as_slice(),
+            [
+                (summary, StructuredResponse::Bulk(text)),
+                (since, StructuredResponse::Null),
+                (history, StructuredResponse::Array(entries)),
+            ] if summary == "summary"
+                && text == "Returns the value"
+                && since == "since"
+                && history == "history"
+                && matches!(
+                    entries.as_slice(),
+                    [StructuredResponse::Bulk(version), StructuredResponse::Bulk(change)]
+                        if version == "6.2.0" && change == "Added EX"
+                )
+        ));
+
+        let StructuredResponse::Map(pairs) = StructuredResponse::from(frame) else {
+            panic!("reply is not a map");
+        };
+        assert!(matches!(
+            pairs.as_slice(),
+            [
+                (StructuredResponse::Bulk(_), StructuredResponse::Bulk(_)),
+                (StructuredResponse::Bulk(_), StructuredResponse::Null),
+                (StructuredResponse::Bulk(key), StructuredResponse::Array(history)),
+            ] if key == "history" && history.len() == 2
+        ));
+
+        // integer keys are decoded, nested maps kept, and an empty string differs from a null
+        let frame = Frame::map_from([
+            (Frame::Integer(1), Frame::bulk("")),
+            (
+                Frame::bulk("module"),
+                Frame::map_from([(Frame::bulk("ver"), Frame::Integer(2))]),
+            ),
+        ]);
+        let Ok(Response::Map(pairs)) = Response::try_from(frame) else {
+            panic!("reply is not a map");
+        };
+        assert!(matches!(
+            pairs.as_slice(),
+            [
+                (one, StructuredResponse::Bulk(empty)),
+                (module, StructuredResponse::Map(properties)),
+            ] if one == "1"
+                && empty.is_empty()
+                && module == "module"
+                && matches!(
+                    properties.as_slice(),
+                    [(StructuredResponse::Bulk(ver), StructuredResponse::Integer(2))] if ver == "ver"
+                )
+        ));
+
+        // scalars read back as the bytes a RESP2 server would have sent
+        let frame = Frame::map_from([(Frame::bulk("n"), Frame::Integer(5))]);
+        let Ok(Response::Map(mut pairs)) = Response::try_from(frame) else {
+            panic!("reply is not a map");
+        };
+        let (_, value) = pairs.remove(0);
+        assert_eq!(
+            value
+                .into_scalar()
+                .unwrap_or_else(|err| panic!("Failed to read scalar: {:?}", err)),
+            Bytes::from("5")
+        );
+        assert!(matches!(
+            StructuredResponse::Null.into_scalar(),
+            Err(RedisError::UnexpectedResponseType)
+        ));
+    }
 
-        // match self.read_response().await? {
-        //     Response::Simple(data) => Ok(Some(data)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+    #[test]
+    fn test_structured_response_nested() {
+        let frame = Frame::Array(vec![
+            Frame::Array(vec![Frame::bulk("a"), Frame::Null]),
+            Frame::SimpleError("WRONGTYPE Operation against a key".to_string()),
+            Frame::Map(vec![(Frame::bulk("len"), Frame::Integer(6))]),
+        ]);
+
+        let StructuredResponse::Array(replies) = StructuredResponse::from(frame) else {
+            panic!("reply is not an array");
+        };
+        assert!(matches!(
+            replies.as_slice(),
+            [
+                StructuredResponse::Array(nested),
+                StructuredResponse::Error(_),
+                StructuredResponse::Map(pairs),
+            ] if matches!(nested.as_slice(), [StructuredResponse::Bulk(_), StructuredResponse::Null])
+                && matches!(pairs.as_slice(), [(StructuredResponse::Bulk(_), StructuredResponse::Integer(6))])
+        ));
     }
 
-    /// Sends a ZREM command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn zrem(&mut self, key: &str, members: Vec<&[u8]>) -> Result<Option<Vec<u8>>> {
-        todo!("ZREM command is not implemented yet");
-        // let frame: Frame = ZRem::new(key, members).into_stream();
+    #[tokio::test]
+    async fn test_exec() {
+        let server = MockRedisServer::start().await;
+        server
+            .expect_command(["MULTI"])
+            .respond(Frame::simple("OK"));
+        server
+            .expect_command(["SET", "mykey", "foo"])
+            .respond(Frame::simple("QUEUED"));
+        server
+            .expect_command(["EXEC"])
+            .respond_raw(&b"*3\r\n+OK\r\n:5\r\n$3\r\nfoo\r\n"[..]);
+
+        let mut client = Client::connect(server.addr())
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
+        client
+            .multi()
+            .await
+            .unwrap_or_else(|err| panic!("Failed to MULTI: {:?}", err));
+        client
+            .command(["SET", "mykey", "foo"])
+            .await
+            .unwrap_or_else(|err| panic!("Failed to queue SET: {:?}", err));
 
-        // self.conn.write_frame(&frame).await?;
+        let replies = client
+            .exec()
+            .await
+            .unwrap_or_else(|err| panic!("Failed to EXEC: {:?}", err));
+        assert_eq!(replies.len(), 3);
+        assert!(matches!(&replies[0], StructuredResponse::Status(status) if status == "OK"));
+        assert!(matches!(replies[1], StructuredResponse::Integer(5)));
+        assert!(matches!(&replies[2], StructuredResponse::Bulk(bulk) if bulk == "foo"));
+    }
 
-        // match self.read_response().await? {
-        //     Response::Simple(data) => Ok(Some(data)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+    #[tokio::test]
+    async fn test_invoke_script_noscript_fallback() {
+        let script = Script::new("return 1");
+        let server = MockRedisServer::start().await;
+        server
+            .expect_command(["EVALSHA", script.sha(), "0"])
+            .respond(Frame::SimpleError(
+                "NOSCRIPT No matching script. Please use EVAL.".to_string(),
+            ));
+        server
+            .expect_command(["EVAL", "return 1", "0"])
+            .respond(Frame::Integer(1));
+        // cached by EVAL
+        server
+            .expect_command(["EVALSHA", script.sha(), "0"])
+            .respond(Frame::Integer(1));
+
+        let mut client = Client::connect(server.addr())
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
+        for _ in 0..2 {
+            let reply = client
+                .invoke_script(&script, Vec::<&str>::new(), Vec::<&str>::new())
+                .await
+                .unwrap_or_else(|err| panic!("Failed to run script: {:?}", err));
+            assert!(matches!(reply, StructuredResponse::Integer(1)));
+        }
     }
 
-    /// Sends a ZRANGE command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn zrange(
-        &mut self,
-        key: &str,
-        start: i64,
-        end: i64,
-    ) -> Result<Option<Vec<Vec<u8>>>> {
-        todo!("ZRANGE command is not implemented yet");
-        // let frame: Frame = ZRange::new(key, start, end).into_stream();
+    #[tokio::test]
+    async fn test_lock() {
+        let server = MockRedisServer::start().await;
+        server
+            .expect_command(["SET", "lock", "token", "NX", "PX", "1500"])
+            .respond(Frame::simple("OK"));
+        server
+            .expect_command(["SET", "lock", "token", "NX", "PX", "1500"])
+            .respond(Frame::Null);
+        server
+            .expect_command(["EVALSHA", RELEASE_LOCK.sha(), "1", "lock", "other"])
+            .respond(Frame::Integer(0));
+        server
+            .expect_command(["EVALSHA", EXTEND_LOCK.sha(), "1", "lock", "token", "2000"])
+            .respond(Frame::Integer(1));
+
+        let mut client = Client::connect(server.addr())
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
+        let ttl = Duration::from_millis(1500);
+        assert!(matches!(
+            client.acquire_lock("lock", b"token", ttl).await,
+            Ok(true)
+        ));
+        assert!(matches!(
+            client.acquire_lock("lock", b"token", ttl).await,
+            Ok(false)
+        ));
+        assert!(matches!(
+            client.release_lock("lock", b"other").await,
+            Ok(false)
+        ));
+        assert!(matches!(
+            client
+                .extend_lock("lock", b"token", Duration::from_secs(2))
+                .await,
+            Ok(true)
+        ));
+
+        // rejected before sending anything
+        assert!(matches!(
+            client.acquire_lock("lock", b"token", Duration::ZERO).await,
+            Err(RedisError::InvalidArgument(_))
+        ));
+    }
 
-        // self.conn.write_frame(&frame).await?;
+    #[tokio::test]
+    async fn test_check_rate_limit_fixed_window() {
+        let server = MockRedisServer::start().await;
+        for (count, set) in [(1, 1), (2, 0), (3, 0)] {
+            server
+                .expect_command(["INCR", "ratelimit"])
+                .respond(Frame::Integer(count));
+            server
+                .expect_command(["PEXPIRE", "ratelimit", "1000", "NX"])
+                .respond(Frame::Integer(set));
+            server
+                .expect_command(["PTTL", "ratelimit"])
+                .respond(Frame::Integer(1000 - count * 100));
+        }
 
-        // match self.read_response().await? {
-        //     Response::Array(data) => Ok(Some(data)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+        let mut client = Client::connect(server.addr())
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
+        let mut decisions = Vec::new();
+        for _ in 0..3 {
+            decisions.push(
+                client
+                    .check_rate_limit(
+                        "ratelimit",
+                        2,
+                        Duration::from_secs(1),
+                        RateLimitAlgorithm::FixedWindow,
+                    )
+                    .await
+                    .unwrap_or_else(|err| panic!("Failed to check rate limit: {:?}", err)),
+            );
+        }
+
+        assert_eq!(
+            decisions,
+            [
+                RateLimitDecision {
+                    allowed: true,
+                    remaining: 1,
+                    reset_after: Duration::from_millis(900),
+                },
+                RateLimitDecision {
+                    allowed: true,
+                    remaining: 0,
+                    reset_after: Duration::from_millis(800),
+                },
+                RateLimitDecision {
+                    allowed: false,
+                    remaining: 0,
+                    reset_after: Duration::from_millis(700),
+                },
+            ]
+        );
     }
 
-    /// Sends a ZREVRANGE command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn zrevrange(
-        &mut self,
-        key: &str,
-        start: i64,
-        end: i64,
-    ) -> Result<Option<Vec<Vec<u8>>>> {
-        todo!("ZREVRANGE command is not implemented yet");
-        // let frame: Frame = ZRevRange::new(key, start, end).into_stream();
+    #[tokio::test]
+    async fn test_check_rate_limit_sliding_window_log() {
+        let server = MockRedisServer::start().await;
+        server
+            .expect_command([
+                "EVALSHA",
+                SLIDING_WINDOW_LOG.sha(),
+                "1",
+                "ratelimit",
+                "1000",
+                "2",
+            ])
+            .respond(Frame::Array(vec![
+                Frame::Integer(0),
+                Frame::Integer(2),
+                Frame::Integer(250),
+            ]));
+
+        let mut client = Client::connect(server.addr())
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
+        let decision = client
+            .check_rate_limit(
+                "ratelimit",
+                2,
+                Duration::from_secs(1),
+                RateLimitAlgorithm::SlidingWindowLog,
+            )
+            .await
+            .unwrap_or_else(|err| panic!("Failed to check rate limit: {:?}", err));
+        assert_eq!(
+            decision,
+            RateLimitDecision {
+                allowed: false,
+                remaining: 0,
+                reset_after: Duration::from_millis(250),
+            }
+        );
+    }
 
-        // self.conn.write_frame(&frame).await?;
+    #[tokio::test]
+    async fn test_key_prefix() {
+        let server = MockRedisServer::start().await;
+        // the value is not a key, so it is left as it is
+        server
+            .expect_command(["SET", "tenant:42:mykey", "myvalue"])
+            .respond(Frame::simple("OK"));
+        server
+            .expect_command(["DEL", "tenant:42:key1", "tenant:42:key2"])
+            .respond(Frame::Integer(2));
+        server
+            .expect_command(["MGET", "tenant:42:key1", "tenant:42:key2", "tenant:42:key3"])
+            .respond(Frame::Array(vec![
+                Frame::bulk("value1"),
+                Frame::bulk(""),
+                Frame::Null,
+            ]));
+        server
+            .expect_command(["SCAN", "0", "MATCH", "tenant:42:user:*"])
+            .respond(Frame::Array(vec![
+                Frame::bulk("0"),
+                Frame::Array(vec![Frame::bulk("tenant:42:user:1")]),
+            ]));
+        server
+            .expect_command(["BLPOP", "tenant:42:queue", "0.5"])
+            .respond(Frame::Array(vec![
+                Frame::bulk("tenant:42:queue"),
+                Frame::bulk("job"),
+            ]));
+
+        let mut client = Client::connect(server.addr())
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
+        client.set_key_prefix("tenant:42:");
 
-        // match self.read_response().await? {
-        //     Response::Array(data) => Ok(Some(data)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+        client
+            .set("mykey", b"myvalue")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to set: {:?}", err));
+        let deleted = client
+            .del(vec!["key1", "key2"])
+            .await
+            .unwrap_or_else(|err| panic!("Failed to delete: {:?}", err));
+        assert_eq!(deleted, 2);
+        // an empty value is told apart from a missing key
+        let values = client
+            .mget(vec!["key1", "key2", "key3"])
+            .await
+            .unwrap_or_else(|err| panic!("Failed to get: {:?}", err));
+        assert_eq!(
+            values,
+            vec![Some(Bytes::from("value1")), Some(Bytes::new()), None]
+        );
+        let (cursor, keys) = client
+            .scan(0, Some("user:*"), None)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to scan: {:?}", err));
+        assert_eq!((cursor, keys), (0, vec![Bytes::from("user:1")]));
+        let popped = client
+            .blpop(vec!["queue"], 0.5)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to pop: {:?}", err));
+        assert_eq!(popped, Some((Bytes::from("queue"), Bytes::from("job"))));
     }
 
-    /// Sends a ZRANK command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn zrank(&mut self, key: &str, member: &[u8]) -> Result<Option<u64>> {
-        todo!("ZRANK command is not implemented yet");
-        // let frame: Frame = ZRank::new(key, member).into_stream();
-
-        // self.conn.write_frame(&frame).await?;
+    #[tokio::test]
+    async fn test_zrange() {
+        let server = MockRedisServer::start().await;
+        server
+            .expect_command(["ZRANGE", "leaderboard", "0", "-1"])
+            .respond(Frame::Array(vec![Frame::bulk("alice"), Frame::bulk("bob")]));
+        server
+            .expect_command(["ZRANGE", "missing", "0", "-1"])
+            .respond(Frame::Array(vec![]));
+
+        let mut client = Client::connect(server.addr())
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
 
-        // match self.read_response().await? {
-        //     Response::Simple(data) => Ok(Some(from_utf8(&data)?.parse::<u64>()?)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+        let members = client
+            .zrange("leaderboard", 0, -1)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to ZRANGE: {:?}", err));
+        assert_eq!(members, vec![Bytes::from("alice"), Bytes::from("bob")]);
+        let members = client
+            .zrange("missing", 0, -1)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to ZRANGE: {:?}", err));
+        assert!(members.is_empty());
     }
 
-    /// Sends a ZREVRANK command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn zrevrank(&mut self, key: &str, member: &[u8]) -> Result<Option<u64>> {
-        todo!("ZREVRANK command is not implemented yet");
-        // let frame: Frame = ZRevRank::new(key, member).into_stream();
+    #[tokio::test]
+    async fn test_frame_too_large() {
+        let server = MockRedisServer::start().await;
+        // a bulk string announced past the 64 MB limit is rejected before it is received
+        server
+            .expect_command(["GET", "mykey"])
+            .respond_raw(&b"$1000000000\r\n"[..]);
 
-        // self.conn.write_frame(&frame).await?;
+        let mut client = Client::connect(server.addr())
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
 
-        // match self.read_response().await? {
-        //     Response::Simple(data) => Ok(Some(from_utf8(&data)?.parse::<u64>()?)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+        // the typed error reaches the caller, not wrapped into `RedisError::Other`
+        let result = client.get("mykey").await;
+        assert!(matches!(result, Err(RedisError::FrameTooLarge(1000000000))));
     }
 
-    /// Sends a ZSCORE command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn zscore(&mut self, key: &str, member: &[u8]) -> Result<Option<f64>> {
-        todo!("ZSCORE command is not implemented yet");
-        // let frame: Frame = ZScore::new(key, member).into_stream();
+    #[tokio::test]
+    async fn test_key_prefix_multiplexed() {
+        let server = MockRedisServer::start().await;
+        server
+            .expect_command(["SET", "tenant:42:mykey", "myvalue"])
+            .respond(Frame::simple("OK"));
+        server
+            .expect_command(["GET", "tenant:42:mykey"])
+            .respond(Frame::bulk("myvalue"));
+        server
+            .expect_command(["DEL", "tenant:42:key1", "tenant:42:key2"])
+            .respond(Frame::Integer(2));
+
+        let mut client = Client::connect(server.addr())
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
+        client.set_key_prefix("tenant:42:");
+        let client = client.into_multiplexed();
+        assert_eq!(client.key_prefix(), b"tenant:42:");
+
+        client
+            .set("mykey", b"myvalue")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to set: {:?}", err));
+        let value = client
+            .get("mykey")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to get: {:?}", err));
+        assert_eq!(value, Some(Bytes::from("myvalue")));
+        let deleted = client
+            .del(vec!["key1", "key2"])
+            .await
+            .unwrap_or_else(|err| panic!("Failed to delete: {:?}", err));
+        assert_eq!(deleted, 2);
+    }
 
-        // self.conn.write_frame(&frame).await?;
+    #[tokio::test]
+    async fn test_key_prefix_non_utf8() {
+        let server = MockRedisServer::start().await;
+        server
+            .expect_command([&b"BZPOPMIN"[..], b"\xffns:scores", b"0.5"])
+            .respond(Frame::Array(vec![
+                Frame::bulk(&b"\xffns:scores"[..]),
+                Frame::bulk("member"),
+                Frame::bulk("1.5"),
+            ]));
+
+        let mut client = Client::connect(server.addr())
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
+        // the prefix is not valid UTF-8, the keys of the namespace are
+        client.set_key_prefix(b"\xffns:");
 
-        // match self.read_response().await? {
-        //     Response::Simple(data) => Ok(Some(from_utf8(&data)?.parse::<f64>()?)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+        let popped = client
+            .bzpopmin(vec!["scores"], Duration::from_millis(500))
+            .await
+            .unwrap_or_else(|err| panic!("Failed to pop: {:?}", err));
+        assert_eq!(
+            popped,
+            Some(("scores".to_string(), Bytes::from("member"), 1.5))
+        );
     }
 
-    /// Sends a ZCARD command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn zcard(&mut self, key: &str) -> Result<Option<u64>> {
-        todo!("ZCARD command is not implemented yet");
-        // let frame: Frame = ZCard::new(key).into_stream();
+    #[tokio::test]
+    async fn test_key_prefix_notifications() {
+        let server = MockRedisServer::start().await;
+        let mut client = Client::connect(server.addr())
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
+        client.set_key_prefix("app[1]:");
+
+        // the glob characters of the prefix are matched literally
+        assert_eq!(
+            client.namespaced_pattern(None).as_deref(),
+            Some("app\\[1\\]:*")
+        );
+
+        let notification = |channel: &str, payload: &str| Message {
+            channel: channel.to_string(),
+            pattern: None,
+            payload: Bytes::from(payload.to_string()),
+        };
+        assert_eq!(
+            client.unprefixed_notification(notification("__keyspace@0__:app[1]:mykey", "del")),
+            notification("__keyspace@0__:mykey", "del")
+        );
+        assert_eq!(
+            client.unprefixed_notification(notification("__keyevent@0__:del", "app[1]:mykey")),
+            notification("__keyevent@0__:del", "mykey")
+        );
+        // neither a key of the namespace nor a keyspace notification
+        for (channel, payload) in [("__keyspace@0__:other", "del"), ("news", "app[1]:mykey")] {
+            assert_eq!(
+                client.unprefixed_notification(notification(channel, payload)),
+                notification(channel, payload)
+            );
+        }
+    }
 
-        // self.conn.write_frame(&frame).await?;
+    #[cfg(feature = "serde")]
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    enum Plan {
+        Free,
+        Paid { seats: u32, renewal: Option<String> },
+    }
 
-        // match self.read_response().await? {
-        //     Response::Simple(data) => Ok(Some(from_utf8(&data)?.parse::<u64>()?)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+    #[cfg(feature = "serde")]
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Account {
+        name: String,
+        plan: Plan,
+        previous: Vec<Plan>,
+        email: Option<String>,
     }
 
-    /// Sends a ZCOUNT command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn zcount(&mut self, key: &str, min: f64, max: f64) -> Result<Option<u64>> {
-        todo!("ZCOUNT command is not implemented yet");
-        // let frame: Frame = ZCount::new(key, min, max).into_stream();
+    #[cfg(feature = "serde")]
+    #[tokio::test]
+    async fn test_json_round_trip() {
+        let account = Account {
+            name: "alice".to_string(),
+            plan: Plan::Paid {
+                seats: 3,
+                renewal: None,
+            },
+            previous: vec![Plan::Free],
+            email: None,
+        };
+        let json = serde_json::to_vec(&account)
+            .unwrap_or_else(|err| panic!("Failed to serialize: {:?}", err));
+
+        let server = MockRedisServer::start().await;
+        server
+            .expect_command([&b"SET"[..], b"account:1", &json])
+            .respond(Frame::simple("OK"));
+        server
+            .expect_command(["GET", "account:1"])
+            .respond(Frame::bulk(json.clone()));
+        server
+            .expect_command([&b"HSET"[..], b"accounts", b"alice", &json])
+            .respond(Frame::Integer(1));
+        server
+            .expect_command(["HGET", "accounts", "alice"])
+            .respond(Frame::bulk(json.clone()));
+        server
+            .expect_command(["GET", "account:missing"])
+            .respond(Frame::Null);
+
+        let mut client = Client::connect(server.addr())
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
 
-        // self.conn.write_frame(&frame).await?;
+        client
+            .set_json("account:1", &account)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to set: {:?}", err));
+        let got: Option<Account> = client
+            .get_json("account:1")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to get: {:?}", err));
+        assert_eq!(got.as_ref(), Some(&account));
 
-        // match self.read_response().await? {
-        //     Response::Simple(data) => Ok(Some(from_utf8(&data)?.parse::<u64>()?)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+        let added = client
+            .hset_json("accounts", "alice", &account)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to hset: {:?}", err));
+        assert_eq!(added, 1);
+        let got: Option<Account> = client
+            .hget_json("accounts", "alice")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to hget: {:?}", err));
+        assert_eq!(got.as_ref(), Some(&account));
+
+        let missing: Option<Account> = client
+            .get_json("account:missing")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to get: {:?}", err));
+        assert_eq!(missing, None);
     }
 
-    /// Sends a ZINCRBY command to the Redis server.
-    #[allow(unused_variables)]
-    pub async fn zincr_by(
-        &mut self,
-        key: &str,
-        increment: f64,
-        member: &[u8],
-    ) -> Result<Option<f64>> {
-        todo!("ZINCRBY command is not implemented yet");
-        // let frame: Frame = ZIncrBy::new(key, increment, member).into_stream();
+    #[cfg(feature = "serde")]
+    #[tokio::test]
+    async fn test_json_corrupted() {
+        let server = MockRedisServer::start().await;
+        server
+            .expect_command(["GET", "account:1"])
+            .respond(Frame::bulk(r#"{"name": "alice", "plan": "Gold"}"#));
 
-        // self.conn.write_frame(&frame).await?;
+        let mut client = Client::connect(server.addr())
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
 
-        // match self.read_response().await? {
-        //     Response::Simple(data) => Ok(Some(from_utf8(&data)?.parse::<f64>()?)),
-        //     Response::Null => Ok(None),
-        //     Response::Error(err) => Err(err),
-        //     _ => Err(RedisError::UnexpectedResponseType),
-        // }
+        let result = client.get_json::<Account>("account:1").await;
+        assert!(
+            matches!(&result, Err(RedisError::Serde { key, .. }) if key == "account:1"),
+            "unexpected result: {result:?}"
+        );
     }
 
-    /// Reads the response from the server. The response is a searilzied frame.
-    /// It decodes the frame and returns the human readable message to the client.
-    ///
-    /// # Returns
-    ///
-    /// * `Ok(Some(Bytes))` if the response is successfully read
-    /// * `Ok(None)` if the response is empty
-    /// * `Err(RedisError)` if an error occurs
-    async fn read_response(&mut self) -> Result<Response> {
-        match self.conn.read_frame().await? {
-            Some(Frame::SimpleString(data)) => Ok(Response::Simple(data.into_bytes())),
-            Some(Frame::SimpleError(data)) => Ok(Response::Error(RedisError::Other(anyhow!(data)))),
-            Some(Frame::Integer(data)) => Ok(Response::Simple(data.to_string().into_bytes())),
-            Some(Frame::BulkString(data)) => Ok(Response::Simple(data.to_vec())),
-            Some(Frame::Array(data)) => {
-                let result: Vec<Vec<u8>> = data
-                    .into_iter()
-                    .map(|frame| match frame {
-                        Frame::BulkString(data) => data.to_vec(),
-                        Frame::SimpleString(data) => data.into_bytes(),
-                        Frame::Integer(data) => data.to_string().into_bytes(),
-                        Frame::Array(data) => {
-                            let result = data
-                                .into_iter()
-                                .map(|frame| match frame {
-                                    Frame::BulkString(data) => data.to_vec(),
-                                    Frame::SimpleString(data) => data.into_bytes(),
-                                    Frame::Integer(data) => data.to_string().into_bytes(),
-                                    Frame::Null => vec![],
-                                    _ => {
-                                        vec![]
-                                    }
-                                })
-                                .collect::<Vec<_>>();
-                            result.concat()
-                        }
-                        _ => vec![],
-                    })
-                    .collect();
-
-                Ok(Response::Array(result))
-            }
-            Some(Frame::Null) => Ok(Response::Null), // nil reply usually means no error
-            Some(Frame::Boolean(data)) => {
-                if data {
-                    Ok(Response::Simple("true".into()))
-                } else {
-                    Ok(Response::Simple("false".into()))
-                }
-            }
-            Some(Frame::Double(data)) => Ok(Response::Simple(data.to_string().into_bytes())),
-            Some(Frame::BulkError(data)) => Ok(Response::Error(RedisError::Other(anyhow!(
-                String::from_utf8_lossy(&data).to_string()
-            )))),
-            Some(Frame::Map(data)) => {
-                let result: HashMap<String, Vec<u8>> = data
-                    .into_iter()
-                    .filter_map(|(key, value)| {
-                        let key = match key {
-                            Frame::BulkString(data) => String::from_utf8(data.to_vec()).ok(),
-                            Frame::SimpleString(data) => Some(data),
-                            Frame::Integer(data) => Some(data.to_string()),
-                            _ => None,
-                        };
-
-                        let value = match value {
-                            Frame::BulkString(data) => Some(data.to_vec()),
-                            Frame::SimpleString(data) => Some(data.into_bytes()),
-                            Frame::Integer(data) => Some(data.to_string().into_bytes()),
-                            _ => None,
-                        };
-
-                        match (key, value) {
-                            (Some(k), Some(v)) => Some((k, v)),
-                            _ => None,
-                        }
-                    })
-                    .collect();
+    #[tokio::test]
+    async fn test_subscriber_stream_backpressure() {
+        const MESSAGES: usize = 1000;
+        let server = MockRedisServer::start().await;
+
+        // sent at once, more than the socket buffers hold
+        let mut messages = Vec::new();
+        for i in 0..MESSAGES {
+            let payload = format!("{i:04}").repeat(256);
+            messages.extend_from_slice(
+                format!("*3\r\n$7\r\nmessage\r\n$4\r\nnews\r\n$1024\r\n{payload}\r\n").as_bytes(),
+            );
+        }
+        server
+            .expect_command(["SUBSCRIBE", "news"])
+            .respond(Frame::Array(vec![
+                Frame::bulk("subscribe"),
+                Frame::bulk("news"),
+                Frame::int(1),
+            ]))
+            .respond_raw(messages);
+
+        let client = Client::connect(server.addr())
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
+        let subscriber = client
+            .subscribe(vec!["news"])
+            .await
+            .unwrap_or_else(|err| panic!("Failed to subscribe: {:?}", err));
+        let mut stream = subscriber.into_stream(2);
 
-                Ok(Response::Map(result))
+        for i in 0..MESSAGES {
+            // a slow consumer, the reader task is held back meanwhile
+            if i % 100 == 0 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
             }
-            // todo: array response needed here
-            Some(_) => unimplemented!(""),
-            None => Err(RedisError::Unknown),
+            assert!(stream.buffered() <= 2);
+
+            let message = stream
+                .next_message()
+                .await
+                .unwrap_or_else(|err| panic!("Failed to read message: {:?}", err))
+                .unwrap_or_else(|| panic!("connection closed after {i} messages"));
+            assert_eq!(message.channel, "news");
+            assert_eq!(message.payload, format!("{i:04}").repeat(256));
         }
     }
 }
@@ -0,0 +1,677 @@
+//! A client for Redis Cluster, routing every command to the node serving its keys.
+//!
+//! The keyspace is split into 16384 hash slots, each served by a single master. The client keeps
+//! the slot map fetched with CLUSTER SLOTS and a multiplexed connection per node. When the map is
+//! out of date, e.g. during a resharding or after a failover, the nodes reply with a MOVED or ASK
+//! redirection and the command is sent again to the node it points to.
+
+use crate::Client;
+use crate::Connection;
+use crate::Frame;
+use crate::MultiplexedClient;
+use crate::RedisError;
+use crate::Result;
+use crate::client::{Response, prefixed_frame};
+use crate::cmd::*;
+use anyhow::anyhow;
+use bytes::Bytes;
+use std::collections::HashMap;
+use std::slice;
+use std::str::from_utf8;
+use std::sync::{Arc, PoisonError, RwLock};
+use tokio::net::TcpStream;
+use tokio::sync::Mutex;
+
+// the number of hash slots of a cluster
+const SLOT_COUNT: usize = 16384;
+
+// how many times a command follows MOVED or ASK before giving up
+const MAX_REDIRECTIONS: usize = 5;
+
+// CRC16-CCITT (XMODEM), the checksum Redis Cluster hashes keys with
+const CRC16_TABLE: [u16; 256] = crc16_table();
+
+const fn crc16_table() -> [u16; 256] {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = (i as u16) << 8;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+fn crc16(data: &[u8]) -> u16 {
+    data.iter().fold(0, |crc, &byte| {
+        (crc << 8) ^ CRC16_TABLE[usize::from((crc >> 8) as u8 ^ byte)]
+    })
+}
+
+/// Returns the hash slot of a key, between 0 and 16383.
+///
+/// If the key contains a non-empty hash tag, i.e. something between its first `{` and the next
+/// `}`, only the hash tag is hashed. Keys sharing a hash tag, such as `{user1000}.following` and
+/// `{user1000}.followers`, are thus served by the same node.
+///
+/// # Examples
+///
+/// ```ignore
+/// use redis_asyncx::key_slot;
+///
+/// assert_eq!(key_slot(b"foo"), 12182);
+/// assert_eq!(key_slot(b"{user1000}.following"), key_slot(b"{user1000}.followers"));
+/// ```
+pub fn key_slot(key: &[u8]) -> u16 {
+    let hashed = match key.iter().position(|&byte| byte == b'{') {
+        Some(open) => match key[open + 1..].iter().position(|&byte| byte == b'}') {
+            Some(len) if len > 0 => &key[open + 1..open + 1 + len],
+            _ => key,
+        },
+        None => key,
+    };
+
+    crc16(hashed) % SLOT_COUNT as u16
+}
+
+/// Returns the slot shared by the given keys once prefixed, None if there is no key.
+fn keys_slot(prefix: &[u8], keys: &[Bytes]) -> Result<Option<u16>> {
+    let mut slots = keys.iter().map(|key| key_slot(&[prefix, key].concat()));
+    let Some(first) = slots.next() else {
+        return Ok(None);
+    };
+
+    if slots.all(|slot| slot == first) {
+        Ok(Some(first))
+    } else {
+        Err(RedisError::CrossSlot)
+    }
+}
+
+/// Fills the host of an address a node left empty with the host of the node itself.
+fn with_host(addr: &str, node: &str) -> String {
+    match (addr.strip_prefix(':'), node.rsplit_once(':')) {
+        (Some(port), Some((host, _))) => format!("{host}:{port}"),
+        _ => addr.to_string(),
+    }
+}
+
+/// A redirection sent by a node that does not serve the slot of a command.
+#[derive(Debug, PartialEq)]
+enum Redirect {
+    /// The slot now belongs to another node, the slot map is out of date.
+    Moved { slot: u16, addr: String },
+    /// The slot is being migrated, this command only is to be sent to the other node.
+    Ask { addr: String },
+}
+
+impl Redirect {
+    fn parse(err: &RedisError, node: &str) -> Option<Self> {
+        let RedisError::Other(err) = err else {
+            return None;
+        };
+
+        let message = err.to_string();
+        match message.split_whitespace().collect::<Vec<_>>().as_slice() {
+            ["MOVED", slot, addr] => Some(Self::Moved {
+                slot: slot.parse().ok()?,
+                addr: with_host(addr, node),
+            }),
+            ["ASK", _, addr] => Some(Self::Ask {
+                addr: with_host(addr, node),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// A Redis Cluster client, sending every command to the master serving the slot of its keys.
+///
+/// Every method takes `&self`, the client can be shared between tasks in an `Arc`. The commands
+/// sent to the same node are pipelined on its connection, like with a `MultiplexedClient`.
+///
+/// The keys of a multi-key command must hash to the same slot, see `key_slot`, otherwise the
+/// command fails with `RedisError::CrossSlot` before it is sent.
+///
+/// # Examples
+///
+/// ```ignore
+/// use redis_asyncx::ClusterClient;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = ClusterClient::connect(vec!["127.0.0.1:7000", "127.0.0.1:7001"])
+///         .await
+///         .unwrap();
+///
+///     client.set("{user1000}.name", b"Alice").await.unwrap();
+///     client.del(vec!["{user1000}.name", "{user1000}.email"]).await.unwrap();
+/// }
+/// ```
+pub struct ClusterClient {
+    seeds: Vec<String>,
+    // the address of the master serving each slot, None for the slots no node serves
+    slots: RwLock<Vec<Option<Arc<str>>>>,
+    // the connections to the nodes, opened on first use
+    nodes: Mutex<HashMap<Arc<str>, MultiplexedClient>>,
+    // added to the keys of every command, see `Client::set_key_prefix`
+    key_prefix: Bytes,
+}
+
+impl ClusterClient {
+    /// Fetches the slot map from the first seed node that answers.
+    ///
+    /// # Arguments
+    ///
+    /// * `seeds` - The addresses of one or more nodes of the cluster, as `host:port`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ClusterClient)` once the slot map is fetched, the connections are opened on first use
+    /// * `Err(RedisError)` if no seed is given or none of them answers
+    pub async fn connect(seeds: Vec<&str>) -> Result<Self> {
+        if seeds.is_empty() {
+            return Err(RedisError::InvalidArgument(
+                "at least one seed node is required".to_string(),
+            ));
+        }
+
+        let client = Self {
+            seeds: seeds.into_iter().map(str::to_string).collect(),
+            slots: RwLock::new(vec![None; SLOT_COUNT]),
+            nodes: Mutex::new(HashMap::new()),
+            key_prefix: Bytes::new(),
+        };
+        client.refresh_slots().await?;
+
+        Ok(client)
+    }
+
+    /// Sets the prefix added to the keys of every command, see `Client::set_key_prefix`.
+    ///
+    /// The slot of a key is the one of the prefixed key, the key actually sent. A prefix with a
+    /// hash tag, e.g. `{tenant:42}:`, sends every key of the namespace to the same slot.
+    pub fn set_key_prefix(&mut self, prefix: impl ToRedisArg) {
+        self.key_prefix = prefix.to_redis_arg();
+    }
+
+    /// Returns the prefix added to the keys of every command, empty for none.
+    pub fn key_prefix(&self) -> &[u8] {
+        &self.key_prefix
+    }
+
+    /// Sends a PING command to any node, see `Client::ping`.
+    pub async fn ping(&self, msg: Option<&[u8]>) -> Result<Pong> {
+        // PING has no key, so there is no redirection to follow
+        let addr = self.node_addr(None)?;
+        self.node(&addr).await?.ping(msg).await
+    }
+
+    /// Sends a GET command, see `Client::get`.
+    pub async fn get(&self, key: impl ToRedisArg) -> Result<Option<Bytes>> {
+        let key = key.to_redis_arg();
+        match self.execute(slice::from_ref(&key), Get::new(&key)).await? {
+            Response::Simple(data) => Ok(Some(data)),
+            Response::Null => Ok(None),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a SET command, see `Client::set`.
+    pub async fn set(&self, key: impl ToRedisArg, val: &[u8]) -> Result<Option<Bytes>> {
+        let key = key.to_redis_arg();
+        match self
+            .execute(slice::from_ref(&key), Set::new(&key, val))
+            .await?
+        {
+            Response::Simple(data) => Ok(Some(data)),
+            Response::Null => Ok(None),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a DEL command, see `Client::del`. The keys must hash to the same slot.
+    pub async fn del(&self, keys: Vec<impl ToRedisArg>) -> Result<u64> {
+        let keys: Vec<Bytes> = keys.iter().map(ToRedisArg::to_redis_arg).collect();
+        match self.execute(&keys, Del::new(keys.clone())).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an EXPIRE command, see `Client::expire`.
+    pub async fn expire(&self, key: impl ToRedisArg, seconds: i64) -> Result<u64> {
+        let key = key.to_redis_arg();
+        match self
+            .execute(slice::from_ref(&key), Expire::new(&key, seconds))
+            .await?
+        {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an INCR command, see `Client::incr`.
+    pub async fn incr(&self, key: impl ToRedisArg) -> Result<i64> {
+        let key = key.to_redis_arg();
+        match self.execute(slice::from_ref(&key), Incr::new(&key)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<i64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a DECR command, see `Client::decr`.
+    pub async fn decr(&self, key: impl ToRedisArg) -> Result<i64> {
+        let key = key.to_redis_arg();
+        match self.execute(slice::from_ref(&key), Decr::new(&key)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<i64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a command to the node serving its keys, following the redirections.
+    async fn execute<C: Command>(&self, keys: &[Bytes], cmd: C) -> Result<Response> {
+        let slot = keys_slot(&self.key_prefix, keys)?;
+        let frame = prefixed_frame(&cmd, &self.key_prefix)?;
+
+        let mut ask: Option<String> = None;
+        for _ in 0..=MAX_REDIRECTIONS {
+            let (node, response) = if let Some(addr) = ask.take() {
+                let response = self.execute_asking(&addr, &frame).await?;
+                (addr, response)
+            } else {
+                let addr = self.node_addr(slot)?;
+                let response = self
+                    .node(&addr)
+                    .await?
+                    .execute_frame(C::NAME, frame.clone())
+                    .await?;
+                (addr.to_string(), response)
+            };
+
+            let Response::Error(err) = response else {
+                return Ok(response);
+            };
+
+            match Redirect::parse(&err, &node) {
+                Some(Redirect::Moved { slot, addr }) => {
+                    // the redirection alone is enough to retry, even if the refresh fails
+                    let _ = self.refresh_slots().await;
+                    self.assign(slot, &addr);
+                }
+                Some(Redirect::Ask { addr }) => ask = Some(addr),
+                None => return Ok(Response::Error(err)),
+            }
+        }
+
+        Err(RedisError::Other(anyhow!(
+            "too many redirections for {} command",
+            C::NAME
+        )))
+    }
+
+    /// Sends a command preceded by ASKING, as asked by a node migrating its slot.
+    ///
+    /// ASKING only applies to the next command of the connection, which a shared connection cannot
+    /// guarantee, so a dedicated connection is opened.
+    async fn execute_asking(&self, addr: &str, frame: &Frame) -> Result<Response> {
+        let stream = TcpStream::connect(addr).await?;
+        let mut conn = Connection::new(stream);
+
+        conn.write_frame_no_flush(&Asking::new().to_frame()?)
+            .await?;
+        conn.write_frame_no_flush(frame).await?;
+        conn.flush().await?;
+
+        match conn.read_frame().await? {
+            Some(Frame::SimpleString(_)) => {}
+            Some(Frame::SimpleError(data)) => return Err(RedisError::Other(anyhow!(data))),
+            _ => return Err(RedisError::UnexpectedResponseType),
+        }
+
+        match conn.read_frame().await? {
+            Some(frame) => Response::try_from(frame),
+            None => Err(RedisError::Other(anyhow!(
+                "connection closed by the server"
+            ))),
+        }
+    }
+
+    /// Returns the address of the node serving a slot, or of any node if there is no slot.
+    fn node_addr(&self, slot: Option<u16>) -> Result<Arc<str>> {
+        let slots = self.slots.read().unwrap_or_else(PoisonError::into_inner);
+
+        let addr = match slot {
+            Some(slot) => slots[usize::from(slot)].clone(),
+            None => slots.iter().flatten().next().cloned(),
+        };
+
+        addr.ok_or_else(|| match slot {
+            Some(slot) => RedisError::Other(anyhow!("slot {slot} is not served by any node")),
+            None => RedisError::Other(anyhow!("no node serves any slot")),
+        })
+    }
+
+    /// Returns the connection to a node, opening it if there is no usable one.
+    async fn node(&self, addr: &Arc<str>) -> Result<MultiplexedClient> {
+        let mut nodes = self.nodes.lock().await;
+
+        if let Some(client) = nodes.get(addr)
+            && !client.is_closed()
+        {
+            return Ok(client.clone());
+        }
+
+        let client = MultiplexedClient::connect(&**addr).await?;
+        nodes.insert(addr.clone(), client.clone());
+
+        Ok(client)
+    }
+
+    fn assign(&self, slot: u16, addr: &str) {
+        let mut slots = self.slots.write().unwrap_or_else(PoisonError::into_inner);
+        slots[usize::from(slot)] = Some(addr.into());
+    }
+
+    /// Fetches the slot map again, from the seeds or any node known to serve a slot.
+    async fn refresh_slots(&self) -> Result<()> {
+        let mut candidates = self.seeds.clone();
+        {
+            let slots = self.slots.read().unwrap_or_else(PoisonError::into_inner);
+            for addr in slots.iter().flatten() {
+                if !candidates.iter().any(|candidate| **candidate == **addr) {
+                    candidates.push(addr.to_string());
+                }
+            }
+        }
+
+        let mut last_err = None;
+        for candidate in candidates {
+            let ranges = match Client::connect(candidate.as_str()).await {
+                Ok(mut client) => client.cluster_slots().await,
+                Err(err) => Err(err),
+            };
+
+            match ranges {
+                Ok(ranges) => {
+                    let mut map = vec![None; SLOT_COUNT];
+                    for (start, end, addr) in ranges {
+                        let addr: Arc<str> = with_host(&addr, &candidate).into();
+                        for slot in start..=end.min(SLOT_COUNT as u16 - 1) {
+                            map[usize::from(slot)] = Some(addr.clone());
+                        }
+                    }
+
+                    *self.slots.write().unwrap_or_else(PoisonError::into_inner) = map;
+                    return Ok(());
+                }
+                Err(err) => last_err = Some(err),
+            }
+        }
+
+        Err(RedisError::Other(anyhow!(
+            "failed to fetch the slot map from any node: {}",
+            last_err.map_or_else(|| "no node to ask".to_string(), |err| err.to_string())
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    // the reference bitwise implementation the table is derived from
+    fn crc16_bitwise(data: &[u8]) -> u16 {
+        let mut crc: u16 = 0;
+        for &byte in data {
+            crc ^= u16::from(byte) << 8;
+            for _ in 0..8 {
+                crc = if crc & 0x8000 != 0 {
+                    (crc << 1) ^ 0x1021
+                } else {
+                    crc << 1
+                };
+            }
+        }
+        crc
+    }
+
+    #[test]
+    fn test_crc16() {
+        // the check value of CRC16-CCITT (XMODEM)
+        assert_eq!(crc16(b"123456789"), 0x31C3);
+        assert_eq!(crc16(b""), 0);
+
+        // every table entry, through every one and two byte input
+        for first in 0..=u8::MAX {
+            assert_eq!(crc16(&[first]), crc16_bitwise(&[first]));
+            for second in 0..=u8::MAX {
+                assert_eq!(crc16(&[first, second]), crc16_bitwise(&[first, second]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_key_slot() {
+        assert_eq!(key_slot(b"foo"), 12182);
+        assert_eq!(key_slot(b"bar"), 5061);
+        assert_eq!(key_slot(b"hello"), 866);
+        assert_eq!(key_slot(b"somekey"), 11058);
+        assert_eq!(key_slot(b""), 0);
+    }
+
+    #[test]
+    fn test_key_slot_hash_tags() {
+        assert_eq!(key_slot(b"foo{hash_tag}"), 2515);
+        assert_eq!(key_slot(b"{user1000}.following"), 3443);
+        assert_eq!(key_slot(b"{user1000}.followers"), 3443);
+        // only the first hash tag counts
+        assert_eq!(key_slot(b"foo{bar}{zap}"), key_slot(b"bar"));
+        // an empty hash tag is ignored, the whole key is hashed
+        assert_eq!(key_slot(b"foo{}{bar}"), crc16(b"foo{}{bar}") % 16384);
+        // the hash tag ends at the first closing brace
+        assert_eq!(key_slot(b"foo{{bar}}zap"), 4015);
+        // an unclosed brace is not a hash tag
+        assert_eq!(key_slot(b"foo{bar"), crc16(b"foo{bar") % 16384);
+        // a closing brace before the first opening one does not end anything
+        assert_eq!(key_slot(b"foo}{bar}"), key_slot(b"bar"));
+        // keys and hash tags are bytes, not necessarily UTF-8
+        assert_eq!(key_slot(b"\xff{\x00\x01}\xfe"), key_slot(b"\x00\x01"));
+    }
+
+    #[test]
+    fn test_keys_slot() {
+        let keys = |keys: &[&'static [u8]]| -> Vec<Bytes> {
+            keys.iter().map(|key| Bytes::from_static(key)).collect()
+        };
+
+        assert!(matches!(keys_slot(b"", &[]), Ok(None)));
+        assert!(matches!(keys_slot(b"", &keys(&[b"foo"])), Ok(Some(12182))));
+        assert!(matches!(
+            keys_slot(
+                b"",
+                &keys(&[b"{user1000}.following", b"{user1000}.followers"])
+            ),
+            Ok(Some(3443))
+        ));
+        assert!(matches!(
+            keys_slot(b"", &keys(&[b"foo", b"bar"])),
+            Err(RedisError::CrossSlot)
+        ));
+        // the slot is the one of the prefixed key, a hash tag in the prefix takes over
+        assert!(matches!(
+            keys_slot(b"{user1000}", &keys(&[b"foo"])),
+            Ok(Some(3443))
+        ));
+        assert!(matches!(
+            keys_slot(b"{user1000}:", &keys(&[b"foo", b"bar"])),
+            Ok(Some(3443))
+        ));
+        // binary keys are routed on their bytes
+        assert!(matches!(
+            keys_slot(b"", &keys(&[b"\xff{user1000}", b"{user1000}\xfe"])),
+            Ok(Some(3443))
+        ));
+    }
+
+    #[test]
+    fn test_redirect_parse() {
+        let moved = RedisError::Other(anyhow!("MOVED 3999 127.0.0.1:6381"));
+        assert_eq!(
+            Redirect::parse(&moved, "127.0.0.1:6379"),
+            Some(Redirect::Moved {
+                slot: 3999,
+                addr: "127.0.0.1:6381".to_string()
+            })
+        );
+
+        let ask = RedisError::Other(anyhow!("ASK 3999 :6381"));
+        assert_eq!(
+            Redirect::parse(&ask, "10.0.0.1:6379"),
+            Some(Redirect::Ask {
+                addr: "10.0.0.1:6381".to_string()
+            })
+        );
+
+        let err = RedisError::Other(anyhow!("WRONGTYPE Operation against a key"));
+        assert_eq!(Redirect::parse(&err, "127.0.0.1:6379"), None);
+    }
+
+    /// Serves every connection of a node, replying to each command with `reply`.
+    ///
+    /// `reply` is also told whether the command follows an ASKING on the same connection.
+    fn serve<F>(listener: TcpListener, reply: F)
+    where
+        F: Fn(&[Frame], bool) -> Vec<u8> + Send + Sync + 'static,
+    {
+        let reply = Arc::new(reply);
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let reply = reply.clone();
+                tokio::spawn(async move {
+                    let mut received = Vec::new();
+                    let mut asking = false;
+                    let mut buf = vec![0; 1024];
+                    loop {
+                        match socket.read(&mut buf).await {
+                            Ok(0) | Err(_) => return,
+                            Ok(n) => received.extend_from_slice(&buf[..n]),
+                        }
+
+                        let mut cursor = Cursor::new(&received[..]);
+                        let mut replies = Vec::new();
+                        while let Ok(Frame::Array(args)) = Frame::try_parse(&mut cursor) {
+                            if args.first() == Some(&Frame::bulk("ASKING")) {
+                                asking = true;
+                                replies.extend_from_slice(b"+OK\r\n");
+                            } else {
+                                replies.extend(reply(&args, asking));
+                                asking = false;
+                            }
+                        }
+                        let consumed = cursor.position() as usize;
+                        received.drain(..consumed);
+                        let _ = socket.write_all(&replies).await;
+                    }
+                });
+            }
+        });
+    }
+
+    fn slots_reply(port: u16) -> Vec<u8> {
+        format!("*1\r\n*3\r\n:0\r\n:16383\r\n*2\r\n$9\r\n127.0.0.1\r\n:{port}\r\n").into_bytes()
+    }
+
+    #[tokio::test]
+    async fn test_cluster_client_redirections() {
+        let bind = async || {
+            let listener = TcpListener::bind("127.0.0.1:0")
+                .await
+                .unwrap_or_else(|err| panic!("Failed to bind listener: {:?}", err));
+            let port = listener
+                .local_addr()
+                .unwrap_or_else(|err| panic!("Failed to get listener address: {:?}", err))
+                .port();
+            (listener, port)
+        };
+        let (first, first_port) = bind().await;
+        let (second, second_port) = bind().await;
+
+        // serves every slot at first, then claims they all moved to the second node, except for
+        // the key migrating back to it
+        let refreshes = Arc::new(AtomicUsize::new(0));
+        let counter = refreshes.clone();
+        serve(first, move |args, asking| match &args[0] {
+            Frame::BulkString(cmd) if cmd[..] == b"CLUSTER"[..] => {
+                if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+                    slots_reply(first_port)
+                } else {
+                    slots_reply(second_port)
+                }
+            }
+            _ if asking => b"$8\r\nmigrated\r\n".to_vec(),
+            _ => format!("-MOVED {} 127.0.0.1:{second_port}\r\n", key_slot(b"foo")).into_bytes(),
+        });
+        serve(second, move |args, _| {
+            if args.get(1) == Some(&Frame::bulk("migrating")) {
+                format!("-ASK {} 127.0.0.1:{first_port}\r\n", key_slot(b"migrating")).into_bytes()
+            } else {
+                b"$3\r\nbar\r\n".to_vec()
+            }
+        });
+
+        let seed = format!("127.0.0.1:{first_port}");
+        let client = ClusterClient::connect(vec![seed.as_str()])
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
+        assert_eq!(refreshes.load(Ordering::SeqCst), 1);
+
+        // MOVED refreshes the slot map, then the command is sent to the second node
+        let value = client
+            .get("foo")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to GET: {:?}", err));
+        assert_eq!(value, Some(Bytes::from_static(b"bar")));
+        assert_eq!(refreshes.load(Ordering::SeqCst), 2);
+
+        // ASK sends the command to the first node once, preceded by ASKING
+        let value = client
+            .get("migrating")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to GET: {:?}", err));
+        assert_eq!(value, Some(Bytes::from_static(b"migrated")));
+        assert_eq!(refreshes.load(Ordering::SeqCst), 2);
+
+        assert!(matches!(
+            client.del(vec!["foo", "bar"]).await,
+            Err(RedisError::CrossSlot)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_cluster_client_without_seeds() {
+        assert!(matches!(
+            ClusterClient::connect(vec![]).await,
+            Err(RedisError::InvalidArgument(_))
+        ));
+    }
+}
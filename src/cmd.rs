@@ -1,11 +1,47 @@
 //! Redis commands.
-use crate::frame::Frame;
+use crate::{Result, frame::Frame};
+use bytes::Bytes;
+use std::time::Duration;
 
 mod hello;
-pub use hello::Hello;
+pub use hello::{Hello, HelloResponse};
+pub(crate) use hello::{frame_to_string, into_pairs};
+
+mod auth;
+pub use auth::Auth;
+
+mod select;
+pub use select::Select;
 
 mod ping;
-pub use ping::Ping;
+pub use ping::{Ping, Pong};
+
+mod quit;
+pub use quit::Quit;
+
+mod monitor;
+pub use monitor::Monitor;
+
+mod role;
+pub use role::Role;
+
+mod sentinel_get_master_addr_by_name;
+pub use sentinel_get_master_addr_by_name::SentinelGetMasterAddrByName;
+
+mod debug_sleep;
+pub use debug_sleep::DebugSleep;
+
+mod command_count;
+pub use command_count::CommandCount;
+
+mod command_info;
+pub use command_info::CommandInfo;
+
+mod asking;
+pub use asking::Asking;
+
+mod cluster_slots;
+pub use cluster_slots::ClusterSlots;
 
 mod get;
 pub use get::Get;
@@ -13,24 +49,75 @@ pub use get::Get;
 mod getex;
 pub use getex::{Expiry, GetEx};
 
+mod mget;
+pub use mget::MGet;
+
 mod set;
-pub use set::Set;
+pub use set::{Set, SetCondition, SetOptions, SetOutcome};
+
+mod mset;
+pub use mset::MSet;
+
+mod msetnx;
+pub use msetnx::MSetNx;
 
 mod del;
 pub use del::Del;
 
+mod unlink;
+pub use unlink::Unlink;
+
 mod exists;
 pub use exists::Exists;
 
+mod touch;
+pub use touch::Touch;
+
+mod randomkey;
+pub use randomkey::RandomKey;
+
 mod expire;
 pub use expire::Expire;
 
 mod ttl;
 pub use ttl::Ttl;
 
+mod pexpire;
+pub use pexpire::PExpire;
+
+mod pttl;
+pub use pttl::PTtl;
+
+mod expiretime;
+pub use expiretime::ExpireTime;
+
+mod pexpiretime;
+pub use pexpiretime::PExpireTime;
+
+mod dump;
+pub use dump::Dump;
+
+mod restore;
+pub use restore::{Restore, RestoreOptions};
+
+mod migrate;
+pub use migrate::{Migrate, MigrateAuth, MigrateOptions};
+
+mod object_freq;
+pub use object_freq::ObjectFreq;
+
+mod object_idletime;
+pub use object_idletime::ObjectIdleTime;
+
+mod object_refcount;
+pub use object_refcount::ObjectRefCount;
+
 mod incr;
 pub use incr::Incr;
 
+mod incrbyfloat;
+pub use incrbyfloat::IncrByFloat;
+
 mod decr;
 pub use decr::Decr;
 
@@ -43,18 +130,320 @@ pub use rpush::RPush;
 mod lpop;
 pub use lpop::LPop;
 
+mod blpop;
+pub use blpop::BLPop;
+
 mod rpop;
 pub use rpop::RPop;
 
 mod lrange;
 pub use lrange::LRange;
 
+mod lcs;
+pub use lcs::{Lcs, LcsMatch, LcsOptions, LcsResult};
+
+mod scan;
+pub use scan::Scan;
+
+mod hset;
+pub use hset::HSet;
+
+mod hgetall;
+pub use hgetall::HGetAll;
+
+mod hrandfield;
+pub use hrandfield::HRandField;
+
+mod hincrby;
+pub use hincrby::HIncrBy;
+
+mod hincrbyfloat;
+pub use hincrbyfloat::HIncrByFloat;
+
+mod hexists;
+pub use hexists::HExists;
+
+mod hget;
+pub use hget::HGet;
+
+mod hstrlen;
+pub use hstrlen::HStrLen;
+
+mod sadd;
+pub use sadd::SAdd;
+
+mod sismember;
+pub use sismember::SIsMember;
+
+mod smismember;
+pub use smismember::SMIsMember;
+
+mod smembers;
+pub use smembers::SMembers;
+
+mod srandmember;
+pub use srandmember::SRandMember;
+
+mod sinter;
+pub use sinter::SInter;
+
+mod sinterstore;
+pub use sinterstore::SInterStore;
+
+mod sintercard;
+pub use sintercard::SInterCard;
+
+mod sunion;
+pub use sunion::SUnion;
+
+mod sunionstore;
+pub use sunionstore::SUnionStore;
+
+mod sdiff;
+pub use sdiff::SDiff;
+
+mod sdiffstore;
+pub use sdiffstore::SDiffStore;
+
+mod zadd;
+pub use zadd::ZAdd;
+pub(crate) use zadd::format_double;
+
+mod zscore;
+pub use zscore::ZScore;
+
+mod zmscore;
+pub use zmscore::ZMScore;
+
+mod zrandmember;
+pub use zrandmember::ZRandMember;
+
+mod zpopmin;
+pub use zpopmin::ZPopMin;
+
+mod zpopmax;
+pub use zpopmax::ZPopMax;
+
+mod bzpopmin;
+pub use bzpopmin::BZPopMin;
+
+mod bzpopmax;
+pub use bzpopmax::BZPopMax;
+
+mod zrangebyscore;
+pub use zrangebyscore::{ScoreBound, ZRangeByScore};
+
+mod zrangebylex;
+pub use zrangebylex::{LexBound, ZRangeByLex};
+
+mod zrange;
+pub use zrange::{ZRange, ZRangeBy, ZRangeOptions};
+
+mod zrangestore;
+pub use zrangestore::ZRangeStore;
+
+mod zunion;
+pub use zunion::{Aggregate, ZCombineOptions, ZUnion};
+
+mod zunionstore;
+pub use zunionstore::ZUnionStore;
+
+mod zinter;
+pub use zinter::ZInter;
+
+mod zinterstore;
+pub use zinterstore::ZInterStore;
+
+mod client_setname;
+pub use client_setname::ClientSetName;
+pub(crate) use client_setname::validate_client_name;
+
+mod client_getname;
+pub use client_getname::ClientGetName;
+
+mod client_tracking;
+pub use client_tracking::ClientTracking;
+
+mod client_no_evict;
+pub use client_no_evict::ClientNoEvict;
+
+mod client_no_touch;
+pub use client_no_touch::ClientNoTouch;
+
+mod client_id;
+pub use client_id::ClientId;
+
+mod client_list;
+pub(crate) use client_list::parse_client_list;
+pub use client_list::{ClientInfo, ClientList, ClientListFilter, ClientType};
+
+mod client_kill;
+pub use client_kill::{ClientKill, ClientKillFilter};
+
+mod config_get;
+pub use config_get::ConfigGet;
+
+mod config_resetstat;
+pub use config_resetstat::ConfigResetStat;
+
+mod config_rewrite;
+pub use config_rewrite::ConfigRewrite;
+
+mod config_set;
+pub use config_set::ConfigSet;
+
+mod slowlog_get;
+pub use slowlog_get::{SlowlogEntry, SlowlogGet};
+
+mod slowlog_len;
+pub use slowlog_len::SlowlogLen;
+
+mod slowlog_reset;
+pub use slowlog_reset::SlowlogReset;
+
+mod watch;
+pub use watch::Watch;
+
+mod unwatch;
+pub use unwatch::Unwatch;
+
+mod multi;
+pub use multi::Multi;
+
+mod exec;
+pub use exec::Exec;
+
+mod discard;
+pub use discard::Discard;
+
+mod eval;
+pub use eval::Eval;
+
+mod evalsha;
+pub use evalsha::EvalSha;
+
 mod publish;
+pub use publish::Publish;
+
+mod pubsub_channels;
+pub use pubsub_channels::PubSubChannels;
+
+mod pubsub_numsub;
+pub use pubsub_numsub::PubSubNumSub;
 
 mod subscribe;
+pub use subscribe::Subscribe;
 
 mod unsubscribe;
+pub use unsubscribe::Unsubscribe;
+
+mod psubscribe;
+pub use psubscribe::PSubscribe;
+
+mod punsubscribe;
+pub use punsubscribe::PUnsubscribe;
 
 /// A trait for all Redis commands.
-#[allow(unused)]
-pub trait Command: TryInto<Frame, Error = crate::RedisError> {}
+pub trait Command {
+    /// The name of the command, e.g. `GET` or `CLIENT SETNAME`, used to label errors.
+    const NAME: &'static str;
+
+    /// Whether running the command twice has the same effect as running it once, so that it can
+    /// be retried after a transient error, see `RetryPolicy`. False for e.g. INCR or LPUSH.
+    const IDEMPOTENT: bool = true;
+
+    /// Builds the frame sent to the server, an array of bulk strings, never a Set or a Map.
+    fn to_frame(&self) -> Result<Frame>;
+
+    /// How long the server may hold the reply back, e.g. the timeout of BLPOP, None for the
+    /// commands replying right away. Zero blocks indefinitely.
+    ///
+    /// The command timeout of the client is extended by it, so that a blocked command is not
+    /// taken for a lost one.
+    fn blocking_timeout(&self) -> Option<Duration> {
+        None
+    }
+
+    /// The positions of the keys in the frame, the name of the command being at 0, e.g. `[1]`
+    /// for GET and `[1, 2]` for DEL with two keys. Empty for the commands without keys.
+    ///
+    /// The key prefix of the client is added to the arguments at these positions, see
+    /// `Client::set_key_prefix`, so the other arguments such as values are left as they are.
+    fn key_indices(&self) -> Vec<usize> {
+        Vec::new()
+    }
+}
+
+/// A binary-safe argument, e.g. a key: `&str`, `String`, `&[u8]`, `Vec<u8>` or `Bytes`.
+///
+/// Redis keys are arbitrary bytes, so they do not have to be valid UTF-8.
+pub trait ToRedisArg {
+    /// Returns the bytes sent to the server.
+    fn to_redis_arg(&self) -> Bytes;
+}
+
+impl ToRedisArg for str {
+    fn to_redis_arg(&self) -> Bytes {
+        Bytes::copy_from_slice(self.as_bytes())
+    }
+}
+
+impl ToRedisArg for String {
+    fn to_redis_arg(&self) -> Bytes {
+        self.as_str().to_redis_arg()
+    }
+}
+
+impl ToRedisArg for [u8] {
+    fn to_redis_arg(&self) -> Bytes {
+        Bytes::copy_from_slice(self)
+    }
+}
+
+impl<const N: usize> ToRedisArg for [u8; N] {
+    fn to_redis_arg(&self) -> Bytes {
+        self.as_slice().to_redis_arg()
+    }
+}
+
+impl ToRedisArg for Vec<u8> {
+    fn to_redis_arg(&self) -> Bytes {
+        self.as_slice().to_redis_arg()
+    }
+}
+
+impl ToRedisArg for Bytes {
+    // a reference count increment, not a copy
+    fn to_redis_arg(&self) -> Bytes {
+        self.clone()
+    }
+}
+
+impl<T: ToRedisArg + ?Sized> ToRedisArg for &T {
+    fn to_redis_arg(&self) -> Bytes {
+        (**self).to_redis_arg()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_redis_arg() {
+        let expected = Bytes::from_static(b"mykey");
+
+        assert_eq!("mykey".to_redis_arg(), expected);
+        assert_eq!("mykey".to_string().to_redis_arg(), expected);
+        assert_eq!(b"mykey".to_redis_arg(), expected);
+        assert_eq!(b"mykey"[..].to_redis_arg(), expected);
+        assert_eq!(b"mykey".to_vec().to_redis_arg(), expected);
+        assert_eq!(expected.to_redis_arg(), expected);
+
+        // keys do not have to be valid UTF-8
+        assert_eq!(
+            b"\x00\xff".to_redis_arg(),
+            Bytes::from_static(&[0x00, 0xff])
+        );
+    }
+}
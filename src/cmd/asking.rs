@@ -0,0 +1,48 @@
+/// A Redis ASKING command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+#[derive(Default)]
+pub struct Asking;
+
+impl Asking {
+    /// Creates a new Asking command.
+    ///
+    /// # Returns
+    ///
+    /// A new Asking command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let asking = Asking::new();
+    /// ```
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Command for Asking {
+    const NAME: &'static str = "ASKING";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("ASKING"))?;
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asking() {
+        let asking = Asking::new();
+        let frame: Frame = asking
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ASKING command: {:?}", err));
+
+        assert_eq!(frame, Frame::Array(vec![Frame::bulk("ASKING")]));
+    }
+}
@@ -0,0 +1,82 @@
+/// A Redis AUTH command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+pub struct Auth {
+    username: Option<String>,
+    password: String,
+}
+
+impl Auth {
+    /// Creates a new Auth command.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - An optional ACL user to authenticate as, the default user if None
+    /// * `password` - The password of the user
+    ///
+    /// # Returns
+    ///
+    /// A new Auth command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let auth = Auth::new(Some("worker"), "secret");
+    /// ```
+    pub fn new(username: Option<&str>, password: &str) -> Self {
+        Self {
+            username: username.map(|u| u.to_string()),
+            password: password.to_string(),
+        }
+    }
+}
+
+impl Command for Auth {
+    const NAME: &'static str = "AUTH";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("AUTH"))?;
+
+        // the single argument form authenticates the default user, it also works before Redis 6
+        if let Some(username) = &self.username {
+            frame.push_frame_to_array(Frame::bulk(username.clone()))?;
+        }
+
+        frame.push_frame_to_array(Frame::bulk(self.password.clone()))?;
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auth() {
+        let auth = Auth::new(None, "secret");
+        let frame: Frame = auth
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create AUTH command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("AUTH"), Frame::bulk("secret")])
+        );
+
+        let auth = Auth::new(Some("worker"), "secret");
+        let frame: Frame = auth
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create AUTH command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("AUTH"),
+                Frame::bulk("worker"),
+                Frame::bulk("secret"),
+            ])
+        );
+    }
+}
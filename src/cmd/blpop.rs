@@ -0,0 +1,89 @@
+/// A Redis BLPOP command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+use std::time::Duration;
+
+pub struct BLPop {
+    keys: Vec<Bytes>,
+    timeout: f64,
+}
+
+impl BLPop {
+    /// Creates a new BLPop command.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The lists to pop from, checked in the given order
+    /// * `timeout` - The maximum number of seconds to block, 0 blocks indefinitely
+    ///
+    /// # Returns
+    ///
+    /// A new BLPop command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let blpop = BLPop::new(vec!["list1", "list2"], 0.5);
+    /// ```
+    pub fn new(keys: Vec<impl ToRedisArg>, timeout: f64) -> Self {
+        Self {
+            keys: keys.iter().map(ToRedisArg::to_redis_arg).collect(),
+            timeout,
+        }
+    }
+}
+
+impl Command for BLPop {
+    const NAME: &'static str = "BLPOP";
+    const IDEMPOTENT: bool = false;
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("BLPOP"))?;
+
+        for key in &self.keys {
+            frame.push_frame_to_array(Frame::bulk(key.clone()))?;
+        }
+
+        frame.push_frame_to_array(Frame::bulk(self.timeout.to_string()))?;
+
+        Ok(frame)
+    }
+
+    fn blocking_timeout(&self) -> Option<Duration> {
+        // a negative timeout is rejected by the server without blocking
+        Duration::try_from_secs_f64(self.timeout).ok()
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        // the timeout follows the keys
+        (1..=self.keys.len()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blpop() {
+        let blpop = BLPop::new(vec!["list1", "list2"], 0.5);
+        let frame: Frame = blpop
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create BLPOP command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("BLPOP"),
+                Frame::bulk("list1"),
+                Frame::bulk("list2"),
+                Frame::bulk("0.5"),
+            ])
+        );
+    }
+}
@@ -0,0 +1,109 @@
+/// A Redis BZPOPMIN command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+use std::time::Duration;
+
+pub struct BZPopMin {
+    keys: Vec<Bytes>,
+    timeout: Duration,
+}
+
+impl BZPopMin {
+    /// Creates a new BZPopMin command.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The sorted sets to pop from, checked in the given order
+    /// * `timeout` - How long to block if all of them are empty, zero blocks indefinitely
+    ///
+    /// # Returns
+    ///
+    /// A new BZPopMin command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let bzpopmin = BZPopMin::new(vec!["jobs:high", "jobs:low"], Duration::from_millis(500));
+    /// ```
+    pub fn new(keys: Vec<impl ToRedisArg>, timeout: Duration) -> Self {
+        Self {
+            keys: keys.iter().map(ToRedisArg::to_redis_arg).collect(),
+            timeout,
+        }
+    }
+}
+
+impl Command for BZPopMin {
+    const NAME: &'static str = "BZPOPMIN";
+    const IDEMPOTENT: bool = false;
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("BZPOPMIN"))?;
+
+        for key in &self.keys {
+            frame.push_frame_to_array(Frame::bulk(key.clone()))?;
+        }
+
+        frame.push_frame_to_array(Frame::bulk(self.timeout.as_secs_f64().to_string()))?;
+
+        Ok(frame)
+    }
+
+    fn blocking_timeout(&self) -> Option<Duration> {
+        Some(self.timeout)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        // the timeout follows the keys
+        (1..=self.keys.len()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bzpopmin() {
+        let bzpopmin = BZPopMin::new(vec!["jobs:high", "jobs:low"], Duration::from_millis(1500));
+        let frame: Frame = bzpopmin
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create BZPOPMIN command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("BZPOPMIN"),
+                Frame::bulk("jobs:high"),
+                Frame::bulk("jobs:low"),
+                Frame::bulk("1.5"),
+            ])
+        );
+        assert_eq!(
+            bzpopmin.blocking_timeout(),
+            Some(Duration::from_millis(1500))
+        );
+    }
+
+    #[test]
+    fn test_bzpopmin_indefinitely() {
+        let bzpopmin = BZPopMin::new(vec!["jobs"], Duration::ZERO);
+        let frame: Frame = bzpopmin
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create BZPOPMIN command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("BZPOPMIN"),
+                Frame::bulk("jobs"),
+                Frame::bulk("0"),
+            ])
+        );
+    }
+}
@@ -0,0 +1,52 @@
+/// A Redis CLIENT GETNAME command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+#[derive(Default)]
+pub struct ClientGetName;
+
+impl ClientGetName {
+    /// Creates a new ClientGetName command.
+    ///
+    /// # Returns
+    ///
+    /// A new ClientGetName command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let getname = ClientGetName::new();
+    /// ```
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Command for ClientGetName {
+    const NAME: &'static str = "CLIENT GETNAME";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("CLIENT"))?;
+        frame.push_frame_to_array(Frame::bulk("GETNAME"))?;
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_client_getname() {
+        let getname = ClientGetName::new();
+        let frame: Frame = getname
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create CLIENT GETNAME command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("CLIENT"), Frame::bulk("GETNAME"),])
+        );
+    }
+}
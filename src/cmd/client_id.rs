@@ -0,0 +1,52 @@
+/// A Redis CLIENT ID command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+#[derive(Default)]
+pub struct ClientId;
+
+impl ClientId {
+    /// Creates a new ClientId command.
+    ///
+    /// # Returns
+    ///
+    /// A new ClientId command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let client_id = ClientId::new();
+    /// ```
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Command for ClientId {
+    const NAME: &'static str = "CLIENT ID";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("CLIENT"))?;
+        frame.push_frame_to_array(Frame::bulk("ID"))?;
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_client_client_id() {
+        let client_id = ClientId::new();
+        let frame: Frame = client_id
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create CLIENT ID command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("CLIENT"), Frame::bulk("ID"),])
+        );
+    }
+}
@@ -0,0 +1,196 @@
+/// A Redis CLIENT KILL command.
+use crate::{
+    RedisError, Result,
+    cmd::{ClientType, Command},
+    frame::Frame,
+};
+
+/// The clients to kill with CLIENT KILL, matching every given condition.
+///
+/// # Examples
+///
+/// ```ignore
+/// let filter = ClientKillFilter::new().client_type(ClientType::Normal).max_age(3600);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct ClientKillFilter {
+    id: Option<u64>,
+    addr: Option<String>,
+    laddr: Option<String>,
+    client_type: Option<ClientType>,
+    max_age: Option<u64>,
+}
+
+impl ClientKillFilter {
+    /// Creates a filter without any condition, at least one is required.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Kills the client with the given ID, as returned by CLIENT ID.
+    pub fn id(mut self, id: u64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Kills the client connected from the given address, as `ip:port`.
+    pub fn addr(mut self, addr: &str) -> Self {
+        self.addr = Some(addr.to_string());
+        self
+    }
+
+    /// Kills the clients connected to the given address of the server, since Redis 6.2.
+    pub fn laddr(mut self, laddr: &str) -> Self {
+        self.laddr = Some(laddr.to_string());
+        self
+    }
+
+    /// Kills the clients of the given type.
+    pub fn client_type(mut self, client_type: ClientType) -> Self {
+        self.client_type = Some(client_type);
+        self
+    }
+
+    /// Kills the clients connected for longer than the given seconds, since Redis 7.4.
+    pub fn max_age(mut self, max_age: u64) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+}
+
+pub struct ClientKill {
+    filter: ClientKillFilter,
+}
+
+impl ClientKill {
+    /// Creates a new ClientKill command.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - The clients to kill
+    ///
+    /// # Returns
+    ///
+    /// A new ClientKill command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let client_kill = ClientKill::new(ClientKillFilter::new().id(42));
+    /// ```
+    pub fn new(filter: ClientKillFilter) -> Self {
+        Self { filter }
+    }
+}
+
+impl Command for ClientKill {
+    const NAME: &'static str = "CLIENT KILL";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let filter = &self.filter;
+        // without a filter, the server would take the next argument for the legacy address form
+        if filter.id.is_none()
+            && filter.addr.is_none()
+            && filter.laddr.is_none()
+            && filter.client_type.is_none()
+            && filter.max_age.is_none()
+        {
+            return Err(RedisError::InvalidArgument(
+                "CLIENT KILL requires at least one filter".to_string(),
+            ));
+        }
+
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("CLIENT"))?;
+        frame.push_frame_to_array(Frame::bulk("KILL"))?;
+
+        if let Some(id) = filter.id {
+            frame.push_frame_to_array(Frame::bulk("ID"))?;
+            frame.push_frame_to_array(Frame::bulk(id.to_string()))?;
+        }
+
+        if let Some(addr) = &filter.addr {
+            frame.push_frame_to_array(Frame::bulk("ADDR"))?;
+            frame.push_frame_to_array(Frame::bulk(addr.clone()))?;
+        }
+
+        if let Some(laddr) = &filter.laddr {
+            frame.push_frame_to_array(Frame::bulk("LADDR"))?;
+            frame.push_frame_to_array(Frame::bulk(laddr.clone()))?;
+        }
+
+        if let Some(client_type) = filter.client_type {
+            frame.push_frame_to_array(Frame::bulk("TYPE"))?;
+            frame.push_frame_to_array(Frame::bulk(client_type.as_str()))?;
+        }
+
+        if let Some(max_age) = filter.max_age {
+            frame.push_frame_to_array(Frame::bulk("MAXAGE"))?;
+            frame.push_frame_to_array(Frame::bulk(max_age.to_string()))?;
+        }
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_client_kill() {
+        let client_kill = ClientKill::new(ClientKillFilter::new().id(42));
+        let frame: Frame = client_kill
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create CLIENT KILL command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("CLIENT"),
+                Frame::bulk("KILL"),
+                Frame::bulk("ID"),
+                Frame::bulk("42"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_client_kill_filters() {
+        let filter = ClientKillFilter::new()
+            .addr("127.0.0.1:52555")
+            .laddr("127.0.0.1:6379")
+            .client_type(ClientType::Normal)
+            .max_age(3600);
+        let client_kill = ClientKill::new(filter);
+        let frame: Frame = client_kill
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create CLIENT KILL command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("CLIENT"),
+                Frame::bulk("KILL"),
+                Frame::bulk("ADDR"),
+                Frame::bulk("127.0.0.1:52555"),
+                Frame::bulk("LADDR"),
+                Frame::bulk("127.0.0.1:6379"),
+                Frame::bulk("TYPE"),
+                Frame::bulk("normal"),
+                Frame::bulk("MAXAGE"),
+                Frame::bulk("3600"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_client_kill_without_filter() {
+        let client_kill = ClientKill::new(ClientKillFilter::new());
+
+        assert!(matches!(
+            client_kill.to_frame(),
+            Err(RedisError::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,330 @@
+/// A Redis CLIENT LIST command.
+use crate::{RedisError, Result, cmd::Command, frame::Frame};
+use std::collections::HashMap;
+use std::str::from_utf8;
+
+/// The kind of a client connection, to filter CLIENT LIST and CLIENT KILL with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClientType {
+    /// A regular client.
+    Normal,
+    /// The master of a replica, as seen from the replica.
+    Master,
+    /// A replica, as seen from its master.
+    Replica,
+    /// A client in pub/sub mode.
+    PubSub,
+}
+
+impl ClientType {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            ClientType::Normal => "normal",
+            ClientType::Master => "master",
+            ClientType::Replica => "replica",
+            ClientType::PubSub => "pubsub",
+        }
+    }
+}
+
+/// The clients to list with CLIENT LIST, every client by default.
+///
+/// # Examples
+///
+/// ```ignore
+/// let filter = ClientListFilter::new().client_type(ClientType::PubSub);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct ClientListFilter {
+    client_type: Option<ClientType>,
+    ids: Vec<u64>,
+}
+
+impl ClientListFilter {
+    /// Creates a filter matching every client.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lists only the clients of the given type.
+    pub fn client_type(mut self, client_type: ClientType) -> Self {
+        self.client_type = Some(client_type);
+        self
+    }
+
+    /// Lists only the clients with the given IDs, since Redis 6.2.
+    pub fn ids(mut self, ids: Vec<u64>) -> Self {
+        self.ids = ids;
+        self
+    }
+}
+
+/// A client connection, as described by a line of CLIENT LIST.
+///
+/// The fields differ between server versions, e.g. `laddr` appeared in Redis 6.2 and `resp` in
+/// Redis 7.0. The fields without a dedicated member are kept in `extra`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClientInfo {
+    /// The unique ID of the connection, as returned by CLIENT ID.
+    pub id: u64,
+    /// The address of the client, as `ip:port`.
+    pub addr: String,
+    /// The address of the server the client connected to, since Redis 6.2.
+    pub laddr: Option<String>,
+    /// The name set with CLIENT SETNAME, None when unnamed.
+    pub name: Option<String>,
+    /// The seconds since the connection was opened.
+    pub age: u64,
+    /// The seconds since the last command.
+    pub idle: u64,
+    /// The flags of the connection, one letter each, e.g. `N` for a regular client or `P` in
+    /// pub/sub mode.
+    pub flags: String,
+    /// The selected database.
+    pub db: u64,
+    /// The number of channel subscriptions.
+    pub sub: u64,
+    /// The number of pattern subscriptions.
+    pub psub: u64,
+    /// The number of commands queued in a transaction, -1 outside of MULTI.
+    pub multi: i64,
+    /// The last command run, e.g. `get` or `client|list` since Redis 7.0.
+    pub cmd: String,
+    /// The ACL user of the connection, since Redis 6.0.
+    pub user: Option<String>,
+    /// The RESP version of the connection, since Redis 7.0.
+    pub resp: Option<u8>,
+    /// The other fields, e.g. buffer sizes and memory usage.
+    pub extra: HashMap<String, String>,
+}
+
+impl TryFrom<&str> for ClientInfo {
+    type Error = RedisError;
+
+    /// Parses a line of CLIENT LIST, space separated `key=value` fields.
+    fn try_from(line: &str) -> Result<Self> {
+        let mut info = ClientInfo {
+            multi: -1,
+            ..Default::default()
+        };
+        let (mut id, mut addr) = (None, None);
+
+        for field in line.split_ascii_whitespace() {
+            let Some((key, value)) = field.split_once('=') else {
+                return Err(RedisError::UnexpectedResponseType);
+            };
+
+            match key {
+                "id" => id = Some(value.parse::<u64>()?),
+                "addr" => addr = Some(value.to_string()),
+                "laddr" => info.laddr = Some(value.to_string()),
+                "name" => info.name = Some(value.to_string()).filter(|name| !name.is_empty()),
+                "age" => info.age = value.parse::<u64>()?,
+                "idle" => info.idle = value.parse::<u64>()?,
+                "flags" => info.flags = value.to_string(),
+                "db" => info.db = value.parse::<u64>()?,
+                "sub" => info.sub = value.parse::<u64>()?,
+                "psub" => info.psub = value.parse::<u64>()?,
+                "multi" => info.multi = value.parse::<i64>()?,
+                "cmd" => info.cmd = value.to_string(),
+                "user" => info.user = Some(value.to_string()),
+                "resp" => info.resp = Some(value.parse::<u8>()?),
+                _ => {
+                    info.extra.insert(key.to_string(), value.to_string());
+                }
+            }
+        }
+
+        // every server version has them
+        info.id = id.ok_or(RedisError::UnexpectedResponseType)?;
+        info.addr = addr.ok_or(RedisError::UnexpectedResponseType)?;
+
+        Ok(info)
+    }
+}
+
+/// Parses the reply of CLIENT LIST, a line per client in a bulk string, or a verbatim string in
+/// RESP3.
+pub(crate) fn parse_client_list(frame: Frame) -> Result<Vec<ClientInfo>> {
+    let data = match frame {
+        Frame::BulkString(data) | Frame::VerbatimString(_, data) => data,
+        _ => return Err(RedisError::UnexpectedResponseType),
+    };
+
+    from_utf8(&data)?
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(ClientInfo::try_from)
+        .collect()
+}
+
+pub struct ClientList {
+    filter: ClientListFilter,
+}
+
+impl ClientList {
+    /// Creates a new ClientList command.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - The clients to list
+    ///
+    /// # Returns
+    ///
+    /// A new ClientList command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let client_list = ClientList::new(ClientListFilter::new().client_type(ClientType::Normal));
+    /// ```
+    pub fn new(filter: ClientListFilter) -> Self {
+        Self { filter }
+    }
+}
+
+impl Command for ClientList {
+    const NAME: &'static str = "CLIENT LIST";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("CLIENT"))?;
+        frame.push_frame_to_array(Frame::bulk("LIST"))?;
+
+        if let Some(client_type) = self.filter.client_type {
+            frame.push_frame_to_array(Frame::bulk("TYPE"))?;
+            frame.push_frame_to_array(Frame::bulk(client_type.as_str()))?;
+        }
+
+        if !self.filter.ids.is_empty() {
+            frame.push_frame_to_array(Frame::bulk("ID"))?;
+            for id in &self.filter.ids {
+                frame.push_frame_to_array(Frame::bulk(id.to_string()))?;
+            }
+        }
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+
+    // captured from Redis 6.0
+    const REDIS_6: &str = "id=3 addr=127.0.0.1:52555 fd=8 name= age=855 idle=0 flags=N db=0 \
+        sub=0 psub=0 multi=-1 qbuf=26 qbuf-free=32742 obl=0 oll=0 omem=0 events=r cmd=client \
+        user=default\n\
+        id=4 addr=127.0.0.1:52556 fd=9 name=worker age=12 idle=3 flags=P db=2 sub=2 psub=1 \
+        multi=-1 qbuf=0 qbuf-free=0 obl=0 oll=0 omem=0 events=r cmd=subscribe user=default\n";
+
+    // captured from Redis 7.2
+    const REDIS_7: &str = "id=5 addr=172.17.0.1:60182 laddr=172.17.0.2:6379 fd=9 name=ops \
+        age=41 idle=0 flags=N db=0 sub=0 psub=0 ssub=0 multi=-1 qbuf=26 qbuf-free=20448 \
+        argv-mem=10 multi-mem=0 rbs=1024 rbp=0 obl=0 oll=0 omem=0 tot-mem=22298 events=r \
+        cmd=client|list user=default redir=-1 resp=3 lib-name= lib-ver=\r\n";
+
+    #[test]
+    fn test_client_list() {
+        let client_list = ClientList::new(ClientListFilter::new());
+        let frame: Frame = client_list
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create CLIENT LIST command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("CLIENT"), Frame::bulk("LIST")])
+        );
+    }
+
+    #[test]
+    fn test_client_list_filter() {
+        let filter = ClientListFilter::new()
+            .client_type(ClientType::PubSub)
+            .ids(vec![3, 4]);
+        let client_list = ClientList::new(filter);
+        let frame: Frame = client_list
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create CLIENT LIST command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("CLIENT"),
+                Frame::bulk("LIST"),
+                Frame::bulk("TYPE"),
+                Frame::bulk("pubsub"),
+                Frame::bulk("ID"),
+                Frame::bulk("3"),
+                Frame::bulk("4"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_client_list_redis_6() {
+        let clients = parse_client_list(Frame::bulk(REDIS_6))
+            .unwrap_or_else(|err| panic!("Failed to parse CLIENT LIST: {:?}", err));
+        assert_eq!(clients.len(), 2);
+
+        let client = &clients[0];
+        assert_eq!(client.id, 3);
+        assert_eq!(client.addr, "127.0.0.1:52555");
+        assert_eq!(client.laddr, None);
+        assert_eq!(client.name, None);
+        assert_eq!((client.age, client.idle), (855, 0));
+        assert_eq!(client.flags, "N");
+        assert_eq!(client.multi, -1);
+        assert_eq!(client.cmd, "client");
+        assert_eq!(client.user.as_deref(), Some("default"));
+        assert_eq!(client.resp, None);
+        assert_eq!(client.extra.get("fd").map(String::as_str), Some("8"));
+        assert_eq!(
+            client.extra.get("qbuf-free").map(String::as_str),
+            Some("32742")
+        );
+
+        let client = &clients[1];
+        assert_eq!(client.name.as_deref(), Some("worker"));
+        assert_eq!((client.db, client.sub, client.psub), (2, 2, 1));
+        assert_eq!(client.flags, "P");
+    }
+
+    #[test]
+    fn test_parse_client_list_redis_7() {
+        // RESP3 replies with a verbatim string
+        let frame = Frame::VerbatimString(
+            Bytes::from_static(b"txt"),
+            Bytes::from_static(REDIS_7.as_bytes()),
+        );
+        let clients = parse_client_list(frame)
+            .unwrap_or_else(|err| panic!("Failed to parse CLIENT LIST: {:?}", err));
+        assert_eq!(clients.len(), 1);
+
+        let client = &clients[0];
+        assert_eq!(client.id, 5);
+        assert_eq!(client.laddr.as_deref(), Some("172.17.0.2:6379"));
+        assert_eq!(client.name.as_deref(), Some("ops"));
+        assert_eq!(client.cmd, "client|list");
+        assert_eq!(client.resp, Some(3));
+        assert_eq!(client.extra.get("ssub").map(String::as_str), Some("0"));
+        assert_eq!(client.extra.get("lib-name").map(String::as_str), Some(""));
+    }
+
+    #[test]
+    fn test_parse_client_list_malformed() {
+        // no id
+        assert!(ClientInfo::try_from("addr=127.0.0.1:52555 age=3").is_err());
+        // not a key=value field
+        assert!(ClientInfo::try_from("id=3 addr=127.0.0.1:52555 garbage").is_err());
+        assert!(ClientInfo::try_from("id=three addr=127.0.0.1:52555").is_err());
+        assert!(parse_client_list(Frame::Integer(3)).is_err());
+        assert_eq!(
+            parse_client_list(Frame::bulk(""))
+                .map(|clients| clients.len())
+                .ok(),
+            Some(0)
+        );
+    }
+}
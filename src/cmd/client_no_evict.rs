@@ -0,0 +1,64 @@
+/// A Redis CLIENT NO-EVICT command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+pub struct ClientNoEvict {
+    on: bool,
+}
+
+impl ClientNoEvict {
+    /// Creates a new ClientNoEvict command.
+    ///
+    /// # Arguments
+    ///
+    /// * `on` - Whether the connection is excluded from client eviction, when the memory used by the
+    ///   clients exceeds `maxmemory-clients`
+    ///
+    /// # Returns
+    ///
+    /// A new ClientNoEvict command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let no_evict = ClientNoEvict::new(true);
+    /// ```
+    pub fn new(on: bool) -> Self {
+        Self { on }
+    }
+}
+
+impl Command for ClientNoEvict {
+    const NAME: &'static str = "CLIENT NO-EVICT";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("CLIENT"))?;
+        frame.push_frame_to_array(Frame::bulk("NO-EVICT"))?;
+        frame.push_frame_to_array(Frame::bulk(if self.on { "ON" } else { "OFF" }))?;
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_client_no_evict() {
+        for (on, state) in [(true, "ON"), (false, "OFF")] {
+            let frame: Frame = ClientNoEvict::new(on).to_frame().unwrap_or_else(|err| {
+                panic!("Failed to create CLIENT NO-EVICT command: {:?}", err)
+            });
+
+            assert_eq!(
+                frame,
+                Frame::Array(vec![
+                    Frame::bulk("CLIENT"),
+                    Frame::bulk("NO-EVICT"),
+                    Frame::bulk(state),
+                ])
+            );
+        }
+    }
+}
@@ -0,0 +1,64 @@
+/// A Redis CLIENT NO-TOUCH command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+pub struct ClientNoTouch {
+    on: bool,
+}
+
+impl ClientNoTouch {
+    /// Creates a new ClientNoTouch command.
+    ///
+    /// # Arguments
+    ///
+    /// * `on` - Whether the commands of the connection leave the LRU and LFU stats of the keys as
+    ///   they are, except for TOUCH
+    ///
+    /// # Returns
+    ///
+    /// A new ClientNoTouch command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let no_touch = ClientNoTouch::new(true);
+    /// ```
+    pub fn new(on: bool) -> Self {
+        Self { on }
+    }
+}
+
+impl Command for ClientNoTouch {
+    const NAME: &'static str = "CLIENT NO-TOUCH";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("CLIENT"))?;
+        frame.push_frame_to_array(Frame::bulk("NO-TOUCH"))?;
+        frame.push_frame_to_array(Frame::bulk(if self.on { "ON" } else { "OFF" }))?;
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_client_no_touch() {
+        for (on, state) in [(true, "ON"), (false, "OFF")] {
+            let frame: Frame = ClientNoTouch::new(on).to_frame().unwrap_or_else(|err| {
+                panic!("Failed to create CLIENT NO-TOUCH command: {:?}", err)
+            });
+
+            assert_eq!(
+                frame,
+                Frame::Array(vec![
+                    Frame::bulk("CLIENT"),
+                    Frame::bulk("NO-TOUCH"),
+                    Frame::bulk(state),
+                ])
+            );
+        }
+    }
+}
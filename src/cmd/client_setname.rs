@@ -0,0 +1,90 @@
+/// A Redis CLIENT SETNAME command.
+use crate::{RedisError, Result, cmd::Command, frame::Frame};
+
+pub struct ClientSetName {
+    name: String,
+}
+
+impl ClientSetName {
+    /// Creates a new ClientSetName command.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name to assign to the current connection, an empty name removes it
+    ///
+    /// # Returns
+    ///
+    /// A new ClientSetName command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let setname = ClientSetName::new("worker-1");
+    /// ```
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+        }
+    }
+}
+
+/// Checks a connection name the same way the server does.
+///
+/// Redis rejects names containing spaces, newlines or other characters outside
+/// of the printable ASCII range, so we fail early instead of sending the command.
+pub(crate) fn validate_client_name(name: &str) -> Result<()> {
+    if name.bytes().all(|b| (b'!'..=b'~').contains(&b)) {
+        Ok(())
+    } else {
+        Err(RedisError::InvalidArgument(format!(
+            "client name {name:?} cannot contain spaces, newlines or special characters"
+        )))
+    }
+}
+
+impl Command for ClientSetName {
+    const NAME: &'static str = "CLIENT SETNAME";
+
+    fn to_frame(&self) -> Result<Frame> {
+        validate_client_name(&self.name)?;
+
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("CLIENT"))?;
+        frame.push_frame_to_array(Frame::bulk("SETNAME"))?;
+        frame.push_frame_to_array(Frame::bulk(self.name.clone()))?;
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_client_setname() {
+        let setname = ClientSetName::new("worker-1");
+        let frame: Frame = setname
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create CLIENT SETNAME command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("CLIENT"),
+                Frame::bulk("SETNAME"),
+                Frame::bulk("worker-1"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_client_setname_invalid_name() {
+        for name in ["my worker", "worker\n1", "worker\r\n"] {
+            let setname = ClientSetName::new(name);
+            let result: Result<Frame> = setname.to_frame();
+
+            assert!(matches!(result, Err(RedisError::InvalidArgument(_))));
+        }
+    }
+}
@@ -0,0 +1,63 @@
+/// A Redis CLIENT TRACKING command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+pub struct ClientTracking {
+    on: bool,
+}
+
+impl ClientTracking {
+    /// Creates a new ClientTracking command.
+    ///
+    /// # Arguments
+    ///
+    /// * `on` - Whether the server should track the keys read by the connection, and send an
+    ///   `invalidate` push frame once one of them is modified
+    ///
+    /// # Returns
+    ///
+    /// A new ClientTracking command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let tracking = ClientTracking::new(true);
+    /// ```
+    pub fn new(on: bool) -> Self {
+        Self { on }
+    }
+}
+
+impl Command for ClientTracking {
+    const NAME: &'static str = "CLIENT TRACKING";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("CLIENT"))?;
+        frame.push_frame_to_array(Frame::bulk("TRACKING"))?;
+        frame.push_frame_to_array(Frame::bulk(if self.on { "ON" } else { "OFF" }))?;
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_client_tracking() {
+        let tracking = ClientTracking::new(true);
+        let frame: Frame = tracking
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create CLIENT TRACKING command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("CLIENT"),
+                Frame::bulk("TRACKING"),
+                Frame::bulk("ON"),
+            ])
+        );
+    }
+}
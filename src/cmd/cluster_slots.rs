@@ -0,0 +1,52 @@
+/// A Redis CLUSTER SLOTS command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+#[derive(Default)]
+pub struct ClusterSlots;
+
+impl ClusterSlots {
+    /// Creates a new ClusterSlots command.
+    ///
+    /// # Returns
+    ///
+    /// A new ClusterSlots command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let slots = ClusterSlots::new();
+    /// ```
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Command for ClusterSlots {
+    const NAME: &'static str = "CLUSTER SLOTS";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("CLUSTER"))?;
+        frame.push_frame_to_array(Frame::bulk("SLOTS"))?;
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cluster_slots() {
+        let slots = ClusterSlots::new();
+        let frame: Frame = slots
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create CLUSTER SLOTS command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("CLUSTER"), Frame::bulk("SLOTS")])
+        );
+    }
+}
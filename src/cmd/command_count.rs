@@ -0,0 +1,52 @@
+/// A Redis COMMAND COUNT command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+#[derive(Default)]
+pub struct CommandCount;
+
+impl CommandCount {
+    /// Creates a new CommandCount command.
+    ///
+    /// # Returns
+    ///
+    /// A new CommandCount command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let command = CommandCount::new();
+    /// ```
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Command for CommandCount {
+    const NAME: &'static str = "COMMAND COUNT";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("COMMAND"))?;
+        frame.push_frame_to_array(Frame::bulk("COUNT"))?;
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_count() {
+        let command = CommandCount::new();
+        let frame: Frame = command
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create COMMAND COUNT command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("COMMAND"), Frame::bulk("COUNT")])
+        );
+    }
+}
@@ -0,0 +1,68 @@
+/// A Redis COMMAND INFO command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+pub struct CommandInfo {
+    names: Vec<String>,
+}
+
+impl CommandInfo {
+    /// Creates a new CommandInfo command.
+    ///
+    /// # Arguments
+    ///
+    /// * `names` - The names of the commands to describe, every command if empty
+    ///
+    /// # Returns
+    ///
+    /// A new CommandInfo command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let command = CommandInfo::new(vec!["get", "set"]);
+    /// ```
+    pub fn new(names: Vec<&str>) -> Self {
+        Self {
+            names: names.into_iter().map(str::to_string).collect(),
+        }
+    }
+}
+
+impl Command for CommandInfo {
+    const NAME: &'static str = "COMMAND INFO";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("COMMAND"))?;
+        frame.push_frame_to_array(Frame::bulk("INFO"))?;
+
+        for name in &self.names {
+            frame.push_frame_to_array(Frame::bulk(name.clone()))?;
+        }
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_info() {
+        let command = CommandInfo::new(vec!["get", "set"]);
+        let frame: Frame = command
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create COMMAND INFO command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("COMMAND"),
+                Frame::bulk("INFO"),
+                Frame::bulk("get"),
+                Frame::bulk("set"),
+            ])
+        );
+    }
+}
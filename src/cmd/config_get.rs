@@ -0,0 +1,64 @@
+/// A Redis CONFIG GET command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+pub struct ConfigGet {
+    parameter: String,
+}
+
+impl ConfigGet {
+    /// Creates a new ConfigGet command.
+    ///
+    /// # Arguments
+    ///
+    /// * `parameter` - The configuration parameter to read, glob-style patterns are allowed
+    ///
+    /// # Returns
+    ///
+    /// A new ConfigGet command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let config_get = ConfigGet::new("maxmemory*");
+    /// ```
+    pub fn new(parameter: &str) -> Self {
+        Self {
+            parameter: parameter.to_string(),
+        }
+    }
+}
+
+impl Command for ConfigGet {
+    const NAME: &'static str = "CONFIG GET";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("CONFIG"))?;
+        frame.push_frame_to_array(Frame::bulk("GET"))?;
+        frame.push_frame_to_array(Frame::bulk(self.parameter.clone()))?;
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_get() {
+        let config_get = ConfigGet::new("maxmemory");
+        let frame: Frame = config_get
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create CONFIG GET command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("CONFIG"),
+                Frame::bulk("GET"),
+                Frame::bulk("maxmemory"),
+            ])
+        );
+    }
+}
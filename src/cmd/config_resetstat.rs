@@ -0,0 +1,52 @@
+/// A Redis CONFIG RESETSTAT command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+#[derive(Default)]
+pub struct ConfigResetStat;
+
+impl ConfigResetStat {
+    /// Creates a new ConfigResetStat command.
+    ///
+    /// # Returns
+    ///
+    /// A new ConfigResetStat command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let resetstat = ConfigResetStat::new();
+    /// ```
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Command for ConfigResetStat {
+    const NAME: &'static str = "CONFIG RESETSTAT";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("CONFIG"))?;
+        frame.push_frame_to_array(Frame::bulk("RESETSTAT"))?;
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_resetstat() {
+        let resetstat = ConfigResetStat::new();
+        let frame: Frame = resetstat
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create CONFIG RESETSTAT command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("CONFIG"), Frame::bulk("RESETSTAT")])
+        );
+    }
+}
@@ -0,0 +1,52 @@
+/// A Redis CONFIG REWRITE command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+#[derive(Default)]
+pub struct ConfigRewrite;
+
+impl ConfigRewrite {
+    /// Creates a new ConfigRewrite command.
+    ///
+    /// # Returns
+    ///
+    /// A new ConfigRewrite command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let rewrite = ConfigRewrite::new();
+    /// ```
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Command for ConfigRewrite {
+    const NAME: &'static str = "CONFIG REWRITE";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("CONFIG"))?;
+        frame.push_frame_to_array(Frame::bulk("REWRITE"))?;
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_rewrite() {
+        let rewrite = ConfigRewrite::new();
+        let frame: Frame = rewrite
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create CONFIG REWRITE command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("CONFIG"), Frame::bulk("REWRITE")])
+        );
+    }
+}
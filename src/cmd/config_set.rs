@@ -0,0 +1,126 @@
+/// A Redis CONFIG SET command.
+use crate::{RedisError, Result, cmd::Command, frame::Frame};
+
+pub struct ConfigSet {
+    pairs: Vec<(String, String)>,
+}
+
+impl ConfigSet {
+    /// Creates a new ConfigSet command.
+    ///
+    /// # Arguments
+    ///
+    /// * `parameter` - The configuration parameter to change
+    /// * `value` - The new value of the parameter
+    ///
+    /// # Returns
+    ///
+    /// A new ConfigSet command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let config_set = ConfigSet::new("maxmemory-policy", "allkeys-lru");
+    /// ```
+    pub fn new(parameter: &str, value: &str) -> Self {
+        Self::many(vec![(parameter, value)])
+    }
+
+    /// Creates a new ConfigSet command changing several parameters at once, since Redis 7.0.
+    ///
+    /// # Arguments
+    ///
+    /// * `pairs` - The configuration parameters to change and their new values
+    ///
+    /// # Returns
+    ///
+    /// A new ConfigSet command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let config_set = ConfigSet::many(vec![("maxmemory", "100mb"), ("maxmemory-policy", "allkeys-lru")]);
+    /// ```
+    pub fn many(pairs: Vec<(&str, &str)>) -> Self {
+        Self {
+            pairs: pairs
+                .into_iter()
+                .map(|(parameter, value)| (parameter.to_string(), value.to_string()))
+                .collect(),
+        }
+    }
+}
+
+impl Command for ConfigSet {
+    const NAME: &'static str = "CONFIG SET";
+
+    fn to_frame(&self) -> Result<Frame> {
+        if self.pairs.is_empty() {
+            return Err(RedisError::InvalidArgument(
+                "CONFIG SET requires at least one parameter".to_string(),
+            ));
+        }
+
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("CONFIG"))?;
+        frame.push_frame_to_array(Frame::bulk("SET"))?;
+        for (parameter, value) in &self.pairs {
+            frame.push_frame_to_array(Frame::bulk(parameter.clone()))?;
+            frame.push_frame_to_array(Frame::bulk(value.clone()))?;
+        }
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_set() {
+        let config_set = ConfigSet::new("maxmemory-policy", "allkeys-lru");
+        let frame: Frame = config_set
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create CONFIG SET command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("CONFIG"),
+                Frame::bulk("SET"),
+                Frame::bulk("maxmemory-policy"),
+                Frame::bulk("allkeys-lru"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_config_set_many() {
+        let config_set = ConfigSet::many(vec![
+            ("maxmemory", "100mb"),
+            ("maxmemory-policy", "allkeys-lru"),
+        ]);
+        let frame: Frame = config_set
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create CONFIG SET command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("CONFIG"),
+                Frame::bulk("SET"),
+                Frame::bulk("maxmemory"),
+                Frame::bulk("100mb"),
+                Frame::bulk("maxmemory-policy"),
+                Frame::bulk("allkeys-lru"),
+            ])
+        );
+
+        let config_set = ConfigSet::many(vec![]);
+        assert!(matches!(
+            config_set.to_frame(),
+            Err(RedisError::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,77 @@
+/// A Redis DEBUG SLEEP command.
+use crate::{
+    Result,
+    cmd::{Command, format_double},
+    frame::Frame,
+};
+
+pub struct DebugSleep {
+    seconds: f64,
+}
+
+impl DebugSleep {
+    /// Creates a new DebugSleep command.
+    ///
+    /// # Arguments
+    ///
+    /// * `seconds` - How long the server sleeps before replying, fractions of a second are allowed
+    ///
+    /// # Returns
+    ///
+    /// A new DebugSleep command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let sleep = DebugSleep::new(0.5);
+    /// ```
+    pub fn new(seconds: f64) -> Self {
+        Self { seconds }
+    }
+}
+
+impl Command for DebugSleep {
+    const NAME: &'static str = "DEBUG SLEEP";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("DEBUG"))?;
+        frame.push_frame_to_array(Frame::bulk("SLEEP"))?;
+        frame.push_frame_to_array(Frame::bulk(format_double(self.seconds)?))?;
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RedisError;
+
+    #[test]
+    fn test_debug_sleep() {
+        let sleep = DebugSleep::new(0.5);
+        let frame: Frame = sleep
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create DEBUG SLEEP command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("DEBUG"),
+                Frame::bulk("SLEEP"),
+                Frame::bulk("0.5"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_debug_sleep_nan() {
+        let sleep = DebugSleep::new(f64::NAN);
+
+        assert!(matches!(
+            sleep.to_frame(),
+            Err(RedisError::InvalidArgument(_))
+        ));
+    }
+}
@@ -1,9 +1,13 @@
 /// A Redis DECR command.
-use crate::{Result, cmd::Command, frame::Frame};
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
 use bytes::Bytes;
 
 pub struct Decr {
-    key: String,
+    key: Bytes,
 }
 
 impl Decr {
@@ -22,25 +26,28 @@ impl Decr {
     /// ```ignore
     /// let decr = Decr::new("mykey");
     /// ```
-    pub fn new(key: &str) -> Self {
+    pub fn new(key: impl ToRedisArg) -> Self {
         Self {
-            key: key.to_string(),
+            key: key.to_redis_arg(),
         }
     }
 }
 
-impl Command for Decr {}
+impl Command for Decr {
+    const NAME: &'static str = "DECR";
+    const IDEMPOTENT: bool = false;
 
-impl TryInto<Frame> for Decr {
-    type Error = crate::RedisError;
-
-    fn try_into(self) -> Result<Frame> {
+    fn to_frame(&self) -> Result<Frame> {
         let mut frame: Frame = Frame::array();
-        frame.push_frame_to_array(Frame::BulkString("DECR".into()))?;
-        frame.push_frame_to_array(Frame::BulkString(Bytes::from(self.key)))?;
+        frame.push_frame_to_array(Frame::bulk("DECR"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
 
         Ok(frame)
     }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
 }
 
 #[cfg(test)]
@@ -51,15 +58,12 @@ mod tests {
     fn test_decr() {
         let decr = Decr::new("mykey");
         let frame: Frame = decr
-            .try_into()
+            .to_frame()
             .unwrap_or_else(|err| panic!("Failed to create DECR command: {:?}", err));
 
         assert_eq!(
             frame,
-            Frame::Array(vec![
-                Frame::BulkString("DECR".into()),
-                Frame::BulkString("mykey".into()),
-            ])
+            Frame::Array(vec![Frame::bulk("DECR"), Frame::bulk("mykey"),])
         )
     }
 }
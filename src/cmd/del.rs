@@ -1,9 +1,13 @@
 /// A Redis DEL command.
-use crate::{Result, cmd::Command, frame::Frame};
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
 use bytes::Bytes;
 
 pub struct Del {
-    keys: Vec<String>,
+    keys: Vec<Bytes>,
 }
 
 impl Del {
@@ -22,28 +26,30 @@ impl Del {
     /// ```ignore
     /// let del = Del::new(vec!["key1", "key2"]);
     /// ```
-    pub fn new(keys: Vec<&str>) -> Self {
+    pub fn new(keys: Vec<impl ToRedisArg>) -> Self {
         Self {
-            keys: keys.iter().map(|s| s.to_string()).collect(),
+            keys: keys.iter().map(ToRedisArg::to_redis_arg).collect(),
         }
     }
 }
 
-impl Command for Del {}
+impl Command for Del {
+    const NAME: &'static str = "DEL";
 
-impl TryInto<Frame> for Del {
-    type Error = crate::RedisError;
-
-    fn try_into(self) -> Result<Frame> {
+    fn to_frame(&self) -> Result<Frame> {
         let mut frame: Frame = Frame::array();
-        frame.push_frame_to_array(Frame::BulkString("DEL".into()))?;
+        frame.push_frame_to_array(Frame::bulk("DEL"))?;
 
-        for key in self.keys {
-            frame.push_frame_to_array(Frame::BulkString(Bytes::from(key)))?;
+        for key in &self.keys {
+            frame.push_frame_to_array(Frame::bulk(key.clone()))?;
         }
 
         Ok(frame)
     }
+
+    fn key_indices(&self) -> Vec<usize> {
+        (1..=self.keys.len()).collect()
+    }
 }
 
 #[cfg(test)]
@@ -54,15 +60,15 @@ mod tests {
     fn test_del() {
         let del = Del::new(vec!["key1", "key2"]);
         let frame: Frame = del
-            .try_into()
+            .to_frame()
             .unwrap_or_else(|err| panic!("Failed to create DEL command: {:?}", err));
 
         assert_eq!(
             frame,
             Frame::Array(vec![
-                Frame::BulkString("DEL".into()),
-                Frame::BulkString("key1".into()),
-                Frame::BulkString("key2".into()),
+                Frame::bulk("DEL"),
+                Frame::bulk("key1"),
+                Frame::bulk("key2"),
             ])
         )
     }
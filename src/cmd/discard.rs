@@ -0,0 +1,48 @@
+/// A Redis DISCARD command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+#[derive(Default)]
+pub struct Discard;
+
+impl Discard {
+    /// Creates a new Discard command.
+    ///
+    /// # Returns
+    ///
+    /// A new Discard command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let discard = Discard::new();
+    /// ```
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Command for Discard {
+    const NAME: &'static str = "DISCARD";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("DISCARD"))?;
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_discard() {
+        let discard = Discard::new();
+        let frame: Frame = discard
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create DISCARD command: {:?}", err));
+
+        assert_eq!(frame, Frame::Array(vec![Frame::bulk("DISCARD")]));
+    }
+}
@@ -0,0 +1,68 @@
+/// A Redis DUMP command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct Dump {
+    key: Bytes,
+}
+
+impl Dump {
+    /// Creates a new Dump command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to serialize
+    ///
+    /// # Returns
+    ///
+    /// A new Dump command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let dump = Dump::new("mykey");
+    /// ```
+    pub fn new(key: impl ToRedisArg) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+        }
+    }
+}
+
+impl Command for Dump {
+    const NAME: &'static str = "DUMP";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("DUMP"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dump() {
+        let dump = Dump::new("mykey");
+        let frame: Frame = dump
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create DUMP command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("DUMP"), Frame::bulk("mykey")])
+        );
+    }
+}
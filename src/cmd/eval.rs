@@ -0,0 +1,107 @@
+/// A Redis EVAL command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct Eval {
+    script: String,
+    keys: Vec<Bytes>,
+    args: Vec<Bytes>,
+}
+
+impl Eval {
+    /// Creates a new Eval command.
+    ///
+    /// # Arguments
+    ///
+    /// * `script` - The Lua source of the script
+    /// * `keys` - The keys the script accesses, as `KEYS` in the script
+    /// * `args` - The other arguments of the script, as `ARGV` in the script
+    ///
+    /// # Returns
+    ///
+    /// A new Eval command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let eval = Eval::new("return redis.call('GET', KEYS[1])", vec!["mykey"], Vec::<&str>::new());
+    /// ```
+    pub fn new(script: &str, keys: Vec<impl ToRedisArg>, args: Vec<impl ToRedisArg>) -> Self {
+        Self {
+            script: script.to_string(),
+            keys: keys.iter().map(ToRedisArg::to_redis_arg).collect(),
+            args: args.iter().map(ToRedisArg::to_redis_arg).collect(),
+        }
+    }
+}
+
+impl Command for Eval {
+    const NAME: &'static str = "EVAL";
+    // a script may write
+    const IDEMPOTENT: bool = false;
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("EVAL"))?;
+        frame.push_frame_to_array(Frame::bulk(self.script.clone()))?;
+        frame.push_frame_to_array(Frame::bulk(self.keys.len().to_string()))?;
+
+        for arg in self.keys.iter().chain(&self.args) {
+            frame.push_frame_to_array(Frame::bulk(arg.clone()))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        // the keys follow the script and their number
+        (3..3 + self.keys.len()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eval() {
+        let eval = Eval::new("return ARGV[1]", vec!["key1", "key2"], vec!["arg1"]);
+        let frame: Frame = eval
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create EVAL command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("EVAL"),
+                Frame::bulk("return ARGV[1]"),
+                Frame::bulk("2"),
+                Frame::bulk("key1"),
+                Frame::bulk("key2"),
+                Frame::bulk("arg1"),
+            ])
+        );
+        assert_eq!(eval.key_indices(), vec![3, 4]);
+    }
+
+    #[test]
+    fn test_eval_without_keys() {
+        let eval = Eval::new("return 1", Vec::<&str>::new(), Vec::<&str>::new());
+        let frame: Frame = eval
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create EVAL command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("EVAL"),
+                Frame::bulk("return 1"),
+                Frame::bulk("0"),
+            ])
+        );
+    }
+}
@@ -0,0 +1,93 @@
+/// A Redis EVALSHA command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct EvalSha {
+    sha: String,
+    keys: Vec<Bytes>,
+    args: Vec<Bytes>,
+}
+
+impl EvalSha {
+    /// Creates a new EvalSha command.
+    ///
+    /// # Arguments
+    ///
+    /// * `sha` - The SHA1 digest of a script the server has cached, in hex
+    /// * `keys` - The keys the script accesses, as `KEYS` in the script
+    /// * `args` - The other arguments of the script, as `ARGV` in the script
+    ///
+    /// # Returns
+    ///
+    /// A new EvalSha command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let evalsha = EvalSha::new("e0e1f9fabfc9d4800c877a703b823ac0578ff8db", vec!["mykey"], vec!["1"]);
+    /// ```
+    pub fn new(sha: &str, keys: Vec<impl ToRedisArg>, args: Vec<impl ToRedisArg>) -> Self {
+        Self {
+            sha: sha.to_string(),
+            keys: keys.iter().map(ToRedisArg::to_redis_arg).collect(),
+            args: args.iter().map(ToRedisArg::to_redis_arg).collect(),
+        }
+    }
+}
+
+impl Command for EvalSha {
+    const NAME: &'static str = "EVALSHA";
+    // a script may write
+    const IDEMPOTENT: bool = false;
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("EVALSHA"))?;
+        frame.push_frame_to_array(Frame::bulk(self.sha.clone()))?;
+        frame.push_frame_to_array(Frame::bulk(self.keys.len().to_string()))?;
+
+        for arg in self.keys.iter().chain(&self.args) {
+            frame.push_frame_to_array(Frame::bulk(arg.clone()))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        // the keys follow the script and their number
+        (3..3 + self.keys.len()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evalsha() {
+        let evalsha = EvalSha::new(
+            "e0e1f9fabfc9d4800c877a703b823ac0578ff8db",
+            vec!["key1"],
+            vec!["arg1", "arg2"],
+        );
+        let frame: Frame = evalsha
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create EVALSHA command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("EVALSHA"),
+                Frame::bulk("e0e1f9fabfc9d4800c877a703b823ac0578ff8db"),
+                Frame::bulk("1"),
+                Frame::bulk("key1"),
+                Frame::bulk("arg1"),
+                Frame::bulk("arg2"),
+            ])
+        );
+    }
+}
@@ -0,0 +1,49 @@
+/// A Redis EXEC command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+#[derive(Default)]
+pub struct Exec;
+
+impl Exec {
+    /// Creates a new Exec command.
+    ///
+    /// # Returns
+    ///
+    /// A new Exec command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let exec = Exec::new();
+    /// ```
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Command for Exec {
+    const NAME: &'static str = "EXEC";
+    const IDEMPOTENT: bool = false;
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("EXEC"))?;
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exec() {
+        let exec = Exec::new();
+        let frame: Frame = exec
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create EXEC command: {:?}", err));
+
+        assert_eq!(frame, Frame::Array(vec![Frame::bulk("EXEC")]));
+    }
+}
@@ -1,9 +1,13 @@
 /// A Redis EXISTS command.
-use crate::{Result, cmd::Command, frame::Frame};
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
 use bytes::Bytes;
 
 pub struct Exists {
-    keys: Vec<String>,
+    keys: Vec<Bytes>,
 }
 
 impl Exists {
@@ -22,28 +26,30 @@ impl Exists {
     /// ```ignore
     /// let exists = Exists::new(vec!["key1", "key2"]);
     /// ```
-    pub fn new(keys: Vec<&str>) -> Self {
+    pub fn new(keys: Vec<impl ToRedisArg>) -> Self {
         Self {
-            keys: keys.iter().map(|s| s.to_string()).collect(),
+            keys: keys.iter().map(ToRedisArg::to_redis_arg).collect(),
         }
     }
 }
 
-impl Command for Exists {}
+impl Command for Exists {
+    const NAME: &'static str = "EXISTS";
 
-impl TryInto<Frame> for Exists {
-    type Error = crate::RedisError;
-
-    fn try_into(self) -> Result<Frame> {
+    fn to_frame(&self) -> Result<Frame> {
         let mut frame: Frame = Frame::array();
-        frame.push_frame_to_array(Frame::BulkString("EXISTS".into()))?;
+        frame.push_frame_to_array(Frame::bulk("EXISTS"))?;
 
-        for key in self.keys {
-            frame.push_frame_to_array(Frame::BulkString(Bytes::from(key)))?;
+        for key in &self.keys {
+            frame.push_frame_to_array(Frame::bulk(key.clone()))?;
         }
 
         Ok(frame)
     }
+
+    fn key_indices(&self) -> Vec<usize> {
+        (1..=self.keys.len()).collect()
+    }
 }
 
 #[cfg(test)]
@@ -54,15 +60,15 @@ mod tests {
     fn test_exists() {
         let exists = Exists::new(vec!["key1", "key2"]);
         let frame: Frame = exists
-            .try_into()
+            .to_frame()
             .unwrap_or_else(|err| panic!("Failed to create EXISTS command: {:?}", err));
 
         assert_eq!(
             frame,
             Frame::Array(vec![
-                Frame::BulkString("EXISTS".into()),
-                Frame::BulkString("key1".into()),
-                Frame::BulkString("key2".into()),
+                Frame::bulk("EXISTS"),
+                Frame::bulk("key1"),
+                Frame::bulk("key2"),
             ])
         )
     }
@@ -1,9 +1,13 @@
 /// A Redis EXPIRE command.
-use crate::{Result, cmd::Command, frame::Frame};
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
 use bytes::Bytes;
 
 pub struct Expire {
-    key: String,
+    key: Bytes,
     seconds: i64,
 }
 
@@ -24,27 +28,29 @@ impl Expire {
     /// ```ignore
     /// let expire = Expire::new("mykey", 60);
     /// ```
-    pub fn new(key: &str, seconds: i64) -> Self {
+    pub fn new(key: impl ToRedisArg, seconds: i64) -> Self {
         Self {
-            key: key.to_string(),
+            key: key.to_redis_arg(),
             seconds,
         }
     }
 }
 
-impl Command for Expire {}
+impl Command for Expire {
+    const NAME: &'static str = "EXPIRE";
 
-impl TryInto<Frame> for Expire {
-    type Error = crate::RedisError;
-
-    fn try_into(self) -> Result<Frame> {
+    fn to_frame(&self) -> Result<Frame> {
         let mut frame: Frame = Frame::array();
-        frame.push_frame_to_array(Frame::BulkString("EXPIRE".into()))?;
-        frame.push_frame_to_array(Frame::BulkString(Bytes::from(self.key)))?;
-        frame.push_frame_to_array(Frame::BulkString(Bytes::from(self.seconds.to_string())))?;
+        frame.push_frame_to_array(Frame::bulk("EXPIRE"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+        frame.push_frame_to_array(Frame::bulk(self.seconds.to_string()))?;
 
         Ok(frame)
     }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
 }
 
 #[cfg(test)]
@@ -55,15 +61,15 @@ mod tests {
     fn test_expire() {
         let expire = Expire::new("mykey", 60);
         let frame: Frame = expire
-            .try_into()
+            .to_frame()
             .unwrap_or_else(|err| panic!("Failed to create EXPIRE command: {:?}", err));
 
         assert_eq!(
             frame,
             Frame::Array(vec![
-                Frame::BulkString("EXPIRE".into()),
-                Frame::BulkString("mykey".into()),
-                Frame::BulkString("60".into()),
+                Frame::bulk("EXPIRE"),
+                Frame::bulk("mykey"),
+                Frame::bulk("60"),
             ])
         )
     }
@@ -0,0 +1,68 @@
+/// A Redis EXPIRETIME command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct ExpireTime {
+    key: Bytes,
+}
+
+impl ExpireTime {
+    /// Creates a new EXPIRETIME command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to get the absolute expiration time for, in seconds
+    ///
+    /// # Returns
+    ///
+    /// A new EXPIRETIME command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let expiretime = ExpireTime::new("mykey");
+    /// ```
+    pub fn new(key: impl ToRedisArg) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+        }
+    }
+}
+
+impl Command for ExpireTime {
+    const NAME: &'static str = "EXPIRETIME";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("EXPIRETIME"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expiretime() {
+        let expiretime = ExpireTime::new("mykey");
+        let frame: Frame = expiretime
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create EXPIRETIME command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("EXPIRETIME"), Frame::bulk("mykey"),])
+        );
+    }
+}
@@ -1,9 +1,13 @@
 /// A Redis GET command.
-use crate::{Result, cmd::Command, frame::Frame};
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
 use bytes::Bytes;
 
 pub struct Get {
-    key: String,
+    key: Bytes,
 }
 
 impl Get {
@@ -22,25 +26,27 @@ impl Get {
     /// ```ignore
     /// let get = Get::new("mykey");
     /// ```
-    pub fn new(key: &str) -> Self {
+    pub fn new(key: impl ToRedisArg) -> Self {
         Self {
-            key: key.to_string(),
+            key: key.to_redis_arg(),
         }
     }
 }
 
-impl Command for Get {}
+impl Command for Get {
+    const NAME: &'static str = "GET";
 
-impl TryInto<Frame> for Get {
-    type Error = crate::RedisError;
-
-    fn try_into(self) -> Result<Frame> {
+    fn to_frame(&self) -> Result<Frame> {
         let mut frame: Frame = Frame::array();
-        frame.push_frame_to_array(Frame::BulkString("GET".into()))?;
-        frame.push_frame_to_array(Frame::BulkString(Bytes::from(self.key)))?;
+        frame.push_frame_to_array(Frame::bulk("GET"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
 
         Ok(frame)
     }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
 }
 
 #[cfg(test)]
@@ -51,15 +57,12 @@ mod tests {
     fn test_get() {
         let get = Get::new("mykey");
         let frame: Frame = get
-            .try_into()
+            .to_frame()
             .unwrap_or_else(|err| panic!("Failed to create GET command: {:?}", err));
 
         assert_eq!(
             frame,
-            Frame::Array(vec![
-                Frame::BulkString("GET".into()),
-                Frame::BulkString("mykey".into()),
-            ])
+            Frame::Array(vec![Frame::bulk("GET"), Frame::bulk("mykey"),])
         )
     }
 }
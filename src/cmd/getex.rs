@@ -1,19 +1,52 @@
 /// A Redis GETEX command.
-use crate::{Result, cmd::Command, frame::Frame};
+use crate::{
+    RedisError, Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
 use bytes::Bytes;
 
 #[derive(Debug)]
 pub enum Expiry {
+    /// Expire after the given number of seconds.
     EX(u64),
+    /// Expire after the given number of milliseconds.
     PX(u64),
+    /// Expire at the given unix time, in seconds.
     EXAT(u64),
+    /// Expire at the given unix time, in milliseconds.
     PXAT(u64),
+    /// Remove the expiry of the key.
     PERSIST,
 }
 
+impl Expiry {
+    /// Returns the option name and its argument, checking that the server accepts the value.
+    ///
+    /// The server rejects values that are not positive, and values that overflow a signed
+    /// 64-bit integer once converted to milliseconds.
+    pub(crate) fn to_args(&self) -> Result<(&'static str, Option<u64>)> {
+        let (option, value, max) = match self {
+            Expiry::EX(seconds) => ("EX", *seconds, i64::MAX as u64 / 1000),
+            Expiry::PX(milliseconds) => ("PX", *milliseconds, i64::MAX as u64),
+            Expiry::EXAT(timestamp) => ("EXAT", *timestamp, i64::MAX as u64 / 1000),
+            Expiry::PXAT(timestamp) => ("PXAT", *timestamp, i64::MAX as u64),
+            Expiry::PERSIST => return Ok(("PERSIST", None)),
+        };
+
+        if value == 0 || value > max {
+            return Err(RedisError::InvalidArgument(format!(
+                "{option} {value} is out of range, expected 1 to {max}"
+            )));
+        }
+
+        Ok((option, Some(value)))
+    }
+}
+
 #[derive(Debug)]
 pub struct GetEx {
-    key: String,
+    key: Bytes,
     expiry: Option<Expiry>,
 }
 
@@ -34,50 +67,38 @@ impl GetEx {
     /// ```ignore
     /// let getex = GetEx::new("mykey");
     /// ```
-    pub fn new(key: &str, expiry: Option<Expiry>) -> Self {
+    pub fn new(key: impl ToRedisArg, expiry: Option<Expiry>) -> Self {
         Self {
-            key: key.to_string(),
+            key: key.to_redis_arg(),
             expiry,
         }
     }
 }
 
-impl Command for GetEx {}
-
-impl TryInto<Frame> for GetEx {
-    type Error = crate::RedisError;
+impl Command for GetEx {
+    const NAME: &'static str = "GETEX";
 
-    fn try_into(self) -> Result<Frame> {
+    fn to_frame(&self) -> Result<Frame> {
         let mut frame: Frame = Frame::array();
-        frame.push_frame_to_array(Frame::BulkString("GETEX".into()))?;
-        frame.push_frame_to_array(Frame::BulkString(Bytes::from(self.key)))?;
-
-        if let Some(expiry) = self.expiry {
-            match expiry {
-                Expiry::EX(seconds) => {
-                    frame.push_frame_to_array(Frame::BulkString("EX".into()))?;
-                    frame.push_frame_to_array(Frame::Integer(seconds as i64))?;
-                }
-                Expiry::PX(milliseconds) => {
-                    frame.push_frame_to_array(Frame::BulkString("PX".into()))?;
-                    frame.push_frame_to_array(Frame::Integer(milliseconds as i64))?;
-                }
-                Expiry::EXAT(timestamp) => {
-                    frame.push_frame_to_array(Frame::BulkString("EXAT".into()))?;
-                    frame.push_frame_to_array(Frame::Integer(timestamp as i64))?;
-                }
-                Expiry::PXAT(timestamp) => {
-                    frame.push_frame_to_array(Frame::BulkString("PXAT".into()))?;
-                    frame.push_frame_to_array(Frame::Integer(timestamp as i64))?;
-                }
-                Expiry::PERSIST => {
-                    frame.push_frame_to_array(Frame::BulkString("PERSIST".into()))?;
-                }
+        frame.push_frame_to_array(Frame::bulk("GETEX"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+
+        if let Some(expiry) = &self.expiry {
+            let (option, value) = expiry.to_args()?;
+            frame.push_frame_to_array(Frame::bulk(option))?;
+
+            // the server only accepts bulk strings as command arguments
+            if let Some(value) = value {
+                frame.push_frame_to_array(Frame::bulk(value.to_string()))?;
             }
         }
 
         Ok(frame)
     }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
 }
 
 #[cfg(test)]
@@ -88,15 +109,57 @@ mod tests {
     fn test_get() {
         let getex = GetEx::new("mykey", None);
         let frame: Frame = getex
-            .try_into()
+            .to_frame()
             .unwrap_or_else(|err| panic!("Failed to create GETEX command: {:?}", err));
 
         assert_eq!(
             frame,
-            Frame::Array(vec![
-                Frame::BulkString("GETEX".into()),
-                Frame::BulkString("mykey".into()),
-            ])
+            Frame::Array(vec![Frame::bulk("GETEX"), Frame::bulk("mykey"),])
         )
     }
+
+    #[test]
+    fn test_getex_expiry() {
+        let cases = vec![
+            (Expiry::EX(100), vec!["EX", "100"]),
+            (Expiry::PX(1500), vec!["PX", "1500"]),
+            (Expiry::EXAT(1_893_456_000), vec!["EXAT", "1893456000"]),
+            (
+                Expiry::PXAT(1_893_456_000_000),
+                vec!["PXAT", "1893456000000"],
+            ),
+            (Expiry::PERSIST, vec!["PERSIST"]),
+        ];
+
+        for (expiry, args) in cases {
+            let frame: Frame = GetEx::new("mykey", Some(expiry))
+                .to_frame()
+                .unwrap_or_else(|err| panic!("Failed to create GETEX command: {:?}", err));
+
+            let mut expected = vec![Frame::bulk("GETEX"), Frame::bulk("mykey")];
+            expected.extend(args.into_iter().map(Frame::bulk));
+
+            assert_eq!(frame, Frame::Array(expected));
+        }
+    }
+
+    #[test]
+    fn test_getex_expiry_out_of_range() {
+        for expiry in [
+            Expiry::EX(0),
+            Expiry::EX(i64::MAX as u64 / 1000 + 1),
+            Expiry::PX(i64::MAX as u64 + 1),
+            Expiry::EXAT(u64::MAX),
+            Expiry::PXAT(0),
+        ] {
+            let result = GetEx::new("mykey", Some(expiry)).to_frame();
+            assert!(matches!(result, Err(RedisError::InvalidArgument(_))));
+        }
+
+        assert!(
+            GetEx::new("mykey", Some(Expiry::PX(i64::MAX as u64)))
+                .to_frame()
+                .is_ok()
+        );
+    }
 }
@@ -1,8 +1,11 @@
 /// A Redis HELLO command.
-use crate::{Result, cmd::Command, frame::Frame};
+use crate::{RedisError, Result, cmd::Command, cmd::validate_client_name, frame::Frame};
+use std::str::from_utf8;
 
 pub struct Hello {
     proto: Option<u8>,
+    auth: Option<(String, String)>,
+    setname: Option<String>,
 }
 
 impl Hello {
@@ -10,64 +13,310 @@ impl Hello {
     ///
     /// # Arguments
     ///
-    /// * `msg` - An optional message to send with ping
+    /// * `proto` - An optional protocol version to switch to
     ///
     /// # Returns
     ///
-    /// A new Ping command
+    /// A new Hello command
     ///
     /// # Examples
     ///
     /// ```ignore
-    /// let hello = Hello::new(Some("hello".into()));
+    /// let hello = Hello::new(Some(3));
     /// ```
     pub fn new(proto: Option<u8>) -> Self {
-        Self { proto }
+        Self {
+            proto,
+            auth: None,
+            setname: None,
+        }
     }
-}
 
-impl Command for Hello {}
+    /// Authenticates the connection as part of the handshake (the AUTH clause).
+    pub fn auth(mut self, username: &str, password: &str) -> Self {
+        self.auth = Some((username.to_string(), password.to_string()));
+        self
+    }
 
-impl TryInto<Frame> for Hello {
-    type Error = crate::RedisError;
+    /// Names the connection as part of the handshake (the SETNAME clause).
+    pub fn setname(mut self, name: &str) -> Self {
+        self.setname = Some(name.to_string());
+        self
+    }
+}
+
+impl Command for Hello {
+    const NAME: &'static str = "HELLO";
 
-    fn try_into(self) -> Result<Frame> {
+    fn to_frame(&self) -> Result<Frame> {
         let mut frame: Frame = Frame::array();
-        frame.push_frame_to_array(Frame::BulkString("HELLO".into()))?;
+        frame.push_frame_to_array(Frame::bulk("HELLO"))?;
+
+        // do not push the protocol version if it is None
+        let Some(proto) = self.proto else {
+            // AUTH and SETNAME are only valid after a protocol version
+            if self.auth.is_some() || self.setname.is_some() {
+                return Err(RedisError::InvalidArgument(
+                    "HELLO AUTH and SETNAME require a protocol version".to_string(),
+                ));
+            }
+
+            return Ok(frame);
+        };
 
-        // do not push the message if it is None
-        if let Some(proto) = self.proto {
-            frame.push_frame_to_array(Frame::BulkString(proto.to_string().into()))?;
+        frame.push_frame_to_array(Frame::bulk(proto.to_string()))?;
+
+        if let Some((username, password)) = &self.auth {
+            frame.push_frame_to_array(Frame::bulk("AUTH"))?;
+            frame.push_frame_to_array(Frame::bulk(username.clone()))?;
+            frame.push_frame_to_array(Frame::bulk(password.clone()))?;
+        }
+
+        if let Some(name) = &self.setname {
+            validate_client_name(name)?;
+
+            frame.push_frame_to_array(Frame::bulk("SETNAME"))?;
+            frame.push_frame_to_array(Frame::bulk(name.clone()))?;
         }
 
         Ok(frame)
     }
 }
 
+/// The server properties returned by the HELLO command.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HelloResponse {
+    pub server: String,
+    pub version: String,
+    /// The negotiated RESP protocol version.
+    pub proto: u8,
+    /// The ID of the connection, as shown in CLIENT LIST.
+    pub id: i64,
+    pub mode: String,
+    pub role: String,
+    /// The names of the loaded modules.
+    pub modules: Vec<String>,
+}
+
+impl TryFrom<Frame> for HelloResponse {
+    type Error = crate::RedisError;
+
+    /// Parses the reply of HELLO, a flat array of key/value pairs in RESP2 or a map in RESP3.
+    fn try_from(frame: Frame) -> Result<Self> {
+        // RESP2 replies with a flat array of alternating properties and values
+        let properties = Frame::Map(into_pairs(frame)?);
+        // newer servers may add more properties, and older ones lack some
+        let string = |name: &str| match properties.get(name) {
+            Some(value) => frame_to_string(value.clone()),
+            None => Ok(String::new()),
+        };
+
+        let proto = match properties.get("proto") {
+            Some(value) => u8::try_from(frame_to_integer(value.clone())?)?,
+            None => return Err(RedisError::UnexpectedResponseType),
+        };
+        let id = match properties.get("id") {
+            Some(value) => frame_to_integer(value.clone())?,
+            None => 0,
+        };
+
+        let mut modules = Vec::new();
+        match properties.get("modules") {
+            // each module is a list of properties, we only keep the name
+            Some(Frame::Array(list)) => {
+                for module in list {
+                    let module = Frame::Map(into_pairs(module.clone())?);
+                    if let Some(name) = module.get("name") {
+                        modules.push(frame_to_string(name.clone())?);
+                    }
+                }
+            }
+            Some(_) => return Err(RedisError::UnexpectedResponseType),
+            None => {}
+        }
+
+        Ok(HelloResponse {
+            server: string("server")?,
+            version: string("version")?,
+            proto,
+            id,
+            mode: string("mode")?,
+            role: string("role")?,
+            modules,
+        })
+    }
+}
+
+/// Turns a RESP3 map or a RESP2 flat array of alternating keys and values into pairs.
+pub(crate) fn into_pairs(frame: Frame) -> Result<Vec<(Frame, Frame)>> {
+    match frame {
+        Frame::Map(pairs) => Ok(pairs),
+        Frame::Array(items) => {
+            if items.len() % 2 != 0 {
+                return Err(RedisError::UnexpectedResponseType);
+            }
+
+            let mut pairs = Vec::with_capacity(items.len() / 2);
+            let mut items = items.into_iter();
+            while let (Some(key), Some(value)) = (items.next(), items.next()) {
+                pairs.push((key, value));
+            }
+
+            Ok(pairs)
+        }
+        _ => Err(RedisError::UnexpectedResponseType),
+    }
+}
+
+pub(crate) fn frame_to_string(frame: Frame) -> Result<String> {
+    match frame {
+        Frame::SimpleString(data) => Ok(data),
+        Frame::BulkString(data) | Frame::VerbatimString(_, data) => {
+            Ok(from_utf8(&data)?.to_string())
+        }
+        Frame::Integer(data) => Ok(data.to_string()),
+        _ => Err(RedisError::UnexpectedResponseType),
+    }
+}
+
+fn frame_to_integer(frame: Frame) -> Result<i64> {
+    match frame {
+        Frame::Integer(data) => Ok(data),
+        Frame::BulkString(data) => Ok(from_utf8(&data)?.parse::<i64>()?),
+        _ => Err(RedisError::UnexpectedResponseType),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::io::Cursor;
 
     #[test]
     fn test_hello() {
         let hello = Hello::new(None);
         let frame: Frame = hello
-            .try_into()
+            .to_frame()
             .unwrap_or_else(|err| panic!("Failed to create HELLO command: {:?}", err));
 
-        assert_eq!(frame, Frame::Array(vec![Frame::BulkString("HELLO".into())]));
+        assert_eq!(frame, Frame::Array(vec![Frame::bulk("HELLO")]));
 
         let hello = Hello::new(Some(3));
         let frame: Frame = hello
-            .try_into()
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create HELLO command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("HELLO"), Frame::bulk(3.to_string()),])
+        );
+    }
+
+    #[test]
+    fn test_hello_auth_setname() {
+        let hello = Hello::new(Some(3))
+            .auth("default", "secret")
+            .setname("worker-1");
+        let frame: Frame = hello
+            .to_frame()
             .unwrap_or_else(|err| panic!("Failed to create HELLO command: {:?}", err));
 
         assert_eq!(
             frame,
             Frame::Array(vec![
-                Frame::BulkString("HELLO".into()),
-                Frame::BulkString(3.to_string().into()),
+                Frame::bulk("HELLO"),
+                Frame::bulk("3"),
+                Frame::bulk("AUTH"),
+                Frame::bulk("default"),
+                Frame::bulk("secret"),
+                Frame::bulk("SETNAME"),
+                Frame::bulk("worker-1"),
             ])
         );
+
+        // AUTH without a protocol version is a syntax error on the server
+        let result: Result<Frame> = Hello::new(None).auth("default", "secret").to_frame();
+        assert!(matches!(result, Err(RedisError::InvalidArgument(_))));
+
+        let result: Result<Frame> = Hello::new(Some(2)).setname("bad name").to_frame();
+        assert!(matches!(result, Err(RedisError::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn test_hello_response_resp2() {
+        let bytes: &[u8] =
+            b"*14\r\n$6\r\nserver\r\n$5\r\nredis\r\n$7\r\nversion\r\n$5\r\n7.2.4\r\n\
+            $5\r\nproto\r\n:2\r\n$2\r\nid\r\n:5\r\n$4\r\nmode\r\n$10\r\nstandalone\r\n\
+            $4\r\nrole\r\n$6\r\nmaster\r\n$7\r\nmodules\r\n*0\r\n";
+        let frame = Frame::try_parse(&mut Cursor::new(bytes))
+            .unwrap_or_else(|err| panic!("Failed to parse HELLO reply: {:?}", err));
+
+        let response = HelloResponse::try_from(frame)
+            .unwrap_or_else(|err| panic!("Failed to parse HELLO response: {:?}", err));
+
+        assert_eq!(
+            response,
+            HelloResponse {
+                server: "redis".to_string(),
+                version: "7.2.4".to_string(),
+                proto: 2,
+                id: 5,
+                mode: "standalone".to_string(),
+                role: "master".to_string(),
+                modules: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn test_hello_response_resp3() {
+        let bytes: &[u8] = b"%7\r\n$6\r\nserver\r\n$5\r\nredis\r\n$7\r\nversion\r\n$5\r\n7.2.4\r\n\
+            $5\r\nproto\r\n:3\r\n$2\r\nid\r\n:8\r\n$4\r\nmode\r\n$10\r\nstandalone\r\n\
+            $4\r\nrole\r\n$6\r\nmaster\r\n$7\r\nmodules\r\n\
+            *1\r\n%4\r\n$4\r\nname\r\n$6\r\nsearch\r\n$3\r\nver\r\n:20810\r\n\
+            $4\r\npath\r\n$11\r\n/redisearch\r\n$4\r\nargs\r\n*0\r\n";
+        let frame = Frame::try_parse(&mut Cursor::new(bytes))
+            .unwrap_or_else(|err| panic!("Failed to parse HELLO reply: {:?}", err));
+
+        let response = HelloResponse::try_from(frame)
+            .unwrap_or_else(|err| panic!("Failed to parse HELLO response: {:?}", err));
+
+        assert_eq!(
+            response,
+            HelloResponse {
+                server: "redis".to_string(),
+                version: "7.2.4".to_string(),
+                proto: 3,
+                id: 8,
+                mode: "standalone".to_string(),
+                role: "master".to_string(),
+                modules: vec!["search".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn test_hello_response_missing_properties() {
+        // an older server without mode, role nor modules
+        let frame = Frame::Map(vec![
+            (Frame::bulk("server"), Frame::bulk("redis")),
+            (Frame::bulk("proto"), Frame::Integer(3)),
+        ]);
+        let response = HelloResponse::try_from(frame)
+            .unwrap_or_else(|err| panic!("Failed to parse HELLO response: {:?}", err));
+        assert_eq!(
+            response,
+            HelloResponse {
+                server: "redis".to_string(),
+                proto: 3,
+                ..HelloResponse::default()
+            }
+        );
+
+        let frame = Frame::Map(vec![(Frame::bulk("server"), Frame::bulk("redis"))]);
+        assert!(matches!(
+            HelloResponse::try_from(frame),
+            Err(RedisError::UnexpectedResponseType)
+        ));
     }
 }
@@ -0,0 +1,76 @@
+/// A Redis HEXISTS command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct HExists {
+    key: Bytes,
+    field: String,
+}
+
+impl HExists {
+    /// Creates a new HExists command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The hash holding the field
+    /// * `field` - The field to check
+    ///
+    /// # Returns
+    ///
+    /// A new HExists command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let hexists = HExists::new("myhash", "field");
+    /// ```
+    pub fn new(key: impl ToRedisArg, field: &str) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+            field: field.to_string(),
+        }
+    }
+}
+
+impl Command for HExists {
+    const NAME: &'static str = "HEXISTS";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("HEXISTS"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+        frame.push_frame_to_array(Frame::bulk(self.field.clone()))?;
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hexists() {
+        let hexists = HExists::new("myhash", "field");
+        let frame: Frame = hexists
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create HEXISTS command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("HEXISTS"),
+                Frame::bulk("myhash"),
+                Frame::bulk("field"),
+            ])
+        );
+    }
+}
@@ -0,0 +1,76 @@
+/// A Redis HGET command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct HGet {
+    key: Bytes,
+    field: String,
+}
+
+impl HGet {
+    /// Creates a new HGet command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The hash holding the field
+    /// * `field` - The field to get the value of
+    ///
+    /// # Returns
+    ///
+    /// A new HGet command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let hget = HGet::new("myhash", "field");
+    /// ```
+    pub fn new(key: impl ToRedisArg, field: &str) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+            field: field.to_string(),
+        }
+    }
+}
+
+impl Command for HGet {
+    const NAME: &'static str = "HGET";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("HGET"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+        frame.push_frame_to_array(Frame::bulk(self.field.clone()))?;
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hget() {
+        let hget = HGet::new("myhash", "field");
+        let frame: Frame = hget
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create HGET command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("HGET"),
+                Frame::bulk("myhash"),
+                Frame::bulk("field"),
+            ])
+        );
+    }
+}
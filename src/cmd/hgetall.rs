@@ -0,0 +1,68 @@
+/// A Redis HGETALL command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct HGetAll {
+    key: Bytes,
+}
+
+impl HGetAll {
+    /// Creates a new HGETALL command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The hash to get the fields and values of
+    ///
+    /// # Returns
+    ///
+    /// A new HGETALL command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let hgetall = HGetAll::new("myhash");
+    /// ```
+    pub fn new(key: impl ToRedisArg) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+        }
+    }
+}
+
+impl Command for HGetAll {
+    const NAME: &'static str = "HGETALL";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("HGETALL"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hgetall() {
+        let hgetall = HGetAll::new("myhash");
+        let frame: Frame = hgetall
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create HGETALL command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("HGETALL"), Frame::bulk("myhash"),])
+        );
+    }
+}
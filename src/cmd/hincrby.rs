@@ -0,0 +1,82 @@
+/// A Redis HINCRBY command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct HIncrBy {
+    key: Bytes,
+    field: String,
+    increment: i64,
+}
+
+impl HIncrBy {
+    /// Creates a new HIncrBy command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The hash holding the field
+    /// * `field` - The field to increment, created with the value 0 first if missing
+    /// * `increment` - The amount to add, negative to decrement
+    ///
+    /// # Returns
+    ///
+    /// A new HIncrBy command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let hincrby = HIncrBy::new("myhash", "visits", 5);
+    /// ```
+    pub fn new(key: impl ToRedisArg, field: &str, increment: i64) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+            field: field.to_string(),
+            increment,
+        }
+    }
+}
+
+impl Command for HIncrBy {
+    const NAME: &'static str = "HINCRBY";
+    const IDEMPOTENT: bool = false;
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("HINCRBY"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+        frame.push_frame_to_array(Frame::bulk(self.field.clone()))?;
+        frame.push_frame_to_array(Frame::bulk(self.increment.to_string()))?;
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hincrby() {
+        let hincrby = HIncrBy::new("myhash", "visits", -5);
+        let frame: Frame = hincrby
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create HINCRBY command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("HINCRBY"),
+                Frame::bulk("myhash"),
+                Frame::bulk("visits"),
+                Frame::bulk("-5"),
+            ])
+        );
+    }
+}
@@ -0,0 +1,94 @@
+/// A Redis HINCRBYFLOAT command.
+use crate::{
+    Result,
+    cmd::format_double,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct HIncrByFloat {
+    key: Bytes,
+    field: String,
+    increment: f64,
+}
+
+impl HIncrByFloat {
+    /// Creates a new HIncrByFloat command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The hash holding the field
+    /// * `field` - The field to increment, created with the value 0 first if missing
+    /// * `increment` - The amount to add, negative to decrement
+    ///
+    /// # Returns
+    ///
+    /// A new HIncrByFloat command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let hincrbyfloat = HIncrByFloat::new("myhash", "balance", 0.1);
+    /// ```
+    pub fn new(key: impl ToRedisArg, field: &str, increment: f64) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+            field: field.to_string(),
+            increment,
+        }
+    }
+}
+
+impl Command for HIncrByFloat {
+    const NAME: &'static str = "HINCRBYFLOAT";
+    const IDEMPOTENT: bool = false;
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("HINCRBYFLOAT"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+        frame.push_frame_to_array(Frame::bulk(self.field.clone()))?;
+        frame.push_frame_to_array(Frame::bulk(format_double(self.increment)?))?;
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RedisError;
+
+    #[test]
+    fn test_hincrbyfloat() {
+        let hincrbyfloat = HIncrByFloat::new("myhash", "balance", -0.1);
+        let frame: Frame = hincrbyfloat
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create HINCRBYFLOAT command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("HINCRBYFLOAT"),
+                Frame::bulk("myhash"),
+                Frame::bulk("balance"),
+                Frame::bulk("-0.1"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_hincrbyfloat_nan() {
+        let hincrbyfloat = HIncrByFloat::new("myhash", "balance", f64::NAN);
+
+        assert!(matches!(
+            hincrbyfloat.to_frame(),
+            Err(RedisError::InvalidArgument(_))
+        ));
+    }
+}
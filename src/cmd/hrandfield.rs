@@ -0,0 +1,159 @@
+/// A Redis HRANDFIELD command.
+use crate::{
+    RedisError, Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct HRandField {
+    key: Bytes,
+    count: Option<i64>,
+    with_values: bool,
+}
+
+impl HRandField {
+    /// Creates a new HRandField command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The hash to pick the fields from
+    /// * `count` - The number of fields to pick, negative to allow picking a field more than once
+    /// * `with_values` - Whether to return the values along with the fields, requires a count
+    ///
+    /// # Returns
+    ///
+    /// A new HRandField command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let hrandfield = HRandField::new("myhash", Some(-5), true);
+    /// ```
+    pub fn new(key: impl ToRedisArg, count: Option<i64>, with_values: bool) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+            count,
+            with_values,
+        }
+    }
+}
+
+impl Command for HRandField {
+    const NAME: &'static str = "HRANDFIELD";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("HRANDFIELD"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+
+        match self.count {
+            Some(count) => frame.push_frame_to_array(Frame::bulk(count.to_string()))?,
+            None if self.with_values => {
+                return Err(RedisError::InvalidArgument(
+                    "WITHVALUES requires a count".to_string(),
+                ));
+            }
+            None => {}
+        }
+
+        if self.with_values {
+            frame.push_frame_to_array(Frame::bulk("WITHVALUES"))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hrandfield() {
+        let hrandfield = HRandField::new("myhash", None, false);
+        let frame: Frame = hrandfield
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create HRANDFIELD command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("HRANDFIELD"), Frame::bulk("myhash")])
+        );
+    }
+
+    #[test]
+    fn test_hrandfield_count() {
+        let hrandfield = HRandField::new("myhash", Some(-5), false);
+        let frame: Frame = hrandfield
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create HRANDFIELD command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("HRANDFIELD"),
+                Frame::bulk("myhash"),
+                Frame::bulk("-5"),
+            ])
+        );
+
+        let hrandfield = HRandField::new("myhash", Some(3), false);
+        let frame: Frame = hrandfield
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create HRANDFIELD command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("HRANDFIELD"),
+                Frame::bulk("myhash"),
+                Frame::bulk("3"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_hrandfield_with_values() {
+        let hrandfield = HRandField::new("myhash", Some(3), true);
+        let frame: Frame = hrandfield
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create HRANDFIELD command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("HRANDFIELD"),
+                Frame::bulk("myhash"),
+                Frame::bulk("3"),
+                Frame::bulk("WITHVALUES"),
+            ])
+        );
+
+        // a negative count allows repeats along with the values
+        let hrandfield = HRandField::new("myhash", Some(-2), true);
+        let frame: Frame = hrandfield
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create HRANDFIELD command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("HRANDFIELD"),
+                Frame::bulk("myhash"),
+                Frame::bulk("-2"),
+                Frame::bulk("WITHVALUES"),
+            ])
+        );
+
+        let hrandfield = HRandField::new("myhash", None, true);
+        assert!(matches!(
+            hrandfield.to_frame(),
+            Err(RedisError::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,81 @@
+/// A Redis HSET command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct HSet {
+    key: Bytes,
+    field: String,
+    value: Bytes,
+}
+
+impl HSet {
+    /// Creates a new HSet command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The hash to set the field in
+    /// * `field` - The field to set
+    /// * `value` - The value of the field
+    ///
+    /// # Returns
+    ///
+    /// A new HSet command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let hset = HSet::new("myhash", "field", b"value");
+    /// ```
+    pub fn new(key: impl ToRedisArg, field: &str, value: &[u8]) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+            field: field.to_string(),
+            value: Bytes::copy_from_slice(value),
+        }
+    }
+}
+
+impl Command for HSet {
+    const NAME: &'static str = "HSET";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("HSET"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+        frame.push_frame_to_array(Frame::bulk(self.field.clone()))?;
+        frame.push_frame_to_array(Frame::bulk(self.value.clone()))?;
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hset() {
+        let hset = HSet::new("myhash", "field", b"value");
+        let frame: Frame = hset
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create HSET command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("HSET"),
+                Frame::bulk("myhash"),
+                Frame::bulk("field"),
+                Frame::bulk("value"),
+            ])
+        );
+    }
+}
@@ -0,0 +1,76 @@
+/// A Redis HSTRLEN command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct HStrLen {
+    key: Bytes,
+    field: String,
+}
+
+impl HStrLen {
+    /// Creates a new HStrLen command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The hash holding the field
+    /// * `field` - The field to get the length of the value of
+    ///
+    /// # Returns
+    ///
+    /// A new HStrLen command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let hstrlen = HStrLen::new("myhash", "field");
+    /// ```
+    pub fn new(key: impl ToRedisArg, field: &str) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+            field: field.to_string(),
+        }
+    }
+}
+
+impl Command for HStrLen {
+    const NAME: &'static str = "HSTRLEN";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("HSTRLEN"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+        frame.push_frame_to_array(Frame::bulk(self.field.clone()))?;
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hstrlen() {
+        let hstrlen = HStrLen::new("myhash", "field");
+        let frame: Frame = hstrlen
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create HSTRLEN command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("HSTRLEN"),
+                Frame::bulk("myhash"),
+                Frame::bulk("field"),
+            ])
+        );
+    }
+}
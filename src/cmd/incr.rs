@@ -1,9 +1,13 @@
 /// A Redis INCR command.
-use crate::{Result, cmd::Command, frame::Frame};
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
 use bytes::Bytes;
 
 pub struct Incr {
-    key: String,
+    key: Bytes,
 }
 
 impl Incr {
@@ -22,25 +26,28 @@ impl Incr {
     /// ```ignore
     /// let incr = Incr::new("mykey");
     /// ```
-    pub fn new(key: &str) -> Self {
+    pub fn new(key: impl ToRedisArg) -> Self {
         Self {
-            key: key.to_string(),
+            key: key.to_redis_arg(),
         }
     }
 }
 
-impl Command for Incr {}
+impl Command for Incr {
+    const NAME: &'static str = "INCR";
+    const IDEMPOTENT: bool = false;
 
-impl TryInto<Frame> for Incr {
-    type Error = crate::RedisError;
-
-    fn try_into(self) -> Result<Frame> {
+    fn to_frame(&self) -> Result<Frame> {
         let mut frame: Frame = Frame::array();
-        frame.push_frame_to_array(Frame::BulkString("INCR".into()))?;
-        frame.push_frame_to_array(Frame::BulkString(Bytes::from(self.key)))?;
+        frame.push_frame_to_array(Frame::bulk("INCR"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
 
         Ok(frame)
     }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
 }
 
 #[cfg(test)]
@@ -51,15 +58,12 @@ mod tests {
     fn test_incr() {
         let incr = Incr::new("mykey");
         let frame: Frame = incr
-            .try_into()
+            .to_frame()
             .unwrap_or_else(|err| panic!("Failed to create INCR command: {:?}", err));
 
         assert_eq!(
             frame,
-            Frame::Array(vec![
-                Frame::BulkString("INCR".into()),
-                Frame::BulkString("mykey".into()),
-            ])
+            Frame::Array(vec![Frame::bulk("INCR"), Frame::bulk("mykey"),])
         )
     }
 }
@@ -0,0 +1,78 @@
+/// A Redis INCRBYFLOAT command.
+use crate::{
+    Result,
+    cmd::format_double,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct IncrByFloat {
+    key: Bytes,
+    increment: f64,
+}
+
+impl IncrByFloat {
+    /// Creates a new IncrByFloat command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to increment
+    /// * `increment` - The amount to add, negative to decrement
+    ///
+    /// # Returns
+    ///
+    /// A new IncrByFloat command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let incr_by_float = IncrByFloat::new("mykey", 0.1);
+    /// ```
+    pub fn new(key: impl ToRedisArg, increment: f64) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+            increment,
+        }
+    }
+}
+
+impl Command for IncrByFloat {
+    const NAME: &'static str = "INCRBYFLOAT";
+    const IDEMPOTENT: bool = false;
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("INCRBYFLOAT"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+        frame.push_frame_to_array(Frame::bulk(format_double(self.increment)?))?;
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_incr_by_float() {
+        let incr_by_float = IncrByFloat::new("mykey", -1.5e-7);
+        let frame: Frame = incr_by_float
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create INCRBYFLOAT command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("INCRBYFLOAT"),
+                Frame::bulk("mykey"),
+                Frame::bulk("-0.00000015"),
+            ])
+        );
+    }
+}
@@ -0,0 +1,351 @@
+/// A Redis LCS command.
+use crate::{
+    RedisError, Result,
+    cmd::{Command, ToRedisArg, frame_to_string, into_pairs},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+/// The options of an LCS command, chosen with the methods of the builder.
+///
+/// # Examples
+///
+/// ```ignore
+/// let options = LcsOptions::new().idx().min_match_len(4).with_match_len();
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct LcsOptions {
+    len: bool,
+    idx: bool,
+    min_match_len: Option<u64>,
+    with_match_len: bool,
+}
+
+impl LcsOptions {
+    /// Creates options asking for the longest common subsequence itself.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Asks for the length of the longest common subsequence only, see `LcsResult::Len`.
+    pub fn len(mut self) -> Self {
+        self.len = true;
+        self
+    }
+
+    /// Asks for the positions of the matches in both strings, see `LcsResult::Idx`.
+    pub fn idx(mut self) -> Self {
+        self.idx = true;
+        self
+    }
+
+    /// Leaves out the matches shorter than `min_match_len`, with `idx` only.
+    pub fn min_match_len(mut self, min_match_len: u64) -> Self {
+        self.min_match_len = Some(min_match_len);
+        self
+    }
+
+    /// Returns the length of every match along with its positions, with `idx` only.
+    pub fn with_match_len(mut self) -> Self {
+        self.with_match_len = true;
+        self
+    }
+}
+
+/// A match of an LCS command with IDX, given as inclusive ranges in both strings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LcsMatch {
+    /// The first and last positions of the match in the first string.
+    pub key1: (u64, u64),
+    /// The first and last positions of the match in the second string.
+    pub key2: (u64, u64),
+    /// The length of the match, only returned with WITHMATCHLEN.
+    pub len: Option<u64>,
+}
+
+/// The reply of an LCS command, whose shape depends on the options.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LcsResult {
+    /// The longest common subsequence, without options.
+    Match(Vec<u8>),
+    /// The length of the longest common subsequence, with LEN.
+    Len(u64),
+    /// The matches, from the last one in the strings to the first one, with IDX.
+    Idx { matches: Vec<LcsMatch>, len: u64 },
+}
+
+impl TryFrom<Frame> for LcsResult {
+    type Error = RedisError;
+
+    /// Parses the reply of LCS, a map in RESP3 or a flat array of key/value pairs in RESP2 with
+    /// IDX.
+    fn try_from(frame: Frame) -> Result<Self> {
+        match frame {
+            Frame::BulkString(data) => Ok(LcsResult::Match(data.to_vec())),
+            Frame::Integer(len) => Ok(LcsResult::Len(u64::try_from(len)?)),
+            frame @ (Frame::Map(_) | Frame::Array(_)) => {
+                let mut matches = Vec::new();
+                let mut len = None;
+
+                for (key, value) in into_pairs(frame)? {
+                    match frame_to_string(key)?.as_str() {
+                        "matches" => {
+                            let Frame::Array(items) = value else {
+                                return Err(RedisError::UnexpectedResponseType);
+                            };
+                            matches = items
+                                .into_iter()
+                                .map(frame_to_match)
+                                .collect::<Result<_>>()?;
+                        }
+                        "len" => len = Some(frame_to_u64(value)?),
+                        _ => {}
+                    }
+                }
+
+                Ok(LcsResult::Idx {
+                    matches,
+                    len: len.ok_or(RedisError::UnexpectedResponseType)?,
+                })
+            }
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+}
+
+/// Parses a match, `[[start1, end1], [start2, end2]]` followed by its length with WITHMATCHLEN.
+fn frame_to_match(frame: Frame) -> Result<LcsMatch> {
+    let Frame::Array(items) = frame else {
+        return Err(RedisError::UnexpectedResponseType);
+    };
+
+    let mut items = items.into_iter();
+    match (items.next(), items.next(), items.next(), items.next()) {
+        (Some(key1), Some(key2), len, None) => Ok(LcsMatch {
+            key1: frame_to_range(key1)?,
+            key2: frame_to_range(key2)?,
+            len: len.map(frame_to_u64).transpose()?,
+        }),
+        _ => Err(RedisError::UnexpectedResponseType),
+    }
+}
+
+fn frame_to_range(frame: Frame) -> Result<(u64, u64)> {
+    match frame {
+        Frame::Array(items) => match <[Frame; 2]>::try_from(items) {
+            Ok([start, end]) => Ok((frame_to_u64(start)?, frame_to_u64(end)?)),
+            Err(_) => Err(RedisError::UnexpectedResponseType),
+        },
+        _ => Err(RedisError::UnexpectedResponseType),
+    }
+}
+
+fn frame_to_u64(frame: Frame) -> Result<u64> {
+    match frame {
+        Frame::Integer(data) => Ok(u64::try_from(data)?),
+        _ => Err(RedisError::UnexpectedResponseType),
+    }
+}
+
+pub struct Lcs {
+    key1: Bytes,
+    key2: Bytes,
+    options: LcsOptions,
+}
+
+impl Lcs {
+    /// Creates a new Lcs command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key1` - The key of the first string
+    /// * `key2` - The key of the second string
+    /// * `options` - The LCS options
+    ///
+    /// # Returns
+    ///
+    /// A new Lcs command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let lcs = Lcs::new("key1", "key2", LcsOptions::new().len());
+    /// ```
+    pub fn new(key1: impl ToRedisArg, key2: impl ToRedisArg, options: LcsOptions) -> Self {
+        Self {
+            key1: key1.to_redis_arg(),
+            key2: key2.to_redis_arg(),
+            options,
+        }
+    }
+}
+
+impl Command for Lcs {
+    const NAME: &'static str = "LCS";
+
+    fn to_frame(&self) -> Result<Frame> {
+        // the server refuses them together, IDX already returns the length
+        if self.options.len && self.options.idx {
+            return Err(RedisError::InvalidArgument(
+                "LEN and IDX cannot be set at the same time".to_string(),
+            ));
+        }
+
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("LCS"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key1.clone()))?;
+        frame.push_frame_to_array(Frame::bulk(self.key2.clone()))?;
+
+        if self.options.len {
+            frame.push_frame_to_array(Frame::bulk("LEN"))?;
+        }
+
+        if self.options.idx {
+            frame.push_frame_to_array(Frame::bulk("IDX"))?;
+        }
+
+        if let Some(min_match_len) = self.options.min_match_len {
+            frame.push_frame_to_array(Frame::bulk("MINMATCHLEN"))?;
+            frame.push_frame_to_array(Frame::bulk(min_match_len.to_string()))?;
+        }
+
+        if self.options.with_match_len {
+            frame.push_frame_to_array(Frame::bulk("WITHMATCHLEN"))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1, 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_lcs() {
+        let lcs = Lcs::new("key1", "key2", LcsOptions::new());
+        let frame: Frame = lcs
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create LCS command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("LCS"),
+                Frame::bulk("key1"),
+                Frame::bulk("key2"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_lcs_idx() {
+        let options = LcsOptions::new().idx().min_match_len(4).with_match_len();
+        let lcs = Lcs::new("key1", "key2", options);
+        let frame: Frame = lcs
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create LCS command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("LCS"),
+                Frame::bulk("key1"),
+                Frame::bulk("key2"),
+                Frame::bulk("IDX"),
+                Frame::bulk("MINMATCHLEN"),
+                Frame::bulk("4"),
+                Frame::bulk("WITHMATCHLEN"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_lcs_len_and_idx() {
+        let lcs = Lcs::new("key1", "key2", LcsOptions::new().len().idx());
+
+        assert!(matches!(
+            lcs.to_frame(),
+            Err(RedisError::InvalidArgument(_))
+        ));
+    }
+
+    fn parse(bytes: &[u8]) -> LcsResult {
+        let frame = Frame::try_parse(&mut Cursor::new(bytes))
+            .unwrap_or_else(|err| panic!("Failed to parse LCS reply: {:?}", err));
+
+        LcsResult::try_from(frame)
+            .unwrap_or_else(|err| panic!("Failed to parse LCS result: {:?}", err))
+    }
+
+    #[test]
+    fn test_lcs_result_match_and_len() {
+        assert_eq!(
+            parse(b"$6\r\nmytext\r\n"),
+            LcsResult::Match(b"mytext".to_vec())
+        );
+        assert_eq!(parse(b":6\r\n"), LcsResult::Len(6));
+    }
+
+    #[test]
+    fn test_lcs_result_idx_resp2() {
+        let bytes: &[u8] = b"*4\r\n$7\r\nmatches\r\n\
+            *2\r\n*2\r\n*2\r\n:4\r\n:7\r\n*2\r\n:5\r\n:8\r\n*2\r\n*2\r\n:2\r\n:3\r\n*2\r\n:0\r\n:1\r\n\
+            $3\r\nlen\r\n:6\r\n";
+
+        assert_eq!(
+            parse(bytes),
+            LcsResult::Idx {
+                matches: vec![
+                    LcsMatch {
+                        key1: (4, 7),
+                        key2: (5, 8),
+                        len: None,
+                    },
+                    LcsMatch {
+                        key1: (2, 3),
+                        key2: (0, 1),
+                        len: None,
+                    },
+                ],
+                len: 6,
+            }
+        );
+    }
+
+    #[test]
+    fn test_lcs_result_idx_resp3_with_match_len() {
+        let bytes: &[u8] = b"%2\r\n$7\r\nmatches\r\n\
+            *1\r\n*3\r\n*2\r\n:4\r\n:7\r\n*2\r\n:5\r\n:8\r\n:4\r\n\
+            $3\r\nlen\r\n:6\r\n";
+
+        assert_eq!(
+            parse(bytes),
+            LcsResult::Idx {
+                matches: vec![LcsMatch {
+                    key1: (4, 7),
+                    key2: (5, 8),
+                    len: Some(4),
+                }],
+                len: 6,
+            }
+        );
+    }
+
+    #[test]
+    fn test_lcs_result_malformed() {
+        let frame = Frame::Array(vec![
+            Frame::bulk("matches"),
+            Frame::Array(vec![Frame::Array(vec![Frame::Integer(4)])]),
+            Frame::bulk("len"),
+            Frame::Integer(6),
+        ]);
+
+        assert!(LcsResult::try_from(frame).is_err());
+    }
+}
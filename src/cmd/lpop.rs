@@ -1,37 +1,44 @@
 /// A Redis LPOP command.
-use crate::{Result, cmd::Command, frame::Frame};
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
 use bytes::Bytes;
 
 pub struct LPop {
-    key: String,
+    key: Bytes,
     count: Option<u64>,
 }
 
 impl LPop {
-    pub fn new(key: &str, count: Option<u64>) -> Self {
+    pub fn new(key: impl ToRedisArg, count: Option<u64>) -> Self {
         Self {
-            key: key.to_string(),
+            key: key.to_redis_arg(),
             count,
         }
     }
 }
 
-impl Command for LPop {}
+impl Command for LPop {
+    const NAME: &'static str = "LPOP";
+    const IDEMPOTENT: bool = false;
 
-impl TryInto<Frame> for LPop {
-    type Error = crate::RedisError;
-
-    fn try_into(self) -> Result<Frame> {
+    fn to_frame(&self) -> Result<Frame> {
         let mut frame: Frame = Frame::array();
-        frame.push_frame_to_array(Frame::BulkString("LPOP".into()))?;
-        frame.push_frame_to_array(Frame::BulkString(Bytes::from(self.key)))?;
+        frame.push_frame_to_array(Frame::bulk("LPOP"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
 
         if let Some(count) = self.count {
-            frame.push_frame_to_array(Frame::Integer(count as i64))?;
+            frame.push_frame_to_array(Frame::bulk(count.to_string()))?;
         }
 
         Ok(frame)
     }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
 }
 
 #[cfg(test)]
@@ -42,28 +49,43 @@ mod tests {
     fn test_lpop() {
         let lpop = LPop::new("mylist", None);
         let frame: Frame = lpop
-            .try_into()
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create LPOP command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("LPOP"), Frame::bulk("mylist")])
+        );
+
+        let lpop = LPop::new("mylist", Some(2));
+        let frame: Frame = lpop
+            .to_frame()
             .unwrap_or_else(|err| panic!("Failed to create LPOP command: {:?}", err));
 
         assert_eq!(
             frame,
             Frame::Array(vec![
-                Frame::BulkString("LPOP".into()),
-                Frame::BulkString("mylist".into())
+                Frame::bulk("LPOP"),
+                Frame::bulk("mylist"),
+                Frame::bulk("2")
             ])
         );
+    }
 
-        let lpop = LPop::new("mylist", Some(2));
+    #[test]
+    fn test_lpop_zero_count() {
+        // the count is sent as is, the server replies with an empty array
+        let lpop = LPop::new("mylist", Some(0));
         let frame: Frame = lpop
-            .try_into()
+            .to_frame()
             .unwrap_or_else(|err| panic!("Failed to create LPOP command: {:?}", err));
 
         assert_eq!(
             frame,
             Frame::Array(vec![
-                Frame::BulkString("LPOP".into()),
-                Frame::BulkString("mylist".into()),
-                Frame::Integer(2)
+                Frame::bulk("LPOP"),
+                Frame::bulk("mylist"),
+                Frame::bulk("0")
             ])
         );
     }
@@ -1,9 +1,13 @@
 /// A Redis LPUSH command.
-use crate::{Result, cmd::Command, frame::Frame};
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
 use bytes::Bytes;
 
 pub struct LPush {
-    key: String,
+    key: Bytes,
     values: Vec<Vec<u8>>,
 }
 
@@ -24,30 +28,33 @@ impl LPush {
     /// ```ignore
     /// let lpush = LPush::new("mylist", vec!["value1", "value2"]);
     /// ```
-    pub fn new(key: &str, values: Vec<&[u8]>) -> Self {
+    pub fn new(key: impl ToRedisArg, values: Vec<&[u8]>) -> Self {
         Self {
-            key: key.to_string(),
+            key: key.to_redis_arg(),
             values: values.iter().map(|s| s.to_vec()).collect(),
         }
     }
 }
 
-impl Command for LPush {}
+impl Command for LPush {
+    const NAME: &'static str = "LPUSH";
+    const IDEMPOTENT: bool = false;
 
-impl TryInto<Frame> for LPush {
-    type Error = crate::RedisError;
-
-    fn try_into(self) -> Result<Frame> {
+    fn to_frame(&self) -> Result<Frame> {
         let mut frame: Frame = Frame::array();
-        frame.push_frame_to_array(Frame::BulkString("LPUSH".into()))?;
-        frame.push_frame_to_array(Frame::BulkString(Bytes::from(self.key)))?;
+        frame.push_frame_to_array(Frame::bulk("LPUSH"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
 
-        for value in self.values {
-            frame.push_frame_to_array(Frame::BulkString(Bytes::from(value)))?;
+        for value in &self.values {
+            frame.push_frame_to_array(Frame::bulk(value.clone()))?;
         }
 
         Ok(frame)
     }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
 }
 
 #[cfg(test)]
@@ -58,16 +65,16 @@ mod tests {
     fn test_lpush() {
         let lpush = LPush::new("mylist", vec![b"value1", b"value2"]);
         let frame: Frame = lpush
-            .try_into()
+            .to_frame()
             .unwrap_or_else(|err| panic!("Failed to create LPUSH command: {:?}", err));
 
         assert_eq!(
             frame,
             Frame::Array(vec![
-                Frame::BulkString("LPUSH".into()),
-                Frame::BulkString("mylist".into()),
-                Frame::BulkString("value1".into()),
-                Frame::BulkString("value2".into()),
+                Frame::bulk("LPUSH"),
+                Frame::bulk("mylist"),
+                Frame::bulk("value1"),
+                Frame::bulk("value2"),
             ])
         )
     }
@@ -1,37 +1,43 @@
 /// A Redis LRANGE command.
-use crate::{Result, cmd::Command, frame::Frame};
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
 use bytes::Bytes;
 
 pub struct LRange {
-    key: String,
+    key: Bytes,
     start: i64,
     end: i64,
 }
 
 impl LRange {
-    pub fn new(key: &str, start: i64, end: i64) -> Self {
+    pub fn new(key: impl ToRedisArg, start: i64, end: i64) -> Self {
         Self {
-            key: key.to_string(),
+            key: key.to_redis_arg(),
             start,
             end,
         }
     }
 }
 
-impl Command for LRange {}
+impl Command for LRange {
+    const NAME: &'static str = "LRANGE";
 
-impl TryInto<Frame> for LRange {
-    type Error = crate::RedisError;
-
-    fn try_into(self) -> Result<Frame> {
+    fn to_frame(&self) -> Result<Frame> {
         let mut frame: Frame = Frame::array();
-        frame.push_frame_to_array(Frame::BulkString("LRANGE".into()))?;
-        frame.push_frame_to_array(Frame::BulkString(Bytes::from(self.key)))?;
-        frame.push_frame_to_array(Frame::Integer(self.start))?;
-        frame.push_frame_to_array(Frame::Integer(self.end))?;
+        frame.push_frame_to_array(Frame::bulk("LRANGE"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+        frame.push_frame_to_array(Frame::bulk(self.start.to_string()))?;
+        frame.push_frame_to_array(Frame::bulk(self.end.to_string()))?;
 
         Ok(frame)
     }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
 }
 
 #[cfg(test)]
@@ -42,16 +48,16 @@ mod tests {
     fn test_lrange() {
         let lrange = LRange::new("mylist", 0, -1);
         let frame: Frame = lrange
-            .try_into()
+            .to_frame()
             .unwrap_or_else(|err| panic!("Failed to create LRANGE command: {:?}", err));
 
         assert_eq!(
             frame,
             Frame::Array(vec![
-                Frame::BulkString("LRANGE".into()),
-                Frame::BulkString("mylist".into()),
-                Frame::Integer(0),
-                Frame::Integer(-1)
+                Frame::bulk("LRANGE"),
+                Frame::bulk("mylist"),
+                Frame::bulk("0"),
+                Frame::bulk("-1")
             ])
         );
     }
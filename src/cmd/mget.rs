@@ -0,0 +1,75 @@
+/// A Redis MGET command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct MGet {
+    keys: Vec<Bytes>,
+}
+
+impl MGet {
+    /// Creates a new MGet command.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The keys to get the values of
+    ///
+    /// # Returns
+    ///
+    /// A new MGet command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let mget = MGet::new(vec!["key1", "key2"]);
+    /// ```
+    pub fn new(keys: Vec<impl ToRedisArg>) -> Self {
+        Self {
+            keys: keys.iter().map(ToRedisArg::to_redis_arg).collect(),
+        }
+    }
+}
+
+impl Command for MGet {
+    const NAME: &'static str = "MGET";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("MGET"))?;
+
+        for key in &self.keys {
+            frame.push_frame_to_array(Frame::bulk(key.clone()))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        (1..=self.keys.len()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mget() {
+        let mget = MGet::new(vec!["key1", "key2"]);
+        let frame: Frame = mget
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create MGET command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("MGET"),
+                Frame::bulk("key1"),
+                Frame::bulk("key2"),
+            ])
+        )
+    }
+}
@@ -0,0 +1,235 @@
+/// A Redis MIGRATE command.
+use crate::{
+    RedisError, Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+/// The credentials MIGRATE authenticates with on the target instance.
+#[derive(Debug, Clone)]
+pub enum MigrateAuth {
+    /// Authenticates as the default user, with AUTH.
+    Password(String),
+    /// Authenticates as an ACL user, with AUTH2.
+    User { username: String, password: String },
+}
+
+/// The options of a MIGRATE command, none of them by default.
+#[derive(Debug, Default, Clone)]
+pub struct MigrateOptions {
+    /// Keeps the keys on the source instance.
+    pub copy: bool,
+    /// Overwrites the keys that already exist on the target instance.
+    pub replace: bool,
+    /// Authenticates on the target instance.
+    pub auth: Option<MigrateAuth>,
+}
+
+pub struct Migrate {
+    host: String,
+    port: u16,
+    keys: Vec<Bytes>,
+    db: u64,
+    timeout: u64,
+    options: MigrateOptions,
+}
+
+impl Migrate {
+    /// Creates a new Migrate command.
+    ///
+    /// # Arguments
+    ///
+    /// * `host` - The host of the target instance
+    /// * `port` - The port of the target instance
+    /// * `keys` - The keys to transfer, at least one
+    /// * `db` - The database of the target instance the keys are transferred to
+    /// * `timeout` - The maximum idle time of the transfer in milliseconds
+    /// * `options` - The MIGRATE options
+    ///
+    /// # Returns
+    ///
+    /// A new Migrate command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let migrate = Migrate::new("10.0.0.2", 6379, vec!["mykey"], 0, 5000, MigrateOptions::default());
+    /// ```
+    pub fn new(
+        host: &str,
+        port: u16,
+        keys: Vec<impl ToRedisArg>,
+        db: u64,
+        timeout: u64,
+        options: MigrateOptions,
+    ) -> Self {
+        Self {
+            host: host.to_string(),
+            port,
+            keys: keys.iter().map(ToRedisArg::to_redis_arg).collect(),
+            db,
+            timeout,
+            options,
+        }
+    }
+}
+
+impl Command for Migrate {
+    const NAME: &'static str = "MIGRATE";
+    const IDEMPOTENT: bool = false;
+
+    fn to_frame(&self) -> Result<Frame> {
+        if self.keys.is_empty() {
+            return Err(RedisError::InvalidArgument(
+                "MIGRATE requires at least one key".to_string(),
+            ));
+        }
+
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("MIGRATE"))?;
+        frame.push_frame_to_array(Frame::bulk(self.host.clone()))?;
+        frame.push_frame_to_array(Frame::bulk(self.port.to_string()))?;
+
+        // several keys go after KEYS, with an empty key in place of the single one
+        match self.keys.as_slice() {
+            [key] => frame.push_frame_to_array(Frame::bulk(key.clone()))?,
+            _ => frame.push_frame_to_array(Frame::bulk(""))?,
+        }
+
+        frame.push_frame_to_array(Frame::bulk(self.db.to_string()))?;
+        frame.push_frame_to_array(Frame::bulk(self.timeout.to_string()))?;
+
+        if self.options.copy {
+            frame.push_frame_to_array(Frame::bulk("COPY"))?;
+        }
+
+        if self.options.replace {
+            frame.push_frame_to_array(Frame::bulk("REPLACE"))?;
+        }
+
+        match &self.options.auth {
+            Some(MigrateAuth::Password(password)) => {
+                frame.push_frame_to_array(Frame::bulk("AUTH"))?;
+                frame.push_frame_to_array(Frame::bulk(password.clone()))?;
+            }
+            Some(MigrateAuth::User { username, password }) => {
+                frame.push_frame_to_array(Frame::bulk("AUTH2"))?;
+                frame.push_frame_to_array(Frame::bulk(username.clone()))?;
+                frame.push_frame_to_array(Frame::bulk(password.clone()))?;
+            }
+            None => {}
+        }
+
+        if self.keys.len() > 1 {
+            frame.push_frame_to_array(Frame::bulk("KEYS"))?;
+            for key in &self.keys {
+                frame.push_frame_to_array(Frame::bulk(key.clone()))?;
+            }
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        if let [_] = self.keys.as_slice() {
+            return vec![3];
+        }
+
+        // a multi-key MIGRATE lists the keys last, after KEYS
+        let auth = match &self.options.auth {
+            Some(MigrateAuth::Password(_)) => 2,
+            Some(MigrateAuth::User { .. }) => 3,
+            None => 0,
+        };
+        let start = 7 + usize::from(self.options.copy) + usize::from(self.options.replace) + auth;
+        (start..start + self.keys.len()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrate() {
+        let migrate = Migrate::new(
+            "10.0.0.2",
+            6379,
+            vec!["mykey"],
+            0,
+            5000,
+            MigrateOptions::default(),
+        );
+        let frame: Frame = migrate
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create MIGRATE command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("MIGRATE"),
+                Frame::bulk("10.0.0.2"),
+                Frame::bulk("6379"),
+                Frame::bulk("mykey"),
+                Frame::bulk("0"),
+                Frame::bulk("5000"),
+            ])
+        );
+        assert_eq!(migrate.key_indices(), vec![3]);
+    }
+
+    #[test]
+    fn test_migrate_keys_with_options() {
+        let options = MigrateOptions {
+            copy: true,
+            replace: true,
+            auth: Some(MigrateAuth::User {
+                username: "worker".to_string(),
+                password: "secret".to_string(),
+            }),
+        };
+        let migrate = Migrate::new("10.0.0.2", 6380, vec!["key1", "key2"], 3, 1000, options);
+        let frame: Frame = migrate
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create MIGRATE command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("MIGRATE"),
+                Frame::bulk("10.0.0.2"),
+                Frame::bulk("6380"),
+                Frame::bulk(""),
+                Frame::bulk("3"),
+                Frame::bulk("1000"),
+                Frame::bulk("COPY"),
+                Frame::bulk("REPLACE"),
+                Frame::bulk("AUTH2"),
+                Frame::bulk("worker"),
+                Frame::bulk("secret"),
+                Frame::bulk("KEYS"),
+                Frame::bulk("key1"),
+                Frame::bulk("key2"),
+            ])
+        );
+        assert_eq!(migrate.key_indices(), vec![12, 13]);
+    }
+
+    #[test]
+    fn test_migrate_no_key() {
+        let migrate = Migrate::new(
+            "10.0.0.2",
+            6379,
+            Vec::<&str>::new(),
+            0,
+            5000,
+            MigrateOptions::default(),
+        );
+
+        assert!(matches!(
+            migrate.to_frame(),
+            Err(RedisError::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,48 @@
+/// A Redis MONITOR command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+#[derive(Default)]
+pub struct Monitor;
+
+impl Monitor {
+    /// Creates a new Monitor command.
+    ///
+    /// # Returns
+    ///
+    /// A new Monitor command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let monitor = Monitor::new();
+    /// ```
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Command for Monitor {
+    const NAME: &'static str = "MONITOR";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("MONITOR"))?;
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_monitor() {
+        let monitor = Monitor::new();
+        let frame: Frame = monitor
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create MONITOR command: {:?}", err));
+
+        assert_eq!(frame, Frame::Array(vec![Frame::bulk("MONITOR")]));
+    }
+}
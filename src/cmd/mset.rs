@@ -0,0 +1,98 @@
+/// A Redis MSET command.
+use crate::{
+    RedisError, Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct MSet {
+    pairs: Vec<(Bytes, Bytes)>,
+}
+
+impl MSet {
+    /// Creates a new MSet command.
+    ///
+    /// # Arguments
+    ///
+    /// * `pairs` - The keys and their values
+    ///
+    /// # Returns
+    ///
+    /// A new MSet command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let mset = MSet::new(vec![("key1", b"value1".as_slice()), ("key2", b"value2".as_slice())]);
+    /// ```
+    pub fn new(pairs: Vec<(impl ToRedisArg, &[u8])>) -> Self {
+        Self {
+            pairs: pairs
+                .into_iter()
+                .map(|(key, value)| (key.to_redis_arg(), Bytes::copy_from_slice(value)))
+                .collect(),
+        }
+    }
+}
+
+impl Command for MSet {
+    const NAME: &'static str = "MSET";
+
+    fn to_frame(&self) -> Result<Frame> {
+        if self.pairs.is_empty() {
+            return Err(RedisError::InvalidArgument(
+                "MSET requires at least one key".to_string(),
+            ));
+        }
+
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("MSET"))?;
+
+        for (key, value) in &self.pairs {
+            frame.push_frame_to_array(Frame::bulk(key.clone()))?;
+            frame.push_frame_to_array(Frame::bulk(value.clone()))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        // every key is followed by its value
+        (1..=2 * self.pairs.len()).step_by(2).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mset() {
+        let mset = MSet::new(vec![
+            ("key1", b"value1".as_slice()),
+            ("key2", b"value2".as_slice()),
+        ]);
+        let frame: Frame = mset
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create MSET command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("MSET"),
+                Frame::bulk("key1"),
+                Frame::bulk("value1"),
+                Frame::bulk("key2"),
+                Frame::bulk("value2"),
+            ])
+        );
+        assert_eq!(mset.key_indices(), vec![1, 3]);
+
+        let mset = MSet::new(Vec::<(&str, &[u8])>::new());
+        assert!(matches!(
+            mset.to_frame(),
+            Err(RedisError::InvalidArgument(_))
+        ));
+    }
+}
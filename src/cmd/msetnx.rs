@@ -0,0 +1,98 @@
+/// A Redis MSETNX command.
+use crate::{
+    RedisError, Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct MSetNx {
+    pairs: Vec<(Bytes, Bytes)>,
+}
+
+impl MSetNx {
+    /// Creates a new MSetNx command.
+    ///
+    /// # Arguments
+    ///
+    /// * `pairs` - The keys and their values
+    ///
+    /// # Returns
+    ///
+    /// A new MSetNx command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let msetnx = MSetNx::new(vec![("key1", b"value1".as_slice()), ("key2", b"value2".as_slice())]);
+    /// ```
+    pub fn new(pairs: Vec<(impl ToRedisArg, &[u8])>) -> Self {
+        Self {
+            pairs: pairs
+                .into_iter()
+                .map(|(key, value)| (key.to_redis_arg(), Bytes::copy_from_slice(value)))
+                .collect(),
+        }
+    }
+}
+
+impl Command for MSetNx {
+    const NAME: &'static str = "MSETNX";
+
+    fn to_frame(&self) -> Result<Frame> {
+        if self.pairs.is_empty() {
+            return Err(RedisError::InvalidArgument(
+                "MSETNX requires at least one key".to_string(),
+            ));
+        }
+
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("MSETNX"))?;
+
+        for (key, value) in &self.pairs {
+            frame.push_frame_to_array(Frame::bulk(key.clone()))?;
+            frame.push_frame_to_array(Frame::bulk(value.clone()))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        // every key is followed by its value
+        (1..=2 * self.pairs.len()).step_by(2).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_msetnx() {
+        let msetnx = MSetNx::new(vec![
+            ("key1", b"value1".as_slice()),
+            ("key2", b"value2".as_slice()),
+        ]);
+        let frame: Frame = msetnx
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create MSETNX command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("MSETNX"),
+                Frame::bulk("key1"),
+                Frame::bulk("value1"),
+                Frame::bulk("key2"),
+                Frame::bulk("value2"),
+            ])
+        );
+        assert_eq!(msetnx.key_indices(), vec![1, 3]);
+
+        let msetnx = MSetNx::new(Vec::<(&str, &[u8])>::new());
+        assert!(matches!(
+            msetnx.to_frame(),
+            Err(RedisError::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,48 @@
+/// A Redis MULTI command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+#[derive(Default)]
+pub struct Multi;
+
+impl Multi {
+    /// Creates a new Multi command.
+    ///
+    /// # Returns
+    ///
+    /// A new Multi command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let multi = Multi::new();
+    /// ```
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Command for Multi {
+    const NAME: &'static str = "MULTI";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("MULTI"))?;
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multi() {
+        let multi = Multi::new();
+        let frame: Frame = multi
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create MULTI command: {:?}", err));
+
+        assert_eq!(frame, Frame::Array(vec![Frame::bulk("MULTI")]));
+    }
+}
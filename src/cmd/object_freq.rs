@@ -0,0 +1,88 @@
+/// A Redis OBJECT FREQ command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct ObjectFreq {
+    key: Bytes,
+}
+
+impl ObjectFreq {
+    /// Creates a new ObjectFreq command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to get the access frequency of
+    ///
+    /// # Returns
+    ///
+    /// A new ObjectFreq command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let object = ObjectFreq::new("mykey");
+    /// ```
+    pub fn new(key: impl ToRedisArg) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+        }
+    }
+}
+
+impl Command for ObjectFreq {
+    const NAME: &'static str = "OBJECT FREQ";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("OBJECT"))?;
+        frame.push_frame_to_array(Frame::bulk("FREQ"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_object_freq() {
+        let object = ObjectFreq::new("mykey");
+        let frame: Frame = object
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create OBJECT FREQ command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("OBJECT"),
+                Frame::bulk("FREQ"),
+                Frame::bulk("mykey"),
+            ])
+        );
+
+        // keys are binary safe
+        let object = ObjectFreq::new(&b"\x00\xff"[..]);
+        let frame: Frame = object
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create OBJECT FREQ command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("OBJECT"),
+                Frame::bulk("FREQ"),
+                Frame::bulk(&b"\x00\xff"[..]),
+            ])
+        );
+    }
+}
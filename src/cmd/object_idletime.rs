@@ -0,0 +1,73 @@
+/// A Redis OBJECT IDLETIME command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct ObjectIdleTime {
+    key: Bytes,
+}
+
+impl ObjectIdleTime {
+    /// Creates a new ObjectIdleTime command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to get the idle time of
+    ///
+    /// # Returns
+    ///
+    /// A new ObjectIdleTime command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let object = ObjectIdleTime::new("mykey");
+    /// ```
+    pub fn new(key: impl ToRedisArg) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+        }
+    }
+}
+
+impl Command for ObjectIdleTime {
+    const NAME: &'static str = "OBJECT IDLETIME";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("OBJECT"))?;
+        frame.push_frame_to_array(Frame::bulk("IDLETIME"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_object_idletime() {
+        let object = ObjectIdleTime::new("mykey");
+        let frame: Frame = object
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create OBJECT IDLETIME command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("OBJECT"),
+                Frame::bulk("IDLETIME"),
+                Frame::bulk("mykey"),
+            ])
+        );
+    }
+}
@@ -0,0 +1,73 @@
+/// A Redis OBJECT REFCOUNT command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct ObjectRefCount {
+    key: Bytes,
+}
+
+impl ObjectRefCount {
+    /// Creates a new ObjectRefCount command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to get the reference count of
+    ///
+    /// # Returns
+    ///
+    /// A new ObjectRefCount command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let object = ObjectRefCount::new("mykey");
+    /// ```
+    pub fn new(key: impl ToRedisArg) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+        }
+    }
+}
+
+impl Command for ObjectRefCount {
+    const NAME: &'static str = "OBJECT REFCOUNT";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("OBJECT"))?;
+        frame.push_frame_to_array(Frame::bulk("REFCOUNT"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_object_refcount() {
+        let object = ObjectRefCount::new("mykey");
+        let frame: Frame = object
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create OBJECT REFCOUNT command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("OBJECT"),
+                Frame::bulk("REFCOUNT"),
+                Frame::bulk("mykey"),
+            ])
+        );
+    }
+}
@@ -0,0 +1,111 @@
+/// A Redis PEXPIRE command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct PExpire {
+    key: Bytes,
+    milliseconds: u64,
+    nx: bool,
+}
+
+impl PExpire {
+    /// Creates a new PExpire command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to set the expiration for
+    /// * `milliseconds` - The number of milliseconds to set the expiration for
+    ///
+    /// # Returns
+    ///
+    /// A new PExpire command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let pexpire = PExpire::new("mykey", 1500);
+    /// ```
+    pub fn new(key: impl ToRedisArg, milliseconds: u64) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+            milliseconds,
+            nx: false,
+        }
+    }
+
+    /// Creates a new PExpire command with NX, only setting the expiration if the key has none.
+    /// Requires Redis 7.0 or later.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let pexpire = PExpire::nx("mykey", 1500);
+    /// ```
+    pub fn nx(key: impl ToRedisArg, milliseconds: u64) -> Self {
+        Self {
+            nx: true,
+            ..Self::new(key, milliseconds)
+        }
+    }
+}
+
+impl Command for PExpire {
+    const NAME: &'static str = "PEXPIRE";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("PEXPIRE"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+        frame.push_frame_to_array(Frame::bulk(self.milliseconds.to_string()))?;
+        if self.nx {
+            frame.push_frame_to_array(Frame::bulk("NX"))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pexpire() {
+        let pexpire = PExpire::new("mykey", 1500);
+        let frame: Frame = pexpire
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create PEXPIRE command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("PEXPIRE"),
+                Frame::bulk("mykey"),
+                Frame::bulk("1500"),
+            ])
+        );
+
+        let pexpire = PExpire::nx("mykey", 1500);
+        let frame: Frame = pexpire
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create PEXPIRE command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("PEXPIRE"),
+                Frame::bulk("mykey"),
+                Frame::bulk("1500"),
+                Frame::bulk("NX"),
+            ])
+        );
+    }
+}
@@ -0,0 +1,68 @@
+/// A Redis PEXPIRETIME command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct PExpireTime {
+    key: Bytes,
+}
+
+impl PExpireTime {
+    /// Creates a new PEXPIRETIME command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to get the absolute expiration time for, in milliseconds
+    ///
+    /// # Returns
+    ///
+    /// A new PEXPIRETIME command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let pexpiretime = PExpireTime::new("mykey");
+    /// ```
+    pub fn new(key: impl ToRedisArg) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+        }
+    }
+}
+
+impl Command for PExpireTime {
+    const NAME: &'static str = "PEXPIRETIME";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("PEXPIRETIME"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pexpiretime() {
+        let pexpiretime = PExpireTime::new("mykey");
+        let frame: Frame = pexpiretime
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create PEXPIRETIME command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("PEXPIRETIME"), Frame::bulk("mykey"),])
+        );
+    }
+}
@@ -1,5 +1,5 @@
 /// A Redis PING command.
-use crate::{Result, cmd::Command, frame::Frame};
+use crate::{RedisError, Result, cmd::Command, frame::Frame};
 use bytes::Bytes;
 
 pub struct Ping {
@@ -29,18 +29,52 @@ impl Ping {
     }
 }
 
-impl Command for Ping {}
+/// The reply of a PING command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Pong {
+    /// The bare `PONG` status reply, to a PING without a message.
+    Pong,
+    /// The message echoed back as a bulk string, byte for byte.
+    Echo(Bytes),
+}
+
+impl TryFrom<Frame> for Pong {
+    type Error = RedisError;
+
+    /// Parses the reply of PING, a status or a bulk string, or an array of `pong` and the message
+    /// on a RESP2 connection in pub/sub mode.
+    fn try_from(frame: Frame) -> Result<Self> {
+        match frame {
+            Frame::SimpleString(_) => Ok(Pong::Pong),
+            Frame::BulkString(data) => Ok(Pong::Echo(data)),
+            Frame::Array(items) | Frame::Push(items) => match <[Frame; 2]>::try_from(items) {
+                Ok([Frame::BulkString(kind), Frame::BulkString(data)])
+                    if kind.eq_ignore_ascii_case(b"pong") =>
+                {
+                    // an empty message cannot be told apart from no message at all
+                    if data.is_empty() {
+                        Ok(Pong::Pong)
+                    } else {
+                        Ok(Pong::Echo(data))
+                    }
+                }
+                _ => Err(RedisError::UnexpectedResponseType),
+            },
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+}
 
-impl TryInto<Frame> for Ping {
-    type Error = crate::RedisError;
+impl Command for Ping {
+    const NAME: &'static str = "PING";
 
-    fn try_into(self) -> Result<Frame> {
+    fn to_frame(&self) -> Result<Frame> {
         let mut frame: Frame = Frame::array();
-        frame.push_frame_to_array(Frame::BulkString("PING".into()))?;
+        frame.push_frame_to_array(Frame::bulk("PING"))?;
 
         // do not push the message if it is None
-        if let Some(msg) = self.msg {
-            frame.push_frame_to_array(Frame::BulkString(msg))?;
+        if let Some(msg) = &self.msg {
+            frame.push_frame_to_array(Frame::bulk(msg.clone()))?;
         }
 
         Ok(frame)
@@ -55,22 +89,53 @@ mod tests {
     fn test_ping() {
         let ping = Ping::new(None);
         let frame: Frame = ping
-            .try_into()
+            .to_frame()
             .unwrap_or_else(|err| panic!("Failed to create PING command: {:?}", err));
 
-        assert_eq!(frame, Frame::Array(vec![Frame::BulkString("PING".into())]));
+        assert_eq!(frame, Frame::Array(vec![Frame::bulk("PING")]));
 
         let ping = Ping::new(Some("hello".as_bytes()));
         let frame: Frame = ping
-            .try_into()
+            .to_frame()
             .unwrap_or_else(|err| panic!("Failed to create PING command: {:?}", err));
 
         assert_eq!(
             frame,
-            Frame::Array(vec![
-                Frame::BulkString("PING".into()),
-                Frame::BulkString("hello".into())
-            ])
+            Frame::Array(vec![Frame::bulk("PING"), Frame::bulk("hello")])
         );
     }
+
+    #[test]
+    fn test_pong() {
+        let cases = vec![
+            (Frame::simple("PONG"), Pong::Pong),
+            (
+                Frame::bulk("hello"),
+                Pong::Echo(Bytes::from_static(b"hello")),
+            ),
+            // binary messages are echoed untouched
+            (
+                Frame::bulk(Bytes::from_static(b"\x00\xff\r\n")),
+                Pong::Echo(Bytes::from_static(b"\x00\xff\r\n")),
+            ),
+            // in pub/sub mode on RESP2
+            (
+                Frame::Array(vec![Frame::bulk("pong"), Frame::bulk("")]),
+                Pong::Pong,
+            ),
+            (
+                Frame::Array(vec![Frame::bulk("pong"), Frame::bulk("hello")]),
+                Pong::Echo(Bytes::from_static(b"hello")),
+            ),
+        ];
+
+        for (frame, expected) in cases {
+            let pong = Pong::try_from(frame)
+                .unwrap_or_else(|err| panic!("Failed to parse PING reply: {:?}", err));
+            assert_eq!(pong, expected);
+        }
+
+        let result = Pong::try_from(Frame::Integer(1));
+        assert!(matches!(result, Err(RedisError::UnexpectedResponseType)));
+    }
 }
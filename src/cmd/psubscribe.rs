@@ -0,0 +1,66 @@
+/// A Redis PSUBSCRIBE command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+pub struct PSubscribe {
+    patterns: Vec<String>,
+}
+
+impl PSubscribe {
+    /// Creates a new PSubscribe command.
+    ///
+    /// # Arguments
+    ///
+    /// * `patterns` - The glob-style patterns to subscribe to, e.g. `news.*`
+    ///
+    /// # Returns
+    ///
+    /// A new PSubscribe command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let psubscribe = PSubscribe::new(vec!["news.*", "weather.*"]);
+    /// ```
+    pub fn new(patterns: Vec<&str>) -> Self {
+        Self {
+            patterns: patterns.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+}
+
+impl Command for PSubscribe {
+    const NAME: &'static str = "PSUBSCRIBE";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("PSUBSCRIBE"))?;
+
+        for pattern in &self.patterns {
+            frame.push_frame_to_array(Frame::bulk(pattern.clone()))?;
+        }
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_psubscribe() {
+        let psubscribe = PSubscribe::new(vec!["news.*", "weather.*"]);
+        let frame: Frame = psubscribe
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create PSUBSCRIBE command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("PSUBSCRIBE"),
+                Frame::bulk("news.*"),
+                Frame::bulk("weather.*"),
+            ])
+        );
+    }
+}
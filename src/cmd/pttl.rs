@@ -0,0 +1,68 @@
+/// A Redis PTTL command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct PTtl {
+    key: Bytes,
+}
+
+impl PTtl {
+    /// Creates a new PTTL command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to get the expiration time for, in milliseconds
+    ///
+    /// # Returns
+    ///
+    /// A new PTTL command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let pttl = PTtl::new("mykey");
+    /// ```
+    pub fn new(key: impl ToRedisArg) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+        }
+    }
+}
+
+impl Command for PTtl {
+    const NAME: &'static str = "PTTL";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("PTTL"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pttl() {
+        let pttl = PTtl::new("mykey");
+        let frame: Frame = pttl
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create PTTL command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("PTTL"), Frame::bulk("mykey"),])
+        );
+    }
+}
@@ -1,8 +1,69 @@
 /// A Redis PUBLISH command.
-#[allow(dead_code)]
+use crate::{Result, cmd::Command, frame::Frame};
+use bytes::Bytes;
+
 pub struct Publish {
     channel: String,
-    message: String,
+    message: Bytes,
+}
+
+impl Publish {
+    /// Creates a new Publish command.
+    ///
+    /// # Arguments
+    ///
+    /// * `channel` - The channel to post the message to
+    /// * `message` - The message to post
+    ///
+    /// # Returns
+    ///
+    /// A new Publish command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let publish = Publish::new("news", b"hello");
+    /// ```
+    pub fn new(channel: &str, message: &[u8]) -> Self {
+        Self {
+            channel: channel.to_string(),
+            message: Bytes::copy_from_slice(message),
+        }
+    }
+}
+
+impl Command for Publish {
+    const NAME: &'static str = "PUBLISH";
+    const IDEMPOTENT: bool = false;
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("PUBLISH"))?;
+        frame.push_frame_to_array(Frame::bulk(self.channel.clone()))?;
+        frame.push_frame_to_array(Frame::bulk(self.message.clone()))?;
+
+        Ok(frame)
+    }
 }
 
-impl Publish {}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_publish() {
+        let publish = Publish::new("news", b"hello");
+        let frame: Frame = publish
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create PUBLISH command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("PUBLISH"),
+                Frame::bulk("news"),
+                Frame::bulk("hello"),
+            ])
+        );
+    }
+}
@@ -0,0 +1,77 @@
+/// A Redis PUBSUB CHANNELS command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+pub struct PubSubChannels {
+    pattern: Option<String>,
+}
+
+impl PubSubChannels {
+    /// Creates a new PubSubChannels command.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - An optional glob-style pattern to filter the channels
+    ///
+    /// # Returns
+    ///
+    /// A new PubSubChannels command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let pubsub_channels = PubSubChannels::new(Some("news.*"));
+    /// ```
+    pub fn new(pattern: Option<&str>) -> Self {
+        Self {
+            pattern: pattern.map(|p| p.to_string()),
+        }
+    }
+}
+
+impl Command for PubSubChannels {
+    const NAME: &'static str = "PUBSUB CHANNELS";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("PUBSUB"))?;
+        frame.push_frame_to_array(Frame::bulk("CHANNELS"))?;
+
+        if let Some(pattern) = &self.pattern {
+            frame.push_frame_to_array(Frame::bulk(pattern.clone()))?;
+        }
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pubsub_channels() {
+        let pubsub_channels = PubSubChannels::new(None);
+        let frame: Frame = pubsub_channels
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create PUBSUB CHANNELS command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("PUBSUB"), Frame::bulk("CHANNELS")])
+        );
+
+        let pubsub_channels = PubSubChannels::new(Some("news.*"));
+        let frame: Frame = pubsub_channels
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create PUBSUB CHANNELS command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("PUBSUB"),
+                Frame::bulk("CHANNELS"),
+                Frame::bulk("news.*"),
+            ])
+        );
+    }
+}
@@ -0,0 +1,68 @@
+/// A Redis PUBSUB NUMSUB command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+pub struct PubSubNumSub {
+    channels: Vec<String>,
+}
+
+impl PubSubNumSub {
+    /// Creates a new PubSubNumSub command.
+    ///
+    /// # Arguments
+    ///
+    /// * `channels` - The channels to count the subscribers of
+    ///
+    /// # Returns
+    ///
+    /// A new PubSubNumSub command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let pubsub_numsub = PubSubNumSub::new(vec!["news", "weather"]);
+    /// ```
+    pub fn new(channels: Vec<&str>) -> Self {
+        Self {
+            channels: channels.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+}
+
+impl Command for PubSubNumSub {
+    const NAME: &'static str = "PUBSUB NUMSUB";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("PUBSUB"))?;
+        frame.push_frame_to_array(Frame::bulk("NUMSUB"))?;
+
+        for channel in &self.channels {
+            frame.push_frame_to_array(Frame::bulk(channel.clone()))?;
+        }
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pubsub_numsub() {
+        let pubsub_numsub = PubSubNumSub::new(vec!["news", "weather"]);
+        let frame: Frame = pubsub_numsub
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create PUBSUB NUMSUB command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("PUBSUB"),
+                Frame::bulk("NUMSUB"),
+                Frame::bulk("news"),
+                Frame::bulk("weather"),
+            ])
+        );
+    }
+}
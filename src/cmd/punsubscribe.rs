@@ -0,0 +1,66 @@
+/// A Redis PUNSUBSCRIBE command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+pub struct PUnsubscribe {
+    patterns: Vec<String>,
+}
+
+impl PUnsubscribe {
+    /// Creates a new PUnsubscribe command.
+    ///
+    /// # Arguments
+    ///
+    /// * `patterns` - The glob-style patterns to unsubscribe from, all of them if empty
+    ///
+    /// # Returns
+    ///
+    /// A new PUnsubscribe command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let punsubscribe = PUnsubscribe::new(vec!["news.*", "weather.*"]);
+    /// ```
+    pub fn new(patterns: Vec<&str>) -> Self {
+        Self {
+            patterns: patterns.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+}
+
+impl Command for PUnsubscribe {
+    const NAME: &'static str = "PUNSUBSCRIBE";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("PUNSUBSCRIBE"))?;
+
+        for pattern in &self.patterns {
+            frame.push_frame_to_array(Frame::bulk(pattern.clone()))?;
+        }
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_punsubscribe() {
+        let punsubscribe = PUnsubscribe::new(vec!["news.*", "weather.*"]);
+        let frame: Frame = punsubscribe
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create PUNSUBSCRIBE command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("PUNSUBSCRIBE"),
+                Frame::bulk("news.*"),
+                Frame::bulk("weather.*"),
+            ])
+        );
+    }
+}
@@ -0,0 +1,49 @@
+/// A Redis QUIT command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+#[derive(Default)]
+pub struct Quit;
+
+impl Quit {
+    /// Creates a new Quit command.
+    ///
+    /// # Returns
+    ///
+    /// A new Quit command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let quit = Quit::new();
+    /// ```
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Command for Quit {
+    const NAME: &'static str = "QUIT";
+    const IDEMPOTENT: bool = false;
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("QUIT"))?;
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quit() {
+        let quit = Quit::new();
+        let frame: Frame = quit
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create QUIT command: {:?}", err));
+
+        assert_eq!(frame, Frame::Array(vec![Frame::bulk("QUIT")]));
+    }
+}
@@ -0,0 +1,48 @@
+/// A Redis RANDOMKEY command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+#[derive(Default)]
+pub struct RandomKey;
+
+impl RandomKey {
+    /// Creates a new RandomKey command.
+    ///
+    /// # Returns
+    ///
+    /// A new RandomKey command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let randomkey = RandomKey::new();
+    /// ```
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Command for RandomKey {
+    const NAME: &'static str = "RANDOMKEY";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("RANDOMKEY"))?;
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_randomkey() {
+        let randomkey = RandomKey::new();
+        let frame: Frame = randomkey
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create RANDOMKEY command: {:?}", err));
+
+        assert_eq!(frame, Frame::Array(vec![Frame::bulk("RANDOMKEY")]));
+    }
+}
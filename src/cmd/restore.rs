@@ -0,0 +1,167 @@
+/// A Redis RESTORE command.
+use crate::{
+    RedisError, Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+/// The options of a RESTORE command, none of them by default.
+#[derive(Debug, Default, Clone)]
+pub struct RestoreOptions {
+    /// Overwrites the key if it already exists, instead of failing with `RedisError::BusyKey`.
+    pub replace: bool,
+    /// Reads the TTL as an absolute unix time in milliseconds, instead of a duration.
+    pub absttl: bool,
+    /// Sets the idle time of the key in seconds, used by the LRU eviction policies.
+    pub idletime: Option<u64>,
+    /// Sets the access frequency of the key, used by the LFU eviction policies.
+    pub freq: Option<u8>,
+}
+
+pub struct Restore {
+    key: Bytes,
+    ttl: u64,
+    payload: Bytes,
+    options: RestoreOptions,
+}
+
+impl Restore {
+    /// Creates a new Restore command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to create
+    /// * `ttl` - The time to live of the key in milliseconds, 0 for no expiry
+    /// * `payload` - The serialized value, as returned by DUMP
+    /// * `options` - The RESTORE options
+    ///
+    /// # Returns
+    ///
+    /// A new Restore command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let restore = Restore::new("mykey", 0, payload, RestoreOptions::default());
+    /// ```
+    pub fn new(key: impl ToRedisArg, ttl: u64, payload: Bytes, options: RestoreOptions) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+            ttl,
+            payload,
+            options,
+        }
+    }
+}
+
+impl Command for Restore {
+    const NAME: &'static str = "RESTORE";
+    const IDEMPOTENT: bool = false;
+
+    fn to_frame(&self) -> Result<Frame> {
+        // the server refuses them together, each one feeds a different eviction policy
+        if self.options.idletime.is_some() && self.options.freq.is_some() {
+            return Err(RedisError::InvalidArgument(
+                "IDLETIME and FREQ cannot be set at the same time".to_string(),
+            ));
+        }
+
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("RESTORE"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+        frame.push_frame_to_array(Frame::bulk(self.ttl.to_string()))?;
+        frame.push_frame_to_array(Frame::bulk(self.payload.clone()))?;
+
+        if self.options.replace {
+            frame.push_frame_to_array(Frame::bulk("REPLACE"))?;
+        }
+
+        if self.options.absttl {
+            frame.push_frame_to_array(Frame::bulk("ABSTTL"))?;
+        }
+
+        if let Some(idletime) = self.options.idletime {
+            frame.push_frame_to_array(Frame::bulk("IDLETIME"))?;
+            frame.push_frame_to_array(Frame::bulk(idletime.to_string()))?;
+        }
+
+        if let Some(freq) = self.options.freq {
+            frame.push_frame_to_array(Frame::bulk("FREQ"))?;
+            frame.push_frame_to_array(Frame::bulk(freq.to_string()))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restore() {
+        let payload = Bytes::from_static(b"\x0e\x01\xff\x00");
+        let restore = Restore::new("mykey", 0, payload.clone(), RestoreOptions::default());
+        let frame: Frame = restore
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create RESTORE command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("RESTORE"),
+                Frame::bulk("mykey"),
+                Frame::bulk("0"),
+                Frame::BulkString(payload),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_restore_with_options() {
+        let options = RestoreOptions {
+            replace: true,
+            absttl: true,
+            idletime: None,
+            freq: Some(5),
+        };
+        let restore = Restore::new("mykey", 1700000000000, Bytes::from_static(b"x"), options);
+        let frame: Frame = restore
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create RESTORE command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("RESTORE"),
+                Frame::bulk("mykey"),
+                Frame::bulk("1700000000000"),
+                Frame::bulk("x"),
+                Frame::bulk("REPLACE"),
+                Frame::bulk("ABSTTL"),
+                Frame::bulk("FREQ"),
+                Frame::bulk("5"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_restore_idletime_and_freq() {
+        let options = RestoreOptions {
+            idletime: Some(10),
+            freq: Some(5),
+            ..RestoreOptions::default()
+        };
+        let restore = Restore::new("mykey", 0, Bytes::from_static(b"x"), options);
+
+        assert!(matches!(
+            restore.to_frame(),
+            Err(RedisError::InvalidArgument(_))
+        ));
+    }
+}
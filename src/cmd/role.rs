@@ -0,0 +1,48 @@
+/// A Redis ROLE command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+#[derive(Default)]
+pub struct Role;
+
+impl Role {
+    /// Creates a new Role command.
+    ///
+    /// # Returns
+    ///
+    /// A new Role command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let role = Role::new();
+    /// ```
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Command for Role {
+    const NAME: &'static str = "ROLE";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("ROLE"))?;
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_role() {
+        let role = Role::new();
+        let frame: Frame = role
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ROLE command: {:?}", err));
+
+        assert_eq!(frame, Frame::Array(vec![Frame::bulk("ROLE")]));
+    }
+}
@@ -1,36 +1,43 @@
 /// A Redis RPOP command.
-use crate::{Result, cmd::Command, frame::Frame};
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
 use bytes::Bytes;
 
 pub struct RPop {
-    key: String,
+    key: Bytes,
     count: Option<u64>,
 }
 
 impl RPop {
-    pub fn new(key: &str, count: Option<u64>) -> Self {
+    pub fn new(key: impl ToRedisArg, count: Option<u64>) -> Self {
         Self {
-            key: key.to_string(),
+            key: key.to_redis_arg(),
             count,
         }
     }
 }
 
-impl Command for RPop {}
+impl Command for RPop {
+    const NAME: &'static str = "RPOP";
+    const IDEMPOTENT: bool = false;
 
-impl TryInto<Frame> for RPop {
-    type Error = crate::RedisError;
-
-    fn try_into(self) -> Result<Frame> {
+    fn to_frame(&self) -> Result<Frame> {
         let mut frame: Frame = Frame::array();
-        frame.push_frame_to_array(Frame::BulkString("RPOP".into()))?;
-        frame.push_frame_to_array(Frame::BulkString(Bytes::from(self.key)))?;
+        frame.push_frame_to_array(Frame::bulk("RPOP"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
         if let Some(count) = self.count {
-            frame.push_frame_to_array(Frame::Integer(count as i64))?;
+            frame.push_frame_to_array(Frame::bulk(count.to_string()))?;
         }
 
         Ok(frame)
     }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
 }
 
 #[cfg(test)]
@@ -41,28 +48,43 @@ mod tests {
     fn test_rpop() {
         let rpop = RPop::new("mylist", None);
         let frame: Frame = rpop
-            .try_into()
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create RPOP command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("RPOP"), Frame::bulk("mylist")])
+        );
+
+        let rpop = RPop::new("mylist", Some(2));
+        let frame: Frame = rpop
+            .to_frame()
             .unwrap_or_else(|err| panic!("Failed to create RPOP command: {:?}", err));
 
         assert_eq!(
             frame,
             Frame::Array(vec![
-                Frame::BulkString("RPOP".into()),
-                Frame::BulkString("mylist".into())
+                Frame::bulk("RPOP"),
+                Frame::bulk("mylist"),
+                Frame::bulk("2")
             ])
         );
+    }
 
-        let rpop = RPop::new("mylist", Some(2));
+    #[test]
+    fn test_rpop_zero_count() {
+        // the count is sent as is, the server replies with an empty array
+        let rpop = RPop::new("mylist", Some(0));
         let frame: Frame = rpop
-            .try_into()
+            .to_frame()
             .unwrap_or_else(|err| panic!("Failed to create RPOP command: {:?}", err));
 
         assert_eq!(
             frame,
             Frame::Array(vec![
-                Frame::BulkString("RPOP".into()),
-                Frame::BulkString("mylist".into()),
-                Frame::Integer(2)
+                Frame::bulk("RPOP"),
+                Frame::bulk("mylist"),
+                Frame::bulk("0")
             ])
         );
     }
@@ -1,9 +1,13 @@
 /// A Redis RPUSH command.
-use crate::{Result, cmd::Command, frame::Frame};
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
 use bytes::Bytes;
 
 pub struct RPush {
-    key: String,
+    key: Bytes,
     values: Vec<Vec<u8>>,
 }
 
@@ -24,30 +28,33 @@ impl RPush {
     /// ```ignore
     /// let rpush = RPush::new("mylist", vec!["value1", "value2"]);
     /// ```
-    pub fn new(key: &str, values: Vec<&[u8]>) -> Self {
+    pub fn new(key: impl ToRedisArg, values: Vec<&[u8]>) -> Self {
         Self {
-            key: key.to_string(),
+            key: key.to_redis_arg(),
             values: values.iter().map(|s| s.to_vec()).collect(),
         }
     }
 }
 
-impl Command for RPush {}
+impl Command for RPush {
+    const NAME: &'static str = "RPUSH";
+    const IDEMPOTENT: bool = false;
 
-impl TryInto<Frame> for RPush {
-    type Error = crate::RedisError;
-
-    fn try_into(self) -> Result<Frame> {
+    fn to_frame(&self) -> Result<Frame> {
         let mut frame: Frame = Frame::array();
-        frame.push_frame_to_array(Frame::BulkString("RPUSH".into()))?;
-        frame.push_frame_to_array(Frame::BulkString(Bytes::from(self.key)))?;
+        frame.push_frame_to_array(Frame::bulk("RPUSH"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
 
-        for value in self.values {
-            frame.push_frame_to_array(Frame::BulkString(Bytes::from(value)))?;
+        for value in &self.values {
+            frame.push_frame_to_array(Frame::bulk(value.clone()))?;
         }
 
         Ok(frame)
     }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
 }
 
 #[cfg(test)]
@@ -58,16 +65,16 @@ mod tests {
     fn test_rpush() {
         let rpush = RPush::new("mylist", vec!["value1".as_bytes(), "value2".as_bytes()]);
         let frame: Frame = rpush
-            .try_into()
+            .to_frame()
             .unwrap_or_else(|err| panic!("Failed to create RPUSH command: {:?}", err));
 
         assert_eq!(
             frame,
             Frame::Array(vec![
-                Frame::BulkString("RPUSH".into()),
-                Frame::BulkString("mylist".into()),
-                Frame::BulkString("value1".into()),
-                Frame::BulkString("value2".into()),
+                Frame::bulk("RPUSH"),
+                Frame::bulk("mylist"),
+                Frame::bulk("value1"),
+                Frame::bulk("value2"),
             ])
         )
     }
@@ -0,0 +1,97 @@
+/// A Redis SADD command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct SAdd {
+    key: Bytes,
+    members: Vec<Vec<u8>>,
+}
+
+impl SAdd {
+    /// Creates a new SADD command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The set to add the members to
+    /// * `members` - The members to add
+    ///
+    /// # Returns
+    ///
+    /// A new SADD command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let sadd = SAdd::new("myset", vec!["member1", "member2"]);
+    /// ```
+    pub fn new(key: impl ToRedisArg, members: Vec<&[u8]>) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+            members: members.iter().map(|s| s.to_vec()).collect(),
+        }
+    }
+}
+
+impl Command for SAdd {
+    const NAME: &'static str = "SADD";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let args = [Frame::bulk("SADD"), Frame::bulk(self.key.clone())];
+        let members = self
+            .members
+            .iter()
+            .map(|member| Frame::bulk(member.clone()));
+
+        Ok(Frame::array_from(args.into_iter().chain(members)))
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sadd() {
+        let sadd = SAdd::new("myset", vec![b"member1", b"member2"]);
+        let frame: Frame = sadd
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create SADD command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("SADD"),
+                Frame::bulk("myset"),
+                Frame::bulk("member1"),
+                Frame::bulk("member2"),
+            ])
+        )
+    }
+
+    #[test]
+    fn test_sadd_duplicate_members() {
+        // sent as an array, the server adds a member given twice once
+        let sadd = SAdd::new("myset", vec![b"member1", b"member1"]);
+        let frame: Frame = sadd
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create SADD command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("SADD"),
+                Frame::bulk("myset"),
+                Frame::bulk("member1"),
+                Frame::bulk("member1"),
+            ])
+        )
+    }
+}
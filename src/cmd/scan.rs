@@ -0,0 +1,92 @@
+/// A Redis SCAN command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+pub struct Scan {
+    cursor: u64,
+    pattern: Option<String>,
+    count: Option<u64>,
+}
+
+impl Scan {
+    /// Creates a new Scan command.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - The cursor to continue the iteration from, 0 starts a new iteration
+    /// * `pattern` - An optional glob-style pattern to filter the keys
+    /// * `count` - An optional hint of how many keys to return per call
+    ///
+    /// # Returns
+    ///
+    /// A new Scan command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let scan = Scan::new(0, Some("user:*"), Some(100));
+    /// ```
+    pub fn new(cursor: u64, pattern: Option<&str>, count: Option<u64>) -> Self {
+        Self {
+            cursor,
+            pattern: pattern.map(|p| p.to_string()),
+            count,
+        }
+    }
+}
+
+impl Command for Scan {
+    const NAME: &'static str = "SCAN";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("SCAN"))?;
+        frame.push_frame_to_array(Frame::bulk(self.cursor.to_string()))?;
+
+        if let Some(pattern) = &self.pattern {
+            frame.push_frame_to_array(Frame::bulk("MATCH"))?;
+            frame.push_frame_to_array(Frame::bulk(pattern.clone()))?;
+        }
+
+        if let Some(count) = self.count {
+            frame.push_frame_to_array(Frame::bulk("COUNT"))?;
+            frame.push_frame_to_array(Frame::bulk(count.to_string()))?;
+        }
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan() {
+        let scan = Scan::new(0, None, None);
+        let frame: Frame = scan
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create SCAN command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("SCAN"), Frame::bulk("0"),])
+        );
+
+        let scan = Scan::new(17, Some("user:*"), Some(100));
+        let frame: Frame = scan
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create SCAN command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("SCAN"),
+                Frame::bulk("17"),
+                Frame::bulk("MATCH"),
+                Frame::bulk("user:*"),
+                Frame::bulk("COUNT"),
+                Frame::bulk("100"),
+            ])
+        );
+    }
+}
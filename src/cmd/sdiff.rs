@@ -0,0 +1,75 @@
+/// A Redis SDIFF command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct SDiff {
+    keys: Vec<Bytes>,
+}
+
+impl SDiff {
+    /// Creates a new SDIFF command.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The first set, followed by the sets to subtract from it
+    ///
+    /// # Returns
+    ///
+    /// A new SDIFF command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let sdiff = SDiff::new(vec!["set1", "set2"]);
+    /// ```
+    pub fn new(keys: Vec<impl ToRedisArg>) -> Self {
+        Self {
+            keys: keys.iter().map(ToRedisArg::to_redis_arg).collect(),
+        }
+    }
+}
+
+impl Command for SDiff {
+    const NAME: &'static str = "SDIFF";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("SDIFF"))?;
+
+        for key in &self.keys {
+            frame.push_frame_to_array(Frame::bulk(key.clone()))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        (1..=self.keys.len()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sdiff() {
+        let sdiff = SDiff::new(vec!["set1", "set2"]);
+        let frame: Frame = sdiff
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create SDIFF command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("SDIFF"),
+                Frame::bulk("set1"),
+                Frame::bulk("set2"),
+            ])
+        );
+    }
+}
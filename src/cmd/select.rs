@@ -0,0 +1,57 @@
+/// A Redis SELECT command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+pub struct Select {
+    db: u64,
+}
+
+impl Select {
+    /// Creates a new Select command.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - The index of the logical database to switch to
+    ///
+    /// # Returns
+    ///
+    /// A new Select command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let select = Select::new(1);
+    /// ```
+    pub fn new(db: u64) -> Self {
+        Self { db }
+    }
+}
+
+impl Command for Select {
+    const NAME: &'static str = "SELECT";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("SELECT"))?;
+        frame.push_frame_to_array(Frame::bulk(self.db.to_string()))?;
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select() {
+        let select = Select::new(1);
+        let frame: Frame = select
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create SELECT command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("SELECT"), Frame::bulk("1")])
+        );
+    }
+}
@@ -0,0 +1,67 @@
+/// A Redis SENTINEL GET-MASTER-ADDR-BY-NAME command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+pub struct SentinelGetMasterAddrByName {
+    master_name: String,
+}
+
+impl SentinelGetMasterAddrByName {
+    /// Creates a new SentinelGetMasterAddrByName command.
+    ///
+    /// # Arguments
+    ///
+    /// * `master_name` - The name of the master monitored by the sentinel
+    ///
+    /// # Returns
+    ///
+    /// A new SentinelGetMasterAddrByName command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let master_addr = SentinelGetMasterAddrByName::new("mymaster");
+    /// ```
+    pub fn new(master_name: &str) -> Self {
+        Self {
+            master_name: master_name.to_string(),
+        }
+    }
+}
+
+impl Command for SentinelGetMasterAddrByName {
+    const NAME: &'static str = "SENTINEL GET-MASTER-ADDR-BY-NAME";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("SENTINEL"))?;
+        frame.push_frame_to_array(Frame::bulk("GET-MASTER-ADDR-BY-NAME"))?;
+        frame.push_frame_to_array(Frame::bulk(self.master_name.clone()))?;
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sentinel_get_master_addr_by_name() {
+        let master_addr = SentinelGetMasterAddrByName::new("mymaster");
+        let frame: Frame = master_addr.to_frame().unwrap_or_else(|err| {
+            panic!(
+                "Failed to create SENTINEL GET-MASTER-ADDR-BY-NAME command: {:?}",
+                err
+            )
+        });
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("SENTINEL"),
+                Frame::bulk("GET-MASTER-ADDR-BY-NAME"),
+                Frame::bulk("mymaster"),
+            ])
+        );
+    }
+}
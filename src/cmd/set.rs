@@ -1,12 +1,51 @@
 /// A Redis SET command.
-use crate::{Result, cmd::Command, frame::Frame};
+use crate::{
+    RedisError, Result,
+    cmd::{Command, Expiry, ToRedisArg},
+    frame::Frame,
+};
 use bytes::Bytes;
 
+/// The condition on the key existing for a SET command to set it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetCondition {
+    /// Only set the key if it does not exist.
+    NX,
+    /// Only set the key if it already exists.
+    XX,
+}
+
+/// The options of a SET command, none of them by default.
+#[derive(Debug, Default)]
+pub struct SetOptions {
+    /// Sets an expiry on the key, `Expiry::PERSIST` is not accepted by SET.
+    pub expiry: Option<Expiry>,
+    /// Keeps the TTL the key already has, instead of removing it.
+    pub keep_ttl: bool,
+    /// Only sets the key if the condition holds.
+    pub condition: Option<SetCondition>,
+    /// Returns the value previously stored at the key.
+    pub get: bool,
+}
+
+/// What a SET command did, as told by its reply.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SetOutcome {
+    /// The key was set.
+    Set,
+    /// The key was not set, because the condition did not hold.
+    NotSet,
+    /// The value previously stored at the key with `SetOptions::get`, None if there was none.
+    ///
+    /// The key may not have been set if a condition is also given.
+    PreviousValue(Option<Vec<u8>>),
+}
+
 /// A Redis SET command.
 pub struct Set {
-    key: String,
+    key: Bytes,
     value: Bytes,
-    _options: Option<Vec<String>>,
+    options: SetOptions,
 }
 
 impl Set {
@@ -26,28 +65,89 @@ impl Set {
     /// ```ignore
     /// let set = Set::new("mykey", "myvalue");
     /// ```
-    pub fn new(key: &str, value: &[u8]) -> Self {
+    pub fn new(key: impl ToRedisArg, value: &[u8]) -> Self {
+        Self::with_options(key, value, SetOptions::default())
+    }
+
+    /// Creates a new Set command with options.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to set in the Redis server
+    /// * `value` - The value to set in the Redis server
+    /// * `options` - The SET options
+    ///
+    /// # Returns
+    ///
+    /// A new Set command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let options = SetOptions {
+    ///     condition: Some(SetCondition::NX),
+    ///     get: true,
+    ///     ..Default::default()
+    /// };
+    /// let set = Set::with_options("mykey", "myvalue", options);
+    /// ```
+    pub fn with_options(key: impl ToRedisArg, value: &[u8], options: SetOptions) -> Self {
         Self {
-            key: key.to_string(),
+            key: key.to_redis_arg(),
             value: Bytes::copy_from_slice(value),
-            _options: None,
+            options,
         }
     }
 }
 
-impl Command for Set {}
+impl Command for Set {
+    const NAME: &'static str = "SET";
 
-impl TryInto<Frame> for Set {
-    type Error = crate::RedisError;
-
-    fn try_into(self) -> Result<Frame> {
+    fn to_frame(&self) -> Result<Frame> {
         let mut frame: Frame = Frame::array();
-        frame.push_frame_to_array(Frame::BulkString("SET".into()))?;
-        frame.push_frame_to_array(Frame::BulkString(Bytes::from(self.key)))?;
-        frame.push_frame_to_array(Frame::BulkString(self.value))?;
+        frame.push_frame_to_array(Frame::bulk("SET"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+        frame.push_frame_to_array(Frame::bulk(self.value.clone()))?;
+
+        match self.options.condition {
+            Some(SetCondition::NX) => frame.push_frame_to_array(Frame::bulk("NX"))?,
+            Some(SetCondition::XX) => frame.push_frame_to_array(Frame::bulk("XX"))?,
+            None => {}
+        }
+
+        if self.options.get {
+            frame.push_frame_to_array(Frame::bulk("GET"))?;
+        }
+
+        match &self.options.expiry {
+            Some(_) if self.options.keep_ttl => {
+                return Err(RedisError::InvalidArgument(
+                    "an expiry cannot be set together with KEEPTTL".to_string(),
+                ));
+            }
+            Some(Expiry::PERSIST) => {
+                return Err(RedisError::InvalidArgument(
+                    "SET does not accept PERSIST, leave the expiry out instead".to_string(),
+                ));
+            }
+            Some(expiry) => {
+                let (option, value) = expiry.to_args()?;
+                frame.push_frame_to_array(Frame::bulk(option))?;
+
+                if let Some(value) = value {
+                    frame.push_frame_to_array(Frame::bulk(value.to_string()))?;
+                }
+            }
+            None if self.options.keep_ttl => frame.push_frame_to_array(Frame::bulk("KEEPTTL"))?,
+            None => {}
+        }
 
         Ok(frame)
     }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
 }
 
 #[cfg(test)]
@@ -58,16 +158,86 @@ mod tests {
     fn test_set() {
         let set = Set::new("mykey", "myvalue".as_bytes());
         let frame: Frame = set
-            .try_into()
+            .to_frame()
             .unwrap_or_else(|err| panic!("Failed to create SET command: {:?}", err));
 
         assert_eq!(
             frame,
             Frame::Array(vec![
-                Frame::BulkString("SET".into()),
-                Frame::BulkString("mykey".into()),
-                Frame::BulkString("myvalue".into()),
+                Frame::bulk("SET"),
+                Frame::bulk("mykey"),
+                Frame::bulk("myvalue"),
             ])
-        )
+        );
+        assert_eq!(set.key_indices(), vec![1]);
+    }
+
+    #[test]
+    fn test_set_options() {
+        let cases = vec![
+            (
+                SetOptions {
+                    condition: Some(SetCondition::NX),
+                    ..Default::default()
+                },
+                vec!["NX"],
+            ),
+            (
+                SetOptions {
+                    condition: Some(SetCondition::XX),
+                    get: true,
+                    expiry: Some(Expiry::PX(1500)),
+                    ..Default::default()
+                },
+                vec!["XX", "GET", "PX", "1500"],
+            ),
+            (
+                SetOptions {
+                    get: true,
+                    keep_ttl: true,
+                    ..Default::default()
+                },
+                vec!["GET", "KEEPTTL"],
+            ),
+        ];
+
+        for (options, args) in cases {
+            let frame: Frame = Set::with_options("mykey", b"myvalue", options)
+                .to_frame()
+                .unwrap_or_else(|err| panic!("Failed to create SET command: {:?}", err));
+
+            let mut expected = vec![
+                Frame::bulk("SET"),
+                Frame::bulk("mykey"),
+                Frame::bulk("myvalue"),
+            ];
+            expected.extend(args.into_iter().map(Frame::bulk));
+
+            assert_eq!(frame, Frame::Array(expected));
+        }
+    }
+
+    #[test]
+    fn test_set_invalid_options() {
+        let cases = vec![
+            SetOptions {
+                expiry: Some(Expiry::EX(10)),
+                keep_ttl: true,
+                ..Default::default()
+            },
+            SetOptions {
+                expiry: Some(Expiry::PERSIST),
+                ..Default::default()
+            },
+            SetOptions {
+                expiry: Some(Expiry::EX(0)),
+                ..Default::default()
+            },
+        ];
+
+        for options in cases {
+            let result = Set::with_options("mykey", b"myvalue", options).to_frame();
+            assert!(matches!(result, Err(RedisError::InvalidArgument(_))));
+        }
     }
 }
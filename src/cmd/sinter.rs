@@ -0,0 +1,75 @@
+/// A Redis SINTER command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct SInter {
+    keys: Vec<Bytes>,
+}
+
+impl SInter {
+    /// Creates a new SINTER command.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The sets to intersect
+    ///
+    /// # Returns
+    ///
+    /// A new SINTER command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let sinter = SInter::new(vec!["set1", "set2"]);
+    /// ```
+    pub fn new(keys: Vec<impl ToRedisArg>) -> Self {
+        Self {
+            keys: keys.iter().map(ToRedisArg::to_redis_arg).collect(),
+        }
+    }
+}
+
+impl Command for SInter {
+    const NAME: &'static str = "SINTER";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("SINTER"))?;
+
+        for key in &self.keys {
+            frame.push_frame_to_array(Frame::bulk(key.clone()))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        (1..=self.keys.len()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sinter() {
+        let sinter = SInter::new(vec!["set1", "set2"]);
+        let frame: Frame = sinter
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create SINTER command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("SINTER"),
+                Frame::bulk("set1"),
+                Frame::bulk("set2"),
+            ])
+        );
+    }
+}
@@ -0,0 +1,109 @@
+/// A Redis SINTERCARD command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct SInterCard {
+    keys: Vec<Bytes>,
+    limit: Option<u64>,
+}
+
+impl SInterCard {
+    /// Creates a new SInterCard command.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The sets to intersect
+    /// * `limit` - Stops counting once the intersection reaches this many members, 0 for no limit
+    ///
+    /// # Returns
+    ///
+    /// A new SInterCard command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let sintercard = SInterCard::new(vec!["set1", "set2"], Some(10));
+    /// ```
+    pub fn new(keys: Vec<impl ToRedisArg>, limit: Option<u64>) -> Self {
+        Self {
+            keys: keys.iter().map(ToRedisArg::to_redis_arg).collect(),
+            limit,
+        }
+    }
+}
+
+impl Command for SInterCard {
+    const NAME: &'static str = "SINTERCARD";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("SINTERCARD"))?;
+        frame.push_frame_to_array(Frame::bulk(self.keys.len().to_string()))?;
+
+        for key in &self.keys {
+            frame.push_frame_to_array(Frame::bulk(key.clone()))?;
+        }
+
+        if let Some(limit) = self.limit {
+            frame.push_frame_to_array(Frame::bulk("LIMIT"))?;
+            frame.push_frame_to_array(Frame::bulk(limit.to_string()))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        // the keys follow their number
+        (2..2 + self.keys.len()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sintercard() {
+        let sintercard = SInterCard::new(vec!["set1", "set2"], None);
+        let frame: Frame = sintercard
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create SINTERCARD command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("SINTERCARD"),
+                Frame::bulk("2"),
+                Frame::bulk("set1"),
+                Frame::bulk("set2"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_sintercard_limit() {
+        // LIMIT 0 is sent as is, the server reads it as no limit
+        for limit in [0, 5] {
+            let sintercard = SInterCard::new(vec!["set1", "set2"], Some(limit));
+            let frame: Frame = sintercard
+                .to_frame()
+                .unwrap_or_else(|err| panic!("Failed to create SINTERCARD command: {:?}", err));
+
+            assert_eq!(
+                frame,
+                Frame::Array(vec![
+                    Frame::bulk("SINTERCARD"),
+                    Frame::bulk("2"),
+                    Frame::bulk("set1"),
+                    Frame::bulk("set2"),
+                    Frame::bulk("LIMIT"),
+                    Frame::bulk(limit.to_string()),
+                ])
+            );
+        }
+    }
+}
@@ -0,0 +1,81 @@
+/// A Redis SINTERSTORE command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct SInterStore {
+    destination: Bytes,
+    keys: Vec<Bytes>,
+}
+
+impl SInterStore {
+    /// Creates a new SINTERSTORE command.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - The key to store the resulting set at, overwritten if it exists
+    /// * `keys` - The sets to intersect
+    ///
+    /// # Returns
+    ///
+    /// A new SINTERSTORE command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let sinterstore = SInterStore::new("result", vec!["set1", "set2"]);
+    /// ```
+    pub fn new(destination: impl ToRedisArg, keys: Vec<impl ToRedisArg>) -> Self {
+        Self {
+            destination: destination.to_redis_arg(),
+            keys: keys.iter().map(ToRedisArg::to_redis_arg).collect(),
+        }
+    }
+}
+
+impl Command for SInterStore {
+    const NAME: &'static str = "SINTERSTORE";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("SINTERSTORE"))?;
+        frame.push_frame_to_array(Frame::bulk(self.destination.clone()))?;
+
+        for key in &self.keys {
+            frame.push_frame_to_array(Frame::bulk(key.clone()))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        // the destination, then the keys
+        (1..=self.keys.len() + 1).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sinterstore() {
+        let sinterstore = SInterStore::new("result", vec!["set1", "set2"]);
+        let frame: Frame = sinterstore
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create SINTERSTORE command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("SINTERSTORE"),
+                Frame::bulk("result"),
+                Frame::bulk("set1"),
+                Frame::bulk("set2"),
+            ])
+        );
+    }
+}
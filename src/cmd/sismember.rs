@@ -0,0 +1,76 @@
+/// A Redis SISMEMBER command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct SIsMember {
+    key: Bytes,
+    member: Bytes,
+}
+
+impl SIsMember {
+    /// Creates a new SIsMember command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The set to look the member up in
+    /// * `member` - The member to check
+    ///
+    /// # Returns
+    ///
+    /// A new SIsMember command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let sismember = SIsMember::new("myset", b"alice");
+    /// ```
+    pub fn new(key: impl ToRedisArg, member: &[u8]) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+            member: Bytes::copy_from_slice(member),
+        }
+    }
+}
+
+impl Command for SIsMember {
+    const NAME: &'static str = "SISMEMBER";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("SISMEMBER"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+        frame.push_frame_to_array(Frame::bulk(self.member.clone()))?;
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sismember() {
+        let sismember = SIsMember::new("myset", b"alice");
+        let frame: Frame = sismember
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create SISMEMBER command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("SISMEMBER"),
+                Frame::bulk("myset"),
+                Frame::bulk("alice"),
+            ])
+        );
+    }
+}
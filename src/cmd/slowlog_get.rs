@@ -0,0 +1,208 @@
+/// A Redis SLOWLOG GET command.
+use crate::{
+    RedisError, Result,
+    cmd::{Command, frame_to_string},
+    frame::Frame,
+};
+
+/// A command logged by the server for exceeding `slowlog-log-slower-than`, returned by
+/// `Client::slowlog_get`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlowlogEntry {
+    /// The unique, increasing ID of the entry, reset only with the server.
+    pub id: u64,
+    /// The Unix time at which the command was processed, in seconds.
+    pub timestamp: u64,
+    /// How long the command took to run, in microseconds.
+    pub duration_micros: u64,
+    /// The command and its arguments, possibly truncated by the server.
+    pub args: Vec<Vec<u8>>,
+    /// The address of the client that sent the command, since Redis 4.0.
+    pub client_addr: Option<String>,
+    /// The name of the client set with CLIENT SETNAME, since Redis 4.0. None when unnamed.
+    pub client_name: Option<String>,
+}
+
+impl TryFrom<Frame> for SlowlogEntry {
+    type Error = RedisError;
+
+    /// Parses an entry, `[id, timestamp, duration, args, addr, name]`, without the last two items
+    /// before Redis 4.0.
+    fn try_from(frame: Frame) -> Result<Self> {
+        let Frame::Array(items) = frame else {
+            return Err(RedisError::UnexpectedResponseType);
+        };
+
+        let mut items = items.into_iter();
+        let (Some(id), Some(timestamp), Some(duration), Some(args)) =
+            (items.next(), items.next(), items.next(), items.next())
+        else {
+            return Err(RedisError::UnexpectedResponseType);
+        };
+        let client_addr = items.next().map(frame_to_string).transpose()?;
+        let client_name = items
+            .next()
+            .map(frame_to_string)
+            .transpose()?
+            .filter(|name| !name.is_empty());
+
+        let Frame::Array(args) = args else {
+            return Err(RedisError::UnexpectedResponseType);
+        };
+
+        Ok(SlowlogEntry {
+            id: frame_to_u64(id)?,
+            timestamp: frame_to_u64(timestamp)?,
+            duration_micros: frame_to_u64(duration)?,
+            args: args
+                .into_iter()
+                .map(|arg| match arg {
+                    Frame::BulkString(data) => Ok(data.to_vec()),
+                    Frame::SimpleString(data) => Ok(data.into_bytes()),
+                    _ => Err(RedisError::UnexpectedResponseType),
+                })
+                .collect::<Result<_>>()?,
+            client_addr,
+            client_name,
+        })
+    }
+}
+
+fn frame_to_u64(frame: Frame) -> Result<u64> {
+    match frame {
+        Frame::Integer(data) => Ok(u64::try_from(data)?),
+        _ => Err(RedisError::UnexpectedResponseType),
+    }
+}
+
+pub struct SlowlogGet {
+    count: Option<i64>,
+}
+
+impl SlowlogGet {
+    /// Creates a new SlowlogGet command.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The number of entries to return, the 10 most recent if None, all of them with
+    ///   -1 since Redis 7.0
+    ///
+    /// # Returns
+    ///
+    /// A new SlowlogGet command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let slowlog_get = SlowlogGet::new(Some(5));
+    /// ```
+    pub fn new(count: Option<i64>) -> Self {
+        Self { count }
+    }
+}
+
+impl Command for SlowlogGet {
+    const NAME: &'static str = "SLOWLOG GET";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("SLOWLOG"))?;
+        frame.push_frame_to_array(Frame::bulk("GET"))?;
+
+        if let Some(count) = self.count {
+            frame.push_frame_to_array(Frame::bulk(count.to_string()))?;
+        }
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_slowlog_get() {
+        let slowlog_get = SlowlogGet::new(None);
+        let frame: Frame = slowlog_get
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create SLOWLOG GET command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("SLOWLOG"), Frame::bulk("GET")])
+        );
+
+        let slowlog_get = SlowlogGet::new(Some(-1));
+        let frame: Frame = slowlog_get
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create SLOWLOG GET command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("SLOWLOG"),
+                Frame::bulk("GET"),
+                Frame::bulk("-1"),
+            ])
+        );
+    }
+
+    fn parse(bytes: &[u8]) -> SlowlogEntry {
+        let frame = Frame::try_parse(&mut Cursor::new(bytes))
+            .unwrap_or_else(|err| panic!("Failed to parse SLOWLOG entry: {:?}", err));
+
+        SlowlogEntry::try_from(frame)
+            .unwrap_or_else(|err| panic!("Failed to parse SLOWLOG entry: {:?}", err))
+    }
+
+    #[test]
+    fn test_slowlog_entry() {
+        let bytes: &[u8] = b"*6\r\n:14\r\n:1700000000\r\n:1250\r\n\
+            *3\r\n$3\r\nSET\r\n$5\r\nmykey\r\n$7\r\nmyvalue\r\n\
+            $15\r\n127.0.0.1:52110\r\n$6\r\nworker\r\n";
+
+        assert_eq!(
+            parse(bytes),
+            SlowlogEntry {
+                id: 14,
+                timestamp: 1700000000,
+                duration_micros: 1250,
+                args: vec![b"SET".to_vec(), b"mykey".to_vec(), b"myvalue".to_vec()],
+                client_addr: Some("127.0.0.1:52110".to_string()),
+                client_name: Some("worker".to_string()),
+            }
+        );
+
+        // an unnamed client has an empty name
+        let bytes: &[u8] = b"*6\r\n:15\r\n:1700000001\r\n:3\r\n\
+            *1\r\n$4\r\nPING\r\n$15\r\n127.0.0.1:52110\r\n$0\r\n\r\n";
+        assert_eq!(parse(bytes).client_name, None);
+    }
+
+    #[test]
+    fn test_slowlog_entry_before_redis_4() {
+        let bytes: &[u8] = b"*4\r\n:3\r\n:1300000000\r\n:12\r\n\
+            *2\r\n$4\r\nKEYS\r\n$1\r\n*\r\n";
+
+        assert_eq!(
+            parse(bytes),
+            SlowlogEntry {
+                id: 3,
+                timestamp: 1300000000,
+                duration_micros: 12,
+                args: vec![b"KEYS".to_vec(), b"*".to_vec()],
+                client_addr: None,
+                client_name: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_slowlog_entry_malformed() {
+        let frame = Frame::Array(vec![Frame::Integer(3), Frame::Integer(1300000000)]);
+
+        assert!(SlowlogEntry::try_from(frame).is_err());
+    }
+}
@@ -0,0 +1,52 @@
+/// A Redis SLOWLOG LEN command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+#[derive(Default)]
+pub struct SlowlogLen;
+
+impl SlowlogLen {
+    /// Creates a new SlowlogLen command.
+    ///
+    /// # Returns
+    ///
+    /// A new SlowlogLen command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let slowlog_len = SlowlogLen::new();
+    /// ```
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Command for SlowlogLen {
+    const NAME: &'static str = "SLOWLOG LEN";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("SLOWLOG"))?;
+        frame.push_frame_to_array(Frame::bulk("LEN"))?;
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slowlog_len() {
+        let slowlog_len = SlowlogLen::new();
+        let frame: Frame = slowlog_len
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create SLOWLOG LEN command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("SLOWLOG"), Frame::bulk("LEN")])
+        );
+    }
+}
@@ -0,0 +1,52 @@
+/// A Redis SLOWLOG RESET command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+#[derive(Default)]
+pub struct SlowlogReset;
+
+impl SlowlogReset {
+    /// Creates a new SlowlogReset command.
+    ///
+    /// # Returns
+    ///
+    /// A new SlowlogReset command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let slowlog_reset = SlowlogReset::new();
+    /// ```
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Command for SlowlogReset {
+    const NAME: &'static str = "SLOWLOG RESET";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("SLOWLOG"))?;
+        frame.push_frame_to_array(Frame::bulk("RESET"))?;
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slowlog_reset() {
+        let slowlog_reset = SlowlogReset::new();
+        let frame: Frame = slowlog_reset
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create SLOWLOG RESET command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("SLOWLOG"), Frame::bulk("RESET")])
+        );
+    }
+}
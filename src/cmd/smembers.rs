@@ -0,0 +1,68 @@
+/// A Redis SMEMBERS command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct SMembers {
+    key: Bytes,
+}
+
+impl SMembers {
+    /// Creates a new SMEMBERS command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The set to get the members of
+    ///
+    /// # Returns
+    ///
+    /// A new SMEMBERS command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let smembers = SMembers::new("myset");
+    /// ```
+    pub fn new(key: impl ToRedisArg) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+        }
+    }
+}
+
+impl Command for SMembers {
+    const NAME: &'static str = "SMEMBERS";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("SMEMBERS"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_smembers() {
+        let smembers = SMembers::new("myset");
+        let frame: Frame = smembers
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create SMEMBERS command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("SMEMBERS"), Frame::bulk("myset"),])
+        );
+    }
+}
@@ -0,0 +1,80 @@
+/// A Redis SMISMEMBER command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct SMIsMember {
+    key: Bytes,
+    members: Vec<Bytes>,
+}
+
+impl SMIsMember {
+    /// Creates a new SMIsMember command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The set to look the members up in
+    /// * `members` - The members to check
+    ///
+    /// # Returns
+    ///
+    /// A new SMIsMember command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let smismember = SMIsMember::new("myset", vec![b"alice", b"bob"]);
+    /// ```
+    pub fn new(key: impl ToRedisArg, members: Vec<&[u8]>) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+            members: members.into_iter().map(Bytes::copy_from_slice).collect(),
+        }
+    }
+}
+
+impl Command for SMIsMember {
+    const NAME: &'static str = "SMISMEMBER";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("SMISMEMBER"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+
+        for member in &self.members {
+            frame.push_frame_to_array(Frame::bulk(member.clone()))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_smismember() {
+        let smismember = SMIsMember::new("myset", vec![b"alice", b"bob"]);
+        let frame: Frame = smismember
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create SMISMEMBER command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("SMISMEMBER"),
+                Frame::bulk("myset"),
+                Frame::bulk("alice"),
+                Frame::bulk("bob"),
+            ])
+        );
+    }
+}
@@ -0,0 +1,92 @@
+/// A Redis SRANDMEMBER command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct SRandMember {
+    key: Bytes,
+    count: Option<i64>,
+}
+
+impl SRandMember {
+    /// Creates a new SRandMember command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The set to pick the members from
+    /// * `count` - The number of members to pick, negative to allow picking a member more than once
+    ///
+    /// # Returns
+    ///
+    /// A new SRandMember command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let srandmember = SRandMember::new("myset", Some(-5));
+    /// ```
+    pub fn new(key: impl ToRedisArg, count: Option<i64>) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+            count,
+        }
+    }
+}
+
+impl Command for SRandMember {
+    const NAME: &'static str = "SRANDMEMBER";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("SRANDMEMBER"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+
+        if let Some(count) = self.count {
+            frame.push_frame_to_array(Frame::bulk(count.to_string()))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_srandmember() {
+        let srandmember = SRandMember::new("myset", None);
+        let frame: Frame = srandmember
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create SRANDMEMBER command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("SRANDMEMBER"), Frame::bulk("myset")])
+        );
+    }
+
+    #[test]
+    fn test_srandmember_negative_count() {
+        let srandmember = SRandMember::new("myset", Some(-5));
+        let frame: Frame = srandmember
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create SRANDMEMBER command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("SRANDMEMBER"),
+                Frame::bulk("myset"),
+                Frame::bulk("-5"),
+            ])
+        );
+    }
+}
@@ -1,11 +1,79 @@
-#[allow(dead_code)]
+/// A Redis SUBSCRIBE command.
+use crate::{Result, cmd::Command, frame::Frame};
+
 pub struct Subscribe {
     channels: Vec<String>,
 }
 
-impl Subscribe {}
+impl Subscribe {
+    /// Creates a new Subscribe command.
+    ///
+    /// # Arguments
+    ///
+    /// * `channels` - The channels to subscribe to
+    ///
+    /// # Returns
+    ///
+    /// A new Subscribe command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let subscribe = Subscribe::new(vec!["news", "weather"]);
+    /// ```
+    pub fn new(channels: Vec<&str>) -> Self {
+        Self {
+            channels: channels.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+}
 
-#[allow(dead_code)]
-pub struct Unsubscribe {
-    channels: Vec<String>,
+impl Command for Subscribe {
+    const NAME: &'static str = "SUBSCRIBE";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("SUBSCRIBE"))?;
+
+        for channel in &self.channels {
+            frame.push_frame_to_array(Frame::bulk(channel.clone()))?;
+        }
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscribe() {
+        let subscribe = Subscribe::new(vec!["news", "weather"]);
+        let frame: Frame = subscribe
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create SUBSCRIBE command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("SUBSCRIBE"),
+                Frame::bulk("news"),
+                Frame::bulk("weather"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_subscribe_single_channel() {
+        let subscribe = Subscribe::new(vec!["news"]);
+        let frame: Frame = subscribe
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create SUBSCRIBE command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("SUBSCRIBE"), Frame::bulk("news")])
+        );
+    }
 }
@@ -0,0 +1,75 @@
+/// A Redis SUNION command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct SUnion {
+    keys: Vec<Bytes>,
+}
+
+impl SUnion {
+    /// Creates a new SUNION command.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The sets to join
+    ///
+    /// # Returns
+    ///
+    /// A new SUNION command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let sunion = SUnion::new(vec!["set1", "set2"]);
+    /// ```
+    pub fn new(keys: Vec<impl ToRedisArg>) -> Self {
+        Self {
+            keys: keys.iter().map(ToRedisArg::to_redis_arg).collect(),
+        }
+    }
+}
+
+impl Command for SUnion {
+    const NAME: &'static str = "SUNION";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("SUNION"))?;
+
+        for key in &self.keys {
+            frame.push_frame_to_array(Frame::bulk(key.clone()))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        (1..=self.keys.len()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sunion() {
+        let sunion = SUnion::new(vec!["set1", "set2"]);
+        let frame: Frame = sunion
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create SUNION command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("SUNION"),
+                Frame::bulk("set1"),
+                Frame::bulk("set2"),
+            ])
+        );
+    }
+}
@@ -0,0 +1,75 @@
+/// A Redis TOUCH command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct Touch {
+    keys: Vec<Bytes>,
+}
+
+impl Touch {
+    /// Creates a new Touch command.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The keys to update the last access time of
+    ///
+    /// # Returns
+    ///
+    /// A new Touch command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let touch = Touch::new(vec!["key1", "key2"]);
+    /// ```
+    pub fn new(keys: Vec<impl ToRedisArg>) -> Self {
+        Self {
+            keys: keys.iter().map(ToRedisArg::to_redis_arg).collect(),
+        }
+    }
+}
+
+impl Command for Touch {
+    const NAME: &'static str = "TOUCH";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("TOUCH"))?;
+
+        for key in &self.keys {
+            frame.push_frame_to_array(Frame::bulk(key.clone()))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        (1..=self.keys.len()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_touch() {
+        let touch = Touch::new(vec!["key1", "key2"]);
+        let frame: Frame = touch
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create TOUCH command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("TOUCH"),
+                Frame::bulk("key1"),
+                Frame::bulk("key2"),
+            ])
+        );
+    }
+}
@@ -1,9 +1,13 @@
 /// A Redis TTL command.
-use crate::{Result, cmd::Command, frame::Frame};
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
 use bytes::Bytes;
 
 pub struct Ttl {
-    key: String,
+    key: Bytes,
 }
 
 impl Ttl {
@@ -22,25 +26,27 @@ impl Ttl {
     /// ```ignore
     /// let ttl = Ttl::new("mykey");
     /// ```
-    pub fn new(key: &str) -> Self {
+    pub fn new(key: impl ToRedisArg) -> Self {
         Self {
-            key: key.to_string(),
+            key: key.to_redis_arg(),
         }
     }
 }
 
-impl Command for Ttl {}
+impl Command for Ttl {
+    const NAME: &'static str = "TTL";
 
-impl TryInto<Frame> for Ttl {
-    type Error = crate::RedisError;
-
-    fn try_into(self) -> Result<Frame> {
+    fn to_frame(&self) -> Result<Frame> {
         let mut frame: Frame = Frame::array();
-        frame.push_frame_to_array(Frame::BulkString("TTL".into()))?;
-        frame.push_frame_to_array(Frame::BulkString(Bytes::from(self.key)))?;
+        frame.push_frame_to_array(Frame::bulk("TTL"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
 
         Ok(frame)
     }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
 }
 
 #[cfg(test)]
@@ -51,15 +57,12 @@ mod tests {
     fn test_ttl() {
         let ttl = Ttl::new("mykey");
         let frame: Frame = ttl
-            .try_into()
+            .to_frame()
             .unwrap_or_else(|err| panic!("Failed to create TTL command: {:?}", err));
 
         assert_eq!(
             frame,
-            Frame::Array(vec![
-                Frame::BulkString("TTL".into()),
-                Frame::BulkString("mykey".into()),
-            ])
+            Frame::Array(vec![Frame::bulk("TTL"), Frame::bulk("mykey"),])
         );
     }
 }
@@ -0,0 +1,75 @@
+/// A Redis UNLINK command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct Unlink {
+    keys: Vec<Bytes>,
+}
+
+impl Unlink {
+    /// Creates a new Unlink command.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The keys to delete from the Redis server
+    ///
+    /// # Returns
+    ///
+    /// A new Unlink command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let unlink = Unlink::new(vec!["key1", "key2"]);
+    /// ```
+    pub fn new(keys: Vec<impl ToRedisArg>) -> Self {
+        Self {
+            keys: keys.iter().map(ToRedisArg::to_redis_arg).collect(),
+        }
+    }
+}
+
+impl Command for Unlink {
+    const NAME: &'static str = "UNLINK";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("UNLINK"))?;
+
+        for key in &self.keys {
+            frame.push_frame_to_array(Frame::bulk(key.clone()))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        (1..=self.keys.len()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlink() {
+        let unlink = Unlink::new(vec!["key1", "key2"]);
+        let frame: Frame = unlink
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create UNLINK command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("UNLINK"),
+                Frame::bulk("key1"),
+                Frame::bulk("key2"),
+            ])
+        );
+    }
+}
@@ -1,6 +1,90 @@
-#[allow(dead_code)]
+/// A Redis UNSUBSCRIBE command.
+use crate::{Result, cmd::Command, frame::Frame};
+
 pub struct Unsubscribe {
     channels: Vec<String>,
 }
 
-impl Unsubscribe {}
+impl Unsubscribe {
+    /// Creates a new Unsubscribe command.
+    ///
+    /// # Arguments
+    ///
+    /// * `channels` - The channels to unsubscribe from, all of them if empty
+    ///
+    /// # Returns
+    ///
+    /// A new Unsubscribe command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let unsubscribe = Unsubscribe::new(vec!["news", "weather"]);
+    /// ```
+    pub fn new(channels: Vec<&str>) -> Self {
+        Self {
+            channels: channels.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+}
+
+impl Command for Unsubscribe {
+    const NAME: &'static str = "UNSUBSCRIBE";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("UNSUBSCRIBE"))?;
+
+        for channel in &self.channels {
+            frame.push_frame_to_array(Frame::bulk(channel.clone()))?;
+        }
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unsubscribe() {
+        let unsubscribe = Unsubscribe::new(vec!["news", "weather"]);
+        let frame: Frame = unsubscribe
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create UNSUBSCRIBE command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("UNSUBSCRIBE"),
+                Frame::bulk("news"),
+                Frame::bulk("weather"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_unsubscribe_single_channel() {
+        let unsubscribe = Unsubscribe::new(vec!["news"]);
+        let frame: Frame = unsubscribe
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create UNSUBSCRIBE command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("UNSUBSCRIBE"), Frame::bulk("news")])
+        );
+    }
+
+    #[test]
+    fn test_unsubscribe_all_channels() {
+        // without channels, the server unsubscribes from all of them
+        let unsubscribe = Unsubscribe::new(vec![]);
+        let frame: Frame = unsubscribe
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create UNSUBSCRIBE command: {:?}", err));
+
+        assert_eq!(frame, Frame::Array(vec![Frame::bulk("UNSUBSCRIBE")]));
+    }
+}
@@ -0,0 +1,48 @@
+/// A Redis UNWATCH command.
+use crate::{Result, cmd::Command, frame::Frame};
+
+#[derive(Default)]
+pub struct Unwatch;
+
+impl Unwatch {
+    /// Creates a new Unwatch command.
+    ///
+    /// # Returns
+    ///
+    /// A new Unwatch command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let unwatch = Unwatch::new();
+    /// ```
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Command for Unwatch {
+    const NAME: &'static str = "UNWATCH";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("UNWATCH"))?;
+
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unwatch() {
+        let unwatch = Unwatch::new();
+        let frame: Frame = unwatch
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create UNWATCH command: {:?}", err));
+
+        assert_eq!(frame, Frame::Array(vec![Frame::bulk("UNWATCH")]));
+    }
+}
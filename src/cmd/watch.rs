@@ -0,0 +1,87 @@
+/// A Redis WATCH command.
+use crate::{
+    RedisError, Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct Watch {
+    keys: Vec<Bytes>,
+}
+
+impl Watch {
+    /// Creates a new Watch command.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The keys to watch for changes until EXEC
+    ///
+    /// # Returns
+    ///
+    /// A new Watch command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let watch = Watch::new(vec!["key1", "key2"]);
+    /// ```
+    pub fn new(keys: Vec<impl ToRedisArg>) -> Self {
+        Self {
+            keys: keys.iter().map(ToRedisArg::to_redis_arg).collect(),
+        }
+    }
+}
+
+impl Command for Watch {
+    const NAME: &'static str = "WATCH";
+
+    fn to_frame(&self) -> Result<Frame> {
+        if self.keys.is_empty() {
+            return Err(RedisError::InvalidArgument(
+                "WATCH requires at least one key".to_string(),
+            ));
+        }
+
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("WATCH"))?;
+
+        for key in &self.keys {
+            frame.push_frame_to_array(Frame::bulk(key.clone()))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        (1..=self.keys.len()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_watch() {
+        let watch = Watch::new(vec!["key1", "key2"]);
+        let frame: Frame = watch
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create WATCH command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("WATCH"),
+                Frame::bulk("key1"),
+                Frame::bulk("key2"),
+            ])
+        );
+
+        let watch = Watch::new(Vec::<&str>::new());
+        assert!(matches!(
+            watch.to_frame(),
+            Err(RedisError::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,105 @@
+/// A Redis ZADD command.
+use crate::{
+    RedisError, Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct ZAdd {
+    key: Bytes,
+    members: Vec<(f64, Bytes)>,
+}
+
+impl ZAdd {
+    /// Creates a new ZAdd command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The sorted set to add the members to
+    /// * `members` - The scores and the members to add, or to update the score of
+    ///
+    /// # Returns
+    ///
+    /// A new ZAdd command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let zadd = ZAdd::new("leaderboard", vec![(1.5, b"alice"), (2.0, b"bob")]);
+    /// ```
+    pub fn new(key: impl ToRedisArg, members: Vec<(f64, &[u8])>) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+            members: members
+                .into_iter()
+                .map(|(score, member)| (score, Bytes::copy_from_slice(member)))
+                .collect(),
+        }
+    }
+}
+
+impl Command for ZAdd {
+    const NAME: &'static str = "ZADD";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("ZADD"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+
+        for (score, member) in &self.members {
+            frame.push_frame_to_array(Frame::bulk(format_double(*score)?))?;
+            frame.push_frame_to_array(Frame::bulk(member.clone()))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+/// Formats a double as a command argument, the server rejects NaN.
+///
+/// The shortest representation that parses back to the same value is used, so no precision is lost.
+pub(crate) fn format_double(value: f64) -> Result<String> {
+    if value.is_nan() {
+        return Err(RedisError::InvalidArgument(
+            "NaN is not a valid double argument".to_string(),
+        ));
+    }
+
+    Ok(value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zadd() {
+        let zadd = ZAdd::new(
+            "leaderboard",
+            vec![(0.1 + 0.2, b"alice"), (f64::NEG_INFINITY, b"bob")],
+        );
+        let frame: Frame = zadd
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ZADD command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("ZADD"),
+                Frame::bulk("leaderboard"),
+                Frame::bulk("0.30000000000000004"),
+                Frame::bulk("alice"),
+                Frame::bulk("-inf"),
+                Frame::bulk("bob"),
+            ])
+        );
+
+        let result = ZAdd::new("leaderboard", vec![(f64::NAN, b"alice")]).to_frame();
+        assert!(matches!(result, Err(RedisError::InvalidArgument(_))));
+    }
+}
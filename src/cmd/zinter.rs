@@ -0,0 +1,95 @@
+/// A Redis ZINTER command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg, ZCombineOptions, zunion::push_combine_args},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct ZInter {
+    keys: Vec<Bytes>,
+    options: ZCombineOptions,
+    with_scores: bool,
+}
+
+impl ZInter {
+    /// Creates a new ZInter command.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The sorted sets to intersect
+    /// * `options` - The weights of the sorted sets and how their scores are combined
+    /// * `with_scores` - Whether to return the scores along with the members
+    ///
+    /// # Returns
+    ///
+    /// A new ZInter command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let zinter = ZInter::new(vec!["daily", "weekly"], ZCombineOptions::default(), true);
+    /// ```
+    pub fn new(keys: Vec<impl ToRedisArg>, options: ZCombineOptions, with_scores: bool) -> Self {
+        Self {
+            keys: keys.iter().map(ToRedisArg::to_redis_arg).collect(),
+            options,
+            with_scores,
+        }
+    }
+}
+
+impl Command for ZInter {
+    const NAME: &'static str = "ZINTER";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("ZINTER"))?;
+        push_combine_args(&mut frame, &self.keys, &self.options)?;
+
+        if self.with_scores {
+            frame.push_frame_to_array(Frame::bulk("WITHSCORES"))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        // the keys follow their number
+        (2..2 + self.keys.len()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cmd::Aggregate;
+
+    #[test]
+    fn test_zinter() {
+        let zinter = ZInter::new(
+            vec!["daily", "weekly"],
+            ZCombineOptions {
+                weights: vec![],
+                aggregate: Some(Aggregate::Min),
+            },
+            true,
+        );
+        let frame: Frame = zinter
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ZINTER command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("ZINTER"),
+                Frame::bulk("2"),
+                Frame::bulk("daily"),
+                Frame::bulk("weekly"),
+                Frame::bulk("AGGREGATE"),
+                Frame::bulk("MIN"),
+                Frame::bulk("WITHSCORES"),
+            ])
+        );
+    }
+}
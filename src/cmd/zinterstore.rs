@@ -0,0 +1,122 @@
+/// A Redis ZINTERSTORE command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg, ZCombineOptions, zunion::push_combine_args},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct ZInterStore {
+    destination: Bytes,
+    keys: Vec<Bytes>,
+    options: ZCombineOptions,
+}
+
+impl ZInterStore {
+    /// Creates a new ZInterStore command.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - The key to store the resulting sorted set at, overwritten if it exists
+    /// * `keys` - The sorted sets to intersect
+    /// * `options` - The weights of the sorted sets and how their scores are combined
+    ///
+    /// # Returns
+    ///
+    /// A new ZInterStore command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let zinterstore = ZInterStore::new(
+    ///     "overall",
+    ///     vec!["daily", "weekly"],
+    ///     ZCombineOptions { weights: vec![2.0, 1.0], aggregate: Some(Aggregate::Sum) },
+    /// );
+    /// ```
+    pub fn new(
+        destination: impl ToRedisArg,
+        keys: Vec<impl ToRedisArg>,
+        options: ZCombineOptions,
+    ) -> Self {
+        Self {
+            destination: destination.to_redis_arg(),
+            keys: keys.iter().map(ToRedisArg::to_redis_arg).collect(),
+            options,
+        }
+    }
+}
+
+impl Command for ZInterStore {
+    const NAME: &'static str = "ZINTERSTORE";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("ZINTERSTORE"))?;
+        frame.push_frame_to_array(Frame::bulk(self.destination.clone()))?;
+        push_combine_args(&mut frame, &self.keys, &self.options)?;
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        // the destination, then the keys after their number
+        std::iter::once(1).chain(3..3 + self.keys.len()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cmd::Aggregate;
+
+    #[test]
+    fn test_zinterstore() {
+        let zinterstore = ZInterStore::new("overall", vec!["daily"], ZCombineOptions::default());
+        let frame: Frame = zinterstore
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ZINTERSTORE command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("ZINTERSTORE"),
+                Frame::bulk("overall"),
+                Frame::bulk("1"),
+                Frame::bulk("daily"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_zinterstore_weights_aggregate() {
+        let zinterstore = ZInterStore::new(
+            "overall",
+            vec!["daily", "weekly"],
+            ZCombineOptions {
+                weights: vec![2.0, -1.5],
+                aggregate: Some(Aggregate::Sum),
+            },
+        );
+        let frame: Frame = zinterstore
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ZINTERSTORE command: {:?}", err));
+
+        // the destination, then the number of keys before the keys themselves
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("ZINTERSTORE"),
+                Frame::bulk("overall"),
+                Frame::bulk("2"),
+                Frame::bulk("daily"),
+                Frame::bulk("weekly"),
+                Frame::bulk("WEIGHTS"),
+                Frame::bulk("2"),
+                Frame::bulk("-1.5"),
+                Frame::bulk("AGGREGATE"),
+                Frame::bulk("SUM"),
+            ])
+        );
+    }
+}
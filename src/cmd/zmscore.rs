@@ -0,0 +1,80 @@
+/// A Redis ZMSCORE command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct ZMScore {
+    key: Bytes,
+    members: Vec<Bytes>,
+}
+
+impl ZMScore {
+    /// Creates a new ZMScore command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The sorted set to look the members up in
+    /// * `members` - The members to get the scores of
+    ///
+    /// # Returns
+    ///
+    /// A new ZMScore command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let zmscore = ZMScore::new("leaderboard", vec![b"alice", b"bob"]);
+    /// ```
+    pub fn new(key: impl ToRedisArg, members: Vec<&[u8]>) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+            members: members.into_iter().map(Bytes::copy_from_slice).collect(),
+        }
+    }
+}
+
+impl Command for ZMScore {
+    const NAME: &'static str = "ZMSCORE";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("ZMSCORE"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+
+        for member in &self.members {
+            frame.push_frame_to_array(Frame::bulk(member.clone()))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zmscore() {
+        let zmscore = ZMScore::new("leaderboard", vec![b"alice", b"bob"]);
+        let frame: Frame = zmscore
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ZMSCORE command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("ZMSCORE"),
+                Frame::bulk("leaderboard"),
+                Frame::bulk("alice"),
+                Frame::bulk("bob"),
+            ])
+        );
+    }
+}
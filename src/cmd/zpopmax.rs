@@ -0,0 +1,90 @@
+/// A Redis ZPOPMAX command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct ZPopMax {
+    key: Bytes,
+    count: Option<u64>,
+}
+
+impl ZPopMax {
+    /// Creates a new ZPopMax command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The sorted set to pop the members from
+    /// * `count` - The number of members with the highest scores to pop, 1 by default
+    ///
+    /// # Returns
+    ///
+    /// A new ZPopMax command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let zpopmax = ZPopMax::new("leaderboard", Some(2));
+    /// ```
+    pub fn new(key: impl ToRedisArg, count: Option<u64>) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+            count,
+        }
+    }
+}
+
+impl Command for ZPopMax {
+    const NAME: &'static str = "ZPOPMAX";
+    const IDEMPOTENT: bool = false;
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("ZPOPMAX"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+
+        if let Some(count) = self.count {
+            frame.push_frame_to_array(Frame::bulk(count.to_string()))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zpopmax() {
+        let zpopmax = ZPopMax::new("leaderboard", None);
+        let frame: Frame = zpopmax
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ZPOPMAX command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("ZPOPMAX"), Frame::bulk("leaderboard")])
+        );
+
+        let zpopmax = ZPopMax::new("leaderboard", Some(2));
+        let frame: Frame = zpopmax
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ZPOPMAX command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("ZPOPMAX"),
+                Frame::bulk("leaderboard"),
+                Frame::bulk("2"),
+            ])
+        );
+    }
+}
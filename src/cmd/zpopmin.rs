@@ -0,0 +1,90 @@
+/// A Redis ZPOPMIN command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct ZPopMin {
+    key: Bytes,
+    count: Option<u64>,
+}
+
+impl ZPopMin {
+    /// Creates a new ZPopMin command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The sorted set to pop the members from
+    /// * `count` - The number of members with the lowest scores to pop, 1 by default
+    ///
+    /// # Returns
+    ///
+    /// A new ZPopMin command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let zpopmin = ZPopMin::new("leaderboard", Some(2));
+    /// ```
+    pub fn new(key: impl ToRedisArg, count: Option<u64>) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+            count,
+        }
+    }
+}
+
+impl Command for ZPopMin {
+    const NAME: &'static str = "ZPOPMIN";
+    const IDEMPOTENT: bool = false;
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("ZPOPMIN"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+
+        if let Some(count) = self.count {
+            frame.push_frame_to_array(Frame::bulk(count.to_string()))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zpopmin() {
+        let zpopmin = ZPopMin::new("leaderboard", None);
+        let frame: Frame = zpopmin
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ZPOPMIN command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("ZPOPMIN"), Frame::bulk("leaderboard")])
+        );
+
+        let zpopmin = ZPopMin::new("leaderboard", Some(2));
+        let frame: Frame = zpopmin
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ZPOPMIN command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("ZPOPMIN"),
+                Frame::bulk("leaderboard"),
+                Frame::bulk("2"),
+            ])
+        );
+    }
+}
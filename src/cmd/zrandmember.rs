@@ -0,0 +1,129 @@
+/// A Redis ZRANDMEMBER command.
+use crate::{
+    RedisError, Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct ZRandMember {
+    key: Bytes,
+    count: Option<i64>,
+    with_scores: bool,
+}
+
+impl ZRandMember {
+    /// Creates a new ZRandMember command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The sorted set to pick the members from
+    /// * `count` - The number of members to pick, negative to allow picking a member more than once
+    /// * `with_scores` - Whether to return the scores along with the members, requires a count
+    ///
+    /// # Returns
+    ///
+    /// A new ZRandMember command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let zrandmember = ZRandMember::new("leaderboard", Some(-5), true);
+    /// ```
+    pub fn new(key: impl ToRedisArg, count: Option<i64>, with_scores: bool) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+            count,
+            with_scores,
+        }
+    }
+}
+
+impl Command for ZRandMember {
+    const NAME: &'static str = "ZRANDMEMBER";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("ZRANDMEMBER"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+
+        match self.count {
+            Some(count) => frame.push_frame_to_array(Frame::bulk(count.to_string()))?,
+            None if self.with_scores => {
+                return Err(RedisError::InvalidArgument(
+                    "WITHSCORES requires a count".to_string(),
+                ));
+            }
+            None => {}
+        }
+
+        if self.with_scores {
+            frame.push_frame_to_array(Frame::bulk("WITHSCORES"))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zrandmember() {
+        let zrandmember = ZRandMember::new("leaderboard", None, false);
+        let frame: Frame = zrandmember
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ZRANDMEMBER command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("ZRANDMEMBER"), Frame::bulk("leaderboard")])
+        );
+    }
+
+    #[test]
+    fn test_zrandmember_count() {
+        let zrandmember = ZRandMember::new("leaderboard", Some(-5), false);
+        let frame: Frame = zrandmember
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ZRANDMEMBER command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("ZRANDMEMBER"),
+                Frame::bulk("leaderboard"),
+                Frame::bulk("-5"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_zrandmember_with_scores() {
+        let zrandmember = ZRandMember::new("leaderboard", Some(3), true);
+        let frame: Frame = zrandmember
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ZRANDMEMBER command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("ZRANDMEMBER"),
+                Frame::bulk("leaderboard"),
+                Frame::bulk("3"),
+                Frame::bulk("WITHSCORES"),
+            ])
+        );
+
+        let zrandmember = ZRandMember::new("leaderboard", None, true);
+        assert!(matches!(
+            zrandmember.to_frame(),
+            Err(RedisError::InvalidArgument(_))
+        ));
+    }
+}
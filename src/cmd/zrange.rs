@@ -0,0 +1,310 @@
+/// A Redis ZRANGE command.
+use crate::{
+    RedisError, Result,
+    cmd::{Command, LexBound, ScoreBound, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+/// The range of a ZRANGE command.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ZRangeBy {
+    /// The members from the `start` rank to the `stop` rank, both included. Negative ranks count
+    /// from the end, -1 being the last member.
+    Rank(i64, i64),
+    /// The members from the `min` score to the `max` score, BYSCORE.
+    Score(ScoreBound, ScoreBound),
+    /// The members from the `min` member to the `max` member, BYLEX.
+    Lex(LexBound, LexBound),
+}
+
+/// The options of a ZRANGE command, none of them by default.
+#[derive(Debug, Default, Clone)]
+pub struct ZRangeOptions {
+    /// Returns the members from the highest to the lowest, REV.
+    ///
+    /// The bounds of a score or lex range are still given with the lowest first, they are sent
+    /// swapped as the server expects.
+    pub rev: bool,
+    /// Returns a window of the range, as the offset of its first member and the number of
+    /// members. Only supported by score and lex ranges.
+    pub limit: Option<(u64, u64)>,
+}
+
+pub struct ZRange {
+    key: Bytes,
+    range: ZRangeBy,
+    options: ZRangeOptions,
+    with_scores: bool,
+}
+
+impl ZRange {
+    /// Creates a new ZRange command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The sorted set to read the members from
+    /// * `range` - The ranks, scores or members to read
+    /// * `options` - The ZRANGE options
+    /// * `with_scores` - Whether to return the scores along with the members, not supported by
+    ///   lex ranges
+    ///
+    /// # Returns
+    ///
+    /// A new ZRange command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let zrange = ZRange::new(
+    ///     "leaderboard",
+    ///     ZRangeBy::Score(ScoreBound::NegInf, ScoreBound::PosInf),
+    ///     ZRangeOptions { rev: true, limit: Some((0, 10)) },
+    ///     true,
+    /// );
+    /// ```
+    pub fn new(
+        key: impl ToRedisArg,
+        range: ZRangeBy,
+        options: ZRangeOptions,
+        with_scores: bool,
+    ) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+            range,
+            options,
+            with_scores,
+        }
+    }
+}
+
+impl Command for ZRange {
+    const NAME: &'static str = "ZRANGE";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("ZRANGE"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+        push_range_args(&mut frame, &self.range, &self.options, self.with_scores)?;
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+/// Pushes the arguments of a ZRANGE or ZRANGESTORE command following the source key, from the
+/// bounds of the range to WITHSCORES.
+pub(crate) fn push_range_args(
+    frame: &mut Frame,
+    range: &ZRangeBy,
+    options: &ZRangeOptions,
+    with_scores: bool,
+) -> Result<()> {
+    let (start, stop, by) = match range {
+        ZRangeBy::Rank(start, stop) => {
+            if options.limit.is_some() {
+                return Err(RedisError::InvalidArgument(
+                    "LIMIT requires a score or lex range".to_string(),
+                ));
+            }
+            (
+                Frame::bulk(start.to_string()),
+                Frame::bulk(stop.to_string()),
+                None,
+            )
+        }
+        ZRangeBy::Score(min, max) => (
+            Frame::bulk(min.to_arg()?),
+            Frame::bulk(max.to_arg()?),
+            Some("BYSCORE"),
+        ),
+        ZRangeBy::Lex(min, max) => {
+            if with_scores {
+                return Err(RedisError::InvalidArgument(
+                    "WITHSCORES is not supported by a lex range".to_string(),
+                ));
+            }
+            (
+                Frame::BulkString(min.to_arg()),
+                Frame::BulkString(max.to_arg()),
+                Some("BYLEX"),
+            )
+        }
+    };
+
+    // REV reads a score or lex range from its highest bound, ranks are taken as given
+    let (start, stop) = if options.rev && by.is_some() {
+        (stop, start)
+    } else {
+        (start, stop)
+    };
+
+    frame.push_frame_to_array(start)?;
+    frame.push_frame_to_array(stop)?;
+
+    if let Some(by) = by {
+        frame.push_frame_to_array(Frame::bulk(by))?;
+    }
+
+    if options.rev {
+        frame.push_frame_to_array(Frame::bulk("REV"))?;
+    }
+
+    if let Some((offset, count)) = options.limit {
+        frame.push_frame_to_array(Frame::bulk("LIMIT"))?;
+        frame.push_frame_to_array(Frame::bulk(offset.to_string()))?;
+        frame.push_frame_to_array(Frame::bulk(count.to_string()))?;
+    }
+
+    if with_scores {
+        frame.push_frame_to_array(Frame::bulk("WITHSCORES"))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+
+    #[test]
+    fn test_zrange_rank() {
+        let zrange = ZRange::new(
+            "leaderboard",
+            ZRangeBy::Rank(0, -1),
+            ZRangeOptions::default(),
+            true,
+        );
+        let frame: Frame = zrange
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ZRANGE command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("ZRANGE"),
+                Frame::bulk("leaderboard"),
+                Frame::bulk("0"),
+                Frame::bulk("-1"),
+                Frame::bulk("WITHSCORES"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_zrange_by_score_exclusive() {
+        let zrange = ZRange::new(
+            "leaderboard",
+            ZRangeBy::Score(ScoreBound::Exclusive(5.0), ScoreBound::PosInf),
+            ZRangeOptions::default(),
+            false,
+        );
+        let frame: Frame = zrange
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ZRANGE command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("ZRANGE"),
+                Frame::bulk("leaderboard"),
+                Frame::bulk("(5"),
+                Frame::bulk("+inf"),
+                Frame::bulk("BYSCORE"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_zrange_by_score_rev_limit() {
+        let zrange = ZRange::new(
+            "leaderboard",
+            ZRangeBy::Score(ScoreBound::NegInf, ScoreBound::Inclusive(10.0)),
+            ZRangeOptions {
+                rev: true,
+                limit: Some((10, 10)),
+            },
+            true,
+        );
+        let frame: Frame = zrange
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ZRANGE command: {:?}", err));
+
+        // the bounds are swapped, the highest first
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("ZRANGE"),
+                Frame::bulk("leaderboard"),
+                Frame::bulk("10"),
+                Frame::bulk("-inf"),
+                Frame::bulk("BYSCORE"),
+                Frame::bulk("REV"),
+                Frame::bulk("LIMIT"),
+                Frame::bulk("10"),
+                Frame::bulk("10"),
+                Frame::bulk("WITHSCORES"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_zrange_by_lex_rev() {
+        let zrange = ZRange::new(
+            "names",
+            ZRangeBy::Lex(LexBound::Exclusive(Bytes::from("a")), LexBound::Max),
+            ZRangeOptions {
+                rev: true,
+                limit: None,
+            },
+            false,
+        );
+        let frame: Frame = zrange
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ZRANGE command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("ZRANGE"),
+                Frame::bulk("names"),
+                Frame::bulk("+"),
+                Frame::bulk("(a"),
+                Frame::bulk("BYLEX"),
+                Frame::bulk("REV"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_zrange_invalid() {
+        let zrange = ZRange::new(
+            "leaderboard",
+            ZRangeBy::Rank(0, -1),
+            ZRangeOptions {
+                rev: false,
+                limit: Some((0, 10)),
+            },
+            false,
+        );
+        assert!(matches!(
+            zrange.to_frame(),
+            Err(RedisError::InvalidArgument(_))
+        ));
+
+        let zrange = ZRange::new(
+            "names",
+            ZRangeBy::Lex(LexBound::Min, LexBound::Max),
+            ZRangeOptions::default(),
+            true,
+        );
+        assert!(matches!(
+            zrange.to_frame(),
+            Err(RedisError::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,154 @@
+/// A Redis ZRANGEBYLEX command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+/// A bound of a lexicographical range, e.g. for `Client::zrangebylex`.
+///
+/// Only meaningful for a sorted set whose members all have the same score.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LexBound {
+    /// Includes this member, `[member`.
+    Inclusive(Bytes),
+    /// Leaves out this member, `(member`.
+    Exclusive(Bytes),
+    /// Below any member, `-`.
+    Min,
+    /// Above any member, `+`.
+    Max,
+}
+
+impl LexBound {
+    /// Formats the bound the way the server reads it, e.g. `[a` for an inclusive bound of a.
+    pub(crate) fn to_arg(&self) -> Bytes {
+        let (prefix, member): (&[u8], &[u8]) = match self {
+            LexBound::Inclusive(member) => (b"[", member),
+            LexBound::Exclusive(member) => (b"(", member),
+            LexBound::Min => (b"-", b""),
+            LexBound::Max => (b"+", b""),
+        };
+        [prefix, member].concat().into()
+    }
+}
+
+pub struct ZRangeByLex {
+    key: Bytes,
+    min: LexBound,
+    max: LexBound,
+    limit: Option<(u64, u64)>,
+}
+
+impl ZRangeByLex {
+    /// Creates a new ZRangeByLex command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The sorted set to read the members from
+    /// * `min` - The lowest member in the range
+    /// * `max` - The highest member in the range
+    /// * `limit` - The offset of the first member to return in the range, and how many to return
+    ///
+    /// # Returns
+    ///
+    /// A new ZRangeByLex command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let zrangebylex = ZRangeByLex::new(
+    ///     "names",
+    ///     LexBound::Inclusive(Bytes::from("a")),
+    ///     LexBound::Exclusive(Bytes::from("c")),
+    ///     None,
+    /// );
+    /// ```
+    pub fn new(
+        key: impl ToRedisArg,
+        min: LexBound,
+        max: LexBound,
+        limit: Option<(u64, u64)>,
+    ) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+            min,
+            max,
+            limit,
+        }
+    }
+}
+
+impl Command for ZRangeByLex {
+    const NAME: &'static str = "ZRANGEBYLEX";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("ZRANGEBYLEX"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+        frame.push_frame_to_array(Frame::BulkString(self.min.to_arg()))?;
+        frame.push_frame_to_array(Frame::BulkString(self.max.to_arg()))?;
+
+        if let Some((offset, count)) = self.limit {
+            frame.push_frame_to_array(Frame::bulk("LIMIT"))?;
+            frame.push_frame_to_array(Frame::bulk(offset.to_string()))?;
+            frame.push_frame_to_array(Frame::bulk(count.to_string()))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zrangebylex() {
+        let zrangebylex = ZRangeByLex::new(
+            "names",
+            LexBound::Inclusive(Bytes::from("a")),
+            LexBound::Exclusive(Bytes::from("c")),
+            None,
+        );
+        let frame: Frame = zrangebylex
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ZRANGEBYLEX command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("ZRANGEBYLEX"),
+                Frame::bulk("names"),
+                Frame::bulk("[a"),
+                Frame::bulk("(c"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_zrangebylex_unbounded_limit() {
+        let zrangebylex = ZRangeByLex::new("names", LexBound::Min, LexBound::Max, Some((5, 5)));
+        let frame: Frame = zrangebylex
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ZRANGEBYLEX command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("ZRANGEBYLEX"),
+                Frame::bulk("names"),
+                Frame::bulk("-"),
+                Frame::bulk("+"),
+                Frame::bulk("LIMIT"),
+                Frame::bulk("5"),
+                Frame::bulk("5"),
+            ])
+        );
+    }
+}
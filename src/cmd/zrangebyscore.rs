@@ -0,0 +1,209 @@
+/// A Redis ZRANGEBYSCORE command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg, format_double},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+/// A bound of a score range, e.g. for `Client::zrangebyscore`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScoreBound {
+    /// Includes the members with this exact score.
+    Inclusive(f64),
+    /// Leaves out the members with this exact score.
+    Exclusive(f64),
+    /// Below any score, `-inf`.
+    NegInf,
+    /// Above any score, `+inf`.
+    PosInf,
+}
+
+impl ScoreBound {
+    /// Formats the bound the way the server reads it, e.g. `(5` for an exclusive bound of 5.
+    pub(crate) fn to_arg(self) -> Result<String> {
+        match self {
+            ScoreBound::Inclusive(score) => format_double(score),
+            ScoreBound::Exclusive(score) => Ok(format!("({}", format_double(score)?)),
+            ScoreBound::NegInf => Ok("-inf".to_string()),
+            ScoreBound::PosInf => Ok("+inf".to_string()),
+        }
+    }
+}
+
+pub struct ZRangeByScore {
+    key: Bytes,
+    min: ScoreBound,
+    max: ScoreBound,
+    with_scores: bool,
+    limit: Option<(u64, u64)>,
+}
+
+impl ZRangeByScore {
+    /// Creates a new ZRangeByScore command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The sorted set to read the members from
+    /// * `min` - The lowest score in the range
+    /// * `max` - The highest score in the range
+    /// * `with_scores` - Whether to return the scores along with the members
+    /// * `limit` - The offset of the first member to return in the range, and how many to return
+    ///
+    /// # Returns
+    ///
+    /// A new ZRangeByScore command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let zrangebyscore = ZRangeByScore::new(
+    ///     "leaderboard",
+    ///     ScoreBound::Exclusive(1.0),
+    ///     ScoreBound::PosInf,
+    ///     true,
+    ///     Some((0, 10)),
+    /// );
+    /// ```
+    pub fn new(
+        key: impl ToRedisArg,
+        min: ScoreBound,
+        max: ScoreBound,
+        with_scores: bool,
+        limit: Option<(u64, u64)>,
+    ) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+            min,
+            max,
+            with_scores,
+            limit,
+        }
+    }
+}
+
+impl Command for ZRangeByScore {
+    const NAME: &'static str = "ZRANGEBYSCORE";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("ZRANGEBYSCORE"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+        frame.push_frame_to_array(Frame::bulk(self.min.to_arg()?))?;
+        frame.push_frame_to_array(Frame::bulk(self.max.to_arg()?))?;
+
+        if self.with_scores {
+            frame.push_frame_to_array(Frame::bulk("WITHSCORES"))?;
+        }
+
+        if let Some((offset, count)) = self.limit {
+            frame.push_frame_to_array(Frame::bulk("LIMIT"))?;
+            frame.push_frame_to_array(Frame::bulk(offset.to_string()))?;
+            frame.push_frame_to_array(Frame::bulk(count.to_string()))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RedisError;
+
+    #[test]
+    fn test_zrangebyscore() {
+        let zrangebyscore = ZRangeByScore::new(
+            "leaderboard",
+            ScoreBound::Inclusive(1.5),
+            ScoreBound::Inclusive(5.0),
+            false,
+            None,
+        );
+        let frame: Frame = zrangebyscore
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ZRANGEBYSCORE command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("ZRANGEBYSCORE"),
+                Frame::bulk("leaderboard"),
+                Frame::bulk("1.5"),
+                Frame::bulk("5"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_zrangebyscore_exclusive_with_scores_limit() {
+        let zrangebyscore = ZRangeByScore::new(
+            "leaderboard",
+            ScoreBound::Exclusive(5.0),
+            ScoreBound::Exclusive(-0.25),
+            true,
+            Some((2, 10)),
+        );
+        let frame: Frame = zrangebyscore
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ZRANGEBYSCORE command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("ZRANGEBYSCORE"),
+                Frame::bulk("leaderboard"),
+                Frame::bulk("(5"),
+                Frame::bulk("(-0.25"),
+                Frame::bulk("WITHSCORES"),
+                Frame::bulk("LIMIT"),
+                Frame::bulk("2"),
+                Frame::bulk("10"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_zrangebyscore_infinite() {
+        let zrangebyscore = ZRangeByScore::new(
+            "leaderboard",
+            ScoreBound::NegInf,
+            ScoreBound::PosInf,
+            false,
+            None,
+        );
+        let frame: Frame = zrangebyscore
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ZRANGEBYSCORE command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("ZRANGEBYSCORE"),
+                Frame::bulk("leaderboard"),
+                Frame::bulk("-inf"),
+                Frame::bulk("+inf"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_zrangebyscore_nan() {
+        let zrangebyscore = ZRangeByScore::new(
+            "leaderboard",
+            ScoreBound::Exclusive(f64::NAN),
+            ScoreBound::PosInf,
+            false,
+            None,
+        );
+
+        assert!(matches!(
+            zrangebyscore.to_frame(),
+            Err(RedisError::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,137 @@
+/// A Redis ZRANGESTORE command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg, ZRangeBy, ZRangeOptions, zrange::push_range_args},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct ZRangeStore {
+    destination: Bytes,
+    source: Bytes,
+    range: ZRangeBy,
+    options: ZRangeOptions,
+}
+
+impl ZRangeStore {
+    /// Creates a new ZRangeStore command.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - The key to store the resulting sorted set at, overwritten if it exists
+    /// * `source` - The sorted set to read the members from
+    /// * `range` - The ranks, scores or members to read
+    /// * `options` - The ZRANGE options
+    ///
+    /// # Returns
+    ///
+    /// A new ZRangeStore command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let zrangestore = ZRangeStore::new(
+    ///     "top10",
+    ///     "leaderboard",
+    ///     ZRangeBy::Rank(0, 9),
+    ///     ZRangeOptions { rev: true, limit: None },
+    /// );
+    /// ```
+    pub fn new(
+        destination: impl ToRedisArg,
+        source: impl ToRedisArg,
+        range: ZRangeBy,
+        options: ZRangeOptions,
+    ) -> Self {
+        Self {
+            destination: destination.to_redis_arg(),
+            source: source.to_redis_arg(),
+            range,
+            options,
+        }
+    }
+}
+
+impl Command for ZRangeStore {
+    const NAME: &'static str = "ZRANGESTORE";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("ZRANGESTORE"))?;
+        frame.push_frame_to_array(Frame::bulk(self.destination.clone()))?;
+        frame.push_frame_to_array(Frame::bulk(self.source.clone()))?;
+        push_range_args(&mut frame, &self.range, &self.options, false)?;
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1, 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cmd::ScoreBound;
+
+    #[test]
+    fn test_zrangestore() {
+        let zrangestore = ZRangeStore::new(
+            "top10",
+            "leaderboard",
+            ZRangeBy::Rank(0, 9),
+            ZRangeOptions {
+                rev: true,
+                limit: None,
+            },
+        );
+        let frame: Frame = zrangestore
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ZRANGESTORE command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("ZRANGESTORE"),
+                Frame::bulk("top10"),
+                Frame::bulk("leaderboard"),
+                Frame::bulk("0"),
+                Frame::bulk("9"),
+                Frame::bulk("REV"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_zrangestore_by_score_limit() {
+        let zrangestore = ZRangeStore::new(
+            "page",
+            "leaderboard",
+            ZRangeBy::Score(ScoreBound::Exclusive(1.0), ScoreBound::PosInf),
+            ZRangeOptions {
+                rev: false,
+                limit: Some((20, 10)),
+            },
+        );
+        let frame: Frame = zrangestore
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ZRANGESTORE command: {:?}", err));
+
+        // the destination comes before the source, unlike most store commands
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("ZRANGESTORE"),
+                Frame::bulk("page"),
+                Frame::bulk("leaderboard"),
+                Frame::bulk("(1"),
+                Frame::bulk("+inf"),
+                Frame::bulk("BYSCORE"),
+                Frame::bulk("LIMIT"),
+                Frame::bulk("20"),
+                Frame::bulk("10"),
+            ])
+        );
+    }
+}
@@ -0,0 +1,76 @@
+/// A Redis ZSCORE command.
+use crate::{
+    Result,
+    cmd::{Command, ToRedisArg},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+pub struct ZScore {
+    key: Bytes,
+    member: Bytes,
+}
+
+impl ZScore {
+    /// Creates a new ZScore command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The sorted set to look the member up in
+    /// * `member` - The member to get the score of
+    ///
+    /// # Returns
+    ///
+    /// A new ZScore command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let zscore = ZScore::new("leaderboard", b"alice");
+    /// ```
+    pub fn new(key: impl ToRedisArg, member: &[u8]) -> Self {
+        Self {
+            key: key.to_redis_arg(),
+            member: Bytes::copy_from_slice(member),
+        }
+    }
+}
+
+impl Command for ZScore {
+    const NAME: &'static str = "ZSCORE";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("ZSCORE"))?;
+        frame.push_frame_to_array(Frame::bulk(self.key.clone()))?;
+        frame.push_frame_to_array(Frame::bulk(self.member.clone()))?;
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zscore() {
+        let zscore = ZScore::new("leaderboard", b"alice");
+        let frame: Frame = zscore
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ZSCORE command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("ZSCORE"),
+                Frame::bulk("leaderboard"),
+                Frame::bulk("alice"),
+            ])
+        );
+    }
+}
@@ -0,0 +1,212 @@
+/// A Redis ZUNION command.
+use crate::{
+    RedisError, Result,
+    cmd::{Command, ToRedisArg, format_double},
+    frame::Frame,
+};
+use bytes::Bytes;
+
+/// How the scores of a member found in several sorted sets are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    /// Adds the scores up, the server default.
+    Sum,
+    /// Keeps the lowest score.
+    Min,
+    /// Keeps the highest score.
+    Max,
+}
+
+/// The options of the ZUNION, ZINTER and their STORE commands, none of them by default.
+#[derive(Debug, Default, Clone)]
+pub struct ZCombineOptions {
+    /// Multiplies the scores of each sorted set by a factor before they are combined, one factor
+    /// per key in the same order. Every factor is 1 if empty.
+    pub weights: Vec<f64>,
+    /// Combines the scores with the given function, SUM if None.
+    pub aggregate: Option<Aggregate>,
+}
+
+pub struct ZUnion {
+    keys: Vec<Bytes>,
+    options: ZCombineOptions,
+    with_scores: bool,
+}
+
+impl ZUnion {
+    /// Creates a new ZUnion command.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The sorted sets to combine
+    /// * `options` - The weights of the sorted sets and how their scores are combined
+    /// * `with_scores` - Whether to return the scores along with the members
+    ///
+    /// # Returns
+    ///
+    /// A new ZUnion command
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let zunion = ZUnion::new(
+    ///     vec!["daily", "weekly"],
+    ///     ZCombineOptions { weights: vec![2.0, 1.0], aggregate: Some(Aggregate::Max) },
+    ///     true,
+    /// );
+    /// ```
+    pub fn new(keys: Vec<impl ToRedisArg>, options: ZCombineOptions, with_scores: bool) -> Self {
+        Self {
+            keys: keys.iter().map(ToRedisArg::to_redis_arg).collect(),
+            options,
+            with_scores,
+        }
+    }
+}
+
+impl Command for ZUnion {
+    const NAME: &'static str = "ZUNION";
+
+    fn to_frame(&self) -> Result<Frame> {
+        let mut frame: Frame = Frame::array();
+        frame.push_frame_to_array(Frame::bulk("ZUNION"))?;
+        push_combine_args(&mut frame, &self.keys, &self.options)?;
+
+        if self.with_scores {
+            frame.push_frame_to_array(Frame::bulk("WITHSCORES"))?;
+        }
+
+        Ok(frame)
+    }
+
+    fn key_indices(&self) -> Vec<usize> {
+        // the keys follow their number
+        (2..2 + self.keys.len()).collect()
+    }
+}
+
+/// Pushes the `numkeys key [key ...] [WEIGHTS weight [weight ...]] [AGGREGATE SUM|MIN|MAX]`
+/// arguments shared by ZUNION, ZINTER and their STORE commands.
+pub(crate) fn push_combine_args(
+    frame: &mut Frame,
+    keys: &[Bytes],
+    options: &ZCombineOptions,
+) -> Result<()> {
+    if keys.is_empty() {
+        return Err(RedisError::InvalidArgument(
+            "at least one key is required".to_string(),
+        ));
+    }
+
+    if !options.weights.is_empty() && options.weights.len() != keys.len() {
+        return Err(RedisError::InvalidArgument(format!(
+            "{} weights given for {} keys",
+            options.weights.len(),
+            keys.len()
+        )));
+    }
+
+    frame.push_frame_to_array(Frame::bulk(keys.len().to_string()))?;
+
+    for key in keys {
+        frame.push_frame_to_array(Frame::bulk(key.clone()))?;
+    }
+
+    if !options.weights.is_empty() {
+        frame.push_frame_to_array(Frame::bulk("WEIGHTS"))?;
+
+        for weight in &options.weights {
+            frame.push_frame_to_array(Frame::bulk(format_double(*weight)?))?;
+        }
+    }
+
+    if let Some(aggregate) = options.aggregate {
+        frame.push_frame_to_array(Frame::bulk("AGGREGATE"))?;
+        frame.push_frame_to_array(Frame::bulk(match aggregate {
+            Aggregate::Sum => "SUM",
+            Aggregate::Min => "MIN",
+            Aggregate::Max => "MAX",
+        }))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zunion() {
+        let zunion = ZUnion::new(vec!["daily", "weekly"], ZCombineOptions::default(), false);
+        let frame: Frame = zunion
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ZUNION command: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("ZUNION"),
+                Frame::bulk("2"),
+                Frame::bulk("daily"),
+                Frame::bulk("weekly"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_zunion_weights_aggregate_with_scores() {
+        let zunion = ZUnion::new(
+            vec!["daily", "weekly", "monthly"],
+            ZCombineOptions {
+                weights: vec![2.0, 1.0, 0.5],
+                aggregate: Some(Aggregate::Max),
+            },
+            true,
+        );
+        let frame: Frame = zunion
+            .to_frame()
+            .unwrap_or_else(|err| panic!("Failed to create ZUNION command: {:?}", err));
+
+        // WITHSCORES comes last, after the options of the combination
+        assert_eq!(
+            frame,
+            Frame::Array(vec![
+                Frame::bulk("ZUNION"),
+                Frame::bulk("3"),
+                Frame::bulk("daily"),
+                Frame::bulk("weekly"),
+                Frame::bulk("monthly"),
+                Frame::bulk("WEIGHTS"),
+                Frame::bulk("2"),
+                Frame::bulk("1"),
+                Frame::bulk("0.5"),
+                Frame::bulk("AGGREGATE"),
+                Frame::bulk("MAX"),
+                Frame::bulk("WITHSCORES"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_zunion_invalid() {
+        let zunion = ZUnion::new(
+            vec!["daily", "weekly"],
+            ZCombineOptions {
+                weights: vec![2.0],
+                aggregate: None,
+            },
+            false,
+        );
+        assert!(matches!(
+            zunion.to_frame(),
+            Err(RedisError::InvalidArgument(_))
+        ));
+
+        let zunion = ZUnion::new(Vec::<&str>::new(), ZCombineOptions::default(), false);
+        assert!(matches!(
+            zunion.to_frame(),
+            Err(RedisError::InvalidArgument(_))
+        ));
+    }
+}
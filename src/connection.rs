@@ -2,40 +2,260 @@ use crate::Frame;
 use crate::RedisError;
 use crate::Result;
 use anyhow::anyhow;
-use bytes::Buf;
 use bytes::{Bytes, BytesMut};
-use std::io::Cursor;
-use tokio::io::{AsyncReadExt, AsyncWriteExt, BufWriter};
-use tokio::net::TcpStream;
+use socket2::{SockRef, TcpKeepalive};
+use std::io::{Cursor, IoSlice};
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufWriter};
+use tokio::net::{TcpSocket, TcpStream, ToSocketAddrs, lookup_host};
 
-// 512 MB = 512 * 1024 * 1024 bytes
-const MAX_BUFFER_SIZE: usize = 512 * 1024 * 1024;
+// 64 MB = 64 * 1024 * 1024 bytes, see `Connection::with_max_frame_size` for larger values
+pub(crate) const MAX_BUFFER_SIZE: usize = 64 * 1024 * 1024;
+
+// the read buffer starts small and grows with the frames it holds
+const INITIAL_BUFFER_SIZE: usize = 4 * 1024;
+
+/// The version of RESP a connection speaks.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProtocolVersion {
+    /// RESP2, which every connection starts with.
+    #[default]
+    Resp2,
+    /// RESP3, negotiated with `HELLO 3` since Redis 6.0.
+    Resp3,
+}
+
+impl From<ProtocolVersion> for u8 {
+    fn from(version: ProtocolVersion) -> Self {
+        match version {
+            ProtocolVersion::Resp2 => 2,
+            ProtocolVersion::Resp3 => 3,
+        }
+    }
+}
+
+impl TryFrom<u8> for ProtocolVersion {
+    type Error = RedisError;
+
+    fn try_from(version: u8) -> Result<Self> {
+        match version {
+            2 => Ok(ProtocolVersion::Resp2),
+            3 => Ok(ProtocolVersion::Resp3),
+            _ => Err(RedisError::UnsupportedProtocol),
+        }
+    }
+}
+
+/// The socket options of a TCP connection to the server, see `Client::connect_with`.
+///
+/// # Examples
+///
+/// ```ignore
+/// use redis_asyncx::{Client, ConnectionOptions};
+/// use std::time::Duration;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let options = ConnectionOptions {
+///         keepalive: Some(Duration::from_secs(60)),
+///         connect_timeout: Duration::from_millis(500),
+///         ..Default::default()
+///     };
+///     let mut client = Client::connect_with("127.0.0.1:6379", options).await.unwrap();
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConnectionOptions {
+    /// Whether to send small writes right away with TCP_NODELAY, rather than waiting to coalesce
+    /// them. On by default, since a command is usually a single small write.
+    pub nodelay: bool,
+    /// How long the connection stays idle before TCP keepalive probes are sent, no probes if None.
+    pub keepalive: Option<Duration>,
+    /// How long to wait for the server to accept the connection, over all its resolved addresses.
+    pub connect_timeout: Duration,
+    /// The size of the socket receive buffer, SO_RCVBUF, left to the OS if None.
+    pub recv_buffer_size: Option<u32>,
+    /// The size of the socket send buffer, SO_SNDBUF, left to the OS if None.
+    pub send_buffer_size: Option<u32>,
+}
+
+impl Default for ConnectionOptions {
+    fn default() -> Self {
+        Self {
+            nodelay: true,
+            keepalive: None,
+            connect_timeout: Duration::from_secs(10),
+            recv_buffer_size: None,
+            send_buffer_size: None,
+        }
+    }
+}
+
+/// Opens a TCP connection to the first of the resolved addresses that accepts it, with the given
+/// socket options.
+///
+/// The buffer sizes are set before connecting, since the TCP window scale is negotiated then.
+///
+/// # Returns
+///
+/// * `Ok(TcpStream)` once connected
+/// * `Err(RedisError::Timeout)` if not connected within `connect_timeout`
+/// * `Err(RedisError)` if the address does not resolve, or every address refused the connection
+pub(crate) async fn connect_tcp<A: ToSocketAddrs>(
+    addr: A,
+    options: &ConnectionOptions,
+) -> Result<TcpStream> {
+    let connect = async {
+        let mut last_err = None;
+        for addr in lookup_host(addr).await? {
+            match connect_socket(addr, options).await {
+                Ok(stream) => return Ok(stream),
+                Err(err) => last_err = Some(err),
+            }
+        }
+
+        Err(last_err
+            .unwrap_or_else(|| std::io::Error::other("the address did not resolve to anything")))
+    };
+    let stream = tokio::time::timeout(options.connect_timeout, connect)
+        .await
+        .map_err(|_| RedisError::Timeout)??;
+
+    stream.set_nodelay(options.nodelay)?;
+    if let Some(idle) = options.keepalive {
+        SockRef::from(&stream).set_tcp_keepalive(&TcpKeepalive::new().with_time(idle))?;
+    }
+
+    Ok(stream)
+}
+
+async fn connect_socket(
+    addr: SocketAddr,
+    options: &ConnectionOptions,
+) -> std::io::Result<TcpStream> {
+    let socket = match addr {
+        SocketAddr::V4(_) => TcpSocket::new_v4()?,
+        SocketAddr::V6(_) => TcpSocket::new_v6()?,
+    };
+    if let Some(size) = options.recv_buffer_size {
+        socket.set_recv_buffer_size(size)?;
+    }
+    if let Some(size) = options.send_buffer_size {
+        socket.set_send_buffer_size(size)?;
+    }
+
+    socket.connect(addr).await
+}
 
 /// Represents a connection bewteen the client and the Redis server.
 ///
 /// The connecton wraps a TCP stream and a buffer for reading and writing Frames.
+/// Any other async byte stream can be used instead of TCP, e.g. an in-memory duplex in tests.
 ///
 /// To read Frames, the connection waits asynchronously until there is enough data to parse a Frame.
 /// On success, it deserializes the bytes into a Frame and returns it to the client.
 ///
 /// To write Frames, the connection serializes the Frame into bytes and writes it to the stream.
 /// It then flushes the stream to ensure the data is sent to the server.
-pub struct Connection {
-    stream: BufWriter<TcpStream>,
+/// Callers sending many frames at once can write them with `write_frame_no_flush` and flush once.
+pub struct Connection<S = TcpStream> {
+    stream: BufWriter<S>,
     buffer: BytesMut,
+    // the largest frame the connection is willing to buffer, MAX_BUFFER_SIZE by default
+    max_frame_size: usize,
+    // set once a write fails midway, the server may have received part of a command
+    poisoned: bool,
+    // set while a command waits for its reply, left set if the caller gave up on it midway
+    in_flight: bool,
+    // the RESP version negotiated by the last successful HELLO
+    protocol: ProtocolVersion,
+    // whether lines not starting with `*` are read as inline commands, for servers only
+    inline_commands: bool,
 }
 
-impl Connection {
-    /// Creates a new connection from a TCP stream. The stream is wrapped in a write buffer.
+impl<S: AsyncRead + AsyncWrite + Unpin> Connection<S> {
+    /// Creates a new connection from a stream, usually TCP. The stream is wrapped in a write buffer.
     /// It also initializes a read buffer for reading from the TCP stream. The read buffer is 4kb.
-    pub fn new(stream: TcpStream) -> Self {
+    pub fn new(stream: S) -> Self {
         Self {
             stream: BufWriter::new(stream),
-            // 512MB buffer for each connection
-            buffer: BytesMut::with_capacity(MAX_BUFFER_SIZE),
+            buffer: BytesMut::with_capacity(INITIAL_BUFFER_SIZE),
+            max_frame_size: MAX_BUFFER_SIZE,
+            poisoned: false,
+            in_flight: false,
+            protocol: ProtocolVersion::Resp2,
+            inline_commands: false,
         }
     }
 
+    /// Sets the RESP version the stream already speaks, RESP2 by default.
+    ///
+    /// The version is not negotiated with the server, e.g. for a stream on which `HELLO 3` was
+    /// sent before it was handed over to the connection.
+    pub fn with_protocol_version(mut self, version: ProtocolVersion) -> Self {
+        self.protocol = version;
+        self
+    }
+
+    /// Returns the RESP version the connection speaks.
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol
+    }
+
+    /// Records the RESP version negotiated by a successful HELLO.
+    pub(crate) fn set_protocol_version(&mut self, version: ProtocolVersion) {
+        self.protocol = version;
+    }
+
+    /// Sets the largest frame the connection accepts from the server, 64MB by default.
+    ///
+    /// Declared lengths above the limit, and partial frames that would need more buffering than that,
+    /// fail with `RedisError::FrameTooLarge` instead of waiting for more data.
+    pub fn with_max_frame_size(mut self, max_frame_size: usize) -> Self {
+        self.max_frame_size = max_frame_size;
+        self
+    }
+
+    /// Sets whether the connection reads inline commands, false by default.
+    ///
+    /// For the server side of a connection only: clients send commands as arrays of bulk
+    /// strings, but a person typing in telnet sends them as a plain line, e.g. `SET key value`.
+    /// Once enabled, `read_frame` reads any line not starting with `*` that way, as an array of
+    /// the words of the line split on whitespace. Empty lines are skipped.
+    pub fn with_inline_commands(mut self, enabled: bool) -> Self {
+        self.inline_commands = enabled;
+        self
+    }
+
+    /// Returns whether the connection can still be used.
+    ///
+    /// A connection becomes unusable after a failed write, because a half-sent command leaves it
+    /// impossible to tell which reply belongs to which command, and for the same reason after a
+    /// command timed out before its reply, or after a failed read or a reply that does not parse.
+    /// It should be dropped and replaced.
+    ///
+    /// So does a connection whose round trip was given up on, e.g. because the future of a
+    /// command was dropped after its write but before its reply, which is left on the socket.
+    pub fn is_usable(&self) -> bool {
+        !self.poisoned && !self.in_flight
+    }
+
+    /// Marks the start of a round trip, the connection is unusable until `end_round_trip`.
+    pub(crate) fn begin_round_trip(&mut self) {
+        self.in_flight = true;
+    }
+
+    /// Marks the end of a round trip, once every reply of the commands written is read.
+    pub(crate) fn end_round_trip(&mut self) {
+        self.in_flight = false;
+    }
+
+    /// Marks the connection as unusable, e.g. when a reply was given up on and may still arrive.
+    pub(crate) fn poison(&mut self) {
+        self.poisoned = true;
+    }
+
     /// Reads a single Redis Frame from the TCP stream.
     ///
     /// The method reads from the stream into the buffer until it has a complete Frame.
@@ -46,13 +266,30 @@ impl Connection {
     /// An Option containing the Frame if it was successfully read and parsed.
     /// None if the Frame is incomplete and more data is needed.
     pub async fn read_frame(&mut self) -> Result<Option<Frame>> {
+        self.check_usable()?;
+
+        // past a failed read or a reply that does not parse, the stream cannot be framed anymore
+        let result = self.read_frame_unchecked().await;
+        if result.is_err() {
+            self.poisoned = true;
+        }
+
+        result
+    }
+
+    async fn read_frame_unchecked(&mut self) -> Result<Option<Frame>> {
         loop {
             if let Some(frame) = self.try_parse_frame().await? {
                 return Ok(Some(frame));
             }
 
+            // the frame is still incomplete, stop buffering once it cannot fit anyway
+            if self.buffer.len() > self.max_frame_size {
+                return Err(RedisError::FrameTooLarge(self.buffer.len()));
+            }
+
             // read from the stream into the buffer until we have a frame
-            if let Ok(0) = self.stream.read_buf(&mut self.buffer).await {
+            if self.stream.read_buf(&mut self.buffer).await? == 0 {
                 if self.buffer.is_empty() {
                     return Ok(None);
                 } else {
@@ -75,10 +312,101 @@ impl Connection {
     ///
     /// A Result indicating success or failure
     pub async fn write_frame(&mut self, frame: &Frame) -> Result<()> {
-        let bytes: Bytes = frame.serialize().await?;
+        self.write_frame_no_flush(frame).await?;
+        self.flush().await
+    }
+
+    /// Writes a single Redis Frame into the write buffer without flushing it.
+    ///
+    /// The frame is only guaranteed to reach the server after `flush` is called,
+    /// which allows pipelines and transactions to send many frames in one go.
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - A reference to the Frame to be written to the stream
+    ///
+    /// # Returns
+    ///
+    /// A Result indicating success or failure
+    pub async fn write_frame_no_flush(&mut self, frame: &Frame) -> Result<()> {
+        self.check_usable()?;
+
+        // large values are not copied into the encoding, nor into the write buffer
+        // nulls are encoded the way the peer expects them
+        let chunks: Vec<Bytes> = frame.serialize_chunks_as(self.protocol).await?;
+
+        let result = match chunks.as_slice() {
+            [bytes] => self.stream.write_all(bytes).await,
+            chunks => self.write_all_vectored(chunks).await,
+        };
+        if let Err(err) = result {
+            self.poisoned = true;
+            return Err(err.into());
+        }
+
+        Ok(())
+    }
+
+    /// Writes every chunk, with as few vectored writes as the stream allows.
+    ///
+    /// Chunks at least as large as the write buffer go straight to the stream, once the buffer
+    /// is flushed.
+    async fn write_all_vectored(&mut self, chunks: &[Bytes]) -> std::io::Result<()> {
+        let mut slices: Vec<IoSlice<'_>> = chunks.iter().map(|chunk| IoSlice::new(chunk)).collect();
+        let mut slices: &mut [IoSlice<'_>] = &mut slices;
+
+        while !slices.is_empty() {
+            let written = self.stream.write_vectored(slices).await?;
+            if written == 0 {
+                return Err(std::io::ErrorKind::WriteZero.into());
+            }
+            IoSlice::advance_slices(&mut slices, written);
+        }
+
+        Ok(())
+    }
+
+    /// Flushes the write buffer, sending all the frames written so far to the server.
+    ///
+    /// # Returns
+    ///
+    /// A Result indicating success or failure
+    pub async fn flush(&mut self) -> Result<()> {
+        self.check_usable()?;
+
+        if let Err(err) = self.stream.flush().await {
+            self.poisoned = true;
+            return Err(err.into());
+        }
+
+        Ok(())
+    }
+
+    /// Flushes the write buffer and shuts down the write half of the stream.
+    ///
+    /// The server sees the end of the stream once it has read every frame written so far, so
+    /// nothing buffered is lost, unlike when the connection is simply dropped.
+    ///
+    /// # Returns
+    ///
+    /// A Result indicating success or failure
+    pub async fn shutdown(&mut self) -> Result<()> {
+        self.check_usable()?;
+
+        if let Err(err) = self.stream.shutdown().await {
+            self.poisoned = true;
+            return Err(err.into());
+        }
+
+        Ok(())
+    }
 
-        self.stream.write_all(&bytes).await?;
-        self.stream.flush().await?;
+    fn check_usable(&self) -> Result<()> {
+        if self.poisoned {
+            return Err(RedisError::Other(anyhow!(
+                "connection is unusable after a failed read or write, or a timed out command"
+            )));
+        }
 
         Ok(())
     }
@@ -95,12 +423,30 @@ impl Connection {
     /// None if the Frame is incomplete and more data is needed.
     /// An error if the Frame is invalid.
     async fn try_parse_frame(&mut self) -> Result<Option<Frame>> {
+        // an empty line is skipped, the line after it may be a frame
+        while self.inline_commands && self.buffer.first().is_some_and(|&byte| byte != b'*') {
+            let Some(end) = self.buffer.iter().position(|&byte| byte == b'\n') else {
+                return Ok(None);
+            };
+            let line = self.buffer.split_to(end + 1).freeze();
+
+            if let Some(command) = inline_command(&line) {
+                return Ok(Some(command));
+            }
+        }
+
+        // measure the frame first, its payloads are not materialized yet
         let mut cursor: Cursor<&[u8]> = Cursor::new(&self.buffer[..]);
 
-        match Frame::try_parse(&mut cursor) {
-            Ok(frame) => {
-                self.buffer.advance(cursor.position() as usize);
-                Ok(Some(frame))
+        match Frame::check(&mut cursor, self.max_frame_size) {
+            Ok(()) => {
+                let len = cursor.position() as usize;
+
+                // hand the bytes of the frame over to the frame, so that its payloads are slices
+                // of the read buffer instead of copies
+                let data = self.buffer.split_to(len).freeze();
+
+                Ok(Some(Frame::parse_shared(&data, self.max_frame_size)?))
             }
             Err(err) => {
                 if let RedisError::IncompleteFrame = err {
@@ -112,3 +458,425 @@ impl Connection {
         }
     }
 }
+
+impl Connection<TcpStream> {
+    /// Returns the address of the server the connection is open to.
+    pub(crate) fn peer_addr(&self) -> std::io::Result<SocketAddr> {
+        self.stream.get_ref().peer_addr()
+    }
+}
+
+/// Splits an inline command on whitespace into an array of bulk strings, None for a blank line.
+/// The words are slices of the line rather than copies.
+fn inline_command(line: &Bytes) -> Option<Frame> {
+    let words: Vec<Frame> = line
+        .split(|byte| byte.is_ascii_whitespace())
+        .filter(|word| !word.is_empty())
+        .map(|word| Frame::bulk(line.slice_ref(word)))
+        .collect();
+
+    (!words.is_empty()).then_some(Frame::Array(words))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::pin::Pin;
+    use std::task::{Context as TaskContext, Poll};
+    use tokio::io::{ReadBuf, duplex};
+    use tokio::net::TcpListener;
+    use tokio::time::timeout;
+
+    /// Starts a fake server that writes `reply` to the first client and keeps the socket open.
+    async fn serve(reply: &'static [u8]) -> TcpStream {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to bind listener: {:?}", err));
+        let addr = listener
+            .local_addr()
+            .unwrap_or_else(|err| panic!("Failed to get listener address: {:?}", err));
+
+        tokio::spawn(async move {
+            if let Ok((mut socket, _)) = listener.accept().await {
+                let _ = socket.write_all(reply).await;
+                // hold the connection so that the client can only fail on its own
+                tokio::time::sleep(Duration::from_secs(10)).await;
+            }
+        });
+
+        TcpStream::connect(addr)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err))
+    }
+
+    #[tokio::test]
+    async fn test_read_frame_absurd_length() {
+        let mut conn = Connection::new(serve(b"$9999999999999\r\n").await);
+
+        let result = timeout(Duration::from_secs(1), conn.read_frame())
+            .await
+            .unwrap_or_else(|_| panic!("read_frame kept waiting for a frame that cannot fit"));
+
+        assert!(matches!(result, Err(RedisError::FrameTooLarge(_))));
+    }
+
+    #[tokio::test]
+    async fn test_read_frame_exceeds_max_frame_size() {
+        // every element is small, but the whole array never fits in 16 bytes
+        let mut conn = Connection::new(serve(b"*10\r\n:1\r\n:2\r\n:3\r\n:4\r\n:5\r\n").await)
+            .with_max_frame_size(16);
+
+        let result = timeout(Duration::from_secs(1), conn.read_frame())
+            .await
+            .unwrap_or_else(|_| panic!("read_frame kept waiting for a frame that cannot fit"));
+
+        assert!(matches!(result, Err(RedisError::FrameTooLarge(_))));
+    }
+
+    #[tokio::test]
+    async fn test_read_frame_bulk_string_exceeds_max_frame_size() {
+        let (client, mut server) = duplex(4096);
+        let mut conn = Connection::new(client).with_max_frame_size(100);
+
+        let mut reply = b"$200\r\n".to_vec();
+        reply.extend_from_slice(&[b'a'; 200]);
+        reply.extend_from_slice(b"\r\n");
+        server
+            .write_all(&reply)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to write reply: {:?}", err));
+
+        let result = conn.read_frame().await;
+        assert!(matches!(result, Err(RedisError::FrameTooLarge(200))));
+
+        // the same bulk string fits once the limit allows it
+        let (client, mut server) = duplex(4096);
+        let mut conn = Connection::new(client).with_max_frame_size(200);
+        server
+            .write_all(&reply)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to write reply: {:?}", err));
+
+        let frame = conn
+            .read_frame()
+            .await
+            .unwrap_or_else(|err| panic!("Failed to read frame: {:?}", err));
+        assert_eq!(frame, Some(Frame::BulkString(Bytes::from(vec![b'a'; 200]))));
+    }
+
+    #[tokio::test]
+    async fn test_write_frame_no_flush() {
+        let (client, mut server) = duplex(4096);
+        let mut conn = Connection::new(client);
+
+        for _ in 0..3 {
+            conn.write_frame_no_flush(&Frame::Array(vec![Frame::BulkString("PING".into())]))
+                .await
+                .unwrap_or_else(|err| panic!("Failed to write frame: {:?}", err));
+        }
+
+        // nothing reaches the peer before the flush
+        let mut buf = vec![0; 4096];
+        let result = timeout(Duration::from_millis(50), server.read(&mut buf)).await;
+        assert!(result.is_err());
+
+        conn.flush()
+            .await
+            .unwrap_or_else(|err| panic!("Failed to flush: {:?}", err));
+
+        // the three commands arrive together
+        let expected = b"*1\r\n$4\r\nPING\r\n".repeat(3);
+        let mut received = vec![0; expected.len()];
+        server
+            .read_exact(&mut received)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to read commands: {:?}", err));
+        assert_eq!(received, expected);
+
+        server
+            .write_all(b"+PONG\r\n+PONG\r\n+PONG\r\n")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to write replies: {:?}", err));
+
+        for _ in 0..3 {
+            let frame = conn
+                .read_frame()
+                .await
+                .unwrap_or_else(|err| panic!("Failed to read frame: {:?}", err));
+
+            assert_eq!(frame, Some(Frame::SimpleString("PONG".to_string())));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_write_frame_large_value() {
+        let (client, mut server) = duplex(4096);
+        let mut conn = Connection::new(client);
+        let frame = Frame::Array(vec![
+            Frame::bulk("SET"),
+            Frame::bulk("mykey"),
+            Frame::BulkString(Bytes::from(vec![b'x'; 1024 * 1024])),
+        ]);
+        let expected = frame
+            .serialize()
+            .await
+            .unwrap_or_else(|err| panic!("Failed to serialize frame: {:?}", err));
+
+        // the value is far larger than the duplex buffer, the peer reads while it is written
+        let reader = tokio::spawn(async move {
+            let mut received = vec![0; expected.len()];
+            server
+                .read_exact(&mut received)
+                .await
+                .unwrap_or_else(|err| panic!("Failed to read command: {:?}", err));
+            (received, expected)
+        });
+
+        conn.write_frame(&frame)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to write frame: {:?}", err));
+
+        let (received, expected) = reader
+            .await
+            .unwrap_or_else(|err| panic!("Failed to join reader: {:?}", err));
+        assert_eq!(received, expected);
+    }
+
+    #[tokio::test]
+    async fn test_write_frame_null_per_version() {
+        for (version, expected) in [
+            (ProtocolVersion::Resp2, &b"$-1\r\n"[..]),
+            (ProtocolVersion::Resp3, b"_\r\n"),
+        ] {
+            let (client, mut server) = duplex(64);
+            let mut conn = Connection::new(client).with_protocol_version(version);
+
+            conn.write_frame(&Frame::Null)
+                .await
+                .unwrap_or_else(|err| panic!("Failed to write frame: {:?}", err));
+
+            let mut received = vec![0; expected.len()];
+            server
+                .read_exact(&mut received)
+                .await
+                .unwrap_or_else(|err| panic!("Failed to read frame: {:?}", err));
+            assert_eq!(received, expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_read_inline_commands() {
+        let (client, mut server) = duplex(1024);
+        let mut conn = Connection::new(client).with_inline_commands(true);
+
+        server
+            .write_all(b"PING\r\n\r\n  SET  key value\n*2\r\n$3\r\nGET\r\n$3\r\nkey\r\nDEL")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to write commands: {:?}", err));
+
+        let mut commands = Vec::new();
+        for _ in 0..3 {
+            let command = conn
+                .read_frame()
+                .await
+                .unwrap_or_else(|err| panic!("Failed to read command: {:?}", err))
+                .unwrap_or_else(|| panic!("connection closed"));
+            commands.push(command);
+        }
+        assert_eq!(
+            commands,
+            vec![
+                Frame::Array(vec![Frame::bulk("PING")]),
+                Frame::Array(vec![
+                    Frame::bulk("SET"),
+                    Frame::bulk("key"),
+                    Frame::bulk("value")
+                ]),
+                Frame::Array(vec![Frame::bulk("GET"), Frame::bulk("key")]),
+            ]
+        );
+
+        // the last line is only complete once its newline arrives
+        let pending = timeout(Duration::from_millis(50), conn.read_frame()).await;
+        assert!(pending.is_err());
+        server
+            .write_all(b" key\r\n")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to write commands: {:?}", err));
+        let command = conn
+            .read_frame()
+            .await
+            .unwrap_or_else(|err| panic!("Failed to read command: {:?}", err));
+        assert_eq!(
+            command,
+            Some(Frame::Array(vec![Frame::bulk("DEL"), Frame::bulk("key")]))
+        );
+
+        // a client connection reads such a line as a malformed frame
+        let (client, mut server) = duplex(1024);
+        let mut conn = Connection::new(client);
+        server
+            .write_all(b"PING\r\n")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to write commands: {:?}", err));
+        assert!(conn.read_frame().await.is_err());
+    }
+
+    #[test]
+    fn test_protocol_version() {
+        let (client, _server) = duplex(64);
+        let conn = Connection::new(client);
+        assert_eq!(conn.protocol_version(), ProtocolVersion::Resp2);
+
+        let conn = conn.with_protocol_version(ProtocolVersion::Resp3);
+        assert_eq!(conn.protocol_version(), ProtocolVersion::Resp3);
+
+        assert_eq!(u8::from(ProtocolVersion::Resp3), 3);
+        assert!(matches!(
+            ProtocolVersion::try_from(2),
+            Ok(ProtocolVersion::Resp2)
+        ));
+        assert!(matches!(
+            ProtocolVersion::try_from(4),
+            Err(RedisError::UnsupportedProtocol)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_shutdown() {
+        let (client, mut server) = duplex(4096);
+        let mut conn = Connection::new(client);
+
+        conn.write_frame_no_flush(&Frame::Array(vec![Frame::BulkString("QUIT".into())]))
+            .await
+            .unwrap_or_else(|err| panic!("Failed to write frame: {:?}", err));
+        conn.shutdown()
+            .await
+            .unwrap_or_else(|err| panic!("Failed to shut down: {:?}", err));
+
+        // the buffered command is flushed before the end of the stream
+        let mut received = Vec::new();
+        server
+            .read_to_end(&mut received)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to read commands: {:?}", err));
+        assert_eq!(received, b"*1\r\n$4\r\nQUIT\r\n");
+    }
+
+    #[tokio::test]
+    async fn test_write_failure_poisons_connection() {
+        let (client, server) = duplex(64);
+        let mut conn = Connection::new(client);
+        assert!(conn.is_usable());
+
+        // the peer goes away, the next write fails
+        drop(server);
+
+        let result = conn
+            .write_frame(&Frame::Array(vec![Frame::BulkString("PING".into())]))
+            .await;
+        assert!(matches!(result, Err(RedisError::Io(_))));
+        assert!(!conn.is_usable());
+
+        // later reads and writes are refused instead of pairing replies with the wrong command
+        assert!(conn.read_frame().await.is_err());
+        assert!(
+            conn.write_frame(&Frame::Array(vec![Frame::BulkString("PING".into())]))
+                .await
+                .is_err()
+        );
+    }
+
+    /// A stream whose reads fail, as when the peer resets the connection.
+    struct ResetStream;
+
+    impl AsyncRead for ResetStream {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            _: &mut TaskContext<'_>,
+            _: &mut ReadBuf<'_>,
+        ) -> Poll<std::io::Result<()>> {
+            Poll::Ready(Err(std::io::ErrorKind::ConnectionReset.into()))
+        }
+    }
+
+    impl AsyncWrite for ResetStream {
+        fn poll_write(
+            self: Pin<&mut Self>,
+            _: &mut TaskContext<'_>,
+            buf: &[u8],
+        ) -> Poll<std::io::Result<usize>> {
+            Poll::Ready(Ok(buf.len()))
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _: &mut TaskContext<'_>) -> Poll<std::io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_shutdown(
+            self: Pin<&mut Self>,
+            _: &mut TaskContext<'_>,
+        ) -> Poll<std::io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_read_failure_poisons_connection() {
+        let mut conn = Connection::new(ResetStream);
+
+        // the read error is returned, not taken for a frame still on its way
+        let result = conn.read_frame().await;
+        assert!(matches!(result, Err(RedisError::Io(_))));
+        assert!(!conn.is_usable());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_frame_poisons_connection() {
+        let (client, mut server) = duplex(64);
+        let mut conn = Connection::new(client);
+
+        server
+            .write_all(b"?foo\r\n+OK\r\n")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to write reply: {:?}", err));
+
+        let result = conn.read_frame().await;
+        assert!(matches!(result, Err(RedisError::InvalidFrame)));
+        assert!(!conn.is_usable());
+
+        // the bytes after the invalid frame are not read as the reply of the next command
+        assert!(conn.read_frame().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_connect_tcp_options() {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to bind listener: {:?}", err));
+        let addr = listener
+            .local_addr()
+            .unwrap_or_else(|err| panic!("Failed to get listener address: {:?}", err));
+
+        let options = ConnectionOptions {
+            keepalive: Some(Duration::from_secs(30)),
+            recv_buffer_size: Some(64 * 1024),
+            ..Default::default()
+        };
+        let stream = connect_tcp(addr, &options)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
+        assert!(stream.nodelay().unwrap_or_default());
+        assert!(SockRef::from(&stream).keepalive().unwrap_or_default());
+
+        let options = ConnectionOptions {
+            nodelay: false,
+            ..Default::default()
+        };
+        let stream = connect_tcp(addr, &options)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
+        assert!(!stream.nodelay().unwrap_or(true));
+        assert!(!SockRef::from(&stream).keepalive().unwrap_or(true));
+    }
+}
@@ -12,16 +12,84 @@ pub enum RedisError {
     /// An invalid frame was received when reading from the socket. According to RESP3 spec.
     #[error("invalid frame")]
     InvalidFrame,
+    /// A frame declared a length larger than the connection accepts.
+    #[error("frame too large: declared length {0} exceeds the maximum frame size")]
+    FrameTooLarge(usize),
     /// So that we can use `?` operator to convert from `std::str::Utf8Error`
     #[error("utf8 error")]
     Utf8(#[from] std::str::Utf8Error),
     /// So that we can use `?` operator to convert from `std::num::ParseIntError`
     #[error("ParseIntError")]
     ParseInt(#[from] std::num::ParseIntError),
+    /// So that we can use `?` operator to convert from `std::num::ParseFloatError`
+    #[error("ParseFloatError")]
+    ParseFloat(#[from] std::num::ParseFloatError),
     #[error("TryFromIntError")]
     TryFromInt(#[from] std::num::TryFromIntError),
     #[error("unexpected response type")]
     UnexpectedResponseType,
+    /// An argument was rejected on the client side, before sending the command.
+    #[error("invalid argument: {0}")]
+    InvalidArgument(String),
+    /// The keys of a command hash to different slots, so no single cluster node can serve it.
+    #[error("keys in request don't hash to the same slot")]
+    CrossSlot,
+    /// RESTORE or MIGRATE found the target key already there, and REPLACE was not given.
+    #[error("target key name already exists")]
+    BusyKey,
+    /// An increment command found a value that does not parse as an integer, or as a float.
+    #[error("value is not a number")]
+    NotANumber,
+    /// OBJECT FREQ was sent to a server whose maxmemory policy is not an LFU one.
+    #[error("an LFU maxmemory policy is not selected, access frequency not tracked")]
+    LfuNotSelected,
+    /// EXEC did not run the transaction, because a key watched with WATCH changed since.
+    #[error("transaction aborted, a watched key changed")]
+    TransactionAborted,
+    /// A reply of a pipeline is an error, or does not convert into the type expected at its
+    /// position, see `Pipeline::execute_typed`.
+    #[error("reply {index} of the pipeline: {source}")]
+    PipelineReply {
+        index: usize,
+        source: Box<RedisError>,
+    },
+    /// EVALSHA was given the digest of a script the server has not cached, or no longer does.
+    #[error("no matching script, use EVAL")]
+    NoScript,
+    /// CONFIG SET was given a parameter the server does not know, or cannot change at runtime.
+    #[error("unknown configuration parameter: {0}")]
+    UnknownConfigParameter(String),
+    /// The server does not speak the requested RESP version, e.g. RESP3 before Redis 6.0.
+    #[error("unsupported protocol version")]
+    UnsupportedProtocol,
+    /// The server is still loading its dataset in memory, after starting up.
+    #[error("{0}")]
+    Loading(String),
+    /// The server is busy running a script or a function, and only accepts a few commands.
+    #[error("{0}")]
+    Busy(String),
+    /// A multi-key command hit a cluster slot being migrated, whose keys are split for now.
+    #[error("{0}")]
+    TryAgain(String),
+    /// The server requires the connection to authenticate first, with AUTH or HELLO.
+    #[error("{0}")]
+    NoAuth(String),
+    /// The server is a replica, which refuses writes, e.g. a master demoted by a failover.
+    #[error("{0}")]
+    ReadOnly(String),
+    /// No reply was received within the command timeout, see `Client::set_command_timeout`.
+    #[error("command timed out")]
+    Timeout,
+    /// The client was closed, no more commands can be sent through it.
+    #[error("client closed")]
+    ClientClosed,
+    /// The value of a key did not convert from or into JSON, see `Client::get_json`.
+    #[cfg(feature = "serde")]
+    #[error("JSON value of {key}: {source}")]
+    Serde {
+        key: String,
+        source: serde_json::Error,
+    },
     /// All other errors are converted to anyhow::Error
     /// This is a catch-all error type that can be used to wrap any other error.
     #[error(transparent)]
@@ -32,5 +100,73 @@ pub enum RedisError {
     Unknown,
 }
 
+impl RedisError {
+    /// Types an error replied by the server after its code, the first word of the message.
+    ///
+    /// Errors without a dedicated variant are kept as is in `RedisError::Other`.
+    pub(crate) fn from_server(message: &str) -> Self {
+        let code = message.split_once(' ').map_or(message, |(code, _)| code);
+
+        match code {
+            "LOADING" => RedisError::Loading(message.to_string()),
+            "BUSY" => RedisError::Busy(message.to_string()),
+            "TRYAGAIN" => RedisError::TryAgain(message.to_string()),
+            "NOSCRIPT" => RedisError::NoScript,
+            "NOAUTH" => RedisError::NoAuth(message.to_string()),
+            "READONLY" => RedisError::ReadOnly(message.to_string()),
+            _ => RedisError::Other(anyhow::anyhow!(message.to_string())),
+        }
+    }
+
+    /// Tells whether the error is likely to go away on its own, so that the same command may
+    /// succeed if sent again a bit later, see `RetryPolicy`.
+    ///
+    /// The server did not run a command it rejected with `LOADING`, `BUSY` or `TRYAGAIN`. A
+    /// timeout is not transient: the command may have run, and its connection is left unusable,
+    /// see `Connection::is_usable`.
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            RedisError::Loading(_) | RedisError::Busy(_) | RedisError::TryAgain(_)
+        )
+    }
+
+    /// Tells whether the server refused a command because the connection is not authenticated,
+    /// e.g. after it reconnected, so that the caller can authenticate again and resend it.
+    pub fn is_auth_error(&self) -> bool {
+        matches!(self, RedisError::NoAuth(_))
+    }
+}
+
 /// A specialized `Result` type for Redis operations.
 pub type Result<T> = anyhow::Result<T, RedisError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_server_noauth() {
+        let err = RedisError::from_server("NOAUTH Authentication required.");
+        assert!(
+            matches!(&err, RedisError::NoAuth(message) if message == "NOAUTH Authentication required.")
+        );
+        assert!(err.is_auth_error());
+        assert!(!err.is_transient());
+
+        assert!(!RedisError::from_server("WRONGTYPE Operation against a key").is_auth_error());
+    }
+
+    #[test]
+    fn test_from_server_readonly() {
+        let err = RedisError::from_server("READONLY You can't write against a read only replica.");
+        assert!(matches!(&err, RedisError::ReadOnly(message) if message.starts_with("READONLY ")));
+        assert!(!err.is_transient());
+
+        // the code is the whole first word
+        assert!(matches!(
+            RedisError::from_server("READONLYX something"),
+            RedisError::Other(_)
+        ));
+    }
+}
@@ -1,21 +1,50 @@
 //! Implements the [RESP3](https://redis.io/docs/latest/develop/reference/protocol-spec)
 //! serialization protocol for Redis client-server communication.
 
-use crate::{RedisError, Result};
+use crate::{
+    RedisError, Result,
+    connection::{MAX_BUFFER_SIZE, ProtocolVersion},
+};
 // use anyhow::Ok; // Removed as it conflicts with the Result type in your crate
 use bytes::{Buf, Bytes, BytesMut};
-use std::io::{BufRead, Cursor};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::io::Cursor;
+use std::str::from_utf8;
 
-#[derive(Debug, PartialEq)]
+// bulk strings from this size on are written from their own memory, see `Frame::serialize_chunks`
+const VECTORED_WRITE_THRESHOLD: usize = 64 * 1024;
+
+// how deep aggregates may nest, so that a hostile reply such as `*1\r\n` repeated cannot overflow
+// the stack of the recursive parser, far more than any Redis reply nests
+const MAX_DEPTH: usize = 128;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct BigInt {
+    // true if the number is negative
     sign: bool,
+    // the ASCII digits of the absolute value
     data: Vec<u8>,
 }
 
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.sign { "-" } else { "" };
+        write!(f, "{sign}{}", String::from_utf8_lossy(&self.data))
+    }
+}
+
 /// Frame represents a single RESP data transmit unit over the socket.
 ///
 /// more on the RESP protocol can be found [here](https://redis.io/topics/protocol)
-#[derive(Debug, PartialEq)]
+///
+/// Cloning a Frame is cheap for binary payloads, which are reference counted `Bytes`.
+/// Frames compare and hash doubles by their bit pattern, so `NaN` equals itself and
+/// `0.0` differs from `-0.0`, which lets any Frame be used as a map key.
+///
+/// Commands are always sent to the server as an Array of bulk strings, whatever their arguments
+/// stand for, e.g. the members of SADD. Sets and Maps are only ever replied by a RESP3 server.
+#[derive(Debug, Clone)]
 pub enum Frame {
     SimpleString(String),
     SimpleError(String),
@@ -32,26 +61,201 @@ pub enum Frame {
     Map(Vec<(Frame, Frame)>),
     Attribute,
     Set(Vec<Frame>),
-    Push,
+    // out-of-band data sent by the server in RESP3, e.g. pub/sub messages
+    Push(Vec<Frame>),
+}
+
+impl PartialEq for Frame {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Frame::SimpleString(a), Frame::SimpleString(b))
+            | (Frame::SimpleError(a), Frame::SimpleError(b)) => a == b,
+            (Frame::Integer(a), Frame::Integer(b)) => a == b,
+            (Frame::BulkString(a), Frame::BulkString(b))
+            | (Frame::BulkError(a), Frame::BulkError(b)) => a == b,
+            (Frame::Array(a), Frame::Array(b))
+            | (Frame::Set(a), Frame::Set(b))
+            | (Frame::Push(a), Frame::Push(b)) => a == b,
+            (Frame::Null, Frame::Null) | (Frame::Attribute, Frame::Attribute) => true,
+            (Frame::Boolean(a), Frame::Boolean(b)) => a == b,
+            (Frame::Double(a), Frame::Double(b)) => a.to_bits() == b.to_bits(),
+            (Frame::BigNumber(a), Frame::BigNumber(b)) => a == b,
+            (Frame::VerbatimString(a_enc, a), Frame::VerbatimString(b_enc, b)) => {
+                a_enc == b_enc && a == b
+            }
+            (Frame::Map(a), Frame::Map(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Frame {}
+
+impl Hash for Frame {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+
+        match self {
+            Frame::SimpleString(val) | Frame::SimpleError(val) => val.hash(state),
+            Frame::Integer(val) => val.hash(state),
+            Frame::BulkString(val) | Frame::BulkError(val) => val.hash(state),
+            Frame::Array(vals) | Frame::Set(vals) | Frame::Push(vals) => vals.hash(state),
+            Frame::Null | Frame::Attribute => {}
+            Frame::Boolean(val) => val.hash(state),
+            Frame::Double(val) => val.to_bits().hash(state),
+            Frame::BigNumber(val) => val.hash(state),
+            Frame::VerbatimString(encoding, val) => {
+                encoding.hash(state);
+                val.hash(state);
+            }
+            Frame::Map(pairs) => pairs.hash(state),
+        }
+    }
+}
+
+/// Formats a Frame the way redis-cli prints replies, e.g. `(integer) 5`, `(nil)` or
+/// `"value"`, with the elements of nested aggregates numbered and indented on their own lines.
+///
+/// The alternate form `{:#}` prints the Frame on a single line, which suits commands,
+/// e.g. `"GET" "mykey"`. Nested aggregates are then wrapped in brackets.
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            self.fmt_inline(f, false)
+        } else {
+            self.fmt_reply(f, 0)
+        }
+    }
 }
 
 impl Frame {
+    /// Returns a Bulk String Frame.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use redis_asyncx::Frame;
+    ///
+    /// assert_eq!(Frame::bulk("GET"), Frame::BulkString("GET".into()));
+    /// ```
+    pub fn bulk(data: impl Into<Bytes>) -> Self {
+        Frame::BulkString(data.into())
+    }
+
+    /// Returns a Simple String Frame.
+    pub fn simple(data: impl Into<String>) -> Self {
+        Frame::SimpleString(data.into())
+    }
+
+    /// Returns an Integer Frame.
+    pub const fn int(data: i64) -> Self {
+        Frame::Integer(data)
+    }
+
     /// Returns an empty Array Frame.
     pub const fn array() -> Self {
         Frame::Array(Vec::new())
     }
 
+    /// Returns an empty Set Frame.
+    pub const fn set() -> Self {
+        Frame::Set(Vec::new())
+    }
+
+    /// Returns an Array Frame of the frames, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use redis_asyncx::Frame;
+    ///
+    /// let frame = Frame::array_from(["GET", "mykey"].map(Frame::bulk));
+    /// assert_eq!(frame, Frame::Array(vec![Frame::bulk("GET"), Frame::bulk("mykey")]));
+    /// ```
+    pub fn array_from(frames: impl IntoIterator<Item = Frame>) -> Self {
+        Frame::Array(frames.into_iter().collect())
+    }
+
+    /// Returns a Set Frame of the frames, in order, skipping those equal to an earlier one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use redis_asyncx::Frame;
+    ///
+    /// let frame = Frame::set_from([Frame::int(1), Frame::int(2), Frame::int(1)]);
+    /// assert_eq!(frame, Frame::Set(vec![Frame::int(1), Frame::int(2)]));
+    /// ```
+    pub fn set_from(frames: impl IntoIterator<Item = Frame>) -> Self {
+        let mut set = Vec::new();
+        for frame in frames {
+            if !set.contains(&frame) {
+                set.push(frame);
+            }
+        }
+
+        Frame::Set(set)
+    }
+
+    /// Returns a Map Frame of the key-value pairs, in order.
+    pub fn map_from(pairs: impl IntoIterator<Item = (Frame, Frame)>) -> Self {
+        Frame::Map(pairs.into_iter().collect())
+    }
+
+    /// Returns the value of the first pair of a Map Frame whose key is the string `key`, e.g. the
+    /// `server` property of the HELLO reply.
+    ///
+    /// Keys match as simple, bulk or verbatim strings. None for other Frames.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use redis_asyncx::Frame;
+    ///
+    /// let frame = Frame::map_from([(Frame::bulk("proto"), Frame::int(3))]);
+    /// assert_eq!(frame.get("proto"), Some(&Frame::int(3)));
+    /// assert_eq!(frame.get("id"), None);
+    /// ```
+    pub fn get(&self, key: &str) -> Option<&Frame> {
+        self.iter().find_map(|(k, value)| {
+            let matches = match k {
+                Frame::SimpleString(k) => k == key,
+                Frame::BulkString(k) | Frame::VerbatimString(_, k) => k == key.as_bytes(),
+                _ => false,
+            };
+
+            matches.then_some(value)
+        })
+    }
+
+    /// Returns an iterator over the key-value pairs of a Map Frame, in the order the server sent
+    /// them. Empty for other Frames.
+    pub fn iter(&self) -> std::slice::Iter<'_, (Frame, Frame)> {
+        match self {
+            Frame::Map(pairs) => pairs.iter(),
+            _ => [].iter(),
+        }
+    }
+
     /// A utility method to push a Frame into an Array/Set Frame.
     ///
+    /// A Set is scanned for an equal Frame first, so pushing many frames into it takes quadratic
+    /// time, see `Frame::set_from`.
+    ///
     /// # Arguments
     ///
     /// * `frame` - A Frame to be pushed into the Array
     ///
-    /// # Panics
+    /// # Returns
     ///
-    /// This method will panic if the Frame is not an Array or Set.
+    /// * `Ok(())` if the Frame is pushed
+    /// * `Err(RedisError::InvalidArgument)` if the Frame is a Set already holding an equal Frame
+    /// * `Err(RedisError::Unknown)` if the Frame is not an Array or Set
     pub fn push_frame_to_array(&mut self, frame: Frame) -> Result<()> {
         match self {
+            Frame::Set(vec) if vec.contains(&frame) => Err(RedisError::InvalidArgument(format!(
+                "{frame:#} is already in the set"
+            ))),
             Frame::Array(vec) | Frame::Set(vec) => {
                 vec.push(frame);
                 Ok(())
@@ -86,10 +290,105 @@ impl Frame {
     /// Caller can get the underlying slice by calling `as_slice` or `as_ref` on the returned value.
     /// It is almost 0 cost to get the slice.
     ///
+    /// Nulls are encoded the RESP3 way, as `_`, see `Frame::serialize_resp2` for RESP2 peers.
+    ///
     /// # Returns
     ///
     /// A Result containing the serialized bytes buffer
     pub async fn serialize(&self) -> Result<Bytes> {
+        self.serialize_as(ProtocolVersion::Resp3).await
+    }
+
+    /// Serializes a Frame the way a RESP2 peer expects it, with nulls encoded as the null bulk
+    /// string `$-1`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the serialized bytes buffer
+    pub async fn serialize_resp2(&self) -> Result<Bytes> {
+        self.serialize_as(ProtocolVersion::Resp2).await
+    }
+
+    /// Serializes a Frame the way a RESP3 peer expects it, the same as `Frame::serialize`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the serialized bytes buffer
+    pub async fn serialize_resp3(&self) -> Result<Bytes> {
+        self.serialize_as(ProtocolVersion::Resp3).await
+    }
+
+    /// Returns the exact length of the Frame once serialized by `Frame::serialize`, without
+    /// serializing it.
+    ///
+    /// Attributes, which cannot be serialized, count for nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use redis_asyncx::Frame;
+    ///
+    /// // *2\r\n$3\r\nGET\r\n$5\r\nmykey\r\n
+    /// let frame = Frame::array_from(["GET", "mykey"].map(Frame::bulk));
+    /// assert_eq!(frame.encoded_len(), 24);
+    /// ```
+    pub fn encoded_len(&self) -> usize {
+        self.encoded_len_as(ProtocolVersion::Resp3)
+    }
+
+    fn encoded_len_as(&self, version: ProtocolVersion) -> usize {
+        // the type byte, then the trailing \r\n
+        let framing = 3;
+
+        match self {
+            Frame::SimpleString(val) | Frame::SimpleError(val) => framing + val.len(),
+            Frame::Integer(val) => framing + integer_len(*val),
+            Frame::BulkString(val) | Frame::BulkError(val) => {
+                framing + decimal_len(val.len()) + 2 + val.len()
+            }
+            Frame::Null => match version {
+                ProtocolVersion::Resp2 => 5,
+                ProtocolVersion::Resp3 => 3,
+            },
+            Frame::Boolean(_) => framing + 1,
+            Frame::Double(val) => {
+                let len = match *val {
+                    val if val.is_nan() => 3,
+                    f64::INFINITY => 3,
+                    f64::NEG_INFINITY => 4,
+                    val => val.to_string().len(),
+                };
+                framing + len
+            }
+            Frame::BigNumber(val) => framing + usize::from(val.sign) + val.data.len(),
+            // the encoding and the colon are part of the payload
+            Frame::VerbatimString(encoding, val) => {
+                let len = encoding.len() + 1 + val.len();
+                framing + decimal_len(len) + 2 + len
+            }
+            Frame::Array(vals) | Frame::Set(vals) | Frame::Push(vals) => {
+                framing
+                    + decimal_len(vals.len())
+                    + vals
+                        .iter()
+                        .map(|frame| frame.encoded_len_as(version))
+                        .sum::<usize>()
+            }
+            Frame::Map(pairs) => {
+                framing
+                    + decimal_len(pairs.len())
+                    + pairs
+                        .iter()
+                        .map(|(key, value)| {
+                            key.encoded_len_as(version) + value.encoded_len_as(version)
+                        })
+                        .sum::<usize>()
+            }
+            Frame::Attribute => 0,
+        }
+    }
+
+    async fn serialize_as(&self, version: ProtocolVersion) -> Result<Bytes> {
         match self {
             Frame::SimpleString(val) => {
                 let mut buf = BytesMut::with_capacity(val.len() + 3);
@@ -139,7 +438,7 @@ impl Frame {
                 Ok(buf.freeze())
             }
             Frame::Array(frame_vec) => {
-                let mut buf = BytesMut::new();
+                let mut buf = BytesMut::with_capacity(self.encoded_len_as(version));
 
                 // * indicates it is an array
                 buf.extend_from_slice(b"*");
@@ -149,19 +448,17 @@ impl Frame {
 
                 // encode each element in the array
                 for frame in frame_vec {
-                    buf.extend_from_slice(&Box::pin(frame.serialize()).await?);
+                    buf.extend_from_slice(&Box::pin(frame.serialize_as(version)).await?);
                 }
 
                 Ok(buf.freeze())
             }
-            Frame::Null => {
-                let mut buf = BytesMut::with_capacity(3);
-
+            Frame::Null => match version {
+                // RESP2 has no null type, a null bulk string stands for it
+                ProtocolVersion::Resp2 => Ok(Bytes::from_static(b"$-1\r\n")),
                 // _ indicates it is a null
-                buf.extend_from_slice(b"_\r\n");
-
-                Ok(buf.freeze())
-            }
+                ProtocolVersion::Resp3 => Ok(Bytes::from_static(b"_\r\n")),
+            },
             Frame::Boolean(val) => {
                 let mut buf: BytesMut = BytesMut::with_capacity(3);
 
@@ -187,6 +484,8 @@ impl Frame {
                         f64::INFINITY => buf.extend_from_slice(b"inf"),
                         f64::NEG_INFINITY => buf.extend_from_slice(b"-inf"),
                         _ => {
+                            // Display never uses the exponent notation, and it prints the shortest
+                            // digits that parse back to the same value, so precision is preserved
                             buf.extend_from_slice(val.to_string().as_bytes());
                         }
                     }
@@ -198,7 +497,18 @@ impl Frame {
                 Ok(buf.freeze())
             }
             Frame::BigNumber(val) => {
-                todo!("BigNumber serialization is not implemented yet {:?}", val)
+                let mut buf: BytesMut = BytesMut::with_capacity(val.data.len() + 4);
+
+                // ( indicates it is a big number
+                buf.extend_from_slice(b"(");
+                if val.sign {
+                    buf.extend_from_slice(b"-");
+                }
+                // encode the digits
+                buf.extend_from_slice(&val.data);
+                buf.extend_from_slice(b"\r\n");
+
+                Ok(buf.freeze())
             }
             Frame::BulkError(val) => {
                 let mut buf = BytesMut::with_capacity(val.len() + 5);
@@ -215,6 +525,11 @@ impl Frame {
                 Ok(buf.freeze())
             }
             Frame::VerbatimString(encoding, val) => {
+                // the encoding must be exactly 3 bytes, otherwise the payload cannot be split back
+                if encoding.len() != 3 {
+                    return Err(RedisError::InvalidFrame);
+                }
+
                 let mut buf: BytesMut = BytesMut::with_capacity(val.len() + 10);
 
                 // = indicates it is a verbatim string
@@ -233,7 +548,7 @@ impl Frame {
                 Ok(buf.freeze())
             }
             Frame::Map(val) => {
-                let mut buf: BytesMut = BytesMut::new();
+                let mut buf: BytesMut = BytesMut::with_capacity(self.encoded_len_as(version));
 
                 // % indicates it is a map
                 buf.extend_from_slice(b"%");
@@ -243,17 +558,14 @@ impl Frame {
 
                 // encode each element in the map
                 for (key, value) in val {
-                    buf.extend_from_slice(&Box::pin(key.serialize()).await?);
-                    buf.extend_from_slice(&Box::pin(value.serialize()).await?);
+                    buf.extend_from_slice(&Box::pin(key.serialize_as(version)).await?);
+                    buf.extend_from_slice(&Box::pin(value.serialize_as(version)).await?);
                 }
 
                 Ok(buf.freeze())
             }
-            Frame::Attribute => {
-                todo!("Attribute serialization is not implemented yet")
-            }
             Frame::Set(val) => {
-                let mut buf: BytesMut = BytesMut::new();
+                let mut buf: BytesMut = BytesMut::with_capacity(self.encoded_len_as(version));
 
                 // ~ indicates it is a set
                 buf.extend_from_slice(b"~");
@@ -263,13 +575,231 @@ impl Frame {
 
                 // encode each element in the set
                 for frame in val {
-                    buf.extend_from_slice(&Box::pin(frame.serialize()).await?);
+                    buf.extend_from_slice(&Box::pin(frame.serialize_as(version)).await?);
                 }
 
                 Ok(buf.freeze())
             }
-            Frame::Push => {
-                todo!("Push serialization is not implemented yet")
+            Frame::Push(val) => {
+                let mut buf: BytesMut = BytesMut::with_capacity(self.encoded_len_as(version));
+
+                // > indicates it is a push
+                buf.extend_from_slice(b">");
+                // encode the number of elements in the push
+                buf.extend_from_slice(val.len().to_string().as_bytes());
+                buf.extend_from_slice(b"\r\n");
+
+                // encode each element in the push
+                for frame in val {
+                    buf.extend_from_slice(&Box::pin(frame.serialize_as(version)).await?);
+                }
+
+                Ok(buf.freeze())
+            }
+            // attributes are only sent by the server
+            Frame::Attribute => Err(RedisError::InvalidFrame),
+        }
+    }
+
+    /// Serializes a Frame into chunks that are written to the stream one after the other.
+    ///
+    /// Unlike `serialize`, the payloads of large bulk strings are not copied: they are chunks of
+    /// their own, sharing the memory of the frame. The rest of the encoding is gathered in the
+    /// chunks in between, so the chunks put together are the same bytes as `serialize` returns.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the chunks, a single one if the frame holds no large bulk string
+    pub async fn serialize_chunks(&self) -> Result<Vec<Bytes>> {
+        self.serialize_chunks_as(ProtocolVersion::Resp3).await
+    }
+
+    /// Serializes a Frame into chunks, encoding nulls the way a peer speaking `version` expects.
+    pub(crate) async fn serialize_chunks_as(&self, version: ProtocolVersion) -> Result<Vec<Bytes>> {
+        self.serialize_chunks_above(VECTORED_WRITE_THRESHOLD, version)
+            .await
+    }
+
+    async fn serialize_chunks_above(
+        &self,
+        threshold: usize,
+        version: ProtocolVersion,
+    ) -> Result<Vec<Bytes>> {
+        let mut chunks = Vec::new();
+        let mut buf = BytesMut::new();
+        self.push_chunks(&mut chunks, &mut buf, threshold, version)
+            .await?;
+
+        if !buf.is_empty() || chunks.is_empty() {
+            chunks.push(buf.freeze());
+        }
+
+        Ok(chunks)
+    }
+
+    /// Appends the encoding of the frame to `buf`, moving `buf` to the chunks before each bulk
+    /// string payload of at least `threshold` bytes, which becomes a chunk as is.
+    async fn push_chunks(
+        &self,
+        chunks: &mut Vec<Bytes>,
+        buf: &mut BytesMut,
+        threshold: usize,
+        version: ProtocolVersion,
+    ) -> Result<()> {
+        match self {
+            Frame::BulkString(val) if val.len() >= threshold => {
+                buf.extend_from_slice(b"$");
+                buf.extend_from_slice(val.len().to_string().as_bytes());
+                buf.extend_from_slice(b"\r\n");
+                chunks.push(buf.split().freeze());
+                // a reference count increment, not a copy
+                chunks.push(val.clone());
+                buf.extend_from_slice(b"\r\n");
+            }
+            Frame::Array(frame_vec) => {
+                buf.extend_from_slice(b"*");
+                buf.extend_from_slice(frame_vec.len().to_string().as_bytes());
+                buf.extend_from_slice(b"\r\n");
+
+                for frame in frame_vec {
+                    Box::pin(frame.push_chunks(chunks, buf, threshold, version)).await?;
+                }
+            }
+            // commands are arrays of bulk strings, the other frames are never large
+            frame => buf.extend_from_slice(&frame.serialize_as(version).await?),
+        }
+
+        Ok(())
+    }
+
+    /// Serializes a Frame and returns the wire encoding as a printable string,
+    /// with CRLF and other control bytes escaped, e.g. `*1\r\n$4\r\nPING\r\n`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the escaped wire encoding
+    pub async fn to_resp_debug_string(&self) -> Result<String> {
+        let bytes = self.serialize().await?;
+
+        let mut escaped = String::with_capacity(bytes.len());
+        for &byte in bytes.iter() {
+            push_escaped(&mut escaped, byte);
+        }
+
+        Ok(escaped)
+    }
+
+    fn fmt_reply(&self, f: &mut fmt::Formatter<'_>, indent: usize) -> fmt::Result {
+        let (items, empty) = match self {
+            Frame::Array(items) => (items, "(empty array)"),
+            Frame::Set(items) => (items, "(empty set)"),
+            Frame::Push(items) => (items, "(empty push)"),
+            Frame::Map(pairs) => {
+                if pairs.is_empty() {
+                    return write!(f, "(empty hash)");
+                }
+
+                let width = pairs.len().to_string().len();
+                for (i, (key, value)) in pairs.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "\n{:indent$}", "")?;
+                    }
+
+                    let prefix = format!("{:>width$}# {key:#} => ", i + 1);
+                    write!(f, "{prefix}")?;
+                    value.fmt_reply(f, indent + prefix.len())?;
+                }
+
+                return Ok(());
+            }
+            _ => return self.fmt_scalar(f),
+        };
+
+        if items.is_empty() {
+            return write!(f, "{empty}");
+        }
+
+        // right-align the indexes so that nested elements line up, like redis-cli does
+        let width = items.len().to_string().len();
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                write!(f, "\n{:indent$}", "")?;
+            }
+
+            let prefix = format!("{:>width$}) ", i + 1);
+            write!(f, "{prefix}")?;
+            item.fmt_reply(f, indent + prefix.len())?;
+        }
+
+        Ok(())
+    }
+
+    fn fmt_inline(&self, f: &mut fmt::Formatter<'_>, nested: bool) -> fmt::Result {
+        match self {
+            Frame::Array(items) | Frame::Set(items) | Frame::Push(items) => {
+                if nested {
+                    write!(f, "[")?;
+                }
+
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " ")?;
+                    }
+                    item.fmt_inline(f, true)?;
+                }
+
+                if nested {
+                    write!(f, "]")?;
+                }
+
+                Ok(())
+            }
+            Frame::Map(pairs) => {
+                write!(f, "{{")?;
+
+                for (i, (key, value)) in pairs.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    key.fmt_inline(f, true)?;
+                    write!(f, " => ")?;
+                    value.fmt_inline(f, true)?;
+                }
+
+                write!(f, "}}")
+            }
+            _ => self.fmt_scalar(f),
+        }
+    }
+
+    fn fmt_scalar(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Frame::SimpleString(val) => write!(f, "{val}"),
+            Frame::SimpleError(val) => write!(f, "(error) {val}"),
+            Frame::Integer(val) => write!(f, "(integer) {val}"),
+            Frame::BulkString(val) | Frame::VerbatimString(_, val) => {
+                let mut quoted = String::with_capacity(val.len() + 2);
+
+                quoted.push('"');
+                for &byte in val.iter() {
+                    match byte {
+                        b'"' => quoted.push_str("\\\""),
+                        byte => push_escaped(&mut quoted, byte),
+                    }
+                }
+                quoted.push('"');
+
+                write!(f, "{quoted}")
+            }
+            Frame::Null => write!(f, "(nil)"),
+            Frame::Boolean(val) => write!(f, "({val})"),
+            Frame::Double(val) if val.is_nan() => write!(f, "(double) nan"),
+            Frame::Double(val) => write!(f, "(double) {val}"),
+            Frame::BigNumber(val) => write!(f, "(big number) {val}"),
+            Frame::BulkError(val) => write!(f, "(error) {}", String::from_utf8_lossy(val)),
+            Frame::Attribute => write!(f, "(attribute)"),
+            Frame::Array(_) | Frame::Set(_) | Frame::Push(_) | Frame::Map(_) => {
+                self.fmt_inline(f, true)
             }
         }
     }
@@ -286,8 +816,8 @@ impl Frame {
     ///
     /// A Result containing the deserialized Frame
     pub async fn deserialize(buf: Bytes) -> Result<Frame> {
-        // the cursor is almost zero cost as it is just a smart ptr to the buffer
-        Frame::try_parse(&mut Cursor::new(&buf[..]))
+        // the payloads are slices of the buffer, nothing is copied
+        Frame::parse_shared(&buf, MAX_BUFFER_SIZE)
     }
 
     /// Tries parsing a Frame from the buffer.
@@ -300,158 +830,260 @@ impl Frame {
     /// * `Ok(usize)` if the buffer contains a complete frame, the number of bytes needed to parse the frame
     /// * `Err(RedisError::IncompleteFrame)` if the buffer contains an incomplete frame
     /// * `Err(RedisError::InvalidFrame)` if the buffer contains an invalid frame
+    /// * `Err(RedisError::FrameTooLarge)` if a declared length exceeds `MAX_BUFFER_SIZE`
     pub fn try_parse(cursor: &mut Cursor<&[u8]>) -> Result<Frame> {
+        Frame::try_parse_with_limit(cursor, MAX_BUFFER_SIZE)
+    }
+
+    /// Tries parsing a Frame from the buffer, rejecting declared lengths larger than `max_len`.
+    ///
+    /// The declared length of a bulk string or an aggregate is checked before waiting for its payload,
+    /// so that a corrupt header fails immediately instead of buffering the connection forever.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - A cursor over the read buffer
+    /// * `max_len` - The maximum number of bytes or elements a single frame can declare
+    pub fn try_parse_with_limit(cursor: &mut Cursor<&[u8]>, max_len: usize) -> Result<Frame> {
+        Frame::parse(cursor, max_len, Payloads::Copy, 0)
+    }
+
+    /// Parses every Frame of a buffer holding several concatenated frames, e.g. pipelined replies.
+    ///
+    /// # Arguments
+    ///
+    /// * `buf` - A buffer of complete frames
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Frame>)` the frames in the order of the buffer, empty if the buffer is empty
+    /// * `Err(RedisError::IncompleteFrame)` if the buffer ends with a partial frame
+    /// * `Err(RedisError::InvalidFrame)` if the buffer contains an invalid frame
+    pub fn parse_all(buf: &[u8]) -> Result<Vec<Frame>> {
+        let mut cursor = Cursor::new(buf);
+        let mut frames = Vec::new();
+
+        while (cursor.position() as usize) < buf.len() {
+            frames.push(Frame::try_parse(&mut cursor)?);
+        }
+
+        Ok(frames)
+    }
+
+    /// Checks whether the buffer starts with a complete and valid Frame, without copying its payloads.
+    ///
+    /// On success the cursor is advanced past the Frame, so its position is the length of the Frame.
+    pub(crate) fn check(cursor: &mut Cursor<&[u8]>, max_len: usize) -> Result<()> {
+        Frame::parse(cursor, max_len, Payloads::Skip, 0).map(drop)
+    }
+
+    /// Parses a Frame from the start of a shared buffer.
+    ///
+    /// The payloads of the Frame are slices sharing the memory of `buf` rather than copies, so large
+    /// replies are never copied out of the read buffer.
+    pub(crate) fn parse_shared(buf: &Bytes, max_len: usize) -> Result<Frame> {
+        Frame::parse(&mut Cursor::new(&buf[..]), max_len, Payloads::Slice(buf), 0)
+    }
+
+    /// Parses a Frame nested in `depth` aggregates, refused past `MAX_DEPTH`.
+    ///
+    /// Only the aggregates recurse, the other types are parsed by `Frame::parse_scalar`, which
+    /// keeps their locals off the stack of every nesting level.
+    fn parse(
+        cursor: &mut Cursor<&[u8]>,
+        max_len: usize,
+        payloads: Payloads,
+        depth: usize,
+    ) -> Result<Frame> {
+        if depth > MAX_DEPTH {
+            return Err(RedisError::InvalidFrame);
+        }
+
         if !cursor.has_remaining() {
             return Err(RedisError::IncompleteFrame);
         }
 
         match cursor.get_u8() {
+            b'*' => {
+                // Array
+                // for RESP2, -1 indicates a null array
+                let Some(len) = parse_len(cursor, max_len)? else {
+                    return Ok(Frame::Null);
+                };
+                // the declared length is not trusted for the allocation, every element takes at least 3 bytes
+                let mut frame_vec: Vec<_> = Vec::with_capacity(len.min(cursor.remaining() / 3));
+
+                for _ in 0..len {
+                    frame_vec.push(Frame::parse(cursor, max_len, payloads, depth + 1)?);
+                }
+
+                Ok(Frame::Array(frame_vec))
+            }
+            b'%' => {
+                // Map
+                let Some(len) = parse_len(cursor, max_len)? else {
+                    return Ok(Frame::Null);
+                };
+                // bounded by the remaining data, as for arrays
+                let mut frame_vec: Vec<_> = Vec::with_capacity(len.min(cursor.remaining() / 3));
+
+                for _ in 0..len {
+                    let key = Frame::parse(cursor, max_len, payloads, depth + 1)?;
+                    let value = Frame::parse(cursor, max_len, payloads, depth + 1)?;
+                    frame_vec.push((key, value));
+                }
+
+                Ok(Frame::Map(frame_vec))
+            }
+            b'|' => {
+                // Attribute
+                // attributes are auxiliary data sent before the actual reply, we skip them for now
+                let len = parse_len(cursor, max_len)?.ok_or(RedisError::InvalidFrame)?;
+
+                for _ in 0..len {
+                    Frame::parse(cursor, max_len, payloads, depth + 1)?;
+                    Frame::parse(cursor, max_len, payloads, depth + 1)?;
+                }
+
+                // one level deeper, so that a run of attributes is bounded as well
+                Frame::parse(cursor, max_len, payloads, depth + 1)
+            }
+            b'~' => {
+                // Set
+                let Some(len) = parse_len(cursor, max_len)? else {
+                    return Ok(Frame::Null);
+                };
+                // bounded by the remaining data, as for arrays
+                let mut frame_vec: Vec<_> = Vec::with_capacity(len.min(cursor.remaining() / 3));
+
+                for _ in 0..len {
+                    frame_vec.push(Frame::parse(cursor, max_len, payloads, depth + 1)?);
+                }
+
+                Ok(Frame::Set(frame_vec))
+            }
+            b'>' => {
+                // Push
+                // there is no null push
+                let len = parse_len(cursor, max_len)?.ok_or(RedisError::InvalidFrame)?;
+                // bounded by the remaining data, as for arrays
+                let mut frame_vec: Vec<_> = Vec::with_capacity(len.min(cursor.remaining() / 3));
+
+                for _ in 0..len {
+                    frame_vec.push(Frame::parse(cursor, max_len, payloads, depth + 1)?);
+                }
+
+                Ok(Frame::Push(frame_vec))
+            }
+            kind => Frame::parse_scalar(kind, cursor, max_len, payloads),
+        }
+    }
+
+    /// Parses a Frame that is not an aggregate, the type byte `kind` already read.
+    fn parse_scalar(
+        kind: u8,
+        cursor: &mut Cursor<&[u8]>,
+        max_len: usize,
+        payloads: Payloads,
+    ) -> Result<Frame> {
+        match kind {
             b'+' => {
                 // Simple string
-                let mut buf = String::new();
-                cursor.read_line(&mut buf)?;
+                let line = read_line(cursor)?;
 
-                if buf.ends_with("\r\n") {
-                    Ok(Frame::SimpleString(
-                        buf.trim_end_matches("\r\n").to_string(),
-                    ))
-                } else {
-                    // fixme: there maybe edge cases here
-                    // we need to guarantee there's no more \r\n in the buffer
-                    Err(RedisError::IncompleteFrame)
-                }
+                Ok(Frame::SimpleString(from_utf8(line)?.to_string()))
             }
             b'-' => {
                 // Simple error
-                let mut buf = String::new();
-                cursor.read_line(&mut buf)?;
+                let line = read_line(cursor)?;
 
-                if buf.ends_with("\r\n") {
-                    Ok(Frame::SimpleError(buf.trim_end_matches("\r\n").to_string()))
-                } else {
-                    // fixme: there maybe edge cases here
-                    // we need to guarantee there's no more \r\n in the buffer
-                    Err(RedisError::IncompleteFrame)
-                }
+                Ok(Frame::SimpleError(from_utf8(line)?.to_string()))
             }
             b':' => {
                 // Integer
-                let mut buf = String::new();
-                cursor.read_line(&mut buf)?;
+                let line = read_line(cursor)?;
 
-                // todo: check whether it is a valid integer
-                if buf.ends_with("\r\n") {
-                    Ok(Frame::Integer(buf.trim_end_matches("\r\n").parse::<i64>()?))
-                } else {
-                    Err(RedisError::IncompleteFrame)
-                }
+                Ok(Frame::Integer(from_utf8(line)?.parse::<i64>()?))
             }
             b'$' => {
                 // Bulk string
-                let mut buf = String::new();
-                // read the length of the bulk string
-                cursor.read_line(&mut buf)?;
-
-                if !buf.ends_with("\r\n") {
-                    return Err(RedisError::IncompleteFrame);
-                }
-
-                let len: isize = buf.trim_end_matches("\r\n").parse::<isize>()?;
-
                 // for RESP2, -1 indicates a null bulk string
-                if len == -1 {
+                let Some(len) = parse_len(cursor, max_len)? else {
                     return Ok(Frame::Null);
-                }
+                };
 
                 // +2 because \r\n
-                if cursor.remaining() < len as usize + 2 {
+                if cursor.remaining() < len + 2 {
                     return Err(RedisError::IncompleteFrame);
                 }
 
-                let data = Bytes::copy_from_slice(&cursor.chunk()[..len as usize]);
+                // check if cursor ends with \r\n
+                if !cursor.chunk()[len..].starts_with(b"\r\n") {
+                    return Err(RedisError::InvalidFrame);
+                }
+
+                let data = payloads.take(cursor, 0, len);
 
                 // advance cursor
-                cursor.advance(len as usize + 2);
+                cursor.advance(len + 2);
 
                 Ok(Frame::BulkString(data))
             }
-            b'*' => {
-                // Array
-                let mut buf = String::new();
-                cursor.read_line(&mut buf)?;
-
-                let len = buf.trim_end_matches("\r\n").parse::<usize>()?;
-                let mut frame_vec: Vec<_> = Vec::with_capacity(len);
-
-                for _ in 0..len {
-                    frame_vec.push(Frame::try_parse(cursor)?);
+            b'_' => {
+                // Null
+                if !read_line(cursor)?.is_empty() {
+                    return Err(RedisError::InvalidFrame);
                 }
 
-                Ok(Frame::Array(frame_vec))
+                Ok(Frame::Null)
             }
-            b'_' => Ok(Frame::Null),
             b'#' => {
                 // Boolean
-                let mut buf = String::new();
-                cursor.read_line(&mut buf)?;
-
-                if buf.ends_with("\r\n") {
-                    let val = buf.trim_end_matches("\r\n");
-                    if val == "t" {
-                        Ok(Frame::Boolean(true))
-                    } else if val == "f" {
-                        Ok(Frame::Boolean(false))
-                    } else {
-                        Err(RedisError::InvalidFrame)
-                    }
-                } else {
-                    Err(RedisError::IncompleteFrame)
+                match read_line(cursor)? {
+                    b"t" => Ok(Frame::Boolean(true)),
+                    b"f" => Ok(Frame::Boolean(false)),
+                    _ => Err(RedisError::InvalidFrame),
                 }
             }
             b',' => {
                 // Double
-                let mut buf = String::new();
-                cursor.read_line(&mut buf)?;
-
-                if buf.ends_with("\r\n") {
-                    let val = buf.trim_end_matches("\r\n");
-                    if val == "nan" {
-                        Ok(Frame::Double(f64::NAN))
-                    } else if val == "inf" {
-                        Ok(Frame::Double(f64::INFINITY))
-                    } else if val == "-inf" {
-                        Ok(Frame::Double(f64::NEG_INFINITY))
-                    } else {
-                        Ok(Frame::Double(
-                            val.parse::<f64>().map_err(|_| RedisError::InvalidFrame)?,
-                        ))
-                    }
-                } else {
-                    Err(RedisError::IncompleteFrame)
+                match read_line(cursor)? {
+                    b"nan" => Ok(Frame::Double(f64::NAN)),
+                    b"inf" => Ok(Frame::Double(f64::INFINITY)),
+                    b"-inf" => Ok(Frame::Double(f64::NEG_INFINITY)),
+                    // f64::from_str is looser than the spec, e.g. it accepts "infinity" or ".5"
+                    val if is_resp_double(val) => Ok(Frame::Double(
+                        from_utf8(val)?
+                            .parse::<f64>()
+                            .map_err(|_| RedisError::InvalidFrame)?,
+                    )),
+                    _ => Err(RedisError::InvalidFrame),
                 }
             }
             b'(' => {
                 // Big number
-                todo!("Big number deserialization is not implemented yet")
-            }
-            b'!' => {
-                // Bulk error
-                let mut buf = String::new();
-                // read the length of the bulk string
-                cursor.read_line(&mut buf)?;
+                let line = read_line(cursor)?;
 
-                if !buf.ends_with("\r\n") {
-                    return Err(RedisError::IncompleteFrame);
-                }
+                let (sign, digits) = match line {
+                    [b'-', digits @ ..] => (true, digits),
+                    [b'+', digits @ ..] | digits => (false, digits),
+                };
 
-                let len: isize = buf.trim_end_matches("\r\n").parse::<isize>()?;
+                if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+                    return Err(RedisError::InvalidFrame);
+                }
 
+                Ok(Frame::BigNumber(BigInt {
+                    sign,
+                    data: digits.to_vec(),
+                }))
+            }
+            b'!' => {
+                // Bulk error
                 // for RESP2, -1 indicates a null bulk error
-                if len == -1 {
+                let Some(len) = parse_len(cursor, max_len)? else {
                     return Ok(Frame::Null);
-                }
-
-                let len: usize = len.try_into()?;
+                };
 
                 // +2 because \r\n
                 if cursor.remaining() < len + 2 {
@@ -463,7 +1095,7 @@ impl Frame {
                     return Err(RedisError::InvalidFrame);
                 }
 
-                let data = Bytes::copy_from_slice(&cursor.chunk()[..len]);
+                let data = payloads.take(cursor, 0, len);
 
                 // advance cursor
                 cursor.advance(len + 2);
@@ -472,80 +1104,168 @@ impl Frame {
             }
             b'=' => {
                 // Verbatim string
-                let mut buf = String::new();
-                // read the length of the bulk string
-                cursor.read_line(&mut buf)?;
+                // there is no null verbatim string
+                let len = parse_len(cursor, max_len)?.ok_or(RedisError::InvalidFrame)?;
 
-                if !buf.ends_with("\r\n") {
-                    return Err(RedisError::IncompleteFrame);
+                // the payload is at least a 3 bytes encoding and a :, no need to wait for it
+                if len < 4 {
+                    return Err(RedisError::InvalidFrame);
                 }
 
-                let len: usize = buf.trim_end_matches("\r\n").parse::<usize>()?;
-
                 // +2 for \r\n
                 if cursor.remaining() < len + 2 {
                     return Err(RedisError::IncompleteFrame);
                 }
 
-                // check if cursor ends with \r\n
-                if !cursor.chunk()[len..].starts_with(b"\r\n") {
-                    return Err(RedisError::InvalidFrame);
-                }
+                // check if cursor ends with \r\n
+                if !cursor.chunk()[len..].starts_with(b"\r\n") {
+                    return Err(RedisError::InvalidFrame);
+                }
+
+                // the encoding is exactly 3 alphanumeric bytes, e.g. txt or mkd, followed by a :
+                let (encoding, delimiter) = (&cursor.chunk()[..3], cursor.chunk()[3]);
+                if !encoding.iter().all(u8::is_ascii_alphanumeric) || delimiter != b':' {
+                    return Err(RedisError::InvalidFrame);
+                }
+
+                // split data into encoding and value, skipping the : delimiter
+                let encoding = payloads.take(cursor, 0, 3);
+                let data = payloads.take(cursor, 4, len - 4);
+
+                // advance cursor
+                cursor.advance(len + 2);
+
+                Ok(Frame::VerbatimString(encoding, data))
+            }
+            _ => Err(RedisError::InvalidFrame),
+        }
+    }
+}
+
+/// How the parser produces the payloads of bulk strings, bulk errors and verbatim strings.
+#[derive(Clone, Copy)]
+enum Payloads<'a> {
+    /// Copy the payloads out of the buffer.
+    Copy,
+    /// Slice the payloads out of the shared buffer the cursor reads from.
+    Slice(&'a Bytes),
+    /// Leave the payloads empty, the Frame is only parsed to be validated.
+    Skip,
+}
+
+impl Payloads<'_> {
+    /// Returns `len` bytes of payload starting `offset` bytes after the cursor.
+    fn take(self, cursor: &Cursor<&[u8]>, offset: usize, len: usize) -> Bytes {
+        match self {
+            Payloads::Copy => Bytes::copy_from_slice(&cursor.chunk()[offset..offset + len]),
+            Payloads::Slice(buf) => {
+                let start = cursor.position() as usize + offset;
+                buf.slice(start..start + len)
+            }
+            Payloads::Skip => Bytes::new(),
+        }
+    }
+}
+
+/// Returns the number of decimal digits of `val`.
+fn decimal_len(val: usize) -> usize {
+    val.checked_ilog10().map_or(1, |log| log as usize + 1)
+}
+
+/// Returns the length of `val` in decimal, sign included.
+fn integer_len(val: i64) -> usize {
+    usize::from(val < 0)
+        + val
+            .unsigned_abs()
+            .checked_ilog10()
+            .map_or(1, |log| log as usize + 1)
+}
 
-                // read the encoding
-                let mut data = Bytes::copy_from_slice(&cursor.chunk()[..len]);
+/// Escapes control characters, backslashes and non-ASCII bytes the same way redis-cli does.
+fn push_escaped(out: &mut String, byte: u8) {
+    match byte {
+        b'\\' => out.push_str("\\\\"),
+        b'\n' => out.push_str("\\n"),
+        b'\r' => out.push_str("\\r"),
+        b'\t' => out.push_str("\\t"),
+        0x20..=0x7e => out.push(byte as char),
+        _ => out.push_str(&format!("\\x{byte:02x}")),
+    }
+}
 
-                // split data into encoding and value, : as the delimiter
-                let encoding: Bytes = data.split_to(3);
+/// Reads a line terminated by \r\n and advances the cursor past the terminator.
+///
+/// The returned slice borrows from the read buffer without the terminator, so reading a line does not allocate.
+///
+/// # Returns
+///
+/// * `Ok(&[u8])` the content of the line
+/// * `Err(RedisError::IncompleteFrame)` if the buffer does not contain a full line yet
+fn read_line<'a>(cursor: &mut Cursor<&'a [u8]>) -> Result<&'a [u8]> {
+    let buf: &'a [u8] = cursor.get_ref();
+    let start = cursor.position() as usize;
+    let rest = buf.get(start..).ok_or(RedisError::IncompleteFrame)?;
 
-                // data[0] is b':', ignore it
-                data.advance(1);
+    let end = rest
+        .windows(2)
+        .position(|window| window == b"\r\n")
+        .ok_or(RedisError::IncompleteFrame)?;
 
-                // advance cursor
-                cursor.advance(len + 2);
+    cursor.set_position((start + end + 2) as u64);
 
-                Ok(Frame::VerbatimString(encoding, data))
-            }
-            b'%' => {
-                // Map
-                let mut buf = String::new();
-                cursor.read_line(&mut buf)?;
+    Ok(&rest[..end])
+}
 
-                let len = buf.trim_end_matches("\r\n").parse::<usize>()?;
-                let mut frame_vec: Vec<_> = Vec::with_capacity(len);
+/// Checks a double against the RESP3 grammar, `[+|-]<integral>[.<fractional>][<E|e>[sign]<exponent>]`.
+fn is_resp_double(val: &[u8]) -> bool {
+    // consumes an optional sign followed by at least one digit
+    fn digits(val: &[u8], signed: bool) -> Option<&[u8]> {
+        let val = match val {
+            [b'+' | b'-', rest @ ..] if signed => rest,
+            _ => val,
+        };
+        let len = val.iter().take_while(|b| b.is_ascii_digit()).count();
+
+        (len > 0).then(|| &val[len..])
+    }
 
-                for _ in 0..len {
-                    let key = Frame::try_parse(cursor)?;
-                    let value = Frame::try_parse(cursor)?;
-                    frame_vec.push((key, value));
-                }
+    let Some(mut rest) = digits(val, true) else {
+        return false;
+    };
 
-                Ok(Frame::Map(frame_vec))
-            }
-            b'&' => {
-                // Attribute
-                todo!("Attribute deserialization is not implemented yet")
-            }
-            b'~' => {
-                // Set
-                let mut buf = String::new();
-                cursor.read_line(&mut buf)?;
+    if let [b'.', fractional @ ..] = rest {
+        let Some(after) = digits(fractional, false) else {
+            return false;
+        };
+        rest = after;
+    }
 
-                let len = buf.trim_end_matches("\r\n").parse::<usize>()?;
-                let mut frame_vec: Vec<_> = Vec::with_capacity(len);
+    if let [b'e' | b'E', exponent @ ..] = rest {
+        let Some(after) = digits(exponent, true) else {
+            return false;
+        };
+        rest = after;
+    }
 
-                for _ in 0..len {
-                    frame_vec.push(Frame::try_parse(cursor)?);
-                }
+    rest.is_empty()
+}
 
-                Ok(Frame::Set(frame_vec))
-            }
-            b'>' => {
-                // Push
-                todo!("Push deserialization is not implemented yet")
-            }
-            _ => Err(RedisError::InvalidFrame),
-        }
+/// Reads the declared length of a frame from its header, the number of bytes of a bulk string
+/// or the number of elements of an aggregate (array, map or set).
+///
+/// # Returns
+///
+/// * `Ok(Some(usize))` the length that follows the header
+/// * `Ok(None)` if the length is -1, the RESP2 way of sending a null bulk string or null array
+/// * `Err(RedisError::IncompleteFrame)` if the header is not terminated yet
+/// * `Err(RedisError::InvalidFrame)` if the length is any other negative value
+/// * `Err(RedisError::FrameTooLarge)` if the length exceeds `max_len`
+fn parse_len(cursor: &mut Cursor<&[u8]>, max_len: usize) -> Result<Option<usize>> {
+    match from_utf8(read_line(cursor)?)?.parse::<isize>()? {
+        -1 => Ok(None),
+        len if len < 0 => Err(RedisError::InvalidFrame),
+        len if len as usize > max_len => Err(RedisError::FrameTooLarge(len as usize)),
+        len => Ok(Some(len as usize)),
     }
 }
 
@@ -687,6 +1407,32 @@ mod tests {
         assert_eq!(bytes, Bytes::from_static(b"_\r\n"));
     }
 
+    /// Tests that nulls are encoded per protocol version, as null bulk strings in RESP2.
+    #[tokio::test]
+    async fn test_serialize_null_per_version() {
+        let frame = Frame::Array(vec![Frame::bulk("a"), Frame::Null]);
+
+        let bytes = frame
+            .serialize_resp2()
+            .await
+            .unwrap_or_else(|err| panic!("Failed to serialize frame: {:?}", err));
+        assert_eq!(bytes, Bytes::from_static(b"*2\r\n$1\r\na\r\n$-1\r\n"));
+
+        let bytes = frame
+            .serialize_resp3()
+            .await
+            .unwrap_or_else(|err| panic!("Failed to serialize frame: {:?}", err));
+        assert_eq!(bytes, Bytes::from_static(b"*2\r\n$1\r\na\r\n_\r\n"));
+
+        // both encodings parse back to the same frame
+        for bytes in [&b"$-1\r\n"[..], b"_\r\n"] {
+            let parsed = Frame::deserialize(Bytes::copy_from_slice(bytes))
+                .await
+                .unwrap_or_else(|err| panic!("Failed to deserialize null: {:?}", err));
+            assert_eq!(parsed, Frame::Null);
+        }
+    }
+
     /// Tests the serialization of a boolean frame.
     #[tokio::test]
     async fn test_serialize_boolean() {
@@ -741,6 +1487,23 @@ mod tests {
             .unwrap_or_else(|err| panic!("Failed to serialize negative infinity frame: {:?}", err));
 
         assert_eq!(bytes, Bytes::from_static(b",-inf\r\n"));
+
+        // large and tiny magnitudes are written without an exponent
+        let frame = Frame::Double(1e21);
+        let bytes = frame
+            .serialize()
+            .await
+            .unwrap_or_else(|err| panic!("Failed to serialize double frame: {:?}", err));
+
+        assert_eq!(bytes, Bytes::from_static(b",1000000000000000000000\r\n"));
+
+        let frame = Frame::Double(-1.5e-7);
+        let bytes = frame
+            .serialize()
+            .await
+            .unwrap_or_else(|err| panic!("Failed to serialize double frame: {:?}", err));
+
+        assert_eq!(bytes, Bytes::from_static(b",-0.00000015\r\n"));
     }
 
     /// Tests the serialization of a bulk error frame.
@@ -828,6 +1591,144 @@ mod tests {
         );
     }
 
+    /// Tests that a set frame rejects a duplicate frame.
+    #[test]
+    fn test_push_duplicate_to_set() {
+        let mut frame = Frame::set();
+        frame
+            .push_frame_to_array(Frame::bulk("Hello"))
+            .unwrap_or_else(|err| panic!("Failed to push to set frame: {:?}", err));
+
+        assert!(matches!(
+            frame.push_frame_to_array(Frame::bulk("Hello")),
+            Err(RedisError::InvalidArgument(_))
+        ));
+        // only equal frames are duplicates
+        frame
+            .push_frame_to_array(Frame::SimpleString("Hello".to_string()))
+            .unwrap_or_else(|err| panic!("Failed to push to set frame: {:?}", err));
+        assert_eq!(
+            frame,
+            Frame::Set(vec![
+                Frame::bulk("Hello"),
+                Frame::SimpleString("Hello".to_string())
+            ])
+        );
+
+        // an array keeps its duplicates
+        let mut frame = Frame::array();
+        for _ in 0..2 {
+            frame
+                .push_frame_to_array(Frame::bulk("Hello"))
+                .unwrap_or_else(|err| panic!("Failed to push to array frame: {:?}", err));
+        }
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("Hello"), Frame::bulk("Hello")])
+        );
+    }
+
+    /// Tests building aggregate frames from iterators.
+    #[tokio::test]
+    async fn test_aggregate_from_iter() {
+        let frame = Frame::array_from(["a", "b", "a"].map(Frame::bulk));
+        assert_eq!(
+            frame,
+            Frame::Array(vec![Frame::bulk("a"), Frame::bulk("b"), Frame::bulk("a")])
+        );
+
+        let frame = Frame::set_from(["a", "b", "a"].map(Frame::bulk));
+        let bytes = frame
+            .serialize()
+            .await
+            .unwrap_or_else(|err| panic!("Failed to serialize set frame: {:?}", err));
+        assert_eq!(bytes, Bytes::from_static(b"~2\r\n$1\r\na\r\n$1\r\nb\r\n"));
+
+        let frame = Frame::map_from([
+            (Frame::simple("first"), Frame::int(1)),
+            (Frame::simple("second"), Frame::int(2)),
+        ]);
+        let bytes = frame
+            .serialize()
+            .await
+            .unwrap_or_else(|err| panic!("Failed to serialize map frame: {:?}", err));
+        assert_eq!(
+            bytes,
+            Bytes::from_static(b"%2\r\n+first\r\n:1\r\n+second\r\n:2\r\n")
+        );
+        assert_eq!(Frame::map_from([]), Frame::Map(Vec::new()));
+    }
+
+    /// Tests that the encoded length is the length of the serialized frame.
+    #[tokio::test]
+    async fn test_encoded_len() {
+        let frames = [
+            Frame::array_from(["SET", "mykey", "myvalue"].map(Frame::bulk)),
+            Frame::array_from((0..1000).map(|i| Frame::bulk(i.to_string()))),
+            Frame::map_from([
+                (Frame::simple("id"), Frame::int(-42)),
+                (Frame::simple("score"), Frame::Double(1.5)),
+                (Frame::simple("nothing"), Frame::Null),
+            ]),
+            Frame::Array(vec![
+                Frame::Set(vec![Frame::Boolean(true), Frame::Double(f64::NEG_INFINITY)]),
+                Frame::map_from([(
+                    Frame::bulk("nested"),
+                    Frame::Push(vec![Frame::bulk(""), Frame::int(i64::MIN)]),
+                )]),
+                Frame::VerbatimString(Bytes::from_static(b"txt"), Bytes::from_static(b"hi")),
+                Frame::BulkError(Bytes::from_static(b"ERR oops")),
+                Frame::BigNumber(BigInt {
+                    sign: true,
+                    data: b"12345678901234567890".to_vec(),
+                }),
+            ]),
+            Frame::array(),
+        ];
+
+        for frame in frames {
+            let bytes = frame
+                .serialize()
+                .await
+                .unwrap_or_else(|err| panic!("Failed to serialize {:?}: {:?}", frame, err));
+            assert_eq!(frame.encoded_len(), bytes.len(), "{:?}", frame);
+
+            // nulls are longer in RESP2
+            let bytes = frame
+                .serialize_resp2()
+                .await
+                .unwrap_or_else(|err| panic!("Failed to serialize {:?}: {:?}", frame, err));
+            assert_eq!(
+                frame.encoded_len_as(ProtocolVersion::Resp2),
+                bytes.len(),
+                "{:?}",
+                frame
+            );
+        }
+    }
+
+    /// Tests looking up the values of a map frame by key.
+    #[test]
+    fn test_map_get() {
+        let flags = Frame::Array(vec![Frame::bulk("readonly"), Frame::bulk("fast")]);
+        let frame = Frame::map_from([
+            (Frame::bulk("name"), Frame::bulk("get")),
+            (Frame::simple("flags"), flags.clone()),
+            (Frame::int(3), Frame::bulk("three")),
+        ]);
+
+        // a nested value is returned as is
+        assert_eq!(frame.get("name"), Some(&Frame::bulk("get")));
+        assert_eq!(frame.get("flags"), Some(&flags));
+        // only string keys match
+        assert_eq!(frame.get("3"), None);
+        assert_eq!(frame.get("missing"), None);
+        assert_eq!(frame.iter().count(), 3);
+
+        assert_eq!(Frame::Array(vec![Frame::bulk("name")]).get("name"), None);
+        assert_eq!(Frame::array().iter().count(), 0);
+    }
+
     /// Tests the deserialization of a simple string frame.
     #[tokio::test]
     async fn test_deserialize_simple_string() {
@@ -945,6 +1846,218 @@ mod tests {
         assert_eq!(frame, expected_frame);
     }
 
+    /// Tests the deserialization of a push frame.
+    #[tokio::test]
+    async fn test_deserialize_push() {
+        let bytes = Bytes::from_static(b">3\r\n$7\r\nmessage\r\n$4\r\nnews\r\n$5\r\nhello\r\n");
+
+        let frame = Frame::deserialize(bytes)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to deserialize push frame: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::Push(vec![
+                Frame::BulkString(Bytes::from_static(b"message")),
+                Frame::BulkString(Bytes::from_static(b"news")),
+                Frame::BulkString(Bytes::from_static(b"hello")),
+            ])
+        );
+
+        let result = Frame::deserialize(Bytes::from_static(b">-1\r\n")).await;
+
+        assert!(matches!(result, Err(RedisError::InvalidFrame)));
+    }
+
+    /// Tests the deserialization of the RESP2 null array and invalid negative lengths.
+    #[tokio::test]
+    async fn test_deserialize_null_array() {
+        let bytes = Bytes::from_static(b"*-1\r\n");
+
+        let frame = Frame::deserialize(bytes)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to deserialize null array frame: {:?}", err));
+
+        assert_eq!(frame, Frame::Null);
+
+        // maps and sets accept the same null length
+        for bytes in [b"%-1\r\n", b"~-1\r\n"] {
+            let frame = Frame::deserialize(Bytes::from_static(bytes))
+                .await
+                .unwrap_or_else(|err| panic!("Failed to deserialize null frame: {:?}", err));
+
+            assert_eq!(frame, Frame::Null);
+        }
+
+        // other negative lengths are invalid
+        for bytes in [&b"*-2\r\n"[..], b"%-5\r\n", b"~-100\r\n"] {
+            let result = Frame::deserialize(Bytes::from_static(bytes)).await;
+
+            assert!(matches!(result, Err(RedisError::InvalidFrame)));
+        }
+
+        // a header without its terminator needs more data
+        let result = Frame::deserialize(Bytes::from_static(b"*-1")).await;
+
+        assert!(matches!(result, Err(RedisError::IncompleteFrame)));
+    }
+
+    /// Tests that absurd declared lengths fail immediately instead of waiting for more data.
+    #[tokio::test]
+    async fn test_deserialize_absurd_lengths() {
+        let headers: [&[u8]; 6] = [
+            b"$9999999999999\r\n",
+            b"!9999999999999\r\n",
+            b"=9999999999999\r\n",
+            b"*9999999999999\r\n",
+            b"%9999999999999\r\n",
+            b"~9999999999999\r\n",
+        ];
+
+        for bytes in headers {
+            let result = Frame::deserialize(Bytes::from_static(bytes)).await;
+
+            assert!(
+                matches!(result, Err(RedisError::FrameTooLarge(9999999999999))),
+                "unexpected result for {:?}: {:?}",
+                bytes,
+                result
+            );
+        }
+
+        // nested aggregates are bounded as well
+        let result = Frame::deserialize(Bytes::from_static(b"*1\r\n$9999999999999\r\n")).await;
+        assert!(matches!(result, Err(RedisError::FrameTooLarge(_))));
+
+        // lengths that do not fit in an isize are rejected as well
+        let result = Frame::deserialize(Bytes::from_static(b"$99999999999999999999\r\n")).await;
+        assert!(result.is_err());
+
+        // negative lengths other than -1
+        for bytes in [&b"$-2\r\n"[..], b"!-2\r\n", b"=-1\r\n"] {
+            let result = Frame::deserialize(Bytes::from_static(bytes)).await;
+
+            assert!(matches!(result, Err(RedisError::InvalidFrame)));
+        }
+
+        // a custom limit
+        let result = Frame::try_parse_with_limit(&mut Cursor::new(b"$10\r\n0123456789\r\n"), 8);
+        assert!(matches!(result, Err(RedisError::FrameTooLarge(10))));
+    }
+
+    /// Tests parsing a buffer of concatenated frames.
+    #[test]
+    fn test_parse_all() {
+        let frames = Frame::parse_all(b"+OK\r\n:5\r\n$3\r\nfoo\r\n")
+            .unwrap_or_else(|err| panic!("Failed to parse frames: {:?}", err));
+        assert_eq!(
+            frames,
+            vec![Frame::simple("OK"), Frame::Integer(5), Frame::bulk("foo")]
+        );
+
+        let frames =
+            Frame::parse_all(b"").unwrap_or_else(|err| panic!("Failed to parse frames: {:?}", err));
+        assert!(frames.is_empty());
+
+        // the last reply is cut short
+        let result = Frame::parse_all(b"+OK\r\n$3\r\nfo");
+        assert!(matches!(result, Err(RedisError::IncompleteFrame)));
+    }
+
+    /// Tests that deeply nested frames are refused instead of overflowing the stack.
+    #[test]
+    fn test_parse_max_depth() {
+        let nested = |prefix: &[u8], depth: usize| {
+            let mut buf = prefix.repeat(depth);
+            buf.extend_from_slice(b":1\r\n");
+            buf
+        };
+
+        // as deep as allowed
+        let mut frame = Frame::try_parse(&mut Cursor::new(&nested(b"*1\r\n", MAX_DEPTH)[..]))
+            .unwrap_or_else(|err| panic!("Failed to parse frame: {:?}", err));
+        let mut depth = 0;
+        while let Frame::Array(mut items) = frame {
+            depth += 1;
+            frame = items.remove(0);
+        }
+        assert_eq!((depth, frame), (MAX_DEPTH, Frame::Integer(1)));
+
+        // a level too deep, of every aggregate type, attributes included
+        for prefix in [
+            &b"*1\r\n"[..],
+            b"~1\r\n",
+            b">1\r\n",
+            b"%1\r\n:0\r\n",
+            b"|0\r\n",
+        ] {
+            let buf = nested(prefix, MAX_DEPTH + 1);
+            let result = Frame::try_parse(&mut Cursor::new(&buf[..]));
+            assert!(matches!(result, Err(RedisError::InvalidFrame)));
+            let result = Frame::check(&mut Cursor::new(&buf[..]), MAX_BUFFER_SIZE);
+            assert!(matches!(result, Err(RedisError::InvalidFrame)));
+        }
+
+        // a hostile reply fails right away instead of overflowing the stack
+        let result = Frame::try_parse(&mut Cursor::new(&nested(b"*1\r\n", 1_000_000)[..]));
+        assert!(matches!(result, Err(RedisError::InvalidFrame)));
+    }
+
+    /// Tests that parsing from a shared buffer slices the payloads instead of copying them.
+    #[test]
+    fn test_parse_shared() {
+        let buf = Bytes::from_static(b"*2\r\n$5\r\nHello\r\n=15\r\ntxt:Some string\r\n:1\r\n");
+
+        // only the array is consumed, the trailing integer is left in the buffer
+        let mut cursor = Cursor::new(&buf[..]);
+        Frame::check(&mut cursor, MAX_BUFFER_SIZE)
+            .unwrap_or_else(|err| panic!("Failed to check frame: {:?}", err));
+        assert_eq!(cursor.position(), 37);
+
+        let frame = Frame::parse_shared(&buf, MAX_BUFFER_SIZE)
+            .unwrap_or_else(|err| panic!("Failed to parse frame: {:?}", err));
+        let Frame::Array(items) = &frame else {
+            panic!("Unexpected frame: {:?}", frame);
+        };
+        let [
+            Frame::BulkString(hello),
+            Frame::VerbatimString(encoding, data),
+        ] = &items[..]
+        else {
+            panic!("Unexpected frame: {:?}", frame);
+        };
+
+        assert_eq!(hello, &b"Hello"[..]);
+        assert_eq!(encoding, &b"txt"[..]);
+        assert_eq!(data, &b"Some string"[..]);
+        assert_eq!(hello.as_ptr(), buf[8..].as_ptr());
+        assert_eq!(encoding.as_ptr(), buf[20..].as_ptr());
+        assert_eq!(data.as_ptr(), buf[24..].as_ptr());
+    }
+
+    /// Tests that truncated payloads within the limit are reported as incomplete.
+    #[tokio::test]
+    async fn test_deserialize_truncated() {
+        let frames: [&[u8]; 5] = [
+            b"$11\r\nHello",
+            b"*2\r\n$5\r\nHello\r\n",
+            b"*1000\r\n:1\r\n",
+            b"%2\r\n+key\r\n",
+            b"~3\r\n:1\r\n:2\r\n",
+        ];
+
+        for bytes in frames {
+            let result = Frame::deserialize(Bytes::from_static(bytes)).await;
+
+            assert!(
+                matches!(result, Err(RedisError::IncompleteFrame)),
+                "unexpected result for {:?}: {:?}",
+                bytes,
+                result
+            );
+        }
+    }
+
     /// Tests the deserialization of a null frame.
     #[tokio::test]
     async fn test_deserialize_null() {
@@ -1018,6 +2131,45 @@ mod tests {
         });
 
         assert_eq!(frame, Frame::Double(f64::NEG_INFINITY));
+
+        // the exponent notation is part of the spec
+        for (bytes, expected) in [
+            (&b",1e21\r\n"[..], 1e21),
+            (b",-1.5E-7\r\n", -1.5e-7),
+            (b",+2.5e+3\r\n", 2500.0),
+            (b",10\r\n", 10.0),
+        ] {
+            let frame = Frame::deserialize(Bytes::from_static(bytes))
+                .await
+                .unwrap_or_else(|err| panic!("Failed to deserialize double frame: {:?}", err));
+
+            assert_eq!(frame, Frame::Double(expected));
+        }
+
+        // inputs accepted by f64::from_str but not by the spec
+        let invalid: [&[u8]; 10] = [
+            b",0x1.2\r\n",
+            b",.5\r\n",
+            b",5.\r\n",
+            b",1e\r\n",
+            b",infinity\r\n",
+            b",NaN\r\n",
+            b",+inf\r\n",
+            b",1_000\r\n",
+            b",\r\n",
+            b", 1\r\n",
+        ];
+
+        for bytes in invalid {
+            let result = Frame::deserialize(Bytes::from_static(bytes)).await;
+
+            assert!(
+                matches!(result, Err(RedisError::InvalidFrame)),
+                "unexpected result for {:?}: {:?}",
+                bytes,
+                result
+            );
+        }
     }
 
     /// Tests the deserialization of a bulk error frame.
@@ -1072,6 +2224,40 @@ mod tests {
         );
     }
 
+    /// Tests that malformed verbatim strings are rejected instead of panicking.
+    #[tokio::test]
+    async fn test_deserialize_verbatim_string_malformed() {
+        let frames: [&[u8]; 6] = [
+            // too short to hold the encoding and the delimiter
+            b"=0\r\n\r\n",
+            b"=2\r\nab\r\n",
+            b"=3\r\ntxt\r\n",
+            // no : after the encoding
+            b"=6\r\ntxtabc\r\n",
+            b"=6\r\nt:t:ab\r\n",
+            // the declared length disagrees with the position of \r\n
+            b"=8\r\ntxt:hello\r\n",
+        ];
+
+        for bytes in frames {
+            let result = Frame::deserialize(Bytes::from_static(bytes)).await;
+
+            assert!(
+                matches!(result, Err(RedisError::InvalidFrame)),
+                "unexpected result for {:?}: {:?}",
+                bytes,
+                result
+            );
+        }
+
+        // the encoding cannot be serialized if it is not 3 bytes long
+        let result = Frame::VerbatimString(Bytes::from_static(b"text"), Bytes::from_static(b"a"))
+            .serialize()
+            .await;
+
+        assert!(matches!(result, Err(RedisError::InvalidFrame)));
+    }
+
     /// Tests the deserialization of a map frame.
     #[tokio::test]
     async fn test_deserialize_map() {
@@ -1111,4 +2297,356 @@ mod tests {
 
         assert_eq!(frame, expected_frame);
     }
+
+    /// Tests the deserialization of a big number frame.
+    #[tokio::test]
+    async fn test_deserialize_big_number() {
+        let bytes = Bytes::from_static(b"(-3492890328409238509324850943850943825024385\r\n");
+
+        let frame = Frame::deserialize(bytes)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to deserialize big number frame: {:?}", err));
+
+        assert_eq!(
+            frame,
+            Frame::BigNumber(BigInt {
+                sign: true,
+                data: b"3492890328409238509324850943850943825024385".to_vec(),
+            })
+        );
+
+        for bytes in [&b"(\r\n"[..], b"(-\r\n", b"(12a\r\n"] {
+            let result = Frame::deserialize(Bytes::from_static(bytes)).await;
+
+            assert!(matches!(result, Err(RedisError::InvalidFrame)));
+        }
+    }
+
+    /// Tests that attributes are skipped and the following reply is returned.
+    #[tokio::test]
+    async fn test_deserialize_attribute() {
+        let bytes = Bytes::from_static(
+            b"|1\r\n+key-popularity\r\n%1\r\n$1\r\na\r\n,0.1923\r\n*1\r\n:2039123\r\n",
+        );
+
+        let frame = Frame::deserialize(bytes)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to deserialize attribute frame: {:?}", err));
+
+        assert_eq!(frame, Frame::Array(vec![Frame::Integer(2039123)]));
+
+        // the reply after the attribute has not arrived yet
+        let result = Frame::deserialize(Bytes::from_static(b"|1\r\n+a\r\n+b\r\n")).await;
+
+        assert!(matches!(result, Err(RedisError::IncompleteFrame)));
+    }
+
+    /// Tests that malformed payloads are rejected instead of panicking.
+    #[tokio::test]
+    async fn test_deserialize_malformed() {
+        let frames: [&[u8]; 5] = [
+            b"=2\r\ntx\r\n",
+            b"=5\r\ntxt-a\r\n",
+            b"$5\r\nHelloXX",
+            b"!3\r\nERRXX",
+            b"?\r\n",
+        ];
+
+        for bytes in frames {
+            let result = Frame::deserialize(Bytes::from_static(bytes)).await;
+
+            assert!(
+                matches!(result, Err(RedisError::InvalidFrame)),
+                "unexpected result for {:?}: {:?}",
+                bytes,
+                result
+            );
+        }
+    }
+
+    /// NaN has many bit patterns, so it is checked separately from the round trip property.
+    #[tokio::test]
+    async fn test_roundtrip_nan() {
+        let bytes = Frame::Double(f64::NAN)
+            .serialize()
+            .await
+            .unwrap_or_else(|err| panic!("Failed to serialize double frame: {:?}", err));
+
+        let frame = Frame::deserialize(bytes)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to deserialize double frame: {:?}", err));
+
+        assert!(matches!(frame, Frame::Double(val) if val.is_nan()));
+    }
+
+    #[test]
+    fn test_display() {
+        let cases = vec![
+            (Frame::simple("OK"), "OK"),
+            (
+                Frame::SimpleError("ERR unknown".into()),
+                "(error) ERR unknown",
+            ),
+            (Frame::int(5), "(integer) 5"),
+            (Frame::bulk("say \"hi\"\r\n\x00"), r#""say \"hi\"\r\n\x00""#),
+            (Frame::Null, "(nil)"),
+            (Frame::Boolean(true), "(true)"),
+            (Frame::Double(1.5), "(double) 1.5"),
+            (Frame::Double(f64::NAN), "(double) nan"),
+            (
+                Frame::BigNumber(BigInt {
+                    sign: true,
+                    data: b"12345678901234567890".to_vec(),
+                }),
+                "(big number) -12345678901234567890",
+            ),
+            (Frame::array(), "(empty array)"),
+            (
+                Frame::Array(vec![Frame::bulk("a"), Frame::int(1), Frame::Null]),
+                "1) \"a\"\n2) (integer) 1\n3) (nil)",
+            ),
+            (
+                Frame::Array(vec![
+                    Frame::bulk("0"),
+                    Frame::Array(vec![Frame::bulk("key:1"), Frame::bulk("key:2")]),
+                ]),
+                "1) \"0\"\n2) 1) \"key:1\"\n   2) \"key:2\"",
+            ),
+            (
+                Frame::Array((0..10).map(Frame::int).collect()),
+                " 1) (integer) 0\n 2) (integer) 1\n 3) (integer) 2\n 4) (integer) 3\n \
+                 5) (integer) 4\n 6) (integer) 5\n 7) (integer) 6\n 8) (integer) 7\n \
+                 9) (integer) 8\n10) (integer) 9",
+            ),
+            (
+                Frame::Map(vec![
+                    (Frame::bulk("proto"), Frame::int(3)),
+                    (
+                        Frame::bulk("modules"),
+                        Frame::Array(vec![Frame::bulk("search"), Frame::bulk("json")]),
+                    ),
+                ]),
+                "1# \"proto\" => (integer) 3\n2# \"modules\" => 1) \"search\"\n                2) \"json\"",
+            ),
+        ];
+
+        for (frame, expected) in cases {
+            assert_eq!(frame.to_string(), expected, "{frame:?}");
+        }
+    }
+
+    #[test]
+    fn test_display_inline() {
+        let frame = Frame::Array(vec![Frame::bulk("GET"), Frame::bulk("mykey")]);
+        assert_eq!(format!("{frame:#}"), r#""GET" "mykey""#);
+
+        let frame = Frame::Array(vec![
+            Frame::bulk("0"),
+            Frame::Array(vec![Frame::bulk("a"), Frame::bulk("b")]),
+            Frame::Map(vec![(Frame::bulk("k"), Frame::int(1))]),
+        ]);
+        assert_eq!(
+            format!("{frame:#}"),
+            r#""0" ["a" "b"] {"k" => (integer) 1}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_to_resp_debug_string() {
+        let frame = Frame::Array(vec![Frame::bulk("GET"), Frame::bulk("my\nkey")]);
+        let debug = frame
+            .to_resp_debug_string()
+            .await
+            .unwrap_or_else(|err| panic!("Failed to serialize frame: {:?}", err));
+
+        assert_eq!(debug, r"*2\r\n$3\r\nGET\r\n$6\r\nmy\nkey\r\n");
+    }
+
+    #[test]
+    fn test_frame_eq_hash() {
+        use std::collections::HashSet;
+
+        let frame = Frame::Array(vec![Frame::bulk("GET"), Frame::Double(f64::NAN)]);
+        assert_eq!(frame.clone(), frame);
+
+        let mut frames = HashSet::new();
+        frames.insert(frame.clone());
+        frames.insert(frame);
+        frames.insert(Frame::Double(0.0));
+        frames.insert(Frame::Double(-0.0));
+        frames.insert(Frame::bulk("1"));
+        frames.insert(Frame::int(1));
+        frames.insert(Frame::simple("1"));
+        frames.insert(Frame::SimpleError("1".into()));
+        assert_eq!(frames.len(), 7);
+    }
+
+    #[tokio::test]
+    async fn test_serialize_chunks() {
+        let value = Bytes::from(vec![b'x'; VECTORED_WRITE_THRESHOLD]);
+        let frame = Frame::Array(vec![
+            Frame::bulk("SET"),
+            Frame::bulk("mykey"),
+            Frame::BulkString(value.clone()),
+        ]);
+
+        let chunks = frame
+            .serialize_chunks()
+            .await
+            .unwrap_or_else(|err| panic!("Failed to serialize frame: {:?}", err));
+        let serialized = frame
+            .serialize()
+            .await
+            .unwrap_or_else(|err| panic!("Failed to serialize frame: {:?}", err));
+
+        // the header, the value and its trailing CRLF
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(
+            &chunks[0][..],
+            b"*3\r\n$3\r\nSET\r\n$5\r\nmykey\r\n$65536\r\n"
+        );
+        assert_eq!(chunks[1].as_ptr(), value.as_ptr());
+        assert_eq!(&chunks[2][..], b"\r\n");
+        assert_eq!(chunks.concat(), serialized);
+
+        // small frames stay in a single chunk
+        let chunks = Frame::bulk("PING")
+            .serialize_chunks()
+            .await
+            .unwrap_or_else(|err| panic!("Failed to serialize frame: {:?}", err));
+        assert_eq!(chunks, vec![Bytes::from_static(b"$4\r\nPING\r\n")]);
+    }
+
+    mod proptests {
+        use super::*;
+        use proptest::collection::vec;
+        use proptest::prelude::*;
+
+        /// Generates an arbitrary frame tree of bounded depth and size.
+        fn arb_frame() -> impl Strategy<Value = Frame> {
+            let leaf = prop_oneof![
+                // simple strings and errors cannot contain \r or \n
+                "[^\r\n]*".prop_map(Frame::SimpleString),
+                "[^\r\n]*".prop_map(Frame::SimpleError),
+                any::<i64>().prop_map(Frame::Integer),
+                vec(any::<u8>(), 0..64).prop_map(|data| Frame::BulkString(Bytes::from(data))),
+                Just(()).prop_map(|_| Frame::Null),
+                any::<bool>().prop_map(Frame::Boolean),
+                any::<f64>()
+                    .prop_filter("NaN is never equal to itself", |val| !val.is_nan())
+                    .prop_map(Frame::Double),
+                (any::<bool>(), "[1-9][0-9]{0,40}").prop_map(|(sign, digits)| {
+                    Frame::BigNumber(BigInt {
+                        sign,
+                        data: digits.into_bytes(),
+                    })
+                }),
+                vec(any::<u8>(), 0..64).prop_map(|data| Frame::BulkError(Bytes::from(data))),
+                ("[a-z]{3}", vec(any::<u8>(), 0..64)).prop_map(|(encoding, data)| {
+                    Frame::VerbatimString(Bytes::from(encoding), Bytes::from(data))
+                }),
+            ];
+
+            leaf.prop_recursive(4, 64, 8, |inner| {
+                prop_oneof![
+                    vec(inner.clone(), 0..8).prop_map(Frame::Array),
+                    vec((inner.clone(), inner.clone()), 0..8).prop_map(Frame::Map),
+                    vec(inner.clone(), 0..8).prop_map(Frame::Set),
+                    vec(inner, 0..8).prop_map(Frame::Push),
+                ]
+            })
+        }
+
+        fn runtime() -> tokio::runtime::Runtime {
+            tokio::runtime::Builder::new_current_thread()
+                .build()
+                .unwrap_or_else(|err| panic!("Failed to build runtime: {:?}", err))
+        }
+
+        proptest! {
+            #[test]
+            fn test_serialize_chunks_same_bytes(frame in arb_frame(), threshold in 0usize..64) {
+                let runtime = runtime();
+                let bytes = runtime
+                    .block_on(frame.serialize())
+                    .unwrap_or_else(|err| panic!("Failed to serialize {:?}: {:?}", frame, err));
+                let chunks = runtime
+                    .block_on(frame.serialize_chunks_above(threshold, ProtocolVersion::Resp3))
+                    .unwrap_or_else(|err| panic!("Failed to serialize {:?}: {:?}", frame, err));
+
+                prop_assert_eq!(chunks.concat(), bytes.to_vec());
+            }
+        }
+
+        proptest! {
+            #[test]
+            fn test_encoded_len_exact(frame in arb_frame()) {
+                let bytes = runtime()
+                    .block_on(frame.serialize())
+                    .unwrap_or_else(|err| panic!("Failed to serialize {:?}: {:?}", frame, err));
+
+                prop_assert_eq!(frame.encoded_len(), bytes.len());
+            }
+        }
+
+        proptest! {
+            #[test]
+            fn test_roundtrip(frame in arb_frame()) {
+                let bytes = runtime()
+                    .block_on(frame.serialize())
+                    .unwrap_or_else(|err| panic!("Failed to serialize {:?}: {:?}", frame, err));
+
+                let mut cursor = Cursor::new(&bytes[..]);
+                let parsed = Frame::try_parse(&mut cursor)
+                    .unwrap_or_else(|err| panic!("Failed to parse {:?}: {:?}", bytes, err));
+
+                prop_assert_eq!(parsed, frame);
+                // the whole serialized frame is consumed
+                prop_assert_eq!(cursor.position() as usize, bytes.len());
+            }
+
+            #[test]
+            fn test_truncated_is_incomplete(frame in arb_frame(), cut in any::<prop::sample::Index>()) {
+                let bytes = runtime()
+                    .block_on(frame.serialize())
+                    .unwrap_or_else(|err| panic!("Failed to serialize {:?}: {:?}", frame, err));
+
+                // every strict prefix of a valid frame needs more data
+                let cut = cut.index(bytes.len());
+                let result = Frame::try_parse(&mut Cursor::new(&bytes[..cut]));
+
+                prop_assert!(matches!(result, Err(RedisError::IncompleteFrame)), "{:?}", result);
+            }
+
+            #[test]
+            fn test_double_roundtrip(
+                val in prop::num::f64::POSITIVE
+                    | prop::num::f64::NEGATIVE
+                    | prop::num::f64::NORMAL
+                    | prop::num::f64::SUBNORMAL
+                    | prop::num::f64::ZERO
+                    | prop::num::f64::INFINITE
+            ) {
+                let bytes = runtime()
+                    .block_on(Frame::Double(val).serialize())
+                    .unwrap_or_else(|err| panic!("Failed to serialize {:?}: {:?}", val, err));
+
+                prop_assert!(!bytes.contains(&b'e'), "exponent in {:?}", bytes);
+
+                let parsed = Frame::try_parse(&mut Cursor::new(&bytes[..]))
+                    .unwrap_or_else(|err| panic!("Failed to parse {:?}: {:?}", bytes, err));
+
+                // compare the bits, so that -0.0 and 0.0 are told apart
+                let Frame::Double(parsed) = parsed else {
+                    panic!("Expected a Double frame, got {:?}", parsed);
+                };
+                prop_assert_eq!(parsed.to_bits(), val.to_bits());
+            }
+
+            #[test]
+            fn test_parse_never_panics(bytes in vec(any::<u8>(), 0..256)) {
+                let _ = Frame::try_parse(&mut Cursor::new(&bytes[..]));
+            }
+        }
+    }
 }
@@ -8,34 +8,210 @@
 //!
 //! # Connection pooling
 //!
+//! A `Pool` hands connections out to one task at a time with `pool.get()`, and takes them back once
+//! the returned `PooledClient` is dropped. Connections idle for longer than `max_idle_time` are
+//! closed by a background reaper, down to `min_idle` of them, and `test_on_checkout` PINGs an idle
+//! connection before handing it out, replacing it if the server already dropped it.
+//!
 //! # Asynchronous operations
 //!
 //! By default, the client runs in asynchronous mode. This means that all
 //! operations are non-blocking and return a `Future` that can be awaited.
 //!
+//! `Client` methods take `&mut self`, one command is in flight at a time. To share a connection
+//! between tasks, use a `MultiplexedClient` instead: it is cheap to clone, and the commands sent
+//! by its clones are pipelined on the same connection.
+//!
+//! # Zero-copy replies
+//!
+//! Values are returned as `bytes::Bytes` rather than `Vec<u8>`. They are slices of the buffer the
+//! reply was read into, so even large values are never copied on their way to the caller. Keeping
+//! a value alive keeps that part of the read buffer alive as well, call `.to_vec()` to detach it.
+//!
+//! # Redis Cluster
+//!
+//! A `ClusterClient` fetches the slot map of a cluster from its seed nodes, and sends every command
+//! to the master serving the hash slot of its keys. MOVED and ASK redirections are followed
+//! transparently, and multi-key commands whose keys hash to different slots fail with
+//! `RedisError::CrossSlot`.
+//!
+//! # Redis Sentinel
+//!
+//! A `SentinelClient` asks the sentinels for the address of the current master, and only uses it
+//! once its ROLE is confirmed. When the master is lost, e.g. after a failover, the sentinels are
+//! asked again and the client reconnects to the new master.
+//!
+//! # Client-side caching
+//!
+//! A `CachingClient` keeps the GET replies of a RESP3 `MultiplexedClient` in an in-process LRU
+//! cache. It turns CLIENT TRACKING on, so the server sends an invalidation once a cached key is
+//! modified by any client, and the key is read from the server again.
+//!
 //! # Pipelining
 //!
+//! A `Pipeline` holds commands given as their name and arguments, and `pipeline.execute(&mut
+//! client)` sends them all in a single round trip. `pipeline.execute_typed` converts the replies
+//! into a tuple, e.g. `(String, i64, Option<String>)`, naming the position of the first reply
+//! that does not convert with `RedisError::PipelineReply`.
+//!
 //! # Transaction
 //!
+//! `client.multi()` starts a transaction, the commands sent with `client.command(args)` are then
+//! queued until `client.exec()` runs them all at once and returns their replies, a
+//! `StructuredResponse` each. For optimistic locking, `client.watch(keys)` before MULTI makes
+//! EXEC fail with `RedisError::TransactionAborted` if another client changed one of the keys in
+//! the meantime.
+//!
+//! # Scripting
+//!
+//! A `Script` is a Lua script along with its SHA1 digest. `client.invoke_script(&script, keys,
+//! args)` runs it with EVALSHA, and sends its source with EVAL only if the server replies
+//! `NOSCRIPT`, e.g. the first time.
+//!
+//! # Locking
+//!
+//! `client.acquire_lock(key, token, ttl)` takes a lock on a single server with `SET NX PX`, and
+//! `client.release_lock(key, token)` and `client.extend_lock(key, token, ttl)` only touch it if it
+//! still holds the token, in a script. `pool.lock(key, token, ttl)` returns a `LockGuard` releasing
+//! the lock once dropped.
+//!
+//! # Rate limiting
+//!
+//! `client.check_rate_limit(key, max, window, algorithm)` counts a request and tells whether it
+//! is within `max` requests per `window`, either in a fixed window with pipelined INCR and PEXPIRE
+//! NX, or in a sliding window log kept by a script.
+//!
 //! # Pub/Sub
 //!
+//! Subscribing puts the connection in pub/sub mode, so `client.subscribe(channels)` and
+//! `client.psubscribe(patterns)` consume the client and return a `Subscriber`. Messages are then
+//! received with `subscriber.next_message()`, and `subscriber.into_client()` gives the client back
+//! once every subscription is dropped.
+//!
+//! `subscriber.into_stream(buffer)` reads the messages from a task of its own instead, into a
+//! buffer of bounded size. Once it is full the task stops reading the socket until the stream is
+//! read, so a slow consumer holds the server back rather than losing messages or memory.
+//!
+//! A subscription is lost along with its connection. With a `ReconnectPolicy` set through
+//! `subscriber.set_reconnect_policy()`, the subscriber reconnects and subscribes again instead,
+//! and `subscriber.next_event()` returns `SubscriberEvent::Reconnected` since messages may have
+//! been missed in the meantime.
+//!
+//! # Monitor
+//!
+//! For debugging only, `client.monitor()` consumes the client and returns a `MonitorStream`,
+//! yielding a line for every command the server processes with `monitor.next_line()`.
+//!
+//! # Metrics
+//!
+//! Register a `MetricsObserver` with `Client::connect_with_observer` to get notified of new
+//! connections and of the latency and outcome of every command. `Pool::connect_with_observer`
+//! also reports the number of idle and in-use connections of the pool. `CountingObserver` keeps plain
+//! atomic counters, other backends can be plugged in by implementing the trait.
+//!
+//! # Retries
+//!
+//! `Client::set_retry_policy` retries the commands the server rejects with a transient error, e.g.
+//! `LOADING` while it starts up, after an exponential backoff. `RedisError::is_transient` tells
+//! these errors apart. Non-idempotent commands such as INCR are only retried once opted in.
+//!
+//! # Key prefixes
+//!
+//! `Client::set_key_prefix` namespaces the keys of a client, e.g. with `tenant:42:`. The prefix is
+//! added to the key arguments of every command and stripped from the keys the server replies with,
+//! so the client only ever sees its own keys.
+//!
+//! # JSON values
+//!
+//! With the `serde` feature, `Client::set_json` and `Client::get_json` store values as JSON with
+//! `serde_json`, `Client::hset_json` and `Client::hget_json` in the fields of a hash.
+//!
+//! # Tracing
+//!
+//! With the `tracing` feature, every command runs in a `redis.command` span recording the name of
+//! the command, its number of arguments and its latency. Failed commands also emit an event.
+//!
+//! # Testing
+//!
+//! With the `testing` feature, `MockRedisServer` is a fake server on a local port replying to
+//! scripted commands, e.g. `server.expect_command(["GET", "k"]).respond(Frame::bulk("v"))`. It can
+//! also reply with raw bytes, hold a reply back or close the connection, to test error paths a
+//! real server never takes.
+//!
 //! # RESP2/RESP3
 //!
 //! RESP version is set per connection. By default, the connection runs in RESP2 mode. RESP3 can be
-//! enabled by sending `HELLO 3` to the server. You can use `client.hello(Some(3))` to achieve it.
-//! Note that RESP3 is only available in Redis 6.0 and later.
+//! enabled by sending `HELLO 3` to the server. You can use `client.hello(Some(3))` to achieve it,
+//! or connect with `Client::connect_resp3`. The version a connection speaks is returned by
+//! `client.protocol_version()`. Note that RESP3 is only available in Redis 6.0 and later, older
+//! servers fail the handshake with `RedisError::UnsupportedProtocol`.
+//!
+//! `Client::builder()` sets a connection up in one call: it authenticates, switches to RESP3 and
+//! selects a database as configured before returning the client.
 
 mod connection;
-pub use connection::Connection;
+pub use connection::{Connection, ConnectionOptions, ProtocolVersion};
 
 mod frame;
 pub use frame::Frame;
 
 mod cmd;
-pub use cmd::Expiry;
+pub use cmd::{
+    Aggregate, ClientInfo, ClientKillFilter, ClientListFilter, ClientType, Expiry, HelloResponse,
+    LcsMatch, LcsOptions, LcsResult, LexBound, MigrateAuth, MigrateOptions, Pong, RestoreOptions,
+    ScoreBound, SetCondition, SetOptions, SetOutcome, SlowlogEntry, ToRedisArg, ZCombineOptions,
+    ZRangeBy, ZRangeOptions,
+};
 
 mod client;
-pub use client::Client;
+pub use client::{
+    Client, KeyHeat, Message, MonitorStream, Response, ScanIter, StructuredResponse, Subscriber,
+    SubscriberEvent, SubscriberStream,
+};
+
+mod builder;
+pub use builder::ClientBuilder;
+
+mod multiplexed;
+pub use multiplexed::MultiplexedClient;
+
+mod cluster;
+pub use cluster::{ClusterClient, key_slot};
+
+mod sentinel;
+pub use sentinel::SentinelClient;
+
+mod caching;
+pub use caching::{CacheConfig, CachingClient};
+
+mod pool;
+pub use pool::{Pool, PoolConfig, PoolStats, PooledClient};
+
+mod pipeline;
+pub use pipeline::{FromPipeline, FromRedisValue, Pipeline};
+
+mod script;
+pub use script::Script;
+
+mod lock;
+pub use lock::LockGuard;
+
+mod rate_limit;
+pub use rate_limit::{RateLimitAlgorithm, RateLimitDecision};
+
+mod retry;
+pub use retry::{ReconnectPolicy, RetryPolicy};
 
 mod error;
 pub use error::{RedisError, Result};
+
+mod metrics;
+pub use metrics::{CountingObserver, MetricsObserver, NoopObserver, Outcome};
+
+#[cfg(feature = "tracing")]
+mod trace;
+
+#[cfg(any(test, feature = "testing"))]
+mod testing;
+#[cfg(feature = "testing")]
+pub use testing::{Expectation, MockRedisServer};
@@ -0,0 +1,175 @@
+//! A lock on a single Redis server, held by whoever set its key first.
+//!
+//! The key holds a token unique to the holder and expires after a TTL, so a crashed holder does
+//! not keep the lock forever. Releasing and extending the lock compare the token in a script,
+//! since a holder whose lock expired must not touch the lock another holder acquired since.
+
+use crate::PooledClient;
+use crate::Result;
+use crate::Script;
+use bytes::Bytes;
+use std::sync::LazyLock;
+use std::time::Duration;
+
+/// Deletes the lock if it still holds the token, returns 1 if it did.
+pub(crate) static RELEASE_LOCK: LazyLock<Script> = LazyLock::new(|| {
+    Script::new(
+        "if redis.call('GET', KEYS[1]) == ARGV[1] then \
+             return redis.call('DEL', KEYS[1]) \
+         else \
+             return 0 \
+         end",
+    )
+});
+
+/// Sets the TTL of the lock to ARGV[2] milliseconds if it still holds the token, returns 1 if it
+/// did.
+pub(crate) static EXTEND_LOCK: LazyLock<Script> = LazyLock::new(|| {
+    Script::new(
+        "if redis.call('GET', KEYS[1]) == ARGV[1] then \
+             return redis.call('PEXPIRE', KEYS[1], ARGV[2]) \
+         else \
+             return 0 \
+         end",
+    )
+});
+
+/// A lock acquired with `Pool::lock`, released once dropped.
+///
+/// Dropping the guard spawns a task releasing the lock on the connection it holds, which is a best
+/// effort: the task may not run before the runtime shuts down, and its error is lost. Call
+/// `LockGuard::release` instead whenever possible. Either way, the lock expires after its TTL.
+///
+/// # Examples
+///
+/// ```ignore
+/// use redis_asyncx::{Pool, PoolConfig};
+/// use std::time::Duration;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let pool = Pool::connect("127.0.0.1:6379", PoolConfig::default()).await.unwrap();
+///
+///     if let Some(guard) = pool.lock("lock:report", b"worker-1", Duration::from_secs(10)).await? {
+///         // ...
+///         guard.release().await?;
+///     }
+/// }
+/// ```
+pub struct LockGuard {
+    // only None once released or dropped
+    client: Option<PooledClient>,
+    key: Bytes,
+    token: Bytes,
+}
+
+impl LockGuard {
+    pub(crate) fn new(client: PooledClient, key: Bytes, token: Bytes) -> Self {
+        Self {
+            client: Some(client),
+            key,
+            token,
+        }
+    }
+
+    /// Returns the key the lock is stored in.
+    pub fn key(&self) -> &[u8] {
+        &self.key
+    }
+
+    /// Returns the token the lock was acquired with.
+    pub fn token(&self) -> &[u8] {
+        &self.token
+    }
+
+    /// Extends the lock, see `Client::extend_lock`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` if the lock is extended
+    /// * `Ok(false)` if the lock expired, or another holder acquired it since
+    /// * `Err(RedisError)` if an error occurs
+    pub async fn extend(&mut self, ttl: Duration) -> Result<bool> {
+        match &mut self.client {
+            Some(client) => client.extend_lock(&self.key, &self.token, ttl).await,
+            None => unreachable!("the client is only taken on release or drop"),
+        }
+    }
+
+    /// Releases the lock, see `Client::release_lock`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` if the lock is released
+    /// * `Ok(false)` if the lock expired, or another holder acquired it since
+    /// * `Err(RedisError)` if an error occurs
+    pub async fn release(mut self) -> Result<bool> {
+        match self.client.take() {
+            Some(mut client) => client.release_lock(&self.key, &self.token).await,
+            None => unreachable!("the client is only taken on release or drop"),
+        }
+    }
+}
+
+impl Drop for LockGuard {
+    fn drop(&mut self) {
+        let Some(mut client) = self.client.take() else {
+            return;
+        };
+        // without a runtime, the lock is left to expire
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+
+        let key = self.key.clone();
+        let token = self.token.clone();
+        runtime.spawn(async move {
+            let _ = client.release_lock(&key, &token).await;
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockRedisServer;
+    use crate::{Frame, Pool, PoolConfig};
+
+    #[tokio::test]
+    async fn test_lock_guard() {
+        let server = MockRedisServer::start().await;
+        for token in ["first", "second"] {
+            server
+                .expect_command(["SET", "lock", token, "NX", "PX", "1000"])
+                .respond(Frame::simple("OK"));
+            server
+                .expect_command(["EVALSHA", RELEASE_LOCK.sha(), "1", "lock", token])
+                .respond(Frame::Integer(1));
+        }
+
+        let pool = Pool::connect(server.addr(), PoolConfig::default())
+            .await
+            .unwrap_or_else(|err| panic!("Failed to create pool: {:?}", err));
+        let ttl = Duration::from_secs(1);
+
+        let guard = pool
+            .lock("lock", b"first", ttl)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to lock: {:?}", err))
+            .unwrap_or_else(|| panic!("lock not acquired"));
+        assert_eq!(guard.key(), b"lock");
+        let released = guard
+            .release()
+            .await
+            .unwrap_or_else(|err| panic!("Failed to release: {:?}", err));
+        assert!(released);
+
+        // released by a task once dropped
+        let guard = pool
+            .lock("lock", b"second", ttl)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to lock: {:?}", err));
+        drop(guard);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+}
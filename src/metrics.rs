@@ -0,0 +1,241 @@
+//! Hooks to export client metrics without depending on a metrics backend.
+//!
+//! Implement `MetricsObserver` to forward the events to Prometheus, StatsD or any other backend,
+//! and register it with `Client::connect_with_observer` or `Client::with_observer`.
+
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::Duration;
+
+/// How a command completed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    /// The server replied successfully.
+    Success,
+    /// The server replied with an error, e.g. `WRONGTYPE`.
+    ServerError,
+    /// No valid reply was received, e.g. because of an I/O or protocol error.
+    Failed,
+}
+
+/// Callbacks invoked by the client, every method defaults to doing nothing.
+///
+/// The callbacks run inline on the task sending the command, so they should be cheap and must not block.
+pub trait MetricsObserver: Send + Sync {
+    /// Called once per command, after its reply is read.
+    ///
+    /// `elapsed` covers the full round trip, from writing the command to reading the reply.
+    fn on_command_complete(&self, _cmd: &str, _elapsed: Duration, _outcome: Outcome) {}
+
+    /// Called when a connection to the server is established.
+    fn on_connect(&self, _elapsed: Duration) {}
+
+    /// Called when a connection is re-established after it was lost.
+    fn on_reconnect(&self) {}
+
+    /// Called by connection pools whenever the number of idle or in-use connections changes.
+    fn on_pool_gauges(&self, _idle: usize, _in_use: usize) {}
+}
+
+/// An observer that ignores every event, used when no observer is registered.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopObserver;
+
+impl MetricsObserver for NoopObserver {}
+
+/// An observer that counts the events with atomics, mostly meant for tests.
+#[derive(Debug, Default)]
+pub struct CountingObserver {
+    commands: AtomicU64,
+    server_errors: AtomicU64,
+    failures: AtomicU64,
+    // in microseconds
+    total_latency: AtomicU64,
+    connects: AtomicU64,
+    reconnects: AtomicU64,
+    idle: AtomicUsize,
+    in_use: AtomicUsize,
+}
+
+impl CountingObserver {
+    /// Creates a new CountingObserver with every counter at zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of completed commands, whatever their outcome.
+    pub fn commands(&self) -> u64 {
+        self.commands.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of commands the server replied to with an error.
+    pub fn server_errors(&self) -> u64 {
+        self.server_errors.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of commands that did not get a valid reply.
+    pub fn failures(&self) -> u64 {
+        self.failures.load(Ordering::Relaxed)
+    }
+
+    /// Returns the sum of the round trip times of every command.
+    pub fn total_latency(&self) -> Duration {
+        Duration::from_micros(self.total_latency.load(Ordering::Relaxed))
+    }
+
+    /// Returns the number of established connections.
+    pub fn connects(&self) -> u64 {
+        self.connects.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of re-established connections.
+    pub fn reconnects(&self) -> u64 {
+        self.reconnects.load(Ordering::Relaxed)
+    }
+
+    /// Returns the last reported number of idle and in-use pooled connections.
+    pub fn pool_gauges(&self) -> (usize, usize) {
+        (
+            self.idle.load(Ordering::Relaxed),
+            self.in_use.load(Ordering::Relaxed),
+        )
+    }
+}
+
+impl MetricsObserver for CountingObserver {
+    fn on_command_complete(&self, _cmd: &str, elapsed: Duration, outcome: Outcome) {
+        self.commands.fetch_add(1, Ordering::Relaxed);
+        self.total_latency.fetch_add(
+            u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX),
+            Ordering::Relaxed,
+        );
+
+        match outcome {
+            Outcome::Success => {}
+            Outcome::ServerError => {
+                self.server_errors.fetch_add(1, Ordering::Relaxed);
+            }
+            Outcome::Failed => {
+                self.failures.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    fn on_connect(&self, _elapsed: Duration) {
+        self.connects.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn on_reconnect(&self) {
+        self.reconnects.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn on_pool_gauges(&self, idle: usize, in_use: usize) {
+        self.idle.store(idle, Ordering::Relaxed);
+        self.in_use.store(in_use, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Client;
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn test_counting_observer() {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to bind listener: {:?}", err));
+        let addr = listener
+            .local_addr()
+            .unwrap_or_else(|err| panic!("Failed to get listener address: {:?}", err));
+
+        // replies to PING, GET and INCR, one command at a time
+        tokio::spawn(async move {
+            if let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = vec![0; 1024];
+                for reply in [&b"+PONG\r\n"[..], b"-WRONGTYPE oops\r\n", b":1\r\n"] {
+                    let _ = socket.read(&mut buf).await;
+                    let _ = socket.write_all(reply).await;
+                }
+            }
+        });
+
+        let observer = Arc::new(CountingObserver::new());
+        let mut client = Client::connect_with_observer(addr, observer.clone())
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
+        assert_eq!(observer.connects(), 1);
+
+        assert!(client.ping(None).await.is_ok());
+        assert!(client.get("mykey").await.is_err());
+        assert!(client.incr("mykey").await.is_ok());
+        // the server is gone, so there is no reply at all
+        assert!(client.incr("mykey").await.is_err());
+
+        assert_eq!(observer.commands(), 4);
+        assert_eq!(observer.server_errors(), 1);
+        assert_eq!(observer.failures(), 1);
+        assert_eq!(observer.reconnects(), 0);
+    }
+
+    // records every command it is told about
+    #[derive(Default)]
+    struct RecordingObserver {
+        commands: Mutex<Vec<(String, Outcome)>>,
+    }
+
+    impl MetricsObserver for RecordingObserver {
+        fn on_command_complete(&self, cmd: &str, _elapsed: Duration, outcome: Outcome) {
+            self.commands
+                .lock()
+                .unwrap_or_else(|err| panic!("Failed to lock commands: {:?}", err))
+                .push((cmd.to_string(), outcome));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_observer() {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to bind listener: {:?}", err));
+        let addr = listener
+            .local_addr()
+            .unwrap_or_else(|err| panic!("Failed to get listener address: {:?}", err));
+
+        // replies to two GETs, then to a GET on a key holding the wrong kind of value
+        tokio::spawn(async move {
+            if let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = vec![0; 1024];
+                for reply in [&b"$3\r\none\r\n"[..], b"$-1\r\n", b"-WRONGTYPE oops\r\n"] {
+                    let _ = socket.read(&mut buf).await;
+                    let _ = socket.write_all(reply).await;
+                }
+            }
+        });
+
+        let observer = Arc::new(RecordingObserver::default());
+        let mut client = Client::connect(addr)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err))
+            .with_observer(observer.clone());
+
+        assert!(client.get("first").await.is_ok());
+        assert!(client.get("second").await.is_ok());
+        assert!(client.get("list").await.is_err());
+
+        let commands = observer
+            .commands
+            .lock()
+            .unwrap_or_else(|err| panic!("Failed to lock commands: {:?}", err));
+        assert_eq!(
+            *commands,
+            vec![
+                ("GET".to_string(), Outcome::Success),
+                ("GET".to_string(), Outcome::Success),
+                ("GET".to_string(), Outcome::ServerError),
+            ]
+        );
+    }
+}
@@ -0,0 +1,641 @@
+//! A client that shares a single connection between many tasks.
+//!
+//! Commands are handed over to a background task that owns the connection. The task writes them
+//! as soon as they arrive, without waiting for the replies of the previous ones, and hands the
+//! replies back in the order the commands were written, which is the order Redis replies in.
+
+use crate::Connection;
+use crate::Frame;
+use crate::ProtocolVersion;
+use crate::RedisError;
+use crate::Result;
+use crate::client::{Client, Response, prefixed_frame};
+use crate::cmd::*;
+use crate::metrics::{MetricsObserver, Outcome};
+use anyhow::{Context, anyhow};
+use bytes::Bytes;
+use std::collections::VecDeque;
+use std::str::from_utf8;
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::net::ToSocketAddrs;
+use tokio::sync::{Mutex, Notify, broadcast, mpsc, oneshot};
+use tokio::task::JoinHandle;
+
+// the number of commands that can be queued before senders have to wait for the connection task
+const REQUEST_QUEUE_SIZE: usize = 1024;
+
+// the number of push frames kept for subscribers lagging behind
+const PUSH_QUEUE_SIZE: usize = 1024;
+
+/// A command handed over to the connection task.
+struct Request {
+    frame: Frame,
+    // None for commands the server does not reply to in order, e.g. SUBSCRIBE in RESP3
+    reply: Option<oneshot::Sender<Result<Frame>>>,
+}
+
+/// A cloneable Redis client, whose clones share a single connection.
+///
+/// Unlike `Client`, every method takes `&self`, so the client can be cloned into many tasks
+/// without a Mutex. Commands sent concurrently are pipelined on the connection, so the tasks do
+/// not wait on each other's round trips.
+///
+/// Push frames, i.e. pub/sub messages in RESP3, are not replies to any command. They are
+/// broadcast to the receivers returned by `MultiplexedClient::pushes` instead.
+#[derive(Clone)]
+pub struct MultiplexedClient {
+    requests: mpsc::Sender<Request>,
+    pushes: broadcast::Sender<Frame>,
+    // asks the connection task to stop once the queued commands are replied to
+    closing: Arc<Notify>,
+    // taken by the first call to close, the others wait for it to join the task
+    task: Arc<Mutex<Option<JoinHandle<()>>>>,
+    // the RESP version negotiated before the client was multiplexed
+    protocol: ProtocolVersion,
+    observer: Arc<dyn MetricsObserver>,
+    // added to the keys of every command, see `Client::set_key_prefix`
+    key_prefix: Bytes,
+}
+
+impl MultiplexedClient {
+    /// Establish a connection to the Redis server and spawn the task driving it.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use redis_asyncx::MultiplexedClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = MultiplexedClient::connect("127.0.0.1:6379").await.unwrap();
+    ///
+    ///     let other = client.clone();
+    ///     tokio::spawn(async move { other.incr("counter").await });
+    ///
+    ///     client.incr("counter").await.unwrap();
+    /// }
+    /// ```
+    pub async fn connect<A: ToSocketAddrs>(addr: A) -> Result<Self> {
+        Ok(Client::connect(addr).await?.into_multiplexed())
+    }
+
+    /// Spawns the task driving the connection, must be called within a Tokio runtime.
+    pub(crate) fn new(conn: Connection, observer: Arc<dyn MetricsObserver>) -> Self {
+        let protocol = conn.protocol_version();
+        let (requests, receiver) = mpsc::channel(REQUEST_QUEUE_SIZE);
+        let (pushes, _) = broadcast::channel(PUSH_QUEUE_SIZE);
+        let closing = Arc::new(Notify::new());
+
+        let task = tokio::spawn(drive(conn, receiver, pushes.clone(), closing.clone()));
+
+        Self {
+            requests,
+            pushes,
+            closing,
+            task: Arc::new(Mutex::new(Some(task))),
+            protocol,
+            observer,
+            key_prefix: Bytes::new(),
+        }
+    }
+
+    /// Namespaces the keys of the client, kept from the `Client` it is made from.
+    pub(crate) fn with_key_prefix(mut self, key_prefix: Bytes) -> Self {
+        self.key_prefix = key_prefix;
+        self
+    }
+
+    /// Returns the prefix added to the keys of every command, see `Client::set_key_prefix`.
+    pub fn key_prefix(&self) -> &[u8] {
+        &self.key_prefix
+    }
+
+    /// Returns the RESP version of the connection, see `Client::protocol_version`.
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol
+    }
+
+    /// Returns true once the connection task has stopped, e.g. because the connection was lost.
+    pub(crate) fn is_closed(&self) -> bool {
+        self.requests.is_closed()
+    }
+
+    /// Returns a receiver for the push frames sent by the server, e.g. pub/sub messages.
+    ///
+    /// Only the push frames received after the call are delivered to the receiver.
+    pub fn pushes(&self) -> broadcast::Receiver<Frame> {
+        self.pushes.subscribe()
+    }
+
+    /// Closes the connection shared by the client and all its clones.
+    ///
+    /// New commands are refused with `RedisError::ClientClosed`, while the commands already sent
+    /// still get their replies. Once every reply is in, the connection is closed with a QUIT.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` once the connection task has stopped
+    /// * `Err(RedisError)` if the connection task panicked
+    pub async fn close(&self) -> Result<()> {
+        self.closing.notify_one();
+
+        let mut task = self.task.lock().await;
+        if let Some(task) = task.take() {
+            task.await.with_context(|| "the connection task panicked")?;
+        }
+
+        Ok(())
+    }
+
+    /// Sends a PING command, see `Client::ping`.
+    pub async fn ping(&self, msg: Option<&[u8]>) -> Result<Pong> {
+        let frame = prefixed_frame(&Ping::new(msg), &self.key_prefix)?;
+        match self.execute_raw_frame(Ping::NAME, frame).await? {
+            Frame::SimpleError(data) => Err(RedisError::from_server(&data)),
+            Frame::BulkError(data) => Err(RedisError::from_server(&String::from_utf8_lossy(&data))),
+            frame => Pong::try_from(frame),
+        }
+    }
+
+    /// Sends a GET command, see `Client::get`.
+    pub async fn get(&self, key: impl ToRedisArg) -> Result<Option<Bytes>> {
+        match self.execute(Get::new(key)).await? {
+            Response::Simple(data) => Ok(Some(data)),
+            Response::Null => Ok(None),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a SET command, see `Client::set`.
+    pub async fn set(&self, key: impl ToRedisArg, val: &[u8]) -> Result<Option<Bytes>> {
+        match self.execute(Set::new(key, val)).await? {
+            Response::Simple(data) => Ok(Some(data)),
+            Response::Null => Ok(None),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a DEL command, see `Client::del`.
+    pub async fn del(&self, keys: Vec<impl ToRedisArg>) -> Result<u64> {
+        match self.execute(Del::new(keys)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an EXPIRE command, see `Client::expire`.
+    pub async fn expire(&self, key: impl ToRedisArg, seconds: i64) -> Result<u64> {
+        match self.execute(Expire::new(key, seconds)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends an INCR command, see `Client::incr`.
+    pub async fn incr(&self, key: impl ToRedisArg) -> Result<i64> {
+        match self.execute(Incr::new(key)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<i64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a DECR command, see `Client::decr`.
+    pub async fn decr(&self, key: impl ToRedisArg) -> Result<i64> {
+        match self.execute(Decr::new(key)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<i64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Sends a PUBLISH command, see `Client::publish`.
+    pub async fn publish(&self, channel: &str, message: &[u8]) -> Result<u64> {
+        match self.execute(Publish::new(channel, message)).await? {
+            Response::Simple(data) => Ok(from_utf8(&data)?.parse::<u64>()?),
+            Response::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+
+    /// Subscribes the connection to the given channels.
+    ///
+    /// The confirmations and the messages are push frames, they are delivered to the receivers
+    /// returned by `MultiplexedClient::pushes`. The connection keeps serving other commands.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` once the command is written
+    /// * `Err(RedisError)` if the connection does not use RESP3, since RESP2 messages cannot be
+    ///   told apart from replies
+    pub async fn subscribe(&self, channels: Vec<&str>) -> Result<()> {
+        self.send(Subscribe::new(channels)).await
+    }
+
+    /// Unsubscribes the connection from the given channels, or from every channel if empty.
+    ///
+    /// Like for `MultiplexedClient::subscribe`, the confirmations are push frames.
+    pub async fn unsubscribe(&self, channels: Vec<&str>) -> Result<()> {
+        self.send(Unsubscribe::new(channels)).await
+    }
+
+    /// Sends a command to the connection task and waits for its reply, with the key prefix added
+    /// to its keys.
+    ///
+    /// The round trip is reported to the metrics observer.
+    pub(crate) async fn execute<C: Command>(&self, cmd: C) -> Result<Response> {
+        self.execute_frame(C::NAME, prefixed_frame(&cmd, &self.key_prefix)?)
+            .await
+    }
+
+    /// Sends a command already turned into a frame, e.g. to send it again after a redirection.
+    ///
+    /// The frame is sent as it is, the key prefix is not added.
+    pub(crate) async fn execute_frame(&self, name: &str, frame: Frame) -> Result<Response> {
+        Response::try_from(self.execute_raw_frame(name, frame).await?)
+    }
+
+    /// Sends a command already turned into a frame and returns the reply frame as is, for replies
+    /// that `Response` cannot represent, e.g. a PONG told apart from an echoed message.
+    ///
+    /// The round trip is reported to the metrics observer.
+    async fn execute_raw_frame(&self, name: &str, frame: Frame) -> Result<Frame> {
+        let start = Instant::now();
+        #[cfg(feature = "tracing")]
+        let span = crate::trace::command_span(name, &frame);
+
+        let result = async {
+            let (reply, receiver) = oneshot::channel();
+            self.enqueue(Request {
+                frame,
+                reply: Some(reply),
+            })
+            .await?;
+
+            receiver.await.map_err(|_| RedisError::ClientClosed)?
+        };
+        #[cfg(feature = "tracing")]
+        let result = tracing::Instrument::instrument(result, span.clone());
+        let result = result.await;
+
+        let outcome = match &result {
+            Ok(Frame::SimpleError(_) | Frame::BulkError(_)) => Outcome::ServerError,
+            Ok(_) => Outcome::Success,
+            Err(_) => Outcome::Failed,
+        };
+        self.observer
+            .on_command_complete(name, start.elapsed(), outcome);
+        #[cfg(feature = "tracing")]
+        match &result {
+            Ok(Frame::SimpleError(err)) => {
+                crate::trace::record_outcome(&span, start.elapsed(), outcome, Some(err));
+            }
+            Ok(Frame::BulkError(err)) => {
+                let err = String::from_utf8_lossy(err);
+                crate::trace::record_outcome(&span, start.elapsed(), outcome, Some(&err));
+            }
+            Err(err) => crate::trace::record_outcome(&span, start.elapsed(), outcome, Some(err)),
+            Ok(_) => crate::trace::record_outcome(&span, start.elapsed(), outcome, None),
+        }
+
+        result
+    }
+
+    /// Sends a pub/sub command whose confirmations are push frames, without waiting for them.
+    async fn send<C: Command>(&self, cmd: C) -> Result<()> {
+        if self.protocol != ProtocolVersion::Resp3 {
+            return Err(RedisError::Other(anyhow!(
+                "{} on a multiplexed client requires RESP3",
+                C::NAME
+            )));
+        }
+
+        self.enqueue(Request {
+            frame: prefixed_frame(&cmd, &self.key_prefix)?,
+            reply: None,
+        })
+        .await
+    }
+
+    async fn enqueue(&self, request: Request) -> Result<()> {
+        self.requests
+            .send(request)
+            .await
+            .map_err(|_| RedisError::ClientClosed)
+    }
+}
+
+/// Owns the connection, writes the queued commands and routes the frames read back.
+///
+/// Once the client is closed or every client handle is dropped, the task waits for the replies
+/// still pending, then quits. When the connection fails, every command still waiting for a reply
+/// fails as well.
+async fn drive(
+    mut conn: Connection,
+    mut requests: mpsc::Receiver<Request>,
+    pushes: broadcast::Sender<Frame>,
+    closing: Arc<Notify>,
+) {
+    // the commands written but not replied to yet, in the order they were written
+    let mut pending: VecDeque<oneshot::Sender<Result<Frame>>> = VecDeque::new();
+    // cleared once no more commands can arrive
+    let mut receiving = true;
+
+    let result = loop {
+        if !receiving && pending.is_empty() {
+            break Ok(());
+        }
+
+        tokio::select! {
+            _ = closing.notified(), if receiving => {
+                // refuse new commands, the ones already queued are still written
+                requests.close();
+            }
+            request = requests.recv(), if receiving => {
+                let Some(request) = request else {
+                    receiving = false;
+                    continue;
+                };
+
+                // write everything already queued before flushing, so concurrent commands share
+                // a single write
+                let mut next = Some(request);
+                let mut written = Ok(());
+                while let Some(request) = next.take() {
+                    if let Err(err) = conn.write_frame_no_flush(&request.frame).await {
+                        if let Some(reply) = request.reply {
+                            let _ = reply.send(Err(RedisError::Other(anyhow!(
+                                "failed to write frame: {err}"
+                            ))));
+                        }
+                        written = Err(err);
+                        break;
+                    }
+
+                    pending.extend(request.reply);
+                    next = requests.try_recv().ok();
+                }
+
+                if let Err(err) = written {
+                    break Err(err);
+                }
+                if let Err(err) = conn.flush().await {
+                    break Err(err);
+                }
+            }
+            // reading is cancel safe, a partial frame stays in the read buffer
+            frame = conn.read_frame() => match frame {
+                Ok(Some(Frame::Push(data))) => {
+                    // no receiver is not an error, the frame is only dropped
+                    let _ = pushes.send(Frame::Push(data));
+                }
+                Ok(Some(frame)) => {
+                    // a reply nobody waits for belongs to a command whose caller gave up
+                    if let Some(reply) = pending.pop_front() {
+                        let _ = reply.send(Ok(frame));
+                    }
+                }
+                Ok(None) => break Err(RedisError::Other(anyhow!("connection closed by the server"))),
+                Err(err) => break Err(err),
+            },
+        }
+    };
+
+    match result {
+        // the server may already be gone, there is nobody left to report to anyway
+        Ok(()) => {
+            let _ = quit(&mut conn).await;
+        }
+        Err(err) => {
+            for reply in pending {
+                let _ = reply.send(Err(RedisError::Other(anyhow!("connection lost: {err}"))));
+            }
+        }
+    }
+}
+
+/// Sends a QUIT command and shuts down the connection once the server replies.
+async fn quit(conn: &mut Connection) -> Result<()> {
+    conn.write_frame(&Quit::new().to_frame()?).await?;
+
+    // pushes may still arrive before the reply, e.g. messages of subscribed channels
+    while let Some(frame) = conn.read_frame().await? {
+        if !matches!(frame, Frame::Push(_)) {
+            break;
+        }
+    }
+
+    conn.shutdown().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockRedisServer;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn test_multiplexed_client() {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to bind listener: {:?}", err));
+        let addr = listener
+            .local_addr()
+            .unwrap_or_else(|err| panic!("Failed to get listener address: {:?}", err));
+
+        // waits for both GETs, then replies with their keys and interleaves a push frame
+        tokio::spawn(async move {
+            if let Ok((mut socket, _)) = listener.accept().await {
+                let mut received = Vec::new();
+                let mut keys = Vec::new();
+                let mut buf = vec![0; 1024];
+                while keys.len() < 2 {
+                    match socket.read(&mut buf).await {
+                        Ok(0) | Err(_) => return,
+                        Ok(n) => received.extend_from_slice(&buf[..n]),
+                    }
+
+                    let mut cursor = std::io::Cursor::new(&received[..]);
+                    keys.clear();
+                    while let Ok(Frame::Array(args)) = Frame::try_parse(&mut cursor) {
+                        keys.extend(args.into_iter().skip(1));
+                    }
+                }
+
+                let mut reply = Vec::new();
+                for (i, key) in keys.into_iter().enumerate() {
+                    if let Frame::BulkString(key) = key {
+                        reply.extend_from_slice(format!("${}\r\n", key.len()).as_bytes());
+                        reply.extend_from_slice(&key);
+                        reply.extend_from_slice(b"\r\n");
+                    }
+                    if i == 0 {
+                        reply.extend_from_slice(
+                            b">3\r\n$7\r\nmessage\r\n$4\r\nnews\r\n$2\r\nhi\r\n",
+                        );
+                    }
+                }
+                let _ = socket.write_all(&reply).await;
+            }
+        });
+
+        let client = MultiplexedClient::connect(addr)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
+        let mut pushes = client.pushes();
+
+        let other = client.clone();
+        let first = tokio::spawn(async move { other.get("first").await });
+        let second = client.get("second").await;
+
+        let first = first
+            .await
+            .unwrap_or_else(|err| panic!("Failed to join task: {:?}", err));
+        assert_eq!(
+            first.unwrap_or_else(|err| panic!("Failed to GET: {:?}", err)),
+            Some(Bytes::from_static(b"first"))
+        );
+        assert_eq!(
+            second.unwrap_or_else(|err| panic!("Failed to GET: {:?}", err)),
+            Some(Bytes::from_static(b"second"))
+        );
+
+        let push = pushes
+            .recv()
+            .await
+            .unwrap_or_else(|err| panic!("Failed to receive push: {:?}", err));
+        assert_eq!(
+            push,
+            Frame::Push(vec![
+                Frame::bulk("message"),
+                Frame::bulk("news"),
+                Frame::bulk("hi"),
+            ])
+        );
+
+        // the server is gone, the next command fails instead of hanging
+        assert!(client.get("third").await.is_err());
+
+        // RESP2 pub/sub messages cannot be told apart from replies
+        assert!(client.subscribe(vec!["news"]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_ping() {
+        let server = MockRedisServer::start().await;
+        server
+            .expect_command(["PING"])
+            .respond(Frame::simple("PONG"));
+        server
+            .expect_command(["PING", "PONG"])
+            .respond(Frame::bulk("PONG"));
+        server
+            .expect_command(["PING", "hello"])
+            .respond(Frame::SimpleError(
+                "NOAUTH Authentication required.".to_string(),
+            ));
+
+        let client = MultiplexedClient::connect(server.addr())
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
+
+        // told apart by the type of the reply, not by the argument
+        let pong = client
+            .ping(None)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to PING: {:?}", err));
+        assert_eq!(pong, Pong::Pong);
+        let pong = client
+            .ping(Some(b"PONG"))
+            .await
+            .unwrap_or_else(|err| panic!("Failed to PING: {:?}", err));
+        assert_eq!(pong, Pong::Echo(Bytes::from_static(b"PONG")));
+
+        let result = client.ping(Some(b"hello")).await;
+        assert!(matches!(result, Err(RedisError::NoAuth(_))));
+    }
+
+    #[tokio::test]
+    async fn test_close() {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to bind listener: {:?}", err));
+        let addr = listener
+            .local_addr()
+            .unwrap_or_else(|err| panic!("Failed to get listener address: {:?}", err));
+
+        let (received_get, get_received) = oneshot::channel();
+        let server = tokio::spawn(async move {
+            let Ok((mut socket, _)) = listener.accept().await else {
+                return Vec::new();
+            };
+
+            let mut received = Vec::new();
+            let mut buf = vec![0; 1024];
+            while !received.ends_with(b"GET\r\n$3\r\nkey\r\n") {
+                match socket.read(&mut buf).await {
+                    Ok(0) | Err(_) => return received,
+                    Ok(n) => received.extend_from_slice(&buf[..n]),
+                }
+            }
+            let _ = received_get.send(());
+
+            // the reply is slow, close has to wait for it
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+            let _ = socket.write_all(b"$5\r\nvalue\r\n").await;
+
+            while !received.ends_with(b"QUIT\r\n") {
+                match socket.read(&mut buf).await {
+                    Ok(0) | Err(_) => return received,
+                    Ok(n) => received.extend_from_slice(&buf[..n]),
+                }
+            }
+            let _ = socket.write_all(b"+OK\r\n").await;
+
+            // the client shuts the stream down after the reply
+            let _ = socket.read_to_end(&mut received).await;
+            received
+        });
+
+        let client = MultiplexedClient::connect(addr)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
+
+        let other = client.clone();
+        let in_flight = tokio::spawn(async move { other.get("key").await });
+        get_received
+            .await
+            .unwrap_or_else(|err| panic!("The server did not receive the GET: {:?}", err));
+
+        client
+            .close()
+            .await
+            .unwrap_or_else(|err| panic!("Failed to close: {:?}", err));
+
+        // the command sent before close still gets its reply
+        let value = in_flight
+            .await
+            .unwrap_or_else(|err| panic!("Failed to join task: {:?}", err))
+            .unwrap_or_else(|err| panic!("Failed to GET: {:?}", err));
+        assert_eq!(value, Some(Bytes::from_static(b"value")));
+
+        // new commands are refused
+        assert!(matches!(
+            client.get("key").await,
+            Err(RedisError::ClientClosed)
+        ));
+        // closing twice is fine
+        assert!(client.close().await.is_ok());
+
+        let received = server
+            .await
+            .unwrap_or_else(|err| panic!("Failed to join server: {:?}", err));
+        assert!(received.ends_with(b"*1\r\n$4\r\nQUIT\r\n"));
+    }
+}
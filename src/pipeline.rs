@@ -0,0 +1,368 @@
+//! Pipelines, sending several commands in a single round trip.
+//!
+//! The commands are written at once, then their replies are read in the same order. Unlike a
+//! transaction, the commands of other clients may run in between, and a failing command does not
+//! stop the following ones.
+
+use crate::Client;
+use crate::Frame;
+use crate::RedisError;
+use crate::Result;
+use crate::StructuredResponse;
+use crate::client::command_frame;
+use bytes::Bytes;
+use std::str::from_utf8;
+
+/// A batch of commands sent in a single round trip, see `Pipeline::execute_typed`.
+///
+/// # Examples
+///
+/// ```ignore
+/// use redis_asyncx::{Client, Pipeline};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+///     let pipeline = Pipeline::new()
+///         .command(["SET", "mykey", "myvalue"])
+///         .command(["INCR", "counter"])
+///         .command(["GET", "missing"]);
+///
+///     let (status, counter, missing): (String, i64, Option<String>) =
+///         pipeline.execute_typed(&mut client).await.unwrap();
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Pipeline {
+    cmds: Vec<(String, Frame)>,
+    // the first command without a name, reported once the pipeline is executed
+    invalid: Option<usize>,
+}
+
+impl Pipeline {
+    /// Creates an empty pipeline.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a command, given as its name followed by its arguments like `Client::command`.
+    pub fn command<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<[u8]>,
+    {
+        match command_frame(args) {
+            Ok(cmd) => self.cmds.push(cmd),
+            Err(_) => {
+                self.invalid.get_or_insert(self.cmds.len());
+            }
+        }
+        self
+    }
+
+    /// Returns the number of commands in the pipeline.
+    pub fn len(&self) -> usize {
+        self.cmds.len()
+    }
+
+    /// Returns true if the pipeline has no command.
+    pub fn is_empty(&self) -> bool {
+        self.cmds.is_empty()
+    }
+
+    /// Sends the commands in a single round trip, and returns their replies in order.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<StructuredResponse>)` the reply of every command. A command that failed has a
+    ///   `StructuredResponse::Error`, the others still ran
+    /// * `Err(RedisError::InvalidArgument)` if a command was added without a name
+    /// * `Err(RedisError)` if an error occurs, e.g. the connection is lost
+    pub async fn execute(&self, client: &mut Client) -> Result<Vec<StructuredResponse>> {
+        if let Some(index) = self.invalid {
+            return Err(RedisError::InvalidArgument(format!(
+                "command {index} of the pipeline requires at least its name"
+            )));
+        }
+        if self.cmds.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let frames = client.execute_raw_batch(&self.cmds).await?;
+        Ok(frames.into_iter().map(StructuredResponse::from).collect())
+    }
+
+    /// Sends the commands in a single round trip, and converts their replies into the given
+    /// types, e.g. a tuple with as many elements as there are commands.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(T)` the replies, converted
+    /// * `Err(RedisError::PipelineReply)` if a reply is an error or does not convert, along with
+    ///   its position
+    /// * `Err(RedisError)` if an error occurs, e.g. there are more commands than tuple elements
+    pub async fn execute_typed<T: FromPipeline>(&self, client: &mut Client) -> Result<T> {
+        T::from_pipeline(self.execute(client).await?)
+    }
+}
+
+/// A type a single reply converts into, see `Pipeline::execute_typed`.
+///
+/// A server error converts into no type but `StructuredResponse`, it is returned instead.
+pub trait FromRedisValue: Sized {
+    /// Converts the reply, or fails with the reason it does not convert.
+    fn from_redis_value(value: StructuredResponse) -> Result<Self>;
+}
+
+impl FromRedisValue for StructuredResponse {
+    fn from_redis_value(value: StructuredResponse) -> Result<Self> {
+        Ok(value)
+    }
+}
+
+impl FromRedisValue for Bytes {
+    fn from_redis_value(value: StructuredResponse) -> Result<Self> {
+        match value {
+            StructuredResponse::Bulk(data) => Ok(data),
+            StructuredResponse::Status(data) | StructuredResponse::BigNumber(data) => {
+                Ok(Bytes::from(data))
+            }
+            StructuredResponse::Integer(data) => Ok(Bytes::from(data.to_string())),
+            StructuredResponse::Double(data) => Ok(Bytes::from(data.to_string())),
+            StructuredResponse::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+}
+
+impl FromRedisValue for Vec<u8> {
+    fn from_redis_value(value: StructuredResponse) -> Result<Self> {
+        Bytes::from_redis_value(value).map(Vec::from)
+    }
+}
+
+impl FromRedisValue for String {
+    fn from_redis_value(value: StructuredResponse) -> Result<Self> {
+        let data = Bytes::from_redis_value(value)?;
+        Ok(from_utf8(&data)?.to_string())
+    }
+}
+
+impl FromRedisValue for i64 {
+    fn from_redis_value(value: StructuredResponse) -> Result<Self> {
+        match value {
+            StructuredResponse::Integer(data) => Ok(data),
+            StructuredResponse::Bool(data) => Ok(i64::from(data)),
+            StructuredResponse::Bulk(data) => Ok(from_utf8(&data)?.parse()?),
+            StructuredResponse::Status(data) => Ok(data.parse()?),
+            StructuredResponse::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+}
+
+impl FromRedisValue for u64 {
+    fn from_redis_value(value: StructuredResponse) -> Result<Self> {
+        Ok(u64::try_from(i64::from_redis_value(value)?)?)
+    }
+}
+
+impl FromRedisValue for f64 {
+    fn from_redis_value(value: StructuredResponse) -> Result<Self> {
+        match value {
+            StructuredResponse::Double(data) => Ok(data),
+            StructuredResponse::Integer(data) => Ok(data as f64),
+            StructuredResponse::Bulk(data) => Ok(from_utf8(&data)?.parse()?),
+            StructuredResponse::Status(data) => Ok(data.parse()?),
+            StructuredResponse::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+}
+
+impl FromRedisValue for bool {
+    fn from_redis_value(value: StructuredResponse) -> Result<Self> {
+        match value {
+            StructuredResponse::Bool(data) => Ok(data),
+            // RESP2 replies with the integers 1 and 0 instead
+            StructuredResponse::Integer(data) => Ok(data != 0),
+            StructuredResponse::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+}
+
+impl FromRedisValue for () {
+    fn from_redis_value(value: StructuredResponse) -> Result<Self> {
+        match value {
+            StructuredResponse::Error(err) => Err(err),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<T: FromRedisValue> FromRedisValue for Option<T> {
+    fn from_redis_value(value: StructuredResponse) -> Result<Self> {
+        match value {
+            StructuredResponse::Null => Ok(None),
+            value => T::from_redis_value(value).map(Some),
+        }
+    }
+}
+
+impl<T: FromRedisValue> FromRedisValue for Vec<T> {
+    fn from_redis_value(value: StructuredResponse) -> Result<Self> {
+        match value {
+            StructuredResponse::Array(items) => {
+                items.into_iter().map(T::from_redis_value).collect()
+            }
+            StructuredResponse::Error(err) => Err(err),
+            _ => Err(RedisError::UnexpectedResponseType),
+        }
+    }
+}
+
+/// The replies of a whole pipeline convert into it, see `Pipeline::execute_typed`.
+///
+/// Implemented for the tuples of up to 8 `FromRedisValue` elements, one per command, and for
+/// `Vec<T>` for pipelines of commands replying the same type.
+pub trait FromPipeline: Sized {
+    /// Converts the replies, or fails with the position of the first one that does not convert.
+    fn from_pipeline(replies: Vec<StructuredResponse>) -> Result<Self>;
+}
+
+/// Converts the reply at the given position, naming it if it does not convert.
+fn convert<T: FromRedisValue>(index: usize, reply: StructuredResponse) -> Result<T> {
+    T::from_redis_value(reply).map_err(|err| RedisError::PipelineReply {
+        index,
+        source: Box::new(err),
+    })
+}
+
+impl<T: FromRedisValue> FromPipeline for Vec<T> {
+    fn from_pipeline(replies: Vec<StructuredResponse>) -> Result<Self> {
+        replies
+            .into_iter()
+            .enumerate()
+            .map(|(index, reply)| convert(index, reply))
+            .collect()
+    }
+}
+
+macro_rules! impl_from_pipeline {
+    ($len:literal; $($index:tt $name:ident),+) => {
+        impl<$($name: FromRedisValue),+> FromPipeline for ($($name,)+) {
+            fn from_pipeline(replies: Vec<StructuredResponse>) -> Result<Self> {
+                if replies.len() != $len {
+                    return Err(RedisError::InvalidArgument(format!(
+                        "a pipeline of {} commands cannot convert into {} values",
+                        replies.len(),
+                        $len
+                    )));
+                }
+
+                let mut replies = replies.into_iter();
+                Ok(($(convert::<$name>(
+                    $index,
+                    replies.next().unwrap_or(StructuredResponse::Null),
+                )?,)+))
+            }
+        }
+    };
+}
+
+impl_from_pipeline!(1; 0 A);
+impl_from_pipeline!(2; 0 A, 1 B);
+impl_from_pipeline!(3; 0 A, 1 B, 2 C);
+impl_from_pipeline!(4; 0 A, 1 B, 2 C, 3 D);
+impl_from_pipeline!(5; 0 A, 1 B, 2 C, 3 D, 4 E);
+impl_from_pipeline!(6; 0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+impl_from_pipeline!(7; 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
+impl_from_pipeline!(8; 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockRedisServer;
+
+    #[test]
+    fn test_from_pipeline() {
+        let replies = vec![
+            StructuredResponse::Status("OK".to_string()),
+            StructuredResponse::Integer(5),
+            StructuredResponse::Null,
+        ];
+        let (status, count, missing): (String, i64, Option<String>) =
+            FromPipeline::from_pipeline(replies)
+                .unwrap_or_else(|err| panic!("Failed to convert replies: {:?}", err));
+        assert_eq!(status, "OK");
+        assert_eq!(count, 5);
+        assert_eq!(missing, None);
+
+        // the position of the reply failing to convert is named
+        let replies = vec![
+            StructuredResponse::Integer(1),
+            StructuredResponse::Bulk(Bytes::from_static(b"not a number")),
+        ];
+        let result: Result<(i64, i64)> = FromPipeline::from_pipeline(replies);
+        assert!(matches!(
+            result,
+            Err(RedisError::PipelineReply { index: 1, .. })
+        ));
+
+        // not as many values as replies
+        let replies = vec![StructuredResponse::Integer(1)];
+        let result: Result<(i64, i64)> = FromPipeline::from_pipeline(replies);
+        assert!(matches!(result, Err(RedisError::InvalidArgument(_))));
+    }
+
+    #[tokio::test]
+    async fn test_execute_typed() {
+        let server = MockRedisServer::start().await;
+        server
+            .expect_command(["SET", "mykey", "myvalue"])
+            .respond(Frame::simple("OK"));
+        server
+            .expect_command(["INCR", "counter"])
+            .respond(Frame::Integer(1));
+        server
+            .expect_command(["GET", "missing"])
+            .respond(Frame::Null);
+
+        let mut client = Client::connect(server.addr())
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
+        let pipeline = Pipeline::new()
+            .command(["SET", "mykey", "myvalue"])
+            .command(["INCR", "counter"])
+            .command(["GET", "missing"]);
+        assert_eq!(pipeline.len(), 3);
+
+        let (status, counter, missing): (String, i64, Option<String>) = pipeline
+            .execute_typed(&mut client)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to execute pipeline: {:?}", err));
+        assert_eq!(status, "OK");
+        assert_eq!(counter, 1);
+        assert_eq!(missing, None);
+    }
+
+    #[tokio::test]
+    async fn test_execute_invalid_command() {
+        let mut client = {
+            let server = MockRedisServer::start().await;
+            Client::connect(server.addr())
+                .await
+                .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err))
+        };
+
+        // rejected before sending anything
+        let pipeline = Pipeline::new()
+            .command(["PING"])
+            .command(Vec::<&str>::new());
+        assert!(matches!(
+            pipeline.execute(&mut client).await,
+            Err(RedisError::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,629 @@
+//! A pool of connections to a Redis server, shared between tasks.
+//!
+//! Each connection is checked out by a single task at a time, and goes back to the pool once the
+//! task is done with it. Connections left idle for too long are closed, since the server or a
+//! middlebox may have dropped them already.
+
+use crate::Client;
+use crate::LockGuard;
+use crate::RedisError;
+use crate::Result;
+use crate::ToRedisArg;
+use crate::metrics::{MetricsObserver, NoopObserver};
+use anyhow::anyhow;
+use bytes::Bytes;
+use std::collections::VecDeque;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, Weak};
+use std::time::{Duration, Instant};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::task::JoinHandle;
+
+/// The limits and health checks of a `Pool`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolConfig {
+    /// The maximum number of connections, checkouts wait once they are all in use.
+    pub max_size: usize,
+    /// The number of idle connections opened with the pool and kept by the reaper.
+    pub min_idle: usize,
+    /// How long a connection may stay idle before it is closed, forever if None.
+    pub max_idle_time: Option<Duration>,
+    /// Whether to PING an idle connection before handing it out, replacing it if the PING fails.
+    pub test_on_checkout: bool,
+    /// How often the reaper closes the connections idle for longer than `max_idle_time`, must not
+    /// be zero.
+    pub reap_interval: Duration,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        Self {
+            max_size: 10,
+            min_idle: 0,
+            max_idle_time: Some(Duration::from_secs(300)),
+            test_on_checkout: false,
+            reap_interval: Duration::from_secs(30),
+        }
+    }
+}
+
+/// A snapshot of the connections of a `Pool`, returned by `Pool::stats`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PoolStats {
+    /// The number of open connections, idle or in use.
+    pub size: usize,
+    /// The number of idle connections.
+    pub idle: usize,
+    /// The number of connections opened since the pool was created.
+    pub created_total: u64,
+    /// The number of connections closed by the pool, because they were idle for too long, failed
+    /// the checkout PING or were returned unusable.
+    pub recycled_total: u64,
+}
+
+/// An idle connection, waiting in the pool.
+struct Idle {
+    client: Client,
+    since: Instant,
+}
+
+/// The state shared by the pool, its checked out connections and its reaper.
+struct Shared {
+    addr: String,
+    config: PoolConfig,
+    observer: Arc<dyn MetricsObserver>,
+    // the most recently returned connection last, so the front is the first to expire
+    idle: Mutex<VecDeque<Idle>>,
+    permits: Arc<Semaphore>,
+    size: AtomicUsize,
+    created_total: AtomicU64,
+    recycled_total: AtomicU64,
+}
+
+impl Shared {
+    async fn create(&self) -> Result<Client> {
+        let client =
+            Client::connect_with_observer(self.addr.as_str(), self.observer.clone()).await?;
+        self.size.fetch_add(1, Ordering::Relaxed);
+        self.created_total.fetch_add(1, Ordering::Relaxed);
+        self.report();
+
+        Ok(client)
+    }
+
+    fn put_back(&self, client: Client) {
+        lock(&self.idle).push_back(Idle {
+            client,
+            since: Instant::now(),
+        });
+        self.report();
+    }
+
+    fn recycle(&self, client: Client) {
+        drop(client);
+        self.size.fetch_sub(1, Ordering::Relaxed);
+        self.recycled_total.fetch_add(1, Ordering::Relaxed);
+        self.report();
+    }
+
+    fn expired(&self, idle: &Idle) -> bool {
+        self.config
+            .max_idle_time
+            .is_some_and(|max_idle_time| idle.since.elapsed() >= max_idle_time)
+    }
+
+    fn stats(&self) -> PoolStats {
+        PoolStats {
+            size: self.size.load(Ordering::Relaxed),
+            idle: lock(&self.idle).len(),
+            created_total: self.created_total.load(Ordering::Relaxed),
+            recycled_total: self.recycled_total.load(Ordering::Relaxed),
+        }
+    }
+
+    fn report(&self) {
+        let stats = self.stats();
+        self.observer
+            .on_pool_gauges(stats.idle, stats.size.saturating_sub(stats.idle));
+    }
+}
+
+// the queue is left consistent by every method, so a panic elsewhere does not corrupt it
+fn lock(idle: &Mutex<VecDeque<Idle>>) -> MutexGuard<'_, VecDeque<Idle>> {
+    idle.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// A pool of connections to a Redis server.
+///
+/// `Pool::get` checks a connection out, opening a new one if none is idle, and the connection
+/// goes back to the pool once the returned `PooledClient` is dropped. Share the pool between tasks
+/// with an `Arc`.
+///
+/// # Examples
+///
+/// ```ignore
+/// use redis_asyncx::{Pool, PoolConfig};
+/// use std::time::Duration;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let config = PoolConfig {
+///         min_idle: 2,
+///         max_idle_time: Some(Duration::from_secs(60)),
+///         test_on_checkout: true,
+///         ..PoolConfig::default()
+///     };
+///     let pool = Pool::connect("127.0.0.1:6379", config).await.unwrap();
+///
+///     let mut client = pool.get().await.unwrap();
+///     client.set("mykey", b"myvalue").await.unwrap();
+/// }
+/// ```
+pub struct Pool {
+    shared: Arc<Shared>,
+    // closes the expired idle connections, aborted when the pool is dropped
+    reaper: JoinHandle<()>,
+}
+
+impl Pool {
+    /// Creates a pool of connections to the given server, and opens `min_idle` of them.
+    ///
+    /// # Arguments
+    ///
+    /// * `addr` - The address of the server, as `host:port`
+    /// * `config` - The limits and health checks of the pool
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Pool)` once the idle connections are open
+    /// * `Err(RedisError::InvalidArgument)` if `max_size` is zero or smaller than `min_idle`, or
+    ///   `reap_interval` is zero
+    /// * `Err(RedisError)` if a connection cannot be opened
+    pub async fn connect(addr: &str, config: PoolConfig) -> Result<Self> {
+        Self::connect_with_observer(addr, config, Arc::new(NoopObserver)).await
+    }
+
+    /// Creates a pool like `Pool::connect`, reporting its connections, their commands and the pool
+    /// gauges to the given metrics observer.
+    pub async fn connect_with_observer(
+        addr: &str,
+        config: PoolConfig,
+        observer: Arc<dyn MetricsObserver>,
+    ) -> Result<Self> {
+        if config.max_size == 0 || config.min_idle > config.max_size {
+            return Err(RedisError::InvalidArgument(format!(
+                "a pool of at most {} connections cannot keep {} idle",
+                config.max_size, config.min_idle
+            )));
+        }
+        if config.reap_interval.is_zero() {
+            return Err(RedisError::InvalidArgument(
+                "the reap interval must not be zero".to_string(),
+            ));
+        }
+
+        let shared = Arc::new(Shared {
+            addr: addr.to_string(),
+            config,
+            observer,
+            idle: Mutex::new(VecDeque::new()),
+            permits: Arc::new(Semaphore::new(config.max_size)),
+            size: AtomicUsize::new(0),
+            created_total: AtomicU64::new(0),
+            recycled_total: AtomicU64::new(0),
+        });
+
+        for _ in 0..config.min_idle {
+            let client = shared.create().await?;
+            shared.put_back(client);
+        }
+
+        let reaper = tokio::spawn(reap(Arc::downgrade(&shared)));
+
+        Ok(Self { shared, reaper })
+    }
+
+    /// Checks a connection out of the pool, waiting for one if they are all in use.
+    ///
+    /// The most recently returned idle connection is handed out first. Expired connections are
+    /// closed on the way, and so are the ones failing the PING if `test_on_checkout` is set. A new
+    /// connection is opened if none is left.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PooledClient)` the connection, back in the pool once dropped
+    /// * `Err(RedisError)` if a new connection cannot be opened
+    pub async fn get(&self) -> Result<PooledClient> {
+        let permit = self
+            .shared
+            .permits
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(|_| RedisError::Other(anyhow!("the pool is closed")))?;
+
+        loop {
+            let Some(idle) = lock(&self.shared.idle).pop_back() else {
+                break;
+            };
+            if self.shared.expired(&idle) {
+                self.shared.recycle(idle.client);
+                continue;
+            }
+
+            let mut client = idle.client;
+            if self.shared.config.test_on_checkout && !client.is_alive().await {
+                self.shared.recycle(client);
+                continue;
+            }
+
+            self.shared.report();
+            return Ok(PooledClient::new(client, self.shared.clone(), permit));
+        }
+
+        let client = self.shared.create().await?;
+        Ok(PooledClient::new(client, self.shared.clone(), permit))
+    }
+
+    /// Returns the number of connections of the pool and how many were opened and closed so far.
+    pub fn stats(&self) -> PoolStats {
+        self.shared.stats()
+    }
+
+    /// Acquires a lock with a connection of the pool, see `Client::acquire_lock`.
+    ///
+    /// The connection is held by the returned guard until the lock is released, and goes back to
+    /// the pool right away if the lock is not acquired.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key the lock is stored in
+    /// * `token` - A value unique to the holder, e.g. a random UUID
+    /// * `ttl` - How long the lock is held at most, at least a millisecond
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(LockGuard))` if the lock is acquired, released once the guard is dropped
+    /// * `Ok(None)` if another holder has it
+    /// * `Err(RedisError)` if an error occurs
+    pub async fn lock(
+        &self,
+        key: impl ToRedisArg,
+        token: &[u8],
+        ttl: Duration,
+    ) -> Result<Option<LockGuard>> {
+        let key = key.to_redis_arg();
+        let mut client = self.get().await?;
+        if !client.acquire_lock(&key, token, ttl).await? {
+            return Ok(None);
+        }
+
+        Ok(Some(LockGuard::new(
+            client,
+            key,
+            Bytes::copy_from_slice(token),
+        )))
+    }
+}
+
+impl Drop for Pool {
+    fn drop(&mut self) {
+        self.reaper.abort();
+    }
+}
+
+/// Closes the idle connections that expired, keeping `min_idle` of them, until the pool is dropped.
+async fn reap(shared: Weak<Shared>) {
+    let Some(interval) = shared.upgrade().map(|shared| shared.config.reap_interval) else {
+        return;
+    };
+    let mut ticks = tokio::time::interval(interval);
+    // the first tick completes right away
+    ticks.tick().await;
+
+    loop {
+        ticks.tick().await;
+        let Some(shared) = shared.upgrade() else {
+            return;
+        };
+
+        let mut expired = Vec::new();
+        {
+            let mut idle = lock(&shared.idle);
+            while idle.len() > shared.config.min_idle
+                && idle.front().is_some_and(|front| shared.expired(front))
+            {
+                expired.extend(idle.pop_front());
+            }
+        }
+
+        for idle in expired {
+            shared.recycle(idle.client);
+        }
+    }
+}
+
+/// A connection checked out of a `Pool`, use it as a `Client`.
+///
+/// The connection goes back to the pool once dropped, unless a failed command left it unusable,
+/// or a command was given up on before its reply, in which case it is closed.
+pub struct PooledClient {
+    // only None while being dropped
+    client: Option<Client>,
+    shared: Arc<Shared>,
+    // frees a slot of the pool once dropped, after the connection is back
+    _permit: OwnedSemaphorePermit,
+}
+
+impl PooledClient {
+    fn new(client: Client, shared: Arc<Shared>, permit: OwnedSemaphorePermit) -> Self {
+        Self {
+            client: Some(client),
+            shared,
+            _permit: permit,
+        }
+    }
+}
+
+impl Deref for PooledClient {
+    type Target = Client;
+
+    fn deref(&self) -> &Client {
+        self.client
+            .as_ref()
+            .unwrap_or_else(|| unreachable!("the client is only taken on drop"))
+    }
+}
+
+impl DerefMut for PooledClient {
+    fn deref_mut(&mut self) -> &mut Client {
+        self.client
+            .as_mut()
+            .unwrap_or_else(|| unreachable!("the client is only taken on drop"))
+    }
+}
+
+impl Drop for PooledClient {
+    fn drop(&mut self) {
+        if let Some(client) = self.client.take() {
+            if client.is_usable() {
+                self.shared.put_back(client);
+            } else {
+                self.shared.recycle(client);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CountingObserver;
+    use crate::Frame;
+    use crate::testing::MockRedisServer;
+    use bytes::Bytes;
+    use std::io::Cursor;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Starts a fake server replying PONG to PING and nil to any other command, which closes the
+    /// connections idle for longer than `idle_timeout`, like the `timeout` option of Redis.
+    async fn serve(idle_timeout: Option<Duration>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to bind listener: {:?}", err));
+        let addr = listener
+            .local_addr()
+            .unwrap_or_else(|err| panic!("Failed to get listener address: {:?}", err));
+
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let mut received = Vec::new();
+                    let mut buf = vec![0; 1024];
+                    loop {
+                        let read = socket.read(&mut buf);
+                        let n = match idle_timeout {
+                            Some(idle_timeout) => {
+                                match tokio::time::timeout(idle_timeout, read).await {
+                                    Ok(n) => n,
+                                    // dropping the socket closes the connection
+                                    Err(_) => return,
+                                }
+                            }
+                            None => read.await,
+                        };
+                        match n {
+                            Ok(0) | Err(_) => return,
+                            Ok(n) => received.extend_from_slice(&buf[..n]),
+                        }
+
+                        let mut cursor = Cursor::new(&received[..]);
+                        let mut replies = Vec::new();
+                        while let Ok(Frame::Array(args)) = Frame::try_parse(&mut cursor) {
+                            if args.first() == Some(&Frame::bulk("PING")) {
+                                replies.extend_from_slice(b"+PONG\r\n");
+                            } else {
+                                replies.extend_from_slice(b"$-1\r\n");
+                            }
+                        }
+                        let consumed = cursor.position() as usize;
+                        received.drain(..consumed);
+                        let _ = socket.write_all(&replies).await;
+                    }
+                });
+            }
+        });
+
+        addr.to_string()
+    }
+
+    async fn get(pool: &Pool) -> PooledClient {
+        pool.get()
+            .await
+            .unwrap_or_else(|err| panic!("Failed to check out a connection: {:?}", err))
+    }
+
+    #[tokio::test]
+    async fn test_pool_reuses_connections() {
+        let addr = serve(None).await;
+        let pool = Pool::connect(&addr, PoolConfig::default())
+            .await
+            .unwrap_or_else(|err| panic!("Failed to create pool: {:?}", err));
+
+        for _ in 0..3 {
+            let mut client = get(&pool).await;
+            let value: Option<Bytes> = client
+                .get("mykey")
+                .await
+                .unwrap_or_else(|err| panic!("Failed to GET: {:?}", err));
+            assert_eq!(value, None);
+        }
+
+        let first = get(&pool).await;
+        let second = get(&pool).await;
+        drop((first, second));
+
+        assert_eq!(
+            pool.stats(),
+            PoolStats {
+                size: 2,
+                idle: 2,
+                created_total: 2,
+                recycled_total: 0,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pool_test_on_checkout() {
+        // the server drops connections idle for 100ms
+        let addr = serve(Some(Duration::from_millis(100))).await;
+        let config = PoolConfig {
+            max_idle_time: None,
+            test_on_checkout: true,
+            ..PoolConfig::default()
+        };
+        let pool = Pool::connect(&addr, config)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to create pool: {:?}", err));
+
+        let mut client = get(&pool).await;
+        assert!(client.get("mykey").await.is_ok());
+        drop(client);
+
+        tokio::time::sleep(Duration::from_millis(300)).await;
+
+        // the closed connection fails the PING and is replaced transparently
+        let mut client = get(&pool).await;
+        assert!(client.get("mykey").await.is_ok());
+        drop(client);
+
+        assert_eq!(
+            pool.stats(),
+            PoolStats {
+                size: 1,
+                idle: 1,
+                created_total: 2,
+                recycled_total: 1,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pool_reaper() {
+        let addr = serve(None).await;
+        let observer = Arc::new(CountingObserver::new());
+        let config = PoolConfig {
+            max_size: 3,
+            min_idle: 1,
+            max_idle_time: Some(Duration::from_millis(50)),
+            test_on_checkout: false,
+            reap_interval: Duration::from_millis(20),
+        };
+        let pool = Pool::connect_with_observer(&addr, config, observer.clone())
+            .await
+            .unwrap_or_else(|err| panic!("Failed to create pool: {:?}", err));
+        assert_eq!(pool.stats().idle, 1);
+
+        let clients = vec![get(&pool).await, get(&pool).await, get(&pool).await];
+        assert_eq!(pool.stats().size, 3);
+        assert_eq!(observer.pool_gauges(), (0, 3));
+        drop(clients);
+        assert_eq!(observer.pool_gauges(), (3, 0));
+
+        // the reaper trims the expired connections down to min_idle
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert_eq!(
+            pool.stats(),
+            PoolStats {
+                size: 1,
+                idle: 1,
+                created_total: 3,
+                recycled_total: 2,
+            }
+        );
+        assert_eq!(observer.pool_gauges(), (1, 0));
+
+        // the last one expired as well, it is replaced on checkout
+        let mut client = get(&pool).await;
+        assert!(client.get("mykey").await.is_ok());
+        assert_eq!(pool.stats().created_total, 4);
+    }
+
+    #[tokio::test]
+    async fn test_pool_cancelled_command() {
+        let server = MockRedisServer::start().await;
+        // the reply of the first GET arrives once its caller gave up on it
+        server
+            .expect_command(["GET", "first"])
+            .delay(Duration::from_millis(100))
+            .respond(Frame::bulk("first"));
+        server
+            .expect_command(["GET", "second"])
+            .respond(Frame::bulk("second"));
+        let config = PoolConfig {
+            max_size: 1,
+            ..PoolConfig::default()
+        };
+        let pool = Pool::connect(server.addr(), config)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to create pool: {:?}", err));
+
+        let mut client = get(&pool).await;
+        let pending = tokio::time::timeout(Duration::from_millis(20), client.get("first")).await;
+        assert!(pending.is_err());
+        drop(client);
+
+        // the connection with the reply pending is closed, not handed out again
+        let mut client = get(&pool).await;
+        let value = client
+            .get("second")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to GET: {:?}", err));
+        assert_eq!(value, Some(Bytes::from_static(b"second")));
+        assert_eq!(pool.stats().recycled_total, 1);
+    }
+
+    #[tokio::test]
+    async fn test_pool_invalid_config() {
+        let config = PoolConfig {
+            max_size: 1,
+            min_idle: 2,
+            ..PoolConfig::default()
+        };
+
+        // rejected before connecting, nothing listens on the port
+        let result = Pool::connect("127.0.0.1:1", config).await;
+        assert!(matches!(result, Err(RedisError::InvalidArgument(_))));
+
+        // the reaper could not tick
+        let config = PoolConfig {
+            reap_interval: Duration::ZERO,
+            ..PoolConfig::default()
+        };
+        let result = Pool::connect("127.0.0.1:1", config).await;
+        assert!(matches!(result, Err(RedisError::InvalidArgument(_))));
+    }
+}
@@ -0,0 +1,64 @@
+//! Rate limiting, counting the requests of a caller in a key.
+//!
+//! A fixed window counts the requests with INCR in a key expiring at the end of the window. It
+//! costs a single key and round trip, but lets up to twice the limit through around the end of a
+//! window. A sliding window log keeps the time of every request allowed in a sorted set instead,
+//! trimmed by a script, so the limit holds over any window at the cost of a member per request.
+
+use crate::Script;
+use std::sync::LazyLock;
+use std::time::Duration;
+
+/// How `Client::check_rate_limit` counts the requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RateLimitAlgorithm {
+    /// Counts the requests since the first one of the window, with INCR and PEXPIRE NX.
+    #[default]
+    FixedWindow,
+    /// Counts the requests of the last `window`, in a sorted set trimmed by a script.
+    SlidingWindowLog,
+}
+
+/// Whether a request is within its rate limit, returned by `Client::check_rate_limit`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitDecision {
+    /// Whether the request is allowed. A denied request still counts in a fixed window, not in a
+    /// sliding window log.
+    pub allowed: bool,
+    /// How many more requests are allowed in the current window.
+    pub remaining: u64,
+    /// How long until a request is allowed again: the end of the window for a fixed window, or
+    /// until the oldest request counted leaves the window for a sliding window log.
+    pub reset_after: Duration,
+}
+
+/// Trims the requests older than ARGV[1] milliseconds, then logs this one if fewer than ARGV[2]
+/// are left. Returns whether it is allowed, the number of requests in the window and how many
+/// milliseconds until the oldest one leaves it.
+///
+/// The time is read from the server, so that the clocks of the clients do not matter. It is in
+/// microseconds, formatted by hand since Lua would print it with too few digits.
+pub(crate) static SLIDING_WINDOW_LOG: LazyLock<Script> = LazyLock::new(|| {
+    Script::new(
+        "local time = redis.call('TIME') \
+         local now = tonumber(time[1]) * 1000000 + tonumber(time[2]) \
+         local window = tonumber(ARGV[1]) * 1000 \
+         local max = tonumber(ARGV[2]) \
+         redis.call('ZREMRANGEBYSCORE', KEYS[1], '-inf', string.format('%.0f', now - window)) \
+         local count = redis.call('ZCARD', KEYS[1]) \
+         local allowed = 0 \
+         if count < max then \
+             local score = string.format('%.0f', now) \
+             redis.call('ZADD', KEYS[1], score, score .. '-' .. count) \
+             count = count + 1 \
+             allowed = 1 \
+         end \
+         local reset = 0 \
+         local oldest = redis.call('ZRANGE', KEYS[1], 0, 0, 'WITHSCORES') \
+         if oldest[2] then \
+             redis.call('PEXPIRE', KEYS[1], ARGV[1]) \
+             reset = math.ceil((tonumber(oldest[2]) + window - now) / 1000) \
+         end \
+         return {allowed, count, reset}",
+    )
+});
@@ -0,0 +1,390 @@
+//! Retries of the commands failing with a transient error.
+//!
+//! A server starting up replies `LOADING` until its dataset is in memory, a server running a long
+//! script replies `BUSY`, and a cluster node migrating a slot replies `TRYAGAIN` to some multi-key
+//! commands. These go away on their own, so the command is sent again after a backoff.
+//!
+//! A `Subscriber` whose connection dropped reconnects the same way, then subscribes again.
+
+use crate::RedisError;
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::time::Duration;
+
+/// How a `Client` retries the commands failing with a transient error, see
+/// `RedisError::is_transient`.
+///
+/// Only idempotent commands are retried by default. The others, e.g. INCR or LPUSH, are retried if
+/// listed in `retry_non_idempotent`.
+///
+/// # Examples
+///
+/// ```ignore
+/// use redis_asyncx::{Client, RetryPolicy};
+/// use std::time::Duration;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+///     client.set_retry_policy(Some(RetryPolicy {
+///         max_attempts: 5,
+///         base_backoff: Duration::from_millis(50),
+///         ..Default::default()
+///     }));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// The number of times a command is sent at most, including the first one.
+    pub max_attempts: u32,
+    /// How long to wait before the first retry, doubled before every following one.
+    pub base_backoff: Duration,
+    /// The upper bound of a random delay added to every backoff, so that the clients of a server
+    /// that is starting up do not all retry at once.
+    pub jitter: Duration,
+    /// The names of the non-idempotent commands to retry anyway, e.g. `INCR`.
+    pub retry_non_idempotent: Vec<String>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_backoff: Duration::from_millis(100),
+            jitter: Duration::from_millis(100),
+            retry_non_idempotent: Vec::new(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Returns how long to wait before sending a command again after its `attempt`th attempt
+    /// failed, or None if it should not be sent again.
+    pub(crate) fn backoff(
+        &self,
+        name: &str,
+        idempotent: bool,
+        err: &RedisError,
+        attempt: u32,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts || !err.is_transient() {
+            return None;
+        }
+        if !idempotent
+            && !self
+                .retry_non_idempotent
+                .iter()
+                .any(|n| n.eq_ignore_ascii_case(name))
+        {
+            return None;
+        }
+
+        let backoff = self
+            .base_backoff
+            .saturating_mul(2_u32.saturating_pow(attempt - 1));
+        let jitter = match self.jitter.as_nanos() as u64 {
+            0 => 0,
+            jitter => RandomState::new().hash_one(attempt) % (jitter + 1),
+        };
+
+        Some(backoff.saturating_add(Duration::from_nanos(jitter)))
+    }
+}
+
+/// How a `Subscriber` reconnects once its connection drops, see
+/// `Subscriber::set_reconnect_policy`.
+///
+/// The first attempt is made right away, the following ones after a backoff doubled every time, up
+/// to `max_backoff`.
+///
+/// # Examples
+///
+/// ```ignore
+/// use redis_asyncx::{Client, ReconnectPolicy};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::connect("127.0.0.1:6379").await.unwrap();
+///     let mut subscriber = client.subscribe(vec!["news"]).await.unwrap();
+///     subscriber.set_reconnect_policy(Some(ReconnectPolicy::default()));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReconnectPolicy {
+    /// The number of times a connection is attempted at most, before giving up.
+    pub max_attempts: u32,
+    /// How long to wait before the second attempt.
+    pub base_backoff: Duration,
+    /// The longest wait between two attempts.
+    pub max_backoff: Duration,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 10,
+            base_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// Returns how long to wait before the `attempt`th connection attempt, or None once out of
+    /// attempts.
+    pub(crate) fn backoff(&self, attempt: u32) -> Option<Duration> {
+        match attempt {
+            0 => None,
+            _ if attempt > self.max_attempts => None,
+            1 => Some(Duration::ZERO),
+            _ => Some(
+                self.base_backoff
+                    .saturating_mul(2_u32.saturating_pow(attempt - 2))
+                    .min(self.max_backoff),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockRedisServer;
+    use crate::{Client, Frame, SubscriberEvent};
+    use bytes::Bytes;
+
+    /// Expects `loading` commands replied with `-LOADING`, then one replied with `+OK` if
+    /// `succeeds`.
+    async fn loading_server(command: &[&str], loading: usize, succeeds: bool) -> MockRedisServer {
+        let server = MockRedisServer::start().await;
+        for _ in 0..loading {
+            server.expect_command(command).respond(Frame::SimpleError(
+                "LOADING Redis is loading the dataset in memory".to_string(),
+            ));
+        }
+        if succeeds {
+            server.expect_command(command).respond(Frame::simple("OK"));
+        }
+
+        server
+    }
+
+    fn policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            base_backoff: Duration::from_millis(1),
+            jitter: Duration::from_millis(1),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_backoff() {
+        let policy = RetryPolicy {
+            max_attempts: 4,
+            base_backoff: Duration::from_millis(10),
+            jitter: Duration::ZERO,
+            retry_non_idempotent: vec!["INCR".to_string()],
+        };
+        let loading = RedisError::from_server("LOADING Redis is loading the dataset in memory");
+
+        assert_eq!(
+            policy.backoff("GET", true, &loading, 1),
+            Some(Duration::from_millis(10))
+        );
+        assert_eq!(
+            policy.backoff("GET", true, &loading, 3),
+            Some(Duration::from_millis(40))
+        );
+        // out of attempts
+        assert_eq!(policy.backoff("GET", true, &loading, 4), None);
+
+        // only the listed non-idempotent commands are retried
+        assert!(policy.backoff("incr", false, &loading, 1).is_some());
+        assert!(policy.backoff("LPUSH", false, &loading, 1).is_none());
+
+        // other errors are not transient
+        let wrongtype = RedisError::from_server("WRONGTYPE Operation against a key");
+        assert!(!wrongtype.is_transient());
+        assert!(policy.backoff("GET", true, &wrongtype, 1).is_none());
+        assert!(RedisError::from_server("BUSY Redis is busy running a script").is_transient());
+        assert!(RedisError::from_server("TRYAGAIN Multiple keys request").is_transient());
+        assert!(!RedisError::from_server("BUSYKEY Target key name already exists").is_transient());
+        assert!(!RedisError::Timeout.is_transient());
+        assert!(
+            policy
+                .backoff("GET", true, &RedisError::Timeout, 1)
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retry_transient_errors() {
+        // the third attempt succeeds
+        let server = loading_server(&["SET", "mykey", "myvalue"], 2, true).await;
+        let mut client = connect(&server).await;
+        client.set_retry_policy(Some(policy(3)));
+
+        let response = client
+            .set("mykey", b"myvalue")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to SET: {:?}", err));
+        assert_eq!(response.as_deref(), Some(&b"OK"[..]));
+    }
+
+    #[tokio::test]
+    async fn test_retry_attempt_cap() {
+        // a third attempt would be an unexpected command
+        let server = loading_server(&["SET", "mykey", "myvalue"], 2, false).await;
+        let mut client = connect(&server).await;
+        client.set_retry_policy(Some(policy(2)));
+
+        let result = client.set("mykey", b"myvalue").await;
+        assert!(matches!(result, Err(RedisError::Loading(_))));
+    }
+
+    #[tokio::test]
+    async fn test_retry_bulk_error() {
+        // RESP3 servers may send the error as a bulk error, to a command read as a raw frame
+        let server = MockRedisServer::start().await;
+        server
+            .expect_command(["ZMSCORE", "scores", "alice"])
+            .respond(Frame::BulkError(Bytes::from_static(
+                b"LOADING Redis is loading the dataset in memory",
+            )));
+        server
+            .expect_command(["ZMSCORE", "scores", "alice"])
+            .respond(Frame::Array(vec![Frame::bulk("1.5")]));
+        let mut client = connect(&server).await;
+        client.set_retry_policy(Some(policy(2)));
+
+        let scores = client
+            .zmscore("scores", vec![b"alice"])
+            .await
+            .unwrap_or_else(|err| panic!("Failed to ZMSCORE: {:?}", err));
+        assert_eq!(scores, vec![Some(1.5)]);
+    }
+
+    #[tokio::test]
+    async fn test_retry_timeout() {
+        // a second attempt would be an unexpected command
+        let server = MockRedisServer::start().await;
+        server
+            .expect_command(["SET", "mykey", "myvalue"])
+            .delay(Duration::from_millis(500))
+            .respond(Frame::simple("OK"));
+        let mut client = connect(&server).await;
+        client.set_command_timeout(Some(Duration::from_millis(50)));
+        client.set_retry_policy(Some(policy(3)));
+
+        // the command may have run, and the connection is unusable
+        let result = client.set("mykey", b"myvalue").await;
+        assert!(matches!(result, Err(RedisError::Timeout)));
+        assert!(!client.is_usable());
+    }
+
+    #[tokio::test]
+    async fn test_retry_non_idempotent() {
+        // INCR is not sent again
+        let server = loading_server(&["INCR", "counter"], 1, false).await;
+        let mut client = connect(&server).await;
+        client.set_retry_policy(Some(policy(3)));
+
+        let result = client.incr("counter").await;
+        assert!(matches!(result, Err(RedisError::Loading(_))));
+    }
+
+    #[test]
+    fn test_reconnect_backoff() {
+        let policy = ReconnectPolicy {
+            max_attempts: 5,
+            base_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_millis(25),
+        };
+
+        // the first attempt is made right away
+        assert_eq!(policy.backoff(1), Some(Duration::ZERO));
+        assert_eq!(policy.backoff(2), Some(Duration::from_millis(10)));
+        assert_eq!(policy.backoff(3), Some(Duration::from_millis(20)));
+        assert_eq!(policy.backoff(4), Some(Duration::from_millis(25)));
+        assert_eq!(policy.backoff(6), None);
+    }
+
+    #[tokio::test]
+    async fn test_subscriber_reconnect() {
+        let confirmation = Frame::Array(vec![
+            Frame::bulk("subscribe"),
+            Frame::bulk("news"),
+            Frame::Integer(1),
+        ]);
+        let server = MockRedisServer::start().await;
+        server
+            .expect_command(["SUBSCRIBE", "news"])
+            .respond(confirmation.clone())
+            .close_connection();
+        // subscribed again on the new connection
+        server
+            .expect_command(["SUBSCRIBE", "news"])
+            .respond(confirmation)
+            .respond(Frame::Array(vec![
+                Frame::bulk("message"),
+                Frame::bulk("news"),
+                Frame::bulk("resumed"),
+            ]));
+
+        let mut subscriber = connect(&server)
+            .await
+            .subscribe(vec!["news"])
+            .await
+            .unwrap_or_else(|err| panic!("Failed to SUBSCRIBE: {:?}", err));
+        subscriber.set_reconnect_policy(Some(ReconnectPolicy {
+            base_backoff: Duration::from_millis(1),
+            ..Default::default()
+        }));
+
+        let event = subscriber
+            .next_event()
+            .await
+            .unwrap_or_else(|err| panic!("Failed to reconnect: {:?}", err));
+        assert_eq!(event, Some(SubscriberEvent::Reconnected));
+        assert_eq!(subscriber.get_subscribed_channels(), ["news"]);
+
+        let message = subscriber
+            .next_message()
+            .await
+            .unwrap_or_else(|err| panic!("Failed to read message: {:?}", err))
+            .map(|message| message.payload);
+        assert_eq!(message, Some(Bytes::from_static(b"resumed")));
+    }
+
+    #[tokio::test]
+    async fn test_subscriber_without_reconnect() {
+        let server = MockRedisServer::start().await;
+        server
+            .expect_command(["SUBSCRIBE", "news"])
+            .respond(Frame::Array(vec![
+                Frame::bulk("subscribe"),
+                Frame::bulk("news"),
+                Frame::Integer(1),
+            ]))
+            .close_connection();
+
+        let mut subscriber = connect(&server)
+            .await
+            .subscribe(vec!["news"])
+            .await
+            .unwrap_or_else(|err| panic!("Failed to SUBSCRIBE: {:?}", err));
+
+        let event = subscriber
+            .next_event()
+            .await
+            .unwrap_or_else(|err| panic!("Failed to read event: {:?}", err));
+        assert_eq!(event, None);
+    }
+
+    async fn connect(server: &MockRedisServer) -> Client {
+        Client::connect(server.addr())
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err))
+    }
+}
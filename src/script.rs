@@ -0,0 +1,124 @@
+//! Lua scripts run with EVALSHA, falling back to EVAL.
+//!
+//! The server caches every script it ran by the SHA1 digest of its source, so a script is sent
+//! once, then referred to by its digest. The cache is emptied by SCRIPT FLUSH or a restart, in
+//! which case EVALSHA replies `NOSCRIPT` and the source is sent again.
+
+/// A Lua script, along with the SHA1 digest the server caches it by.
+///
+/// Create it once, e.g. in a `static`, and run it with `Client::invoke_script`.
+///
+/// # Examples
+///
+/// ```ignore
+/// use redis_asyncx::{Client, Script};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut client = Client::connect("127.0.0.1:6379").await.unwrap();
+///     let script = Script::new("return redis.call('GET', KEYS[1])");
+///     let value = client
+///         .invoke_script(&script, vec!["mykey"], Vec::<&str>::new())
+///         .await
+///         .unwrap();
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Script {
+    code: String,
+    sha: String,
+}
+
+impl Script {
+    /// Creates a script from its Lua source.
+    pub fn new(code: &str) -> Self {
+        Self {
+            code: code.to_string(),
+            sha: sha1_hex(code.as_bytes()),
+        }
+    }
+
+    /// Returns the Lua source of the script.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Returns the SHA1 digest of the source in lowercase hex, as SCRIPT LOAD replies it.
+    pub fn sha(&self) -> &str {
+        &self.sha
+    }
+}
+
+/// Returns the SHA1 digest of the data in lowercase hex, see RFC 3174.
+fn sha1_hex(data: &[u8]) -> String {
+    let mut state: [u32; 5] = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
+
+    // the data, a 1 bit, zeros up to 8 bytes short of a whole block, then the length in bits
+    let mut message = data.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&((data.len() as u64).wrapping_mul(8)).to_be_bytes());
+
+    for block in message.chunks_exact(64) {
+        let mut w = [0u32; 80];
+        for (i, word) in block.chunks_exact(4).enumerate() {
+            w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for i in 16..80 {
+            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e] = state;
+        for (i, word) in w.iter().enumerate() {
+            let (f, k) = match i {
+                0..=19 => ((b & c) | (!b & d), 0x5A827999),
+                20..=39 => (b ^ c ^ d, 0x6ED9EBA1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8F1BBCDC),
+                _ => (b ^ c ^ d, 0xCA62C1D6),
+            };
+            let temp = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(*word);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+        }
+
+        for (h, v) in state.iter_mut().zip([a, b, c, d, e]) {
+            *h = h.wrapping_add(v);
+        }
+    }
+
+    state.iter().map(|h| format!("{h:08x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha1_hex() {
+        assert_eq!(sha1_hex(b""), "da39a3ee5e6b4b0d3255bfef95601890afd80709");
+        assert_eq!(sha1_hex(b"abc"), "a9993e364706816aba3e25717850c26c9cd0d89d");
+        // two blocks once padded
+        assert_eq!(
+            sha1_hex(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+            "84983e441c3bd26ebaae4aa1f95129e5e54670f1"
+        );
+    }
+
+    #[test]
+    fn test_script() {
+        // as replied by SCRIPT LOAD
+        let script = Script::new("return 1");
+        assert_eq!(script.code(), "return 1");
+        assert_eq!(script.sha(), "e0e1f9fabfc9d4800c877a703b823ac0578ff8db");
+    }
+}
@@ -0,0 +1,380 @@
+//! A client following the master of a Redis Sentinel deployment.
+//!
+//! The sentinels monitor a master and its replicas, and promote a replica once the master fails.
+//! The client asks them for the address of the current master, and asks again whenever the master
+//! it is connected to stops being usable.
+
+use crate::Client;
+use crate::RedisError;
+use crate::Result;
+use crate::ToRedisArg;
+use anyhow::anyhow;
+use bytes::Bytes;
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+
+/// A Redis client connected to the master a set of sentinels agree on.
+///
+/// The master is re-discovered when a command fails because the connection is lost, or because
+/// the server was demoted to a replica and replies with `READONLY`. The command is then sent once
+/// more, to the new master.
+///
+/// # Examples
+///
+/// ```ignore
+/// use redis_asyncx::SentinelClient;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut client = SentinelClient::connect(
+///         &["10.0.0.1:26379", "10.0.0.2:26379", "10.0.0.3:26379"],
+///         "mymaster",
+///     )
+///     .await
+///     .unwrap();
+///
+///     client.set("mykey", b"myvalue").await.unwrap();
+/// }
+/// ```
+pub struct SentinelClient {
+    sentinels: Vec<String>,
+    master_name: String,
+    client: Client,
+}
+
+impl SentinelClient {
+    /// Discovers the master through the sentinels and connects to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `sentinels` - The addresses of the sentinels, as `host:port`, asked in a random order
+    /// * `master_name` - The name the sentinels monitor the master under
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SentinelClient)` once connected to a server whose ROLE is master
+    /// * `Err(RedisError)` if no sentinel is given, or none of them leads to a master
+    pub async fn connect(sentinels: &[impl AsRef<str>], master_name: &str) -> Result<Self> {
+        if sentinels.is_empty() {
+            return Err(RedisError::InvalidArgument(
+                "at least one sentinel is required".to_string(),
+            ));
+        }
+
+        let sentinels: Vec<String> = sentinels
+            .iter()
+            .map(|sentinel| sentinel.as_ref().to_string())
+            .collect();
+        let client = discover(&sentinels, master_name).await?;
+
+        Ok(Self {
+            sentinels,
+            master_name: master_name.to_string(),
+            client,
+        })
+    }
+
+    /// Returns the client connected to the current master, to send any other command.
+    ///
+    /// Commands sent through it are not retried after a failover, call
+    /// `SentinelClient::reconnect` once they fail.
+    pub fn client(&mut self) -> &mut Client {
+        &mut self.client
+    }
+
+    /// Discovers the master again and replaces the connection with one to the new master.
+    pub async fn reconnect(&mut self) -> Result<()> {
+        self.client = discover(&self.sentinels, &self.master_name).await?;
+
+        Ok(())
+    }
+
+    /// Sends a GET command to the master, see `Client::get`.
+    pub async fn get(&mut self, key: impl ToRedisArg) -> Result<Option<Bytes>> {
+        let key = key.to_redis_arg();
+        match self.client.get(&key).await {
+            Err(err) if self.lost_master(&err).await => {
+                self.reconnect().await?;
+                self.client.get(key).await
+            }
+            result => result,
+        }
+    }
+
+    /// Sends a SET command to the master, see `Client::set`.
+    pub async fn set(&mut self, key: impl ToRedisArg, val: &[u8]) -> Result<Option<Bytes>> {
+        let key = key.to_redis_arg();
+        match self.client.set(&key, val).await {
+            Err(err) if self.lost_master(&err).await => {
+                self.reconnect().await?;
+                self.client.set(key, val).await
+            }
+            result => result,
+        }
+    }
+
+    /// Sends a DEL command to the master, see `Client::del`.
+    pub async fn del(&mut self, keys: Vec<impl ToRedisArg>) -> Result<u64> {
+        let keys: Vec<Bytes> = keys.iter().map(ToRedisArg::to_redis_arg).collect();
+        match self.client.del(keys.clone()).await {
+            Err(err) if self.lost_master(&err).await => {
+                self.reconnect().await?;
+                self.client.del(keys).await
+            }
+            result => result,
+        }
+    }
+
+    /// Tells whether a failed command calls for a new master, rather than being a regular error.
+    async fn lost_master(&mut self, err: &RedisError) -> bool {
+        // sent by a former master demoted to a replica by a failover
+        matches!(err, RedisError::ReadOnly(_)) || !self.client.is_alive().await
+    }
+}
+
+/// Asks the sentinels in a random order for the master, until one leads to a server whose ROLE
+/// is master.
+///
+/// The order is shuffled so that clients do not all ask the same sentinel first.
+async fn discover(sentinels: &[String], master_name: &str) -> Result<Client> {
+    let state = RandomState::new();
+    let mut sentinels = sentinels.to_vec();
+    sentinels.sort_by_cached_key(|sentinel| state.hash_one(sentinel));
+
+    let mut last_err = None;
+    for sentinel in sentinels {
+        match connect_master(&sentinel, master_name).await {
+            Ok(client) => return Ok(client),
+            Err(err) => last_err = Some(err),
+        }
+    }
+
+    Err(RedisError::Other(anyhow!(
+        "failed to discover master {master_name} from any sentinel: {}",
+        last_err.map_or_else(|| "no sentinel to ask".to_string(), |err| err.to_string())
+    )))
+}
+
+async fn connect_master(sentinel: &str, master_name: &str) -> Result<Client> {
+    let addr = Client::connect(sentinel)
+        .await?
+        .sentinel_master_addr(master_name)
+        .await?
+        .ok_or_else(|| {
+            RedisError::Other(anyhow!(
+                "sentinel {sentinel} does not know master {master_name}"
+            ))
+        })?;
+
+    // the sentinel may not have noticed a failover yet
+    let mut client = Client::connect(addr.as_str()).await?;
+    match client.role().await?.as_str() {
+        "master" => Ok(client),
+        role => Err(RedisError::Other(anyhow!(
+            "{addr} given by sentinel {sentinel} is a {role}, not a master"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Frame;
+    use std::io::Cursor;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    async fn bind() -> (TcpListener, String) {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to bind listener: {:?}", err));
+        let addr = listener
+            .local_addr()
+            .unwrap_or_else(|err| panic!("Failed to get listener address: {:?}", err));
+        (listener, addr.to_string())
+    }
+
+    /// Serves up to `connections` connections, replying to each command with `reply`.
+    ///
+    /// The connection is closed instead when `reply` returns None.
+    fn serve<F>(listener: TcpListener, connections: usize, reply: F)
+    where
+        F: Fn(&[Frame]) -> Option<Vec<u8>> + Send + Sync + 'static,
+    {
+        let reply = Arc::new(reply);
+        tokio::spawn(async move {
+            for _ in 0..connections {
+                let Ok((mut socket, _)) = listener.accept().await else {
+                    return;
+                };
+                let reply = reply.clone();
+                tokio::spawn(async move {
+                    let mut received = Vec::new();
+                    let mut buf = vec![0; 1024];
+                    loop {
+                        match socket.read(&mut buf).await {
+                            Ok(0) | Err(_) => return,
+                            Ok(n) => received.extend_from_slice(&buf[..n]),
+                        }
+
+                        let mut cursor = Cursor::new(&received[..]);
+                        let mut replies = Vec::new();
+                        while let Ok(Frame::Array(args)) = Frame::try_parse(&mut cursor) {
+                            match reply(&args) {
+                                Some(reply) => replies.extend(reply),
+                                None => return,
+                            }
+                        }
+                        let consumed = cursor.position() as usize;
+                        received.drain(..consumed);
+                        let _ = socket.write_all(&replies).await;
+                    }
+                });
+            }
+        });
+    }
+
+    fn addr_reply(addr: &str) -> Option<Vec<u8>> {
+        let (host, port) = addr.rsplit_once(':').unwrap_or((addr, ""));
+        let reply = format!(
+            "*2\r\n${}\r\n{host}\r\n${}\r\n{port}\r\n",
+            host.len(),
+            port.len()
+        );
+        Some(reply.into_bytes())
+    }
+
+    // replies to ROLE with the given role, and to the first `commands` other commands with the
+    // value, then closes the connection
+    fn node(
+        role: &'static str,
+        value: &'static str,
+        commands: usize,
+    ) -> impl Fn(&[Frame]) -> Option<Vec<u8>> {
+        let replied = AtomicUsize::new(0);
+        move |args| {
+            if args.first() == Some(&Frame::bulk("ROLE")) {
+                let reply = format!("*3\r\n${}\r\n{role}\r\n:0\r\n*0\r\n", role.len());
+                Some(reply.into_bytes())
+            } else if replied.fetch_add(1, Ordering::SeqCst) < commands {
+                Some(format!("${}\r\n{value}\r\n", value.len()).into_bytes())
+            } else {
+                None
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sentinel_client_failover() {
+        let (first, first_addr) = bind().await;
+        let (second, second_addr) = bind().await;
+        let (sentinel, sentinel_addr) = bind().await;
+
+        // the first master goes away after a single command
+        serve(first, 1, node("master", "first", 1));
+        serve(second, usize::MAX, node("master", "second", usize::MAX));
+
+        // points to the first master, then to the second one once asked again
+        let queries = Arc::new(AtomicUsize::new(0));
+        let counter = queries.clone();
+        serve(sentinel, usize::MAX, move |_| {
+            if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+                addr_reply(&first_addr)
+            } else {
+                addr_reply(&second_addr)
+            }
+        });
+
+        let mut client = SentinelClient::connect(&[sentinel_addr], "mymaster")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
+        let value = client
+            .get("key")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to GET: {:?}", err));
+        assert_eq!(value, Some(Bytes::from_static(b"first")));
+
+        // the first master is gone, the command is sent again to the second one
+
+        let value = client
+            .get("key")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to GET: {:?}", err));
+        assert_eq!(value, Some(Bytes::from_static(b"second")));
+        assert_eq!(queries.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_sentinel_client_readonly() {
+        let (demoted, demoted_addr) = bind().await;
+        let (promoted, promoted_addr) = bind().await;
+        let (sentinel, sentinel_addr) = bind().await;
+
+        // the former master is still up, but was demoted to a replica by a failover
+        serve(demoted, usize::MAX, |args| {
+            if args.first() == Some(&Frame::bulk("ROLE")) {
+                Some(b"*3\r\n$6\r\nmaster\r\n:0\r\n*0\r\n".to_vec())
+            } else if args.first() == Some(&Frame::bulk("PING")) {
+                Some(b"+PONG\r\n".to_vec())
+            } else {
+                Some(b"-READONLY You can't write against a read only replica.\r\n".to_vec())
+            }
+        });
+        serve(promoted, usize::MAX, node("master", "OK", usize::MAX));
+
+        let queries = Arc::new(AtomicUsize::new(0));
+        let counter = queries.clone();
+        serve(sentinel, usize::MAX, move |_| {
+            if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+                addr_reply(&demoted_addr)
+            } else {
+                addr_reply(&promoted_addr)
+            }
+        });
+
+        let mut client = SentinelClient::connect(&[sentinel_addr], "mymaster")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
+
+        // the connection is alive, the READONLY error alone calls for the new master
+        let reply = client
+            .set("key", b"value")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to SET: {:?}", err));
+        assert_eq!(reply, Some(Bytes::from_static(b"OK")));
+        assert_eq!(queries.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_sentinel_client_skips_replicas() {
+        let (replica, replica_addr) = bind().await;
+        let (master, master_addr) = bind().await;
+        let (stale, stale_addr) = bind().await;
+        let (sentinel, sentinel_addr) = bind().await;
+
+        serve(replica, usize::MAX, node("slave", "replica", usize::MAX));
+        serve(master, usize::MAX, node("master", "master", usize::MAX));
+        // one sentinel has not noticed the failover yet
+        serve(stale, usize::MAX, move |_| addr_reply(&replica_addr));
+        serve(sentinel, usize::MAX, move |_| addr_reply(&master_addr));
+
+        // whichever sentinel is asked first, the client ends up on the master
+        let mut client = SentinelClient::connect(&[&stale_addr, &sentinel_addr], "mymaster")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
+        let value = client
+            .get("key")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to GET: {:?}", err));
+        assert_eq!(value, Some(Bytes::from_static(b"master")));
+
+        // no sentinel leads to a master
+        assert!(
+            SentinelClient::connect(&[stale_addr], "mymaster")
+                .await
+                .is_err()
+        );
+    }
+}
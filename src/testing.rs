@@ -0,0 +1,415 @@
+//! An in-process fake Redis server replying with scripted frames, enabled with the `testing`
+//! feature.
+//!
+//! Unlike a real server, `MockRedisServer` can reply with malformed frames, hold a reply back or
+//! close the connection halfway through, to test how a client copes with them. No Docker needed.
+
+use crate::{Frame, RedisError};
+use bytes::{Buf, Bytes, BytesMut};
+use std::collections::VecDeque;
+use std::io::Cursor;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::{JoinHandle, JoinSet};
+
+/// What the server does once it received the expected command, in the order they were scripted.
+enum Action {
+    Reply(Frame),
+    Raw(Bytes),
+    Delay(Duration),
+    Close,
+}
+
+struct Expected {
+    command: Vec<Bytes>,
+    actions: Vec<Action>,
+}
+
+#[derive(Default)]
+struct Script {
+    // the commands still to come, in the order they are expected
+    expected: VecDeque<Expected>,
+    // what went wrong on the server side, reported once the server is dropped
+    failures: Vec<String>,
+}
+
+impl Script {
+    fn fail(&mut self, failure: String) {
+        self.failures.push(failure);
+    }
+}
+
+/// A fake Redis server on an ephemeral local port, expecting a script of commands.
+///
+/// Commands are expected in order, across all the connections. A command that does not match the
+/// next expectation closes its connection. Dropping the server panics if a command was unexpected
+/// or if an expectation was never fulfilled.
+///
+/// # Examples
+///
+/// ```ignore
+/// use redis_asyncx::{Client, Frame, MockRedisServer};
+///
+/// #[tokio::test]
+/// async fn test_get() {
+///     let server = MockRedisServer::start().await;
+///     server.expect_command(["GET", "mykey"]).respond(Frame::bulk("myvalue"));
+///
+///     let mut client = Client::connect(server.addr()).await.unwrap();
+///     assert_eq!(client.get("mykey").await.unwrap(), Some("myvalue".into()));
+/// }
+/// ```
+pub struct MockRedisServer {
+    addr: String,
+    script: Arc<Mutex<Script>>,
+    task: JoinHandle<()>,
+}
+
+impl MockRedisServer {
+    /// Binds a local port and starts accepting connections.
+    ///
+    /// # Panics
+    ///
+    /// If no local port can be bound.
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to bind mock server: {:?}", err));
+        let addr = listener
+            .local_addr()
+            .unwrap_or_else(|err| panic!("Failed to get mock server address: {:?}", err))
+            .to_string();
+
+        let script = Arc::new(Mutex::new(Script::default()));
+        let task = tokio::spawn(accept(listener, script.clone()));
+
+        Self { addr, script, task }
+    }
+
+    /// Returns the address to connect to, as `host:port`.
+    pub fn addr(&self) -> &str {
+        &self.addr
+    }
+
+    /// Expects a command, given as its name followed by its arguments.
+    ///
+    /// The expectation is added once the returned `Expectation` is dropped, usually at the end of
+    /// the statement scripting what the server does about the command.
+    pub fn expect_command<I, S>(&self, command: I) -> Expectation
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<[u8]>,
+    {
+        Expectation {
+            script: self.script.clone(),
+            expected: Some(Expected {
+                command: command
+                    .into_iter()
+                    .map(|arg| Bytes::copy_from_slice(arg.as_ref()))
+                    .collect(),
+                actions: Vec::new(),
+            }),
+        }
+    }
+}
+
+impl Drop for MockRedisServer {
+    fn drop(&mut self) {
+        // the connections are aborted along with the task accepting them
+        self.task.abort();
+        if std::thread::panicking() {
+            return;
+        }
+
+        let script = lock(&self.script);
+        assert!(
+            script.failures.is_empty(),
+            "mock server failures: {:?}",
+            script.failures
+        );
+        let unfulfilled: Vec<String> = script
+            .expected
+            .iter()
+            .map(|expected| describe(&expected.command))
+            .collect();
+        assert!(
+            unfulfilled.is_empty(),
+            "unfulfilled expectations: {:?}",
+            unfulfilled
+        );
+    }
+}
+
+/// What the server does about an expected command, created by `MockRedisServer::expect_command`.
+///
+/// The actions run in the order they were added once the command is received. Without any, the
+/// command is read and left unanswered.
+pub struct Expectation {
+    script: Arc<Mutex<Script>>,
+    expected: Option<Expected>,
+}
+
+impl Expectation {
+    /// Replies with a frame. Nulls are sent as RESP2 null bulk strings, understood by either
+    /// protocol version.
+    pub fn respond(self, frame: Frame) -> Self {
+        self.then(Action::Reply(frame))
+    }
+
+    /// Replies with raw bytes, e.g. a malformed or truncated frame.
+    pub fn respond_raw(self, bytes: impl Into<Bytes>) -> Self {
+        self.then(Action::Raw(bytes.into()))
+    }
+
+    /// Waits before running the following actions, e.g. to hold a reply back.
+    pub fn delay(self, delay: Duration) -> Self {
+        self.then(Action::Delay(delay))
+    }
+
+    /// Closes the connection, after the replies scripted so far.
+    pub fn close_connection(self) -> Self {
+        self.then(Action::Close)
+    }
+
+    fn then(mut self, action: Action) -> Self {
+        if let Some(expected) = &mut self.expected {
+            expected.actions.push(action);
+        }
+        self
+    }
+}
+
+impl Drop for Expectation {
+    fn drop(&mut self) {
+        if let Some(expected) = self.expected.take() {
+            lock(&self.script).expected.push_back(expected);
+        }
+    }
+}
+
+async fn accept(listener: TcpListener, script: Arc<Mutex<Script>>) {
+    // dropped along with the connections once the task is aborted
+    let mut connections = JoinSet::new();
+
+    while let Ok((socket, _)) = listener.accept().await {
+        while connections.try_join_next().is_some() {}
+        connections.spawn(serve(socket, script.clone()));
+    }
+}
+
+/// Reads the commands of a connection and runs the actions scripted for them.
+async fn serve(mut socket: TcpStream, script: Arc<Mutex<Script>>) {
+    let mut buf = BytesMut::with_capacity(4096);
+
+    loop {
+        loop {
+            let mut cursor = Cursor::new(&buf[..]);
+            let frame = match Frame::try_parse(&mut cursor) {
+                Ok(frame) => frame,
+                Err(RedisError::IncompleteFrame) => break,
+                Err(err) => {
+                    lock(&script).fail(format!("invalid command frame: {err}"));
+                    return;
+                }
+            };
+            let consumed = cursor.position() as usize;
+            buf.advance(consumed);
+
+            let Some(command) = into_command(frame) else {
+                lock(&script).fail("a command is not an array of strings".to_string());
+                return;
+            };
+
+            let expected = {
+                let mut script = lock(&script);
+                match script.expected.pop_front() {
+                    Some(expected) if expected.command == command => expected,
+                    Some(expected) => {
+                        script.fail(format!(
+                            "unexpected command {}, expected {}",
+                            describe(&command),
+                            describe(&expected.command)
+                        ));
+                        return;
+                    }
+                    None => {
+                        script.fail(format!("unexpected command {}", describe(&command)));
+                        return;
+                    }
+                }
+            };
+
+            for action in expected.actions {
+                let result = match action {
+                    Action::Reply(frame) => match frame.serialize_resp2().await {
+                        Ok(bytes) => socket.write_all(&bytes).await,
+                        Err(err) => {
+                            lock(&script).fail(format!("failed to serialize reply: {err}"));
+                            return;
+                        }
+                    },
+                    Action::Raw(bytes) => socket.write_all(&bytes).await,
+                    Action::Delay(delay) => {
+                        tokio::time::sleep(delay).await;
+                        Ok(())
+                    }
+                    Action::Close => return,
+                };
+                // the client went away
+                if result.is_err() {
+                    return;
+                }
+            }
+        }
+
+        match socket.read_buf(&mut buf).await {
+            Ok(0) | Err(_) => return,
+            Ok(_) => {}
+        }
+    }
+}
+
+fn lock(script: &Mutex<Script>) -> std::sync::MutexGuard<'_, Script> {
+    script.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn into_command(frame: Frame) -> Option<Vec<Bytes>> {
+    let Frame::Array(args) = frame else {
+        return None;
+    };
+
+    args.into_iter()
+        .map(|arg| match arg {
+            Frame::BulkString(arg) => Some(arg),
+            Frame::SimpleString(arg) => Some(Bytes::from(arg)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn describe(command: &[Bytes]) -> String {
+    command
+        .iter()
+        .map(|arg| String::from_utf8_lossy(arg))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Client;
+
+    async fn connect(server: &MockRedisServer) -> Client {
+        Client::connect(server.addr())
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err))
+    }
+
+    #[tokio::test]
+    async fn test_mock_server() {
+        let server = MockRedisServer::start().await;
+        server
+            .expect_command(["SET", "mykey", "myvalue"])
+            .respond(Frame::simple("OK"));
+        server
+            .expect_command(["GET", "mykey"])
+            .respond(Frame::bulk("myvalue"));
+        server
+            .expect_command(["GET", "missing"])
+            .respond(Frame::Null);
+
+        let mut client = connect(&server).await;
+        let response = client
+            .set("mykey", b"myvalue")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to SET: {:?}", err));
+        assert_eq!(response.as_deref(), Some(&b"OK"[..]));
+
+        let value = client
+            .get("mykey")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to GET: {:?}", err));
+        assert_eq!(value, Some(Bytes::from_static(b"myvalue")));
+
+        let value = client
+            .get("missing")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to GET: {:?}", err));
+        assert_eq!(value, None);
+    }
+
+    #[tokio::test]
+    async fn test_malformed_reply() {
+        let server = MockRedisServer::start().await;
+        // no type starts with ?
+        server
+            .expect_command(["GET", "mykey"])
+            .respond_raw(&b"?garbage\r\n"[..]);
+
+        let mut client = connect(&server).await;
+        assert!(client.get("mykey").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_truncated_reply() {
+        let server = MockRedisServer::start().await;
+        // the bulk string announces more bytes than sent before the connection is closed
+        server
+            .expect_command(["GET", "mykey"])
+            .respond_raw(&b"$10\r\nabc"[..])
+            .close_connection();
+
+        let mut client = connect(&server).await;
+        assert!(client.get("mykey").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_closed_connection() {
+        let server = MockRedisServer::start().await;
+        server.expect_command(["GET", "mykey"]).close_connection();
+
+        let mut client = connect(&server).await;
+        assert!(client.get("mykey").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_slow_reply() {
+        let server = MockRedisServer::start().await;
+        server
+            .expect_command(["GET", "mykey"])
+            .delay(Duration::from_millis(500))
+            .respond(Frame::bulk("late"));
+
+        let mut client = connect(&server).await;
+        client.set_command_timeout(Some(Duration::from_millis(50)));
+
+        let result = client.get("mykey").await;
+        assert!(matches!(result, Err(RedisError::Timeout)));
+        // the reply may still arrive, so the connection is not used again
+        assert!(!client.is_usable());
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "unfulfilled expectations")]
+    async fn test_unfulfilled_expectation() {
+        let server = MockRedisServer::start().await;
+        server
+            .expect_command(["PING"])
+            .respond(Frame::simple("PONG"));
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "unexpected command GET other")]
+    async fn test_unexpected_command() {
+        let server = MockRedisServer::start().await;
+        server
+            .expect_command(["GET", "mykey"])
+            .respond(Frame::bulk("myvalue"));
+
+        let mut client = connect(&server).await;
+        assert!(client.get("other").await.is_err());
+    }
+}
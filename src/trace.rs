@@ -0,0 +1,224 @@
+//! Spans around the commands, enabled with the `tracing` feature.
+//!
+//! Every command runs in a `redis.command` span recording its name, its number of arguments and,
+//! once the reply is read, its latency in microseconds. A failed command also emits an event at
+//! the ERROR level, and a command the server replied to with an error one at the WARN level.
+
+use crate::Frame;
+use crate::metrics::Outcome;
+use std::fmt::Display;
+use std::time::Duration;
+use tracing::{Span, field};
+
+/// Creates the span of a command, entered for the whole round trip of the command.
+///
+/// The arguments do not include the words of the command name, e.g. `CLIENT SETNAME name` has a
+/// single argument.
+pub(crate) fn command_span(name: &str, frame: &Frame) -> Span {
+    let args = match frame {
+        Frame::Array(args) => args.len().saturating_sub(name.split(' ').count()),
+        _ => 0,
+    };
+
+    tracing::info_span!(
+        "redis.command",
+        command = name,
+        args,
+        batch = field::Empty,
+        latency_us = field::Empty,
+    )
+}
+
+/// Creates the span of a batch of commands sent in a single round trip.
+pub(crate) fn batch_span<S: AsRef<str>>(cmds: &[(S, Frame)]) -> Span {
+    let span = match cmds.first() {
+        Some((name, frame)) => command_span(name.as_ref(), frame),
+        None => command_span("", &Frame::Null),
+    };
+    span.record("batch", cmds.len());
+    span
+}
+
+/// Records the latency of a command in its span, and emits an event if it did not succeed.
+pub(crate) fn record_outcome(
+    span: &Span,
+    elapsed: Duration,
+    outcome: Outcome,
+    error: Option<&dyn Display>,
+) {
+    span.record("latency_us", elapsed.as_micros() as u64);
+
+    let error = error.map(ToString::to_string).unwrap_or_default();
+    match outcome {
+        Outcome::Success => {}
+        Outcome::ServerError => {
+            tracing::warn!(parent: span, %error, "server replied with an error");
+        }
+        Outcome::Failed => {
+            tracing::error!(parent: span, %error, "command failed");
+        }
+    }
+}
+
+/// Emits an event before a command that failed with a transient error is sent again.
+pub(crate) fn record_retry(name: &str, attempt: u32, backoff: Duration, error: &dyn Display) {
+    tracing::warn!(
+        command = name,
+        attempt,
+        backoff_ms = backoff.as_millis() as u64,
+        %error,
+        "retrying after a transient error"
+    );
+}
+
+/// Emits an event once a retried command is done, with the attempt it succeeded or gave up on.
+pub(crate) fn record_attempts(name: &str, attempts: u32, succeeded: bool) {
+    if attempts > 1 {
+        tracing::info!(command = name, attempts, succeeded, "command retried");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Client;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber, field::Visit};
+
+    #[derive(Debug, Default)]
+    struct Captured {
+        // the name and the fields of every span, by id
+        spans: HashMap<u64, (String, HashMap<String, String>)>,
+        // the message and the parent span of every event
+        events: Vec<(String, Option<u64>)>,
+    }
+
+    struct Fields<'a>(&'a mut HashMap<String, String>);
+
+    impl Visit for Fields<'_> {
+        fn record_debug(&mut self, field: &field::Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .insert(field.name().to_string(), format!("{value:?}"));
+        }
+
+        fn record_str(&mut self, field: &field::Field, value: &str) {
+            self.0.insert(field.name().to_string(), value.to_string());
+        }
+    }
+
+    /// Keeps every span and event in memory, instead of depending on a subscriber crate.
+    #[derive(Clone, Default)]
+    struct CapturingSubscriber {
+        captured: Arc<Mutex<Captured>>,
+        next_id: Arc<AtomicU64>,
+    }
+
+    impl CapturingSubscriber {
+        fn captured(&self) -> std::sync::MutexGuard<'_, Captured> {
+            self.captured
+                .lock()
+                .unwrap_or_else(|err| panic!("Failed to lock captured spans: {:?}", err))
+        }
+    }
+
+    impl Subscriber for CapturingSubscriber {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let id = self.next_id.fetch_add(1, Ordering::SeqCst) + 1;
+            let mut fields = HashMap::new();
+            span.record(&mut Fields(&mut fields));
+            self.captured()
+                .spans
+                .insert(id, (span.metadata().name().to_string(), fields));
+            Id::from_u64(id)
+        }
+
+        fn record(&self, span: &Id, values: &Record<'_>) {
+            if let Some((_, fields)) = self.captured().spans.get_mut(&span.into_u64()) {
+                values.record(&mut Fields(fields));
+            }
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut fields = HashMap::new();
+            event.record(&mut Fields(&mut fields));
+            let message = fields.remove("message").unwrap_or_default();
+            let parent = event.parent().map(Id::into_u64);
+            self.captured().events.push((message, parent));
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    /// Starts a fake server replying to every command with the next of the given replies.
+    async fn serve(replies: &'static [&'static [u8]]) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to bind listener: {:?}", err));
+        let addr = listener
+            .local_addr()
+            .unwrap_or_else(|err| panic!("Failed to get listener address: {:?}", err));
+
+        tokio::spawn(async move {
+            if let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = vec![0; 1024];
+                for reply in replies {
+                    if !matches!(socket.read(&mut buf).await, Ok(n) if n > 0) {
+                        return;
+                    }
+                    let _ = socket.write_all(reply).await;
+                }
+            }
+        });
+
+        addr.to_string()
+    }
+
+    #[tokio::test]
+    async fn test_command_span() {
+        let addr = serve(&[b"$5\r\nvalue\r\n", b"-ERR wrong number of arguments\r\n"]).await;
+        let mut client = Client::connect(addr)
+            .await
+            .unwrap_or_else(|err| panic!("Failed to connect: {:?}", err));
+
+        let subscriber = CapturingSubscriber::default();
+        let _guard = tracing::subscriber::set_default(subscriber.clone());
+
+        client
+            .get("mykey")
+            .await
+            .unwrap_or_else(|err| panic!("Failed to GET: {:?}", err));
+        assert!(client.incr("mykey").await.is_err());
+
+        let captured = subscriber.captured();
+        let mut spans: Vec<_> = captured.spans.iter().collect();
+        spans.sort_by_key(|(id, _)| **id);
+
+        let (get_id, (name, fields)) = spans[0];
+        assert_eq!(name, "redis.command");
+        assert_eq!(fields.get("command").map(String::as_str), Some("GET"));
+        assert_eq!(fields.get("args").map(String::as_str), Some("1"));
+        assert!(fields.contains_key("latency_us"));
+
+        // only the command the server rejected has an event
+        let (incr_id, (_, fields)) = spans[1];
+        assert_eq!(fields.get("command").map(String::as_str), Some("INCR"));
+        assert_eq!(
+            captured.events,
+            vec![("server replied with an error".to_string(), Some(*incr_id))]
+        );
+        assert_ne!(get_id, incr_id);
+    }
+}
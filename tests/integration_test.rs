@@ -1,9 +1,21 @@
 use assert_cmd::prelude::*; // Add methods on commands
+use bytes::Bytes;
 use predicates::prelude::*; // Used for writing assertions
-use redis_asyncx::Client;
+use redis_asyncx::{
+    Aggregate, CacheConfig, CachingClient, Client, ClientKillFilter, ClientListFilter, ClientType,
+    ClusterClient, ConnectionOptions, CountingObserver, Expiry, Frame, KeyHeat, LcsMatch,
+    LcsOptions, LcsResult, LexBound, Message, MigrateAuth, MigrateOptions, MultiplexedClient,
+    Pipeline, Pong, Pool, PoolConfig, ProtocolVersion, RateLimitAlgorithm, ReconnectPolicy,
+    RedisError, Response, RestoreOptions, ScoreBound, Script, SentinelClient, SetCondition,
+    SetOptions, SetOutcome, StructuredResponse, SubscriberEvent, ZCombineOptions, ZRangeBy,
+    ZRangeOptions, key_slot,
+};
+use std::collections::HashMap;
 use std::process::Command; // Run programs
+use std::sync::Arc;
+use std::time::Duration;
 use testcontainers::{
-    GenericImage,
+    GenericImage, ImageExt,
     core::{IntoContainerPort, WaitFor},
     runners::AsyncRunner,
 };
@@ -23,6 +35,8 @@ async fn setup_redis() -> &'static testcontainers::ContainerAsync<GenericImage>
             GenericImage::new("redis", "7.2.4")
                 .with_exposed_port(REDIS_PORT.tcp())
                 .with_wait_for(WaitFor::message_on_stdout("Ready to accept connections"))
+                // DEBUG SLEEP is used to force slow replies
+                .with_cmd(["redis-server", "--enable-debug-command", "yes"])
                 .start()
                 .await
                 .unwrap_or_else(|err| {
@@ -38,6 +52,116 @@ async fn setup_redis() -> &'static testcontainers::ContainerAsync<GenericImage>
     container
 }
 
+static SECURED_REDIS_CONTAINER: OnceCell<testcontainers::ContainerAsync<GenericImage>> =
+    OnceCell::const_new();
+
+const REDIS_PASSWORD: &str = "secret";
+
+// a separate server that requires a password, so the shared one stays open
+async fn setup_secured_redis() -> &'static testcontainers::ContainerAsync<GenericImage> {
+    let container = SECURED_REDIS_CONTAINER
+        .get_or_init(|| async {
+            GenericImage::new("redis", "7.2.4")
+                .with_exposed_port(REDIS_PORT.tcp())
+                .with_wait_for(WaitFor::message_on_stdout("Ready to accept connections"))
+                .with_cmd(["redis-server", "--requirepass", REDIS_PASSWORD])
+                .start()
+                .await
+                .unwrap_or_else(|err| {
+                    panic!("Failed to start secured Redis container: {:?}", err);
+                })
+        })
+        .await
+        .to_owned();
+
+    // wait until the Redis server is ready
+    tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+
+    container
+}
+
+static CLUSTER_CONTAINER: OnceCell<testcontainers::ContainerAsync<GenericImage>> =
+    OnceCell::const_new();
+
+// the nodes announce 127.0.0.1 and their own port, so they are mapped to the same host ports
+const CLUSTER_PORTS: [u16; 3] = [7000, 7001, 7002];
+
+// a 3-master cluster in a single container, without replicas
+async fn setup_cluster() -> &'static testcontainers::ContainerAsync<GenericImage> {
+    CLUSTER_CONTAINER
+        .get_or_init(|| async {
+            let nodes: Vec<String> = CLUSTER_PORTS
+                .iter()
+                .map(|port| format!("127.0.0.1:{port}"))
+                .collect();
+            let servers: Vec<String> = CLUSTER_PORTS
+                .iter()
+                .map(|port| {
+                    format!(
+                        "redis-server --port {port} --cluster-enabled yes \
+                         --cluster-config-file nodes-{port}.conf \
+                         --cluster-announce-ip 127.0.0.1 --daemonize yes"
+                    )
+                })
+                .collect();
+            let script = format!(
+                "{} && redis-cli --cluster create {} --cluster-replicas 0 --cluster-yes \
+                 && until redis-cli -p {} cluster info | grep -q cluster_state:ok; do sleep 0.1; done \
+                 && echo 'Cluster ready' && tail -f /dev/null",
+                servers.join(" && "),
+                nodes.join(" "),
+                CLUSTER_PORTS[0],
+            );
+
+            let mut image = GenericImage::new("redis", "7.2.4")
+                .with_entrypoint("sh")
+                .with_wait_for(WaitFor::message_on_stdout("Cluster ready"))
+                .with_cmd(["-c", script.as_str()]);
+            for port in CLUSTER_PORTS {
+                image = image.with_mapped_port(port, port.tcp());
+            }
+
+            image.start().await.unwrap_or_else(|err| {
+                panic!("Failed to start Redis Cluster container: {:?}", err);
+            })
+        })
+        .await
+}
+
+// a master, its replica and a sentinel in one container, mapped to the same ports on the host so
+// that the addresses the sentinel reports can be reached
+const SENTINEL_MASTER_PORT: u16 = 6390;
+const SENTINEL_REPLICA_PORT: u16 = 6391;
+const SENTINEL_PORT: u16 = 26390;
+
+// a container of its own per run, since the test fails the master over
+async fn setup_sentinel() -> testcontainers::ContainerAsync<GenericImage> {
+    let script = format!(
+        "redis-server --port {master} --daemonize yes \
+         && redis-server --port {replica} --replicaof 127.0.0.1 {master} --daemonize yes \
+         && printf 'port {sentinel}\\nsentinel monitor mymaster 127.0.0.1 {master} 1\\n\
+         sentinel down-after-milliseconds mymaster 1000\\nsentinel failover-timeout mymaster 5000\\n' \
+         > /tmp/sentinel.conf && redis-sentinel /tmp/sentinel.conf --daemonize yes \
+         && until redis-cli -p {sentinel} sentinel replicas mymaster | grep -q {replica}; do sleep 0.1; done \
+         && echo 'Sentinel ready' && tail -f /dev/null",
+        master = SENTINEL_MASTER_PORT,
+        replica = SENTINEL_REPLICA_PORT,
+        sentinel = SENTINEL_PORT,
+    );
+
+    let mut image = GenericImage::new("redis", "7.2.4")
+        .with_entrypoint("sh")
+        .with_wait_for(WaitFor::message_on_stdout("Sentinel ready"))
+        .with_cmd(["-c", script.as_str()]);
+    for port in [SENTINEL_MASTER_PORT, SENTINEL_REPLICA_PORT, SENTINEL_PORT] {
+        image = image.with_mapped_port(port, port.tcp());
+    }
+
+    image.start().await.unwrap_or_else(|err| {
+        panic!("Failed to start Redis Sentinel container: {:?}", err);
+    })
+}
+
 #[tokio::test]
 async fn redis_async_cli_ping() -> TestResult {
     let container = setup_redis().await;
@@ -103,6 +227,69 @@ async fn redis_async_cli_set_get() -> TestResult {
     Ok(())
 }
 
+#[tokio::test]
+async fn redis_client_command() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+
+    let response = client.command(["SET", "raw-key", "raw-value"]).await?;
+    assert!(matches!(response, Response::Simple(data) if data == "OK"));
+
+    let response = client.command(["GET", "raw-key"]).await?;
+    assert!(matches!(response, Response::Simple(data) if data == "raw-value"));
+
+    // binary arguments are sent as is
+    client
+        .command([&b"SET"[..], b"raw-bytes", b"\x00\xff"])
+        .await?;
+    assert_eq!(
+        client.get("raw-bytes").await?,
+        Some(Bytes::from_static(b"\x00\xff"))
+    );
+
+    let response = client.command(["NOSUCHCOMMAND"]).await?;
+    assert!(matches!(response, Response::Error(_)));
+
+    let result = client.command(Vec::<&str>::new()).await;
+    assert!(matches!(result, Err(RedisError::InvalidArgument(_))));
+
+    // nested replies are returned as is
+    client.command(["RPUSH", "raw-list", "a", "b"]).await?;
+    let frame = client
+        .command_raw(["LRANGE", "raw-list", "0", "-1"])
+        .await?;
+    assert_eq!(
+        frame,
+        Some(Frame::Array(vec![Frame::bulk("a"), Frame::bulk("b")]))
+    );
+    let frame = client.command_raw(["NOSUCHCOMMAND"]).await?;
+    assert!(matches!(frame, Some(Frame::SimpleError(_))));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_command_count_exists() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+
+    assert!(client.command_count().await? > 100);
+
+    assert!(client.command_exists("GET").await?);
+    assert!(client.command_exists("config").await?);
+    assert!(!client.command_exists("NOSUCHCOMMAND").await?);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn redis_client_ping() -> TestResult {
     let container = setup_redis().await;
@@ -112,13 +299,18 @@ async fn redis_client_ping() -> TestResult {
 
     let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
 
-    let response = client.ping(None).await?;
+    assert_eq!(client.ping(None).await?, Pong::Pong);
+    assert_eq!(
+        client.ping(Some(b"PONG")).await?,
+        Pong::Echo(Bytes::from_static(b"PONG"))
+    );
 
-    if let Ok(string) = std::str::from_utf8(&response) {
-        assert_eq!(string, "PONG");
-    } else {
-        panic!("Invalid response: {:?}", response);
-    }
+    // binary messages are echoed untouched
+    let message = b"\x00\xff\r\nbin";
+    assert_eq!(
+        client.ping(Some(message)).await?,
+        Pong::Echo(Bytes::from_static(message))
+    );
 
     Ok(())
 }
@@ -132,7 +324,7 @@ async fn redis_client_set_get() -> TestResult {
 
     let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
 
-    let response: Option<Vec<u8>> = client.set("mykey", "myvalue".as_bytes()).await?;
+    let response: Option<Bytes> = client.set("mykey", "myvalue".as_bytes()).await?;
 
     if let Some(value) = response {
         if let Ok(string) = std::str::from_utf8(&value) {
@@ -157,3 +349,2846 @@ async fn redis_client_set_get() -> TestResult {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn redis_client_set_opts() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+
+    let nx = || SetOptions {
+        condition: Some(SetCondition::NX),
+        ..Default::default()
+    };
+    assert_eq!(
+        client.set_opts("mykey", b"first", nx()).await?,
+        SetOutcome::Set
+    );
+    // NX fails on an existing key
+    assert_eq!(
+        client.set_opts("mykey", b"second", nx()).await?,
+        SetOutcome::NotSet
+    );
+
+    let get = || SetOptions {
+        get: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        client.set_opts("mykey", b"second", get()).await?,
+        SetOutcome::PreviousValue(Some(b"first".to_vec()))
+    );
+    // GET on a missing key sets it and has no previous value to return
+    assert_eq!(
+        client.set_opts("missing", b"value", get()).await?,
+        SetOutcome::PreviousValue(None)
+    );
+    assert_eq!(
+        client.get("missing").await?,
+        Some(Bytes::from_static(b"value"))
+    );
+
+    let options = SetOptions {
+        expiry: Some(Expiry::EX(100)),
+        condition: Some(SetCondition::XX),
+        ..Default::default()
+    };
+    assert_eq!(
+        client.set_opts("mykey", b"third", options).await?,
+        SetOutcome::Set
+    );
+    assert!(client.ttl("mykey").await? > 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_mset_msetnx() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+
+    client
+        .mset(vec![
+            ("mset:key1", b"value1".as_slice()),
+            ("mset:key2", b"\x00\xff".as_slice()),
+        ])
+        .await?;
+    assert_eq!(
+        client.get("mset:key1").await?,
+        Some(Bytes::from_static(b"value1"))
+    );
+    assert_eq!(
+        client.get("mset:key2").await?,
+        Some(Bytes::from_static(b"\x00\xff"))
+    );
+
+    // mset:key1 exists, so neither key is set
+    let set = client
+        .msetnx(vec![
+            ("msetnx:key", b"value".as_slice()),
+            ("mset:key1", b"other".as_slice()),
+        ])
+        .await?;
+    assert!(!set);
+    assert_eq!(client.get("msetnx:key").await?, None);
+    assert_eq!(
+        client.get("mset:key1").await?,
+        Some(Bytes::from_static(b"value1"))
+    );
+
+    assert!(
+        client
+            .msetnx(vec![("msetnx:key", b"value".as_slice())])
+            .await?
+    );
+    assert_eq!(
+        client.get("msetnx:key").await?,
+        Some(Bytes::from_static(b"value"))
+    );
+
+    // rejected before anything is sent
+    assert!(matches!(
+        client.mset(Vec::<(&str, &[u8])>::new()).await,
+        Err(RedisError::InvalidArgument(_))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_binary_keys() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+
+    // not valid UTF-8
+    let key: &[u8] = b"bin\x00\xffkey";
+
+    client.set(key, b"myvalue").await?;
+    assert_eq!(client.get(key).await?, Some(Bytes::from_static(b"myvalue")));
+    assert_eq!(
+        client.get(Bytes::from_static(key)).await?,
+        Some(Bytes::from_static(b"myvalue"))
+    );
+    // a different key from the lossy UTF-8 version of it
+    assert_eq!(
+        client
+            .exists(vec![String::from_utf8_lossy(key).into_owned()])
+            .await?,
+        0
+    );
+
+    assert_eq!(client.exists(vec![key]).await?, 1);
+    assert_eq!(client.del(vec![key.to_vec()]).await?, 1);
+    assert_eq!(client.exists(vec![key]).await?, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_async_cli_scan() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+
+    for i in 0..100 {
+        client
+            .set(&format!("cli_scan:{i}"), "value".as_bytes())
+            .await?;
+    }
+
+    let mut cmd = Command::cargo_bin("redis-async-cli")?;
+
+    cmd.args([
+        "--host",
+        &host.to_string(),
+        "--port",
+        &host_port.to_string(),
+    ]);
+
+    cmd.args(["--scan", "--pattern", "cli_scan:*", "--count", "10"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::function(|out: &str| out.lines().count() == 100))
+        .stderr(predicate::str::is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_async_cli_raw_command() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    // CONFIG has no subcommand of its own, it is sent as is
+    let mut cmd = Command::cargo_bin("redis-async-cli")?;
+    cmd.args([
+        "--host",
+        &host.to_string(),
+        "--port",
+        &host_port.to_string(),
+    ]);
+    cmd.args(["config", "get", "maxmemory"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("1) \"maxmemory\"\n2) \"0\""));
+
+    // quoted arguments and binary escapes in interactive mode
+    let mut cmd = assert_cmd::Command::cargo_bin("redis-async-cli")?;
+    cmd.args([
+        "--host",
+        &host.to_string(),
+        "--port",
+        &host_port.to_string(),
+    ]);
+    cmd.write_stdin("append \"raw cli\" \"a b\\x00\"\nexit\n");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("(integer) 4"));
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    assert_eq!(
+        client.get("raw cli").await?,
+        Some(Bytes::from_static(b"a b\x00"))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_async_cli_repeat() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut cmd = Command::cargo_bin("redis-async-cli")?;
+
+    cmd.args([
+        "--host",
+        &host.to_string(),
+        "--port",
+        &host_port.to_string(),
+    ]);
+
+    cmd.args(["-r", "5", "ping"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::function(|out: &str| {
+            out.lines().filter(|line| *line == "PONG").count() == 5
+        }))
+        .stderr(predicate::str::contains("5 succeeded, 0 failed"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_async_cli_latency() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut cmd = Command::cargo_bin("redis-async-cli")?;
+
+    cmd.args([
+        "--host",
+        &host.to_string(),
+        "--port",
+        &host_port.to_string(),
+    ]);
+
+    cmd.args(["--latency", "--count", "10"]);
+    cmd.assert().success().stdout(predicate::str::is_match(
+        r"(?m)^min: \d+\.\d{2}ms, max: \d+\.\d{2}ms, avg: \d+\.\d{2}ms, p50: \d+\.\d{2}ms, p99: \d+\.\d{2}ms \(10 samples\)$",
+    )?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_set_get_client_name() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+
+    assert_eq!(client.get_client_name().await?, None);
+
+    client.set_client_name("integration-test").await?;
+    assert_eq!(
+        client.get_client_name().await?,
+        Some("integration-test".to_string())
+    );
+
+    assert!(matches!(
+        client.set_client_name("has spaces").await,
+        Err(RedisError::InvalidArgument(_))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_hello() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+
+    let response = client.hello(Some(2)).await?;
+    assert_eq!(response.proto, 2);
+    assert_eq!(response.server, "redis");
+    assert_eq!(response.version, "7.2.4");
+
+    let response = client.hello(Some(3)).await?;
+    assert_eq!(response.proto, 3);
+    assert_eq!(response.role, "master");
+
+    // the default user has no password, so any password is accepted
+    let response = client
+        .hello_with(3, Some(("default", "secret")), Some("hello-test"))
+        .await?;
+    assert_eq!(response.proto, 3);
+    assert_eq!(
+        client.get_client_name().await?,
+        Some("hello-test".to_string())
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_noauth() -> TestResult {
+    let container = setup_secured_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    match client.get("noauth:key").await {
+        Err(err) => assert!(err.is_auth_error(), "unexpected error: {err:?}"),
+        Ok(value) => panic!("GET succeeded before AUTH: {value:?}"),
+    }
+
+    // a middleware would authenticate and resend the command
+    client.auth(None, REDIS_PASSWORD).await?;
+    assert_eq!(client.get("noauth:key").await?, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_builder() -> TestResult {
+    let container = setup_secured_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    // the server refuses commands until authenticated
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    assert!(client.get("builder").await.is_err());
+
+    let mut client = Client::builder()
+        .host(&host.to_string())
+        .port(host_port)
+        .password(REDIS_PASSWORD)
+        .db(1)
+        .resp3(true)
+        .connect_timeout(std::time::Duration::from_secs(5))
+        .build()
+        .await?;
+    assert_eq!(client.protocol_version(), ProtocolVersion::Resp3);
+
+    client.set("builder", b"value").await?;
+    assert_eq!(client.get("builder").await?, Some(Bytes::from("value")));
+
+    // the key was written to database 1
+    client.select(0).await?;
+    assert_eq!(client.get("builder").await?, None);
+
+    let wrong_password = Client::builder()
+        .host(&host.to_string())
+        .port(host_port)
+        .password("wrong")
+        .build()
+        .await;
+    assert!(wrong_password.is_err());
+
+    let username_only = Client::builder()
+        .host(&host.to_string())
+        .port(host_port)
+        .username("default")
+        .build()
+        .await;
+    assert!(matches!(username_only, Err(RedisError::InvalidArgument(_))));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_caching_client_invalidation() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+    let addr = format!("{}:{}", host, host_port);
+
+    let observer = Arc::new(CountingObserver::new());
+    let mut client = Client::connect_with_observer(addr.as_str(), observer.clone()).await?;
+    client.hello(Some(3)).await?;
+    let client = CachingClient::new(client.into_multiplexed(), CacheConfig::default()).await?;
+
+    let mut other = Client::connect(addr.as_str()).await?;
+    other.set("cached", b"first").await?;
+
+    assert_eq!(client.get("cached").await?, Some(Bytes::from("first")));
+    let commands = observer.commands();
+
+    // the second read does not touch the network
+    assert_eq!(client.get("cached").await?, Some(Bytes::from("first")));
+    assert_eq!(observer.commands(), commands);
+
+    other.set("cached", b"second").await?;
+    tokio::time::timeout(std::time::Duration::from_secs(5), async {
+        while !client.is_empty() {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+    })
+    .await?;
+
+    assert_eq!(client.get("cached").await?, Some(Bytes::from("second")));
+    assert_eq!(observer.commands(), commands + 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_is_alive() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    assert!(client.is_alive().await);
+    // a clean PONG leaves the connection usable
+    assert!(client.is_alive().await);
+    assert_eq!(client.ping(None).await?, Pong::Pong);
+
+    // a server closing the connection right away
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    tokio::spawn(async move {
+        if let Ok((socket, _)) = listener.accept().await {
+            drop(socket);
+        }
+    });
+
+    let mut client = Client::connect(addr).await?;
+    assert!(!client.is_alive().await);
+
+    // a server that never replies
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    tokio::spawn(async move {
+        if let Ok((socket, _)) = listener.accept().await {
+            tokio::time::sleep(std::time::Duration::from_secs(10)).await;
+            drop(socket);
+        }
+    });
+
+    let mut client = Client::connect(addr).await?;
+    assert!(!client.is_alive().await);
+    // the PONG may still arrive, the connection is not reused
+    assert!(client.ping(None).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_command_timeout() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::builder()
+        .host(&host.to_string())
+        .port(host_port)
+        .command_timeout(std::time::Duration::from_millis(500))
+        .build()
+        .await?;
+
+    // a reply in time
+    client.debug_sleep(0.1).await?;
+
+    assert!(matches!(
+        client.debug_sleep(2.0).await,
+        Err(RedisError::Timeout)
+    ));
+    // the late reply would be taken for the reply of the next command
+    assert!(client.ping(None).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_protocol_version() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    assert_eq!(client.protocol_version(), ProtocolVersion::Resp2);
+
+    client.hello(Some(3)).await?;
+    assert_eq!(client.protocol_version(), ProtocolVersion::Resp3);
+
+    // a failed HELLO keeps the current version
+    assert!(matches!(
+        client.hello(Some(4)).await,
+        Err(RedisError::UnsupportedProtocol)
+    ));
+    assert_eq!(client.protocol_version(), ProtocolVersion::Resp3);
+
+    client.hello(Some(2)).await?;
+    assert_eq!(client.protocol_version(), ProtocolVersion::Resp2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_connect_resp3() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect_resp3(format!("{}:{}", host, host_port)).await?;
+    assert_eq!(client.protocol_version(), ProtocolVersion::Resp3);
+    assert!(client.ping(None).await.is_ok());
+
+    // Redis 5 only speaks RESP2, and does not know the HELLO command
+    let legacy = GenericImage::new("redis", "5.0.14")
+        .with_exposed_port(REDIS_PORT.tcp())
+        .with_wait_for(WaitFor::message_on_stdout("Ready to accept connections"))
+        .start()
+        .await?;
+    let host = legacy.get_host().await?;
+    let host_port = legacy.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let result = Client::connect_resp3(format!("{}:{}", host, host_port)).await;
+    assert!(matches!(result, Err(RedisError::UnsupportedProtocol)));
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    assert_eq!(client.protocol_version(), ProtocolVersion::Resp2);
+    assert!(client.ping(None).await.is_ok());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_list_kill() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut admin = Client::connect(format!("{}:{}", host, host_port)).await?;
+    let mut victim = Client::connect(format!("{}:{}", host, host_port)).await?;
+    victim.set_client_name("list-kill-victim").await?;
+    let admin_id = admin.client_id().await?;
+    let victim_id = victim.client_id().await?;
+    assert_ne!(admin_id, victim_id);
+
+    let clients = admin
+        .client_list(ClientListFilter::new().ids(vec![admin_id as u64, victim_id as u64]))
+        .await?;
+    assert_eq!(clients.len(), 2);
+    let listed = clients
+        .iter()
+        .find(|client| client.id == victim_id as u64)
+        .ok_or("missing victim")?;
+    assert_eq!(listed.name.as_deref(), Some("list-kill-victim"));
+    assert_eq!(listed.db, 0);
+    assert!(listed.laddr.is_some());
+
+    // every connection is listed without a filter
+    let clients = admin.client_list(ClientListFilter::new()).await?;
+    assert!(clients.iter().any(|client| client.id == admin_id as u64));
+
+    let killed = admin
+        .client_kill(ClientKillFilter::new().id(victim_id as u64))
+        .await?;
+    assert_eq!(killed, 1);
+    assert!(victim.get("list-kill:key").await.is_err());
+
+    // nothing left to kill
+    let killed = admin
+        .client_kill(
+            ClientKillFilter::new()
+                .id(victim_id as u64)
+                .client_type(ClientType::Normal),
+        )
+        .await?;
+    assert_eq!(killed, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_config_get_set() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+
+    let config = client.config_get("maxmemory").await?;
+    assert_eq!(
+        config,
+        HashMap::from([("maxmemory".to_string(), "0".to_string())])
+    );
+
+    client.config_set("maxmemory", "104857600").await?;
+    let config = client.config_get("maxmemory").await?;
+    assert_eq!(
+        config.get("maxmemory").map(String::as_str),
+        Some("104857600")
+    );
+
+    // several parameters at once, since Redis 7.0
+    client
+        .config_set_many(vec![
+            ("maxmemory", "0"),
+            ("maxmemory-policy", "allkeys-lru"),
+        ])
+        .await?;
+
+    // patterns return every matched parameter
+    let config = client.config_get("maxmemory*").await?;
+    assert_eq!(config.get("maxmemory").map(String::as_str), Some("0"));
+    assert_eq!(
+        config.get("maxmemory-policy").map(String::as_str),
+        Some("allkeys-lru")
+    );
+
+    assert!(client.config_get("no-such-parameter").await?.is_empty());
+    assert!(matches!(
+        client.config_set("no-such-parameter", "1").await,
+        Err(RedisError::UnknownConfigParameter(_))
+    ));
+
+    client.config_resetstat().await?;
+    client.config_set("maxmemory-policy", "noeviction").await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_slowlog() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    client.set_client_name("slowlog-test").await?;
+
+    // log every command, and keep enough of them for the ones of the other tests
+    client
+        .config_set_many(vec![
+            ("slowlog-log-slower-than", "0"),
+            ("slowlog-max-len", "100000"),
+        ])
+        .await?;
+    client.slowlog_reset().await?;
+    client.set("slowlog:key", b"slowlog:value").await?;
+
+    let entries = client.slowlog_get(Some(-1)).await;
+    let len = client.slowlog_len().await;
+    client
+        .config_set_many(vec![
+            ("slowlog-log-slower-than", "10000"),
+            ("slowlog-max-len", "128"),
+        ])
+        .await?;
+    client.slowlog_reset().await?;
+
+    let entries = entries?;
+    assert!(len? >= 1);
+    let entry = entries
+        .iter()
+        .find(|entry| entry.args == [&b"SET"[..], b"slowlog:key", b"slowlog:value"])
+        .ok_or("missing SET entry")?;
+    assert_eq!(entry.client_name.as_deref(), Some("slowlog-test"));
+    assert!(entry.client_addr.is_some());
+    assert!(entry.timestamp > 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_watch_multi_exec() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    let mut other = Client::connect(format!("{}:{}", host, host_port)).await?;
+    client.set("watch:balance", b"100").await?;
+
+    // nobody touches the key, the transaction runs
+    client.watch(vec!["watch:balance"]).await?;
+    client.multi().await?;
+    client.command(["DECRBY", "watch:balance", "30"]).await?;
+    client.command(["GET", "watch:balance"]).await?;
+    let replies = client.exec().await?;
+    assert!(matches!(
+        replies.as_slice(),
+        [StructuredResponse::Integer(70), StructuredResponse::Bulk(balance)] if balance == "70"
+    ));
+
+    // another client changes the key before EXEC, nothing runs
+    client.watch(vec!["watch:balance"]).await?;
+    other.set("watch:balance", b"500").await?;
+    client.multi().await?;
+    client.command(["DECRBY", "watch:balance", "30"]).await?;
+    assert!(matches!(
+        client.exec().await,
+        Err(RedisError::TransactionAborted)
+    ));
+    assert_eq!(
+        client.get("watch:balance").await?,
+        Some(Bytes::from_static(b"500"))
+    );
+
+    // once unwatched, changes by others no longer abort the transaction
+    client.watch(vec!["watch:balance"]).await?;
+    client.unwatch().await?;
+    other.set("watch:balance", b"10").await?;
+    client.multi().await?;
+    client.command(["INCR", "watch:balance"]).await?;
+    let replies = client.exec().await?;
+    assert!(matches!(
+        replies.as_slice(),
+        [StructuredResponse::Integer(11)]
+    ));
+
+    client.multi().await?;
+    client.command(["INCR", "watch:balance"]).await?;
+    client.discard().await?;
+    assert_eq!(
+        client.get("watch:balance").await?,
+        Some(Bytes::from_static(b"11"))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_lcs() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    client.set("lcs:key1", b"ohmytext").await?;
+    client.set("lcs:key2", b"mynewtext").await?;
+
+    assert_eq!(
+        client
+            .lcs("lcs:key1", "lcs:key2", LcsOptions::new())
+            .await?,
+        LcsResult::Match(b"mytext".to_vec())
+    );
+    assert_eq!(
+        client
+            .lcs("lcs:key1", "lcs:key2", LcsOptions::new().len())
+            .await?,
+        LcsResult::Len(6)
+    );
+
+    let options = LcsOptions::new().idx().min_match_len(4).with_match_len();
+    let expected = LcsResult::Idx {
+        matches: vec![LcsMatch {
+            key1: (4, 7),
+            key2: (5, 8),
+            len: Some(4),
+        }],
+        len: 6,
+    };
+    assert_eq!(
+        client.lcs("lcs:key1", "lcs:key2", options.clone()).await?,
+        expected
+    );
+
+    // a map instead of a flat array
+    client.hello(Some(3)).await?;
+    assert_eq!(client.lcs("lcs:key1", "lcs:key2", options).await?, expected);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_object_idletime_refcount() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    client
+        .set("object:fresh", b"a value long enough to not be shared")
+        .await?;
+
+    let idle = client
+        .object_idletime("object:fresh")
+        .await?
+        .ok_or("missing key")?;
+    assert!(idle <= 1);
+    assert_eq!(client.object_refcount("object:fresh").await?, Some(1));
+
+    assert_eq!(client.object_idletime("object:missing").await?, None);
+    assert_eq!(client.object_refcount("object:missing").await?, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_sample_hot_keys() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    for key in ["heat:cold", "heat:warm", "heat:hot"] {
+        client.set(key, b"value").await?;
+    }
+
+    // the default policy tracks idle times only
+    let result = client.object_freq("heat:hot").await;
+    assert!(matches!(result, Err(RedisError::LfuNotSelected)));
+
+    // the idle time is counted in seconds
+    tokio::time::sleep(std::time::Duration::from_millis(2100)).await;
+    client.get("heat:warm").await?;
+    tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+    client.get("heat:hot").await?;
+
+    assert_eq!(client.object_idletime("heat:missing").await?, None);
+    let idle = client
+        .object_idletime("heat:cold")
+        .await?
+        .ok_or("missing key")?;
+    assert!(idle >= 3);
+
+    let sampled = client.sample_hot_keys(10, Some("heat:*"), Some(2)).await?;
+    let keys: Vec<&[u8]> = sampled.iter().map(|(key, _)| &key[..]).collect();
+    assert_eq!(keys, vec![&b"heat:hot"[..], b"heat:warm", b"heat:cold"]);
+    assert!(matches!(sampled[0].1, KeyHeat::IdleTime(_)));
+
+    assert_eq!(
+        client.sample_hot_keys(1, Some("heat:*"), None).await?.len(),
+        1
+    );
+
+    // every access increments the counter with a log factor of 0
+    client.config_set("maxmemory-policy", "allkeys-lfu").await?;
+    client.config_set("lfu-log-factor", "0").await?;
+    // the counters of the keys created before are not meaningful yet
+    for key in ["heat:cold", "heat:warm", "heat:hot"] {
+        client.set(key, b"value").await?;
+    }
+    for _ in 0..3 {
+        client.get("heat:cold").await?;
+    }
+    for _ in 0..10 {
+        client.get("heat:hot").await?;
+    }
+
+    let sampled = client.sample_hot_keys(10, Some("heat:*"), Some(2)).await;
+    let hot = client.object_freq("heat:hot").await;
+    let cold = client.object_freq("heat:cold").await;
+    let missing = client.object_freq("heat:missing").await;
+    client.config_set("maxmemory-policy", "noeviction").await?;
+    client.config_set("lfu-log-factor", "10").await?;
+
+    let sampled = sampled?;
+    let keys: Vec<&[u8]> = sampled.iter().map(|(key, _)| &key[..]).collect();
+    assert_eq!(keys, vec![&b"heat:hot"[..], b"heat:cold", b"heat:warm"]);
+    assert!(matches!(sampled[0].1, KeyHeat::Freq(_)));
+
+    let (hot, cold) = (hot?.ok_or("missing key")?, cold?.ok_or("missing key")?);
+    assert!(hot > cold);
+    assert_eq!(missing?, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_random_key_touch_unlink() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    // no other test uses this database
+    client.select(9).await?;
+
+    assert_eq!(client.random_key().await?, None);
+
+    client.set("keyspace:one", b"1").await?;
+    client.set("keyspace:two", b"2").await?;
+    let key = client.random_key().await?.ok_or("missing key")?;
+    assert!(key == "keyspace:one" || key == "keyspace:two");
+
+    assert_eq!(
+        client
+            .touch(vec!["keyspace:one", "keyspace:two", "keyspace:missing"])
+            .await?,
+        2
+    );
+
+    assert_eq!(
+        client
+            .unlink(vec!["keyspace:one", "keyspace:missing"])
+            .await?,
+        1
+    );
+    assert_eq!(client.exists(vec!["keyspace:one"]).await?, 0);
+    assert_eq!(client.random_key().await?, Some("keyspace:two".to_string()));
+
+    client.unlink(vec!["keyspace:two"]).await?;
+    assert_eq!(client.random_key().await?, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_touch_resets_idletime() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    client.set("touch:idle", b"value").await?;
+
+    // the idle time is counted in seconds
+    tokio::time::sleep(Duration::from_millis(2100)).await;
+    let idle = client
+        .object_idletime("touch:idle")
+        .await?
+        .ok_or("missing key")?;
+    assert!(idle >= 2);
+
+    assert_eq!(client.touch(vec!["touch:idle", "touch:missing"]).await?, 1);
+    let idle = client
+        .object_idletime("touch:idle")
+        .await?
+        .ok_or("missing key")?;
+    assert!(idle <= 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_no_touch_no_evict() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    client.client_no_evict(true).await?;
+    client.client_no_evict(false).await?;
+
+    client.set("notouch:idle", b"value").await?;
+    client.client_no_touch(true).await?;
+
+    // the idle time is counted in seconds
+    tokio::time::sleep(Duration::from_millis(2100)).await;
+    assert_eq!(
+        client.get("notouch:idle").await?,
+        Some(Bytes::from_static(b"value"))
+    );
+    let idle = client
+        .object_idletime("notouch:idle")
+        .await?
+        .ok_or("missing key")?;
+    assert!(idle >= 2);
+
+    // read again once off, the GET resets the idle time
+    client.client_no_touch(false).await?;
+    client.get("notouch:idle").await?;
+    let idle = client
+        .object_idletime("notouch:idle")
+        .await?
+        .ok_or("missing key")?;
+    assert!(idle <= 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_async_cli_unlink() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    client.set("cli-unlink:one", b"1").await?;
+    client.set("cli-unlink:two", b"2").await?;
+
+    let mut cmd = Command::cargo_bin("redis-async-cli")?;
+
+    cmd.args([
+        "--host",
+        &host.to_string(),
+        "--port",
+        &host_port.to_string(),
+    ]);
+
+    cmd.arg("unlink")
+        .arg("cli-unlink:one")
+        .arg("cli-unlink:two")
+        .arg("cli-unlink:missing");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("(integer) 2"))
+        .stderr(predicate::str::is_empty());
+
+    assert_eq!(
+        client
+            .exists(vec!["cli-unlink:one", "cli-unlink:two"])
+            .await?,
+        0
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_dump_restore() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+
+    // elements that are not valid UTF-8
+    let elements: Vec<&[u8]> = vec![b"\x00\xff", b"\xc3\x28", b"plain", b""];
+    client.rpush("dump:list", elements.clone()).await?;
+
+    let payload = client.dump("dump:list").await?.ok_or("missing payload")?;
+    assert_eq!(client.del(vec!["dump:list"]).await?, 1);
+
+    client
+        .restore("dump:list", 0, &payload, RestoreOptions::default())
+        .await?;
+    let restored = client.lrange("dump:list", 0, -1).await?;
+    assert_eq!(restored, elements);
+    assert_eq!(client.ttl("dump:list").await?, -1);
+
+    // the key exists now
+    let result = client
+        .restore("dump:list", 0, &payload, RestoreOptions::default())
+        .await;
+    assert!(matches!(result, Err(RedisError::BusyKey)));
+
+    let options = RestoreOptions {
+        replace: true,
+        ..RestoreOptions::default()
+    };
+    client
+        .restore("dump:list", 60_000, &payload, options)
+        .await?;
+    assert!(client.ttl("dump:list").await? > 0);
+
+    assert_eq!(client.dump("dump:missing").await?, None);
+
+    let result = client
+        .restore("dump:corrupt", 0, b"garbage", RestoreOptions::default())
+        .await;
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_migrate() -> TestResult {
+    let source = setup_redis().await;
+    let target = setup_secured_redis().await;
+
+    let host = source.get_host().await?;
+    let host_port = source.get_host_port_ipv4(REDIS_PORT).await?;
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+
+    let target_host = target.get_host().await?;
+    let target_port = target.get_host_port_ipv4(REDIS_PORT).await?;
+    let mut target_client = Client::connect(format!("{}:{}", target_host, target_port)).await?;
+    target_client.auth(None, REDIS_PASSWORD).await?;
+
+    // the source server reaches the target through the bridge network
+    let bridge_ip = target.get_bridge_ip_address().await?.to_string();
+    let options = || MigrateOptions {
+        auth: Some(MigrateAuth::Password(REDIS_PASSWORD.to_string())),
+        ..MigrateOptions::default()
+    };
+
+    client.set("migrate:one", b"\x00one").await?;
+    assert!(
+        client
+            .migrate(
+                &bridge_ip,
+                REDIS_PORT,
+                vec!["migrate:one"],
+                1,
+                5000,
+                options()
+            )
+            .await?
+    );
+    assert_eq!(client.get("migrate:one").await?, None);
+
+    target_client.select(1).await?;
+    assert_eq!(
+        target_client.get("migrate:one").await?,
+        Some(Bytes::from_static(b"\x00one"))
+    );
+
+    // several keys, kept on the source
+    client.set("migrate:one", b"\x00one").await?;
+    client.set("migrate:two", b"two").await?;
+    let result = client
+        .migrate(
+            &bridge_ip,
+            REDIS_PORT,
+            vec!["migrate:one", "migrate:two"],
+            1,
+            5000,
+            MigrateOptions {
+                copy: true,
+                ..options()
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(RedisError::BusyKey)));
+
+    assert!(
+        client
+            .migrate(
+                &bridge_ip,
+                REDIS_PORT,
+                vec!["migrate:one", "migrate:two"],
+                1,
+                5000,
+                MigrateOptions {
+                    copy: true,
+                    replace: true,
+                    ..options()
+                },
+            )
+            .await?
+    );
+    assert_eq!(client.exists(vec!["migrate:one", "migrate:two"]).await?, 2);
+    assert_eq!(
+        target_client.get("migrate:two").await?,
+        Some(Bytes::from_static(b"two"))
+    );
+
+    assert!(
+        !client
+            .migrate(
+                &bridge_ip,
+                REDIS_PORT,
+                vec!["migrate:missing"],
+                1,
+                5000,
+                options()
+            )
+            .await?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_lpop_rpop_count() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    client
+        .rpush("pop-count", vec![b"a", b"b", b"c", b"d", b"e"])
+        .await?;
+
+    // no count, a single element
+    assert_eq!(client.lpop("pop-count").await?, Some(Bytes::from("a")));
+    assert_eq!(client.rpop("pop-count").await?, Some(Bytes::from("e")));
+
+    // a count of 1, still an array
+    assert_eq!(
+        client.lpop_n("pop-count", 1).await?,
+        Some(vec![Bytes::from("b")])
+    );
+    assert_eq!(
+        client.rpop_n("pop-count", 1).await?,
+        Some(vec![Bytes::from("d")])
+    );
+
+    // a count of 0, an empty array
+    assert_eq!(client.lpop_n("pop-count", 0).await?, Some(vec![]));
+    assert_eq!(client.rpop_n("pop-count", 0).await?, Some(vec![]));
+
+    // a count larger than the list
+    assert_eq!(
+        client.lpop_n("pop-count", 10).await?,
+        Some(vec![Bytes::from("c")])
+    );
+
+    // the last pop deleted the key
+    assert_eq!(client.lpop("pop-count").await?, None);
+    assert_eq!(client.rpop("pop-count").await?, None);
+    assert_eq!(client.lpop_n("pop-count", 2).await?, None);
+    assert_eq!(client.rpop_n("pop-count", 0).await?, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_async_cli_lpop_count() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    client.rpush("cli-pop", vec![b"a", b"b", b"c"]).await?;
+
+    let lpop = |args: &[&str]| -> std::result::Result<_, Box<dyn std::error::Error>> {
+        let mut cmd = Command::cargo_bin("redis-async-cli")?;
+        cmd.args([
+            "--host",
+            &host.to_string(),
+            "--port",
+            &host_port.to_string(),
+            "lpop",
+        ]);
+        cmd.args(args);
+        Ok(cmd.assert().success())
+    };
+
+    // without a count, the element alone
+    lpop(&["cli-pop"])?.stdout("\"a\"\n");
+    lpop(&["cli-pop", "1"])?.stdout("1) \"b\"\n");
+    lpop(&["cli-pop", "0"])?.stdout("(empty array)\n");
+    lpop(&["cli-pop", "5"])?.stdout("1) \"c\"\n");
+    lpop(&["cli-pop"])?.stdout("(nil)\n");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_blpop() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+
+    // a RESP2 BLPOP timeout is replied with the null array *-1
+    assert_eq!(client.blpop(vec!["blpop-empty"], 0.1).await?, None);
+
+    client
+        .rpush("blpop-list", vec![b"first", b"second"])
+        .await?;
+    assert_eq!(
+        client.blpop(vec!["blpop-empty", "blpop-list"], 0.1).await?,
+        Some((
+            Bytes::from_static(b"blpop-list"),
+            Bytes::from_static(b"first")
+        ))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_bzpopmin_bzpopmax() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+    let addr = format!("{}:{}", host, host_port);
+
+    let mut client = Client::connect(addr.as_str()).await?;
+
+    // two jobs scheduled at different times, the earliest one first
+    client
+        .zadd("jobs", vec![(200.0, b"later"), (100.0, b"sooner")])
+        .await?;
+    assert_eq!(
+        client.zpopmin("jobs", None).await?,
+        vec![(Bytes::from_static(b"sooner"), 100.0)]
+    );
+    assert_eq!(
+        client
+            .bzpopmax(vec!["jobs:empty", "jobs"], Duration::from_millis(100))
+            .await?,
+        Some(("jobs".to_string(), Bytes::from_static(b"later"), 200.0))
+    );
+
+    // the wait of a blocking command does not count against the command timeout
+    client.set_command_timeout(Some(Duration::from_millis(100)));
+    assert_eq!(
+        client
+            .bzpopmin(vec!["jobs"], Duration::from_millis(300))
+            .await?,
+        None
+    );
+
+    // released by a ZADD from another client
+    let producer = tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        let mut client = Client::connect(addr.as_str()).await?;
+        client.zadd("jobs", vec![(300.0, b"queued")]).await
+    });
+    assert_eq!(
+        client.bzpopmin(vec!["jobs"], Duration::ZERO).await?,
+        Some(("jobs".to_string(), Bytes::from_static(b"queued"), 300.0))
+    );
+    producer.await??;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_monitor() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+    let addr = format!("{}:{}", host, host_port);
+
+    let mut monitor = Client::connect(addr.as_str()).await?.monitor().await?;
+
+    let mut client = Client::connect(addr.as_str()).await?;
+    client.set("monitored", b"value").await?;
+
+    // other tests may run commands concurrently, skip their lines
+    let line = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+        loop {
+            match monitor.next_line().await {
+                Ok(Some(line)) if line.contains(r#""SET" "monitored" "value""#) => break Ok(line),
+                Ok(Some(_)) => continue,
+                Ok(None) => break Err(RedisError::Other(anyhow::anyhow!("monitor closed"))),
+                Err(err) => break Err(err),
+            }
+        }
+    })
+    .await??;
+    assert!(line.ends_with(r#""SET" "monitored" "value""#));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_publish() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+
+    // nobody listens on the channel
+    assert_eq!(client.publish("publish:nobody", b"hello").await?, 0);
+    assert_eq!(client.publish("publish:nobody", b"\x00\xff").await?, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_psubscribe() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    let mut subscriber = client.psubscribe(vec!["news.*"]).await?;
+    assert_eq!(subscriber.get_subscribed_patterns(), ["news.*".to_string()]);
+
+    let mut publisher = Client::connect(format!("{}:{}", host, host_port)).await?;
+    assert_eq!(publisher.publish("news.tech", b"hello").await?, 1);
+
+    assert_eq!(
+        subscriber.next_message().await?,
+        Some(Message {
+            channel: "news.tech".to_string(),
+            pattern: Some("news.*".to_string()),
+            payload: Bytes::from_static(b"hello"),
+        })
+    );
+
+    subscriber.punsubscribe(vec!["news.*"]).await?;
+    assert!(subscriber.get_subscribed_patterns().is_empty());
+    assert_eq!(publisher.publish("news.tech", b"hello").await?, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_subscribe_reconnect() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    let id = match client.command(["CLIENT", "ID"]).await? {
+        Response::Simple(id) => String::from_utf8(id.to_vec())?,
+        response => return Err(format!("unexpected CLIENT ID reply: {response:?}").into()),
+    };
+    let mut subscriber = client.subscribe(vec!["reconnect:news"]).await?;
+    subscriber.set_reconnect_policy(Some(ReconnectPolicy {
+        base_backoff: Duration::from_millis(10),
+        ..Default::default()
+    }));
+
+    let mut publisher = Client::connect(format!("{}:{}", host, host_port)).await?;
+    assert_eq!(publisher.publish("reconnect:news", b"before").await?, 1);
+    assert_eq!(
+        subscriber.next_event().await?,
+        Some(SubscriberEvent::Message(Message {
+            channel: "reconnect:news".to_string(),
+            pattern: None,
+            payload: Bytes::from_static(b"before"),
+        }))
+    );
+
+    // drop the subscriber's connection from the server side, mid-stream
+    publisher
+        .command(["CLIENT", "KILL", "ID", id.as_str()])
+        .await?;
+    assert_eq!(
+        subscriber.next_event().await?,
+        Some(SubscriberEvent::Reconnected)
+    );
+    assert_eq!(
+        subscriber.get_subscribed_channels(),
+        ["reconnect:news".to_string()]
+    );
+
+    assert_eq!(publisher.publish("reconnect:news", b"after").await?, 1);
+    assert_eq!(
+        subscriber.next_message().await?,
+        Some(Message {
+            channel: "reconnect:news".to_string(),
+            pattern: None,
+            payload: Bytes::from_static(b"after"),
+        })
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_subscribe_unsubscribe() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    let mut subscriber = client.subscribe(vec!["news", "weather"]).await?;
+    assert_eq!(
+        subscriber.get_subscribed_channels(),
+        ["news".to_string(), "weather".to_string()]
+    );
+
+    subscriber.unsubscribe(vec!["news"]).await?;
+    assert_eq!(
+        subscriber.get_subscribed_channels(),
+        ["weather".to_string()]
+    );
+
+    // the server replies to PING in pub/sub mode
+    assert_eq!(subscriber.ping(None).await?, Pong::Pong);
+    assert_eq!(
+        subscriber.ping(Some(b"\x00\xff")).await?,
+        Pong::Echo(Bytes::from_static(b"\x00\xff"))
+    );
+
+    let mut publisher = Client::connect(format!("{}:{}", host, host_port)).await?;
+    assert_eq!(publisher.publish("news", b"dropped").await?, 0);
+    assert_eq!(publisher.publish("weather", b"sunny").await?, 1);
+
+    // the message on the dropped channel never arrives, so the first one is on the remaining channel
+    assert_eq!(
+        subscriber.next_message().await?,
+        Some(Message {
+            channel: "weather".to_string(),
+            pattern: None,
+            payload: Bytes::from_static(b"sunny"),
+        })
+    );
+
+    subscriber.unsubscribe(vec![]).await?;
+    assert!(subscriber.get_subscribed_channels().is_empty());
+
+    let mut client = subscriber.into_client()?;
+    assert_eq!(client.ping(None).await?, Pong::Pong);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_pubsub_introspection() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    assert!(client.pubsub_channels(None).await?.is_empty());
+
+    let subscriber = Client::connect(format!("{}:{}", host, host_port))
+        .await?
+        .subscribe(vec!["news.tech"])
+        .await?;
+
+    assert_eq!(
+        client.pubsub_channels(Some("news.*")).await?,
+        vec!["news.tech".to_string()]
+    );
+    assert!(client.pubsub_channels(Some("weather.*")).await?.is_empty());
+
+    let counts = client.pubsub_numsub(vec!["news.tech", "weather"]).await?;
+    assert_eq!(counts.get("news.tech"), Some(&1));
+    assert_eq!(counts.get("weather"), Some(&0));
+
+    drop(subscriber);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_get_ex() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    client.set("getex:key", b"value").await?;
+
+    let value = client.get_ex("getex:key", Some(Expiry::EX(100))).await?;
+    assert_eq!(value, Some(Bytes::from_static(b"value")));
+
+    let ttl = client.ttl("getex:key").await?;
+    assert!((99..=100).contains(&ttl), "unexpected TTL {ttl}");
+
+    let value = client.get_ex_persist("getex:key").await?;
+    assert_eq!(value, Some(Bytes::from_static(b"value")));
+    assert_eq!(client.ttl("getex:key").await?, -1);
+
+    let result = client.get_ex("getex:key", Some(Expiry::EX(0))).await;
+    assert!(matches!(result, Err(RedisError::InvalidArgument(_))));
+
+    Ok(())
+}
+
+#[tokio::test]
+// the score is taken from a bug report, it is not meant to be PI
+#[allow(clippy::approx_constant)]
+async fn redis_client_zscore_resp3_double() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    client.hello(Some(3)).await?;
+
+    let score = 3.14159265358979;
+    assert_eq!(client.zadd("zscore:key", vec![(score, b"pi")]).await?, 1);
+    assert_eq!(client.zscore("zscore:key", b"pi").await?, Some(score));
+    assert_eq!(client.zscore("zscore:key", b"missing").await?, None);
+
+    assert_eq!(client.incr_by_float("zscore:float", 0.1).await?, 0.1);
+
+    let result = client.zadd("zscore:key", vec![(f64::NAN, b"nan")]).await;
+    assert!(matches!(result, Err(RedisError::InvalidArgument(_))));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_zpop_zmscore() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+
+    for (key, protocol) in [("zpop:resp2", 2), ("zpop:resp3", 3)] {
+        client.hello(Some(protocol)).await?;
+        client
+            .zadd(
+                key,
+                vec![
+                    (1.5, b"a"),
+                    (-2.0, b"b"),
+                    (f64::INFINITY, b"c"),
+                    (3.0, b"d"),
+                ],
+            )
+            .await?;
+
+        assert_eq!(
+            client.zmscore(key, vec![b"a", b"missing", b"c"]).await?,
+            vec![Some(1.5), None, Some(f64::INFINITY)]
+        );
+
+        // the lowest score, without a count
+        assert_eq!(
+            client.zpopmin(key, None).await?,
+            vec![(Bytes::from("b"), -2.0)]
+        );
+        assert_eq!(
+            client.zpopmax(key, Some(2)).await?,
+            vec![(Bytes::from("c"), f64::INFINITY), (Bytes::from("d"), 3.0)]
+        );
+        assert_eq!(
+            client.zpopmin(key, Some(10)).await?,
+            vec![(Bytes::from("a"), 1.5)]
+        );
+
+        // the last pop deleted the key
+        assert!(client.zpopmin(key, Some(10)).await?.is_empty());
+        assert!(client.zpopmax(key, None).await?.is_empty());
+        assert_eq!(client.zmscore(key, vec![b"a"]).await?, vec![None]);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_zrangebyscore() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+
+    for (key, protocol) in [("zrangebyscore:resp2", 2), ("zrangebyscore:resp3", 3)] {
+        client.hello(Some(protocol)).await?;
+        client
+            .zadd(
+                key,
+                vec![
+                    (1.0, b"a"),
+                    (2.0, b"b"),
+                    (3.0, b"c"),
+                    (4.0, b"d"),
+                    (5.0, b"e"),
+                ],
+            )
+            .await?;
+
+        let members = client
+            .zrangebyscore(
+                key,
+                ScoreBound::Inclusive(2.0),
+                ScoreBound::Inclusive(4.0),
+                None,
+            )
+            .await?;
+        assert_eq!(
+            members,
+            vec![Bytes::from("b"), Bytes::from("c"), Bytes::from("d")]
+        );
+
+        let members = client
+            .zrangebyscore(
+                key,
+                ScoreBound::Exclusive(2.0),
+                ScoreBound::Exclusive(4.0),
+                None,
+            )
+            .await?;
+        assert_eq!(members, vec![Bytes::from("c")]);
+
+        // a window of two members, after skipping the first one
+        let members = client
+            .zrangebyscore_withscores(key, ScoreBound::NegInf, ScoreBound::PosInf, Some((1, 2)))
+            .await?;
+        assert_eq!(
+            members,
+            vec![(Bytes::from("b"), 2.0), (Bytes::from("c"), 3.0)]
+        );
+
+        let members = client
+            .zrangebyscore(key, ScoreBound::Exclusive(5.0), ScoreBound::PosInf, None)
+            .await?;
+        assert!(members.is_empty());
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_zrange_by() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+
+    for (key, protocol) in [("zrange:resp2", 2), ("zrange:resp3", 3)] {
+        client.hello(Some(protocol)).await?;
+        client
+            .zadd(
+                key,
+                vec![
+                    (1.0, b"a"),
+                    (2.0, b"b"),
+                    (3.0, b"c"),
+                    (4.0, b"d"),
+                    (5.0, b"e"),
+                ],
+            )
+            .await?;
+
+        let members = client
+            .zrange_by(
+                key,
+                ZRangeBy::Score(ScoreBound::Exclusive(1.0), ScoreBound::Exclusive(5.0)),
+                ZRangeOptions::default(),
+            )
+            .await?;
+        assert_eq!(
+            members,
+            vec![Bytes::from("b"), Bytes::from("c"), Bytes::from("d")]
+        );
+
+        // the second page of two members, from the highest score down
+        let page = client
+            .zrange_by_withscores(
+                key,
+                ZRangeBy::Score(ScoreBound::NegInf, ScoreBound::PosInf),
+                ZRangeOptions {
+                    rev: true,
+                    limit: Some((2, 2)),
+                },
+            )
+            .await?;
+        assert_eq!(page, vec![(Bytes::from("c"), 3.0), (Bytes::from("b"), 2.0)]);
+
+        let top = client
+            .zrange_by_withscores(
+                key,
+                ZRangeBy::Rank(0, 1),
+                ZRangeOptions {
+                    rev: true,
+                    limit: None,
+                },
+            )
+            .await?;
+        assert_eq!(top, vec![(Bytes::from("e"), 5.0), (Bytes::from("d"), 4.0)]);
+    }
+
+    // the lex ranges need all the members to have the same score
+    client
+        .zadd(
+            "zrange:lex",
+            vec![
+                (0.0, b"apple"),
+                (0.0, b"banana"),
+                (0.0, b"cherry"),
+                (0.0, b"date"),
+            ],
+        )
+        .await?;
+
+    let members = client
+        .zrange_by(
+            "zrange:lex",
+            ZRangeBy::Lex(LexBound::Exclusive(Bytes::from("apple")), LexBound::Max),
+            ZRangeOptions {
+                rev: true,
+                limit: Some((0, 2)),
+            },
+        )
+        .await?;
+    assert_eq!(members, vec![Bytes::from("date"), Bytes::from("cherry")]);
+
+    let members = client
+        .zrangebylex(
+            "zrange:lex",
+            LexBound::Inclusive(Bytes::from("banana")),
+            LexBound::Exclusive(Bytes::from("date")),
+            None,
+        )
+        .await?;
+    assert_eq!(members, vec![Bytes::from("banana"), Bytes::from("cherry")]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_zset_combine() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    client
+        .zadd("combine:daily", vec![(10.0, b"alice"), (20.0, b"bob")])
+        .await?;
+    client
+        .zadd("combine:weekly", vec![(5.0, b"alice"), (30.0, b"carol")])
+        .await?;
+
+    // alice is in both, weighted 2 * 10 + 1 * 5
+    let weighted = ZCombineOptions {
+        weights: vec![2.0, 1.0],
+        aggregate: None,
+    };
+    assert_eq!(
+        client
+            .zunionstore(
+                "combine:overall",
+                vec!["combine:daily", "combine:weekly"],
+                weighted.clone(),
+            )
+            .await?,
+        3
+    );
+    assert_eq!(
+        client.zscore("combine:overall", b"alice").await?,
+        Some(25.0)
+    );
+    assert_eq!(client.zscore("combine:overall", b"bob").await?, Some(40.0));
+
+    let max = ZCombineOptions {
+        weights: vec![],
+        aggregate: Some(Aggregate::Max),
+    };
+    assert_eq!(
+        client
+            .zinterstore(
+                "combine:both",
+                vec!["combine:daily", "combine:weekly"],
+                max.clone(),
+            )
+            .await?,
+        1
+    );
+    assert_eq!(client.zscore("combine:both", b"alice").await?, Some(10.0));
+
+    for protocol in [2, 3] {
+        client.hello(Some(protocol)).await?;
+        assert_eq!(
+            client
+                .zunion_withscores(vec!["combine:daily", "combine:weekly"], weighted.clone())
+                .await?,
+            vec![
+                (Bytes::from("alice"), 25.0),
+                (Bytes::from("carol"), 30.0),
+                (Bytes::from("bob"), 40.0),
+            ]
+        );
+        assert_eq!(
+            client
+                .zinter_withscores(vec!["combine:daily", "combine:weekly"], max.clone())
+                .await?,
+            vec![(Bytes::from("alice"), 10.0)]
+        );
+    }
+
+    assert_eq!(
+        client
+            .zinter(
+                vec!["combine:daily", "combine:weekly"],
+                ZCombineOptions {
+                    weights: vec![],
+                    aggregate: Some(Aggregate::Min),
+                },
+            )
+            .await?,
+        vec![Bytes::from("alice")]
+    );
+    assert_eq!(
+        client
+            .zunion(
+                vec!["combine:daily", "combine:weekly"],
+                ZCombineOptions::default()
+            )
+            .await?,
+        vec![
+            Bytes::from("alice"),
+            Bytes::from("bob"),
+            Bytes::from("carol")
+        ]
+    );
+
+    // the two highest scores of the union
+    assert_eq!(
+        client
+            .zrangestore(
+                "combine:top2",
+                "combine:overall",
+                ZRangeBy::Rank(0, 1),
+                ZRangeOptions {
+                    rev: true,
+                    limit: None,
+                },
+            )
+            .await?,
+        2
+    );
+    assert_eq!(client.zscore("combine:top2", b"bob").await?, Some(40.0));
+    assert_eq!(client.zscore("combine:top2", b"alice").await?, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_sismember_resp3_bool() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    client.hello(Some(3)).await?;
+
+    assert_eq!(client.sadd("sismember:key", vec![b"foo", b"bar"]).await?, 2);
+    assert_eq!(client.sadd("sismember:key", vec![b"foo"]).await?, 0);
+
+    assert!(client.sismember("sismember:key", b"foo").await?);
+    assert!(!client.sismember("sismember:key", b"baz").await?);
+    assert!(!client.sismember("sismember:missing", b"foo").await?);
+
+    Ok(())
+}
+
+// the typed replies to the same commands, to compare RESP2 with RESP3
+async fn typed_replies(
+    client: &mut Client,
+    prefix: &str,
+) -> redis_asyncx::Result<(Vec<bool>, Vec<Option<f64>>, f64, i64, u64, u64)> {
+    let set = format!("{prefix}:set");
+    let zset = format!("{prefix}:zset");
+    let float = format!("{prefix}:float");
+    let counter = format!("{prefix}:counter");
+
+    let added = client.sadd(&set, vec![b"foo", b"bar"]).await?;
+    let members = vec![
+        client.sismember(&set, b"foo").await?,
+        client.sismember(&set, b"baz").await?,
+    ];
+
+    client
+        .zadd(
+            &zset,
+            vec![(1.5, b"a"), (-0.25, b"b"), (f64::INFINITY, b"c")],
+        )
+        .await?;
+    let scores = vec![
+        client.zscore(&zset, b"a").await?,
+        client.zscore(&zset, b"b").await?,
+        client.zscore(&zset, b"c").await?,
+        client.zscore(&zset, b"missing").await?,
+    ];
+
+    client.incr_by_float(&float, 10.5).await?;
+    let float = client.incr_by_float(&float, 0.1).await?;
+    let counter = client.incr(&counter).await?;
+    let exists = client.exists(vec![&set, &zset, "missing"]).await?;
+
+    Ok((members, scores, float, counter, added, exists))
+}
+
+#[tokio::test]
+async fn redis_client_typed_replies_resp2_resp3() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    let resp2 = typed_replies(&mut client, "typed:resp2").await?;
+
+    client.hello(Some(3)).await?;
+    let resp3 = typed_replies(&mut client, "typed:resp3").await?;
+
+    assert_eq!(resp2, resp3);
+    assert_eq!(
+        resp3,
+        (
+            vec![true, false],
+            vec![Some(1.5), Some(-0.25), Some(f64::INFINITY), None],
+            10.6,
+            1,
+            2,
+            2,
+        )
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_hash_counters() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+
+    // a missing field starts from 0
+    assert_eq!(client.hincrby("counters", "visits", 5).await?, 5);
+    assert_eq!(client.hincrby("counters", "visits", -2).await?, 3);
+
+    client.hset("counters", "name", b"alice").await?;
+    let result = client.hincrby("counters", "name", 1).await;
+    assert!(matches!(result, Err(RedisError::NotANumber)));
+    let result = client.hincrbyfloat("counters", "name", 1.0).await;
+    assert!(matches!(result, Err(RedisError::NotANumber)));
+
+    for protocol in [2, 3] {
+        client.hello(Some(protocol)).await?;
+        client.hset("counters", "balance", b"10.5").await?;
+        assert_eq!(
+            client.hincrbyfloat("counters", "balance", 0.25).await?,
+            10.75
+        );
+
+        assert!(client.hexists("counters", "visits").await?);
+        assert!(!client.hexists("counters", "missing").await?);
+        assert!(!client.hexists("counters:missing", "visits").await?);
+    }
+
+    assert_eq!(client.hstrlen("counters", "name").await?, 5);
+    assert_eq!(client.hstrlen("counters", "missing").await?, 0);
+    assert_eq!(client.hstrlen("counters:missing", "name").await?, 0);
+
+    client.set("counters:string", b"abc").await?;
+    let result = client.incr("counters:string").await;
+    assert!(matches!(result, Err(RedisError::NotANumber)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_hrandfield_zrandmember() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    let hash = [("a", "1"), ("b", "2"), ("c", "3")];
+    for (field, value) in hash {
+        client
+            .hset("hrandfield:key", field, value.as_bytes())
+            .await?;
+    }
+    let sorted_set = [(1.0, "x"), (2.0, "y"), (3.0, "z")];
+    client
+        .zadd(
+            "zrandmember:key",
+            sorted_set
+                .iter()
+                .map(|(score, member)| (*score, member.as_bytes()))
+                .collect(),
+        )
+        .await?;
+
+    for protocol in [2, 3] {
+        client.hello(Some(protocol)).await?;
+
+        let field = client
+            .hrandfield("hrandfield:key")
+            .await?
+            .ok_or("missing field")?;
+        assert!(hash.iter().any(|(name, _)| *name == field));
+        assert_eq!(client.hrandfield("hrandfield:missing").await?, None);
+
+        let mut fields = client.hrandfield_n("hrandfield:key", 10).await?;
+        fields.sort();
+        assert_eq!(fields, vec!["a", "b", "c"]);
+
+        // a negative count returns exactly that many fields, with repeats
+        let picked = client.hrandfield_n_withvalues("hrandfield:key", -7).await?;
+        assert_eq!(picked.len(), 7);
+        for (field, value) in &picked {
+            assert!(hash.contains(&(field.as_str(), std::str::from_utf8(value)?)));
+        }
+
+        let member = client
+            .zrandmember("zrandmember:key")
+            .await?
+            .ok_or("missing member")?;
+        assert!(sorted_set.iter().any(|(_, name)| name.as_bytes() == member));
+        assert_eq!(client.zrandmember("zrandmember:missing").await?, None);
+
+        assert_eq!(client.zrandmember_n("zrandmember:key", 2).await?.len(), 2);
+        let picked = client
+            .zrandmember_n_withscores("zrandmember:key", -5)
+            .await?;
+        assert_eq!(picked.len(), 5);
+        for (member, score) in &picked {
+            assert!(sorted_set.contains(&(*score, std::str::from_utf8(member)?)));
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_srandmember_smismember() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    client
+        .sadd("srandmember:key", vec![b"a", b"b", b"c"])
+        .await?;
+    let members = client.smembers("srandmember:key").await?;
+
+    let member = client
+        .srandmember("srandmember:key")
+        .await?
+        .ok_or("missing member")?;
+    assert!(members.contains(&member));
+    assert_eq!(client.srandmember("srandmember:missing").await?, None);
+
+    // a positive count never repeats a member, and is capped by the size of the set
+    let mut picked = client.srandmember_n("srandmember:key", 10).await?;
+    picked.sort();
+    assert_eq!(
+        picked,
+        vec![Bytes::from("a"), Bytes::from("b"), Bytes::from("c")]
+    );
+    assert_eq!(client.srandmember_n("srandmember:key", 1).await?.len(), 1);
+
+    // a negative count returns exactly that many members
+    let picked = client.srandmember_n("srandmember:key", -10).await?;
+    assert_eq!(picked.len(), 10);
+    assert!(picked.iter().all(|member| members.contains(member)));
+
+    assert!(client.srandmember_n("srandmember:key", 0).await?.is_empty());
+    assert!(
+        client
+            .srandmember_n("srandmember:missing", 5)
+            .await?
+            .is_empty()
+    );
+
+    assert_eq!(
+        client
+            .smismember("srandmember:key", vec![b"a", b"z", b"c"])
+            .await?,
+        vec![true, false, true]
+    );
+    assert_eq!(
+        client.smismember("srandmember:missing", vec![b"a"]).await?,
+        vec![false]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_set_algebra() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    client
+        .sadd("algebra:set1", vec![b"a", b"b", b"c", b"d"])
+        .await?;
+    client.sadd("algebra:set2", vec![b"c", b"d", b"e"]).await?;
+
+    // the members come in no particular order
+    let sorted = |mut members: Vec<Bytes>| {
+        members.sort();
+        members
+    };
+    let members = |members: &[&'static str]| -> Vec<Bytes> {
+        members.iter().map(|member| Bytes::from(*member)).collect()
+    };
+    let keys = vec!["algebra:set1", "algebra:set2"];
+
+    assert_eq!(
+        sorted(client.sinter(keys.clone()).await?),
+        members(&["c", "d"])
+    );
+    assert_eq!(
+        sorted(client.sunion(keys.clone()).await?),
+        members(&["a", "b", "c", "d", "e"])
+    );
+    assert_eq!(
+        sorted(client.sdiff(keys.clone()).await?),
+        members(&["a", "b"])
+    );
+    assert!(
+        client
+            .sinter(vec!["algebra:set1", "algebra:missing"])
+            .await?
+            .is_empty()
+    );
+
+    assert_eq!(client.sinterstore("algebra:inter", keys.clone()).await?, 2);
+    assert_eq!(
+        sorted(client.smembers("algebra:inter").await?),
+        members(&["c", "d"])
+    );
+    assert_eq!(client.sunionstore("algebra:union", keys.clone()).await?, 5);
+    assert_eq!(
+        sorted(client.smembers("algebra:union").await?),
+        members(&["a", "b", "c", "d", "e"])
+    );
+    assert_eq!(client.sdiffstore("algebra:diff", keys.clone()).await?, 2);
+    assert_eq!(
+        sorted(client.smembers("algebra:diff").await?),
+        members(&["a", "b"])
+    );
+
+    assert_eq!(client.sintercard(keys.clone(), None).await?, 2);
+    assert_eq!(client.sintercard(keys.clone(), Some(0)).await?, 2);
+    assert_eq!(client.sintercard(keys.clone(), Some(1)).await?, 1);
+    assert_eq!(
+        client
+            .sintercard(vec!["algebra:set1", "algebra:missing"], None)
+            .await?,
+        0
+    );
+
+    // RESP3 replies with set frames
+    client.hello(Some(3)).await?;
+    assert_eq!(
+        sorted(client.sunion(keys.clone()).await?),
+        members(&["a", "b", "c", "d", "e"])
+    );
+    assert_eq!(sorted(client.sinter(keys).await?), members(&["c", "d"]));
+
+    // an empty result deletes the destination
+    assert_eq!(
+        client
+            .sdiffstore("algebra:diff", vec!["algebra:set1", "algebra:set1"])
+            .await?,
+        0
+    );
+    assert_eq!(client.exists(vec!["algebra:diff"]).await?, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_smembers_resp3_set() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    client.hello(Some(3)).await?;
+
+    client
+        .sadd("smembers:key", vec![b"foo", b"bar", b"baz"])
+        .await?;
+
+    // sets are unordered
+    let mut members = client.smembers("smembers:key").await?;
+    members.sort();
+    assert_eq!(
+        members,
+        vec![
+            Bytes::from_static(b"bar"),
+            Bytes::from_static(b"baz"),
+            Bytes::from_static(b"foo"),
+        ]
+    );
+
+    assert!(client.smembers("smembers:missing").await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_multiplexed_client_concurrent_incr() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let client = MultiplexedClient::connect(format!("{}:{}", host, host_port)).await?;
+
+    // every task increments its own key, the commands of all tasks interleave on the connection
+    let mut tasks = Vec::new();
+    for task in 0..64 {
+        let client = client.clone();
+        tasks.push(tokio::spawn(async move {
+            let key = format!("multiplexed:{}", task);
+            for expected in 1..=100 {
+                assert_eq!(client.incr(&key).await?, expected);
+            }
+
+            Ok::<(), RedisError>(())
+        }));
+    }
+
+    for task in tasks {
+        task.await??;
+    }
+
+    for task in 0..64 {
+        let value = client.get(&format!("multiplexed:{}", task)).await?;
+        assert_eq!(value, Some(Bytes::from_static(b"100")));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_quit_and_close() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    client.quit().await?;
+
+    let client = MultiplexedClient::connect(format!("{}:{}", host, host_port)).await?;
+
+    let mut in_flight = Vec::new();
+    for _ in 0..16 {
+        let client = client.clone();
+        in_flight.push(tokio::spawn(async move { client.incr("close:key").await }));
+    }
+    // wait for the first command, so that the others are queued by the time close is called
+    in_flight.remove(0).await??;
+
+    client.close().await?;
+
+    // the commands sent before closing complete, the ones sent after are refused
+    for task in in_flight {
+        match task.await? {
+            Ok(_) | Err(RedisError::ClientClosed) => {}
+            Err(err) => panic!("Unexpected error: {:?}", err),
+        }
+    }
+    assert!(matches!(
+        client.incr("close:key").await,
+        Err(RedisError::ClientClosed)
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_hget_all_ordered() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    client.hello(Some(3)).await?;
+
+    let fields = ["zulu", "alpha", "mike", "bravo", "yankee"];
+    for (i, field) in fields.iter().enumerate() {
+        assert_eq!(
+            client
+                .hset("hgetall:key", field, i.to_string().as_bytes())
+                .await?,
+            1
+        );
+    }
+
+    // small hashes keep the insertion order, which must survive the RESP3 map reply
+    let pairs = client.hget_all("hgetall:key").await?;
+    assert_eq!(
+        pairs,
+        fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| (field.to_string(), Bytes::from(i.to_string())))
+            .collect::<Vec<_>>()
+    );
+
+    assert!(client.hget_all("hgetall:missing").await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_invoke_script() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    client.set("script:key", b"value").await?;
+
+    // not cached yet, sent with EVAL
+    let script = Script::new("return redis.call('GET', KEYS[1])");
+    assert!(matches!(
+        client
+            .evalsha(script.sha(), vec!["script:key"], Vec::<&str>::new())
+            .await,
+        Err(RedisError::NoScript)
+    ));
+    for _ in 0..2 {
+        let reply = client
+            .invoke_script(&script, vec!["script:key"], Vec::<&str>::new())
+            .await?;
+        assert!(matches!(reply, StructuredResponse::Bulk(value) if value == "value"));
+    }
+
+    let reply = client
+        .eval("return {ARGV[1], 2}", Vec::<&str>::new(), vec!["one"])
+        .await?;
+    assert!(matches!(
+        reply,
+        StructuredResponse::Array(items) if matches!(
+            items.as_slice(),
+            [StructuredResponse::Bulk(one), StructuredResponse::Integer(2)] if one == "one"
+        )
+    ));
+    assert!(
+        client
+            .eval(
+                "return redis.error_reply('FAILED')",
+                Vec::<&str>::new(),
+                Vec::<&str>::new()
+            )
+            .await
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_lock() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    let mut other = Client::connect(format!("{}:{}", host, host_port)).await?;
+    let ttl = Duration::from_secs(10);
+
+    // only one of two contending clients gets the lock
+    assert!(client.acquire_lock("lock:report", b"first", ttl).await?);
+    assert!(!other.acquire_lock("lock:report", b"second", ttl).await?);
+
+    // the other client cannot release nor extend a lock it does not hold
+    assert!(!other.release_lock("lock:report", b"second").await?);
+    assert!(!other.extend_lock("lock:report", b"second", ttl).await?);
+    assert_eq!(
+        client.get("lock:report").await?,
+        Some(Bytes::from_static(b"first"))
+    );
+
+    assert!(
+        client
+            .extend_lock("lock:report", b"first", Duration::from_secs(60))
+            .await?
+    );
+    assert!(client.ttl("lock:report").await? > 10);
+    assert!(client.release_lock("lock:report", b"first").await?);
+
+    // free again
+    assert!(other.acquire_lock("lock:report", b"second", ttl).await?);
+    assert!(other.release_lock("lock:report", b"second").await?);
+
+    // a guard releases the lock once dropped
+    let pool = Pool::connect(&format!("{}:{}", host, host_port), PoolConfig::default()).await?;
+    let guard = pool.lock("lock:pool", b"guard", ttl).await?;
+    assert!(guard.is_some());
+    assert!(pool.lock("lock:pool", b"other", ttl).await?.is_none());
+    drop(guard);
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(client.get("lock:pool").await?, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_cluster_client_set_get() -> TestResult {
+    let _container = setup_cluster().await;
+
+    let client = ClusterClient::connect(vec!["127.0.0.1:7000"]).await?;
+
+    // spread over the slots of all three masters
+    let keys: Vec<String> = (0..30).map(|i| format!("cluster:key:{i}")).collect();
+    let slots: Vec<u16> = keys.iter().map(|key| key_slot(key.as_bytes())).collect();
+    assert!(slots.iter().any(|&slot| slot < 5461));
+    assert!(slots.iter().any(|&slot| (5461..10923).contains(&slot)));
+    assert!(slots.iter().any(|&slot| slot >= 10923));
+
+    for key in &keys {
+        client.set(key, key.as_bytes()).await?;
+    }
+    for key in &keys {
+        assert_eq!(client.get(key).await?, Some(Bytes::from(key.clone())));
+    }
+
+    assert_eq!(client.incr("cluster:counter").await?, 1);
+    assert_eq!(client.del(vec!["{cluster}:a", "{cluster}:b"]).await?, 0);
+    assert!(matches!(
+        client.del(vec!["cluster:key:0", "cluster:key:1"]).await,
+        Err(RedisError::CrossSlot)
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_check_rate_limit() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    let window = Duration::from_millis(500);
+
+    for (key, algorithm) in [
+        ("ratelimit:fixed", RateLimitAlgorithm::FixedWindow),
+        ("ratelimit:sliding", RateLimitAlgorithm::SlidingWindowLog),
+    ] {
+        // allowed up to the limit, then denied
+        for remaining in (0..5).rev() {
+            let decision = client.check_rate_limit(key, 5, window, algorithm).await?;
+            assert!(decision.allowed, "{algorithm:?}");
+            assert_eq!(decision.remaining, remaining);
+            assert!(decision.reset_after <= window);
+        }
+        for _ in 0..5 {
+            let decision = client.check_rate_limit(key, 5, window, algorithm).await?;
+            assert!(!decision.allowed, "{algorithm:?}");
+            assert_eq!(decision.remaining, 0);
+        }
+
+        // allowed again once the window elapsed
+        tokio::time::sleep(window + Duration::from_millis(100)).await;
+        let decision = client.check_rate_limit(key, 5, window, algorithm).await?;
+        assert!(decision.allowed, "{algorithm:?}");
+        assert_eq!(decision.remaining, 4);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_connect_timeout() -> TestResult {
+    // a non-routable address, the SYN is never answered
+    let options = ConnectionOptions {
+        connect_timeout: Duration::from_millis(200),
+        ..Default::default()
+    };
+
+    let start = std::time::Instant::now();
+    let result = Client::connect_with("10.255.255.1:6379", options).await;
+    assert!(matches!(result, Err(RedisError::Timeout)));
+    assert!(start.elapsed() < Duration::from_secs(2));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_pipeline_typed() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+    client.del(vec!["pipeline:counter"]).await?;
+
+    let pipeline = Pipeline::new()
+        .command(["SET", "pipeline:key", "value"])
+        .command(["INCR", "pipeline:counter"])
+        .command(["GET", "pipeline:missing"]);
+    let (status, counter, missing): (String, i64, Option<String>) =
+        pipeline.execute_typed(&mut client).await?;
+    assert_eq!(status, "OK");
+    assert_eq!(counter, 1);
+    assert_eq!(missing, None);
+
+    // the reply of GET does not parse as an integer
+    let pipeline = Pipeline::new()
+        .command(["INCR", "pipeline:counter"])
+        .command(["GET", "pipeline:key"]);
+    assert!(matches!(
+        pipeline.execute_typed::<(i64, i64)>(&mut client).await,
+        Err(RedisError::PipelineReply { index: 1, .. })
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_expiretime() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut client = Client::connect(format!("{}:{}", host, host_port)).await?;
+
+    assert_eq!(client.expiretime("expiretime:key").await?, -2);
+    assert_eq!(client.pexpiretime("expiretime:key").await?, -2);
+
+    client.set("expiretime:key", b"value").await?;
+    assert_eq!(client.expiretime("expiretime:key").await?, -1);
+    assert_eq!(client.pexpiretime("expiretime:key").await?, -1);
+
+    // an hour from now
+    let at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)?
+        .as_secs()
+        + 3600;
+    client
+        .command(["EXPIREAT", "expiretime:key", &at.to_string()])
+        .await?;
+    assert_eq!(
+        client.expiretime("expiretime:key").await?,
+        i64::try_from(at)?
+    );
+    assert_eq!(
+        client.pexpiretime("expiretime:key").await?,
+        i64::try_from(at * 1000)?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_client_key_prefix() -> TestResult {
+    let container = setup_redis().await;
+
+    let host = container.get_host().await?;
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).await?;
+
+    let mut raw = Client::connect(format!("{}:{}", host, host_port)).await?;
+    let mut client = Client::builder()
+        .host(&host.to_string())
+        .port(host_port)
+        .key_prefix("tenant:42:")
+        .build()
+        .await?;
+
+    client.set("prefix:key", b"value").await?;
+    assert_eq!(
+        raw.get("tenant:42:prefix:key").await?,
+        Some(Bytes::from_static(b"value"))
+    );
+    assert_eq!(raw.get("prefix:key").await?, None);
+    assert_eq!(
+        client.get("prefix:key").await?,
+        Some(Bytes::from_static(b"value"))
+    );
+
+    // outside of the namespace, so neither scanned nor deleted
+    raw.set("prefix:other", b"value").await?;
+    let mut keys = Vec::new();
+    let mut iter = client.scan_iter(Some("prefix:*"), None);
+    while let Some(key) = iter.next_key().await? {
+        keys.push(key);
+    }
+    assert_eq!(keys, vec![Bytes::from_static(b"prefix:key")]);
+
+    assert_eq!(client.del(vec!["prefix:key", "prefix:other"]).await?, 1);
+    assert_eq!(raw.exists(vec!["prefix:other"]).await?, 1);
+
+    Ok(())
+}
+
+/// The toy server binary of the crate, killed once dropped.
+struct LoopbackServer {
+    child: std::process::Child,
+    addr: String,
+}
+
+impl Drop for LoopbackServer {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+// a server of its own per test, on a port picked by the system
+fn setup_loopback_server() -> Result<LoopbackServer, Box<dyn std::error::Error + 'static>> {
+    use std::io::BufRead;
+
+    let mut child = Command::cargo_bin("server")?
+        .args(["--port", "0"])
+        .stdout(std::process::Stdio::piped())
+        .spawn()?;
+
+    // the server prints the address it listens on once bound
+    let mut line = String::new();
+    if let Some(stdout) = child.stdout.take() {
+        std::io::BufReader::new(stdout).read_line(&mut line)?;
+    }
+    let addr = line
+        .trim()
+        .strip_prefix("Listening on ")
+        .ok_or_else(|| format!("unexpected server output: {line:?}"))?
+        .to_string();
+
+    Ok(LoopbackServer { child, addr })
+}
+
+#[tokio::test]
+async fn redis_loopback_server_ping_set_get() -> TestResult {
+    let server = setup_loopback_server()?;
+
+    let mut client = Client::connect(&server.addr).await?;
+    assert_eq!(client.ping(None).await?, Pong::Pong);
+    assert_eq!(
+        client.ping(Some(b"hello")).await?,
+        Pong::Echo(Bytes::from_static(b"hello"))
+    );
+
+    client.set("mykey", b"myvalue").await?;
+    assert_eq!(
+        client.get("mykey").await?,
+        Some(Bytes::from_static(b"myvalue"))
+    );
+    assert_eq!(client.exists(vec!["mykey", "missing"]).await?, 1);
+    assert_eq!(client.del(vec!["mykey"]).await?, 1);
+    assert_eq!(client.get("mykey").await?, None);
+
+    // the keys are shared by the connections, served concurrently
+    let mut other = Client::connect(&server.addr).await?;
+    client.set("shared", b"value").await?;
+    assert_eq!(
+        other.get("shared").await?,
+        Some(Bytes::from_static(b"value"))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_loopback_server_inline_commands() -> TestResult {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let server = setup_loopback_server()?;
+
+    // as typed in telnet
+    let mut socket = tokio::net::TcpStream::connect(&server.addr).await?;
+    socket
+        .write_all(b"PING\r\nSET key value\r\nGET key\r\n")
+        .await?;
+
+    let expected = b"+PONG\r\n+OK\r\n$5\r\nvalue\r\n";
+    let mut reply = vec![0; expected.len()];
+    tokio::time::timeout(Duration::from_secs(1), socket.read_exact(&mut reply)).await??;
+    assert_eq!(reply, expected);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_sentinel_client_failover() -> TestResult {
+    let _container = setup_sentinel().await;
+    let sentinel = format!("127.0.0.1:{SENTINEL_PORT}");
+
+    let mut client = SentinelClient::connect(&[&sentinel], "mymaster").await?;
+    client.set("sentinel:key", b"before").await?;
+
+    let mut admin = Client::connect(&sentinel).await?;
+    admin.command(["SENTINEL", "FAILOVER", "mymaster"]).await?;
+
+    // the sentinel reports the replica once promoted
+    let promoted = format!("127.0.0.1:{SENTINEL_REPLICA_PORT}");
+    tokio::time::timeout(Duration::from_secs(30), async {
+        while admin.sentinel_master_addr("mymaster").await? != Some(promoted.clone()) {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        Ok::<_, RedisError>(())
+    })
+    .await??;
+
+    // and the former master turns into its replica
+    let mut former = Client::connect(format!("127.0.0.1:{SENTINEL_MASTER_PORT}")).await?;
+    tokio::time::timeout(Duration::from_secs(30), async {
+        while former.role().await? != "slave" {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        Ok::<_, RedisError>(())
+    })
+    .await??;
+
+    // the former master replies READONLY, the client follows the new one
+    client.set("sentinel:key", b"after").await?;
+    assert_eq!(
+        client.get("sentinel:key").await?,
+        Some(Bytes::from_static(b"after"))
+    );
+
+    let mut promoted = Client::connect(promoted).await?;
+    assert_eq!(promoted.role().await?, "master");
+    assert_eq!(
+        promoted.get("sentinel:key").await?,
+        Some(Bytes::from_static(b"after"))
+    );
+
+    Ok(())
+}